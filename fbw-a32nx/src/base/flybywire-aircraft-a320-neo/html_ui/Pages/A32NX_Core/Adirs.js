@@ -23,16 +23,17 @@ class ADIRS {
 
     static mapNotAvailable(displayIndex) {
         const inertialReferenceSource = ADIRS.getNdInertialReferenceSource(displayIndex);
-        return !Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_LATITUDE`).isNormalOperation() ||
-            !Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_LONGITUDE`).isNormalOperation();
+        const latitude = Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_GPIRS_LATITUDE`);
+        const longitude = Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_GPIRS_LONGITUDE`);
+        return !latitude.isNormalOperation() || !longitude.isNormalOperation();
     }
 
     static getLatitude() {
-        return ADIRS.getFromAnyAdiru('IR', 'LATITUDE');
+        return ADIRS.getFromAnyAdiru('IR', 'GPIRS_LATITUDE');
     }
 
     static getLongitude() {
-        return ADIRS.getFromAnyAdiru('IR', 'LONGITUDE');
+        return ADIRS.getFromAnyAdiru('IR', 'GPIRS_LONGITUDE');
     }
 
     static getTrueTrack() {
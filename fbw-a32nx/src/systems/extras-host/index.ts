@@ -7,7 +7,12 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import { EventBus, HEventPublisher, InstrumentBackplane } from '@microsoft/msfs-sdk';
-import { FlightDeckBounds, NotificationManager, PilotSeatManager } from '@flybywiresim/fbw-sdk';
+import {
+  FlightDeckBounds,
+  HydraulicPumpWearManager,
+  NotificationManager,
+  PilotSeatManager,
+} from '@flybywiresim/fbw-sdk';
 import { ExtrasSimVarPublisher } from 'extras-host/modules/common/ExtrasSimVarPublisher';
 import { PushbuttonCheck } from 'extras-host/modules/pushbutton_check/PushbuttonCheck';
 import { FlightPlanAsoboSync } from 'extras-host/modules/flightplan_sync/FlightPlanAsoboSync';
@@ -66,6 +71,13 @@ class ExtrasHost extends BaseInstrument {
 
   private readonly pilotSeatManager = new PilotSeatManager(ExtrasHost.flightDeckBounds);
 
+  private readonly hydraulicPumpWearManager = new HydraulicPumpWearManager([
+    'HYD_GREEN_EDPUMP',
+    'HYD_YELLOW_EDPUMP',
+    'HYD_BLUE_EPUMP',
+    'HYD_YELLOW_EPUMP',
+  ]);
+
   public readonly xmlConfig: Document;
 
   /**
@@ -93,6 +105,7 @@ class ExtrasHost extends BaseInstrument {
     this.aircraftSync = new AircraftSync(process.env.AIRCRAFT_PROJECT_PREFIX, this.bus);
 
     this.backplane.addInstrument('PilotSeatManager', this.pilotSeatManager);
+    this.backplane.addInstrument('HydraulicPumpWearManager', this.hydraulicPumpWearManager);
 
     console.log('A32NX_EXTRASHOST: Created');
   }
@@ -36,6 +36,11 @@ export const A320Failure = Object.freeze({
   Generator1: 24020,
   Generator2: 24021,
   ApuGenerator1: 24030,
+  Generator1Feeder: 24040,
+  Generator2Feeder: 24041,
+  ApuGenerator1Feeder: 24050,
+  IntegratedDriveGenerator1OilLeak: 24060,
+  IntegratedDriveGenerator2OilLeak: 24061,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrentEssential: 24102,
@@ -50,6 +55,8 @@ export const A320Failure = Object.freeze({
   DirectCurrentHot1: 24111,
   DirectCurrentHot2: 24112,
   DirectCurrentGndFltService: 24113,
+  BlueElecPumpCircuitBreaker: 24200,
+  YellowElecPumpCircuitBreaker: 24201,
 
   Elac1Failure: 27000,
   Elac2Failure: 27001,
@@ -59,6 +66,21 @@ export const A320Failure = Object.freeze({
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
 
+  LeftTankPump1: 28000,
+  LeftTankPump2: 28001,
+  CenterTankPump1: 28002,
+  CenterTankPump2: 28003,
+  RightTankPump1: 28004,
+  RightTankPump2: 28005,
+  CenterTankQuantityProbe: 28010,
+  LeftInnerTankQuantityProbe: 28011,
+  LeftOuterTankQuantityProbe: 28012,
+  RightInnerTankQuantityProbe: 28013,
+  RightOuterTankQuantityProbe: 28014,
+  FuelQuantityIndicationChannel1: 28020,
+  FuelQuantityIndicationChannel2: 28021,
+  FuelDensitometer: 28030,
+
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
   YellowReservoirLeak: 29002,
@@ -72,6 +94,10 @@ export const A320Failure = Object.freeze({
   BlueEpumpOverheat: 29010,
   YellowEdpOverheat: 29011,
   YellowEpumpOverheat: 29012,
+  GreenEdpWear: 29013,
+  BlueEpumpWear: 29014,
+  YellowEdpWear: 29015,
+  YellowEpumpWear: 29016,
 
   LeftPfdDisplay: 31000,
   RightPfdDisplay: 31001,
@@ -108,6 +134,26 @@ export const A320Failure = Object.freeze({
 
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
+  Adiru1: 34100,
+  Adiru2: 34101,
+  Adiru3: 34102,
+  Adr1: 34110,
+  Adr2: 34111,
+  Adr3: 34112,
+  Ir1: 34120,
+  Ir2: 34121,
+  Ir3: 34122,
+  PitotProbeBlocked1: 34130,
+  PitotProbeBlocked2: 34131,
+  PitotProbeBlocked3: 34132,
+  StaticPortBlocked1: 34140,
+  StaticPortBlocked2: 34141,
+  StaticPortBlocked3: 34142,
+  AngleOfAttackVaneJammed1: 34150,
+  AngleOfAttackVaneJammed2: 34151,
+  AngleOfAttackVaneJammed3: 34152,
+  GpsSatelliteLoss1: 34200,
+  GpsSatelliteLoss2: 34201,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -142,6 +188,11 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.Generator1, 'Generator 1'],
   [24, A320Failure.Generator2, 'Generator 2'],
   [24, A320Failure.ApuGenerator1, 'APU Generator'],
+  [24, A320Failure.Generator1Feeder, 'Generator 1 Feeder'],
+  [24, A320Failure.Generator2Feeder, 'Generator 2 Feeder'],
+  [24, A320Failure.ApuGenerator1Feeder, 'APU Generator Feeder'],
+  [24, A320Failure.IntegratedDriveGenerator1OilLeak, 'IDG 1 Oil Leak'],
+  [24, A320Failure.IntegratedDriveGenerator2OilLeak, 'IDG 2 Oil Leak'],
   [24, A320Failure.AlternatingCurrent1, 'AC 1'],
   [24, A320Failure.AlternatingCurrent2, 'AC 2'],
   [24, A320Failure.AlternatingCurrentEssential, 'AC ESS'],
@@ -156,6 +207,8 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.DirectCurrentHot1, 'DC HOT 1'],
   [24, A320Failure.DirectCurrentHot2, 'DC HOT 2'],
   [24, A320Failure.DirectCurrentGndFltService, 'DC GND FLT SRV'],
+  [24, A320Failure.BlueElecPumpCircuitBreaker, 'BLUE ELEC PUMP CB'],
+  [24, A320Failure.YellowElecPumpCircuitBreaker, 'YELLOW ELEC PUMP CB'],
 
   [27, A320Failure.Elac1Failure, 'ELAC 1'],
   [27, A320Failure.Elac2Failure, 'ELAC 2'],
@@ -165,6 +218,21 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
 
+  [28, A320Failure.LeftTankPump1, 'L TK pump 1'],
+  [28, A320Failure.LeftTankPump2, 'L TK pump 2'],
+  [28, A320Failure.CenterTankPump1, 'CTR TK pump 1'],
+  [28, A320Failure.CenterTankPump2, 'CTR TK pump 2'],
+  [28, A320Failure.RightTankPump1, 'R TK pump 1'],
+  [28, A320Failure.RightTankPump2, 'R TK pump 2'],
+  [28, A320Failure.CenterTankQuantityProbe, 'CTR TK quantity probe'],
+  [28, A320Failure.LeftInnerTankQuantityProbe, 'L inner TK quantity probe'],
+  [28, A320Failure.LeftOuterTankQuantityProbe, 'L outer TK quantity probe'],
+  [28, A320Failure.RightInnerTankQuantityProbe, 'R inner TK quantity probe'],
+  [28, A320Failure.RightOuterTankQuantityProbe, 'R outer TK quantity probe'],
+  [28, A320Failure.FuelQuantityIndicationChannel1, 'FQIC channel 1'],
+  [28, A320Failure.FuelQuantityIndicationChannel2, 'FQIC channel 2'],
+  [28, A320Failure.FuelDensitometer, 'Fuel densitometer'],
+
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
   [29, A320Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -178,6 +246,10 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [29, A320Failure.BlueEpumpOverheat, 'Blue electric pump overheat'],
   [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
   [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
+  [29, A320Failure.GreenEdpWear, 'Green engine pump worn'],
+  [29, A320Failure.BlueEpumpWear, 'Blue electric pump worn'],
+  [29, A320Failure.YellowEdpWear, 'Yellow engine pump worn'],
+  [29, A320Failure.YellowEpumpWear, 'Yellow electric pump worn'],
 
   [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
   [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -213,4 +285,24 @@ export const A320FailureDefinitions: FailureDefinition[] = [
 
   [34, A320Failure.RadioAltimeter1, 'RA 1'],
   [34, A320Failure.RadioAltimeter2, 'RA 2'],
+  [34, A320Failure.Adiru1, 'ADIRU 1'],
+  [34, A320Failure.Adiru2, 'ADIRU 2'],
+  [34, A320Failure.Adiru3, 'ADIRU 3'],
+  [34, A320Failure.Adr1, 'ADR 1'],
+  [34, A320Failure.Adr2, 'ADR 2'],
+  [34, A320Failure.Adr3, 'ADR 3'],
+  [34, A320Failure.Ir1, 'IR 1'],
+  [34, A320Failure.Ir2, 'IR 2'],
+  [34, A320Failure.Ir3, 'IR 3'],
+  [34, A320Failure.PitotProbeBlocked1, 'Pitot probe 1 blocked'],
+  [34, A320Failure.PitotProbeBlocked2, 'Pitot probe 2 blocked'],
+  [34, A320Failure.PitotProbeBlocked3, 'Pitot probe 3 blocked'],
+  [34, A320Failure.StaticPortBlocked1, 'Static port 1 blocked'],
+  [34, A320Failure.StaticPortBlocked2, 'Static port 2 blocked'],
+  [34, A320Failure.StaticPortBlocked3, 'Static port 3 blocked'],
+  [34, A320Failure.AngleOfAttackVaneJammed1, 'AOA vane 1 jammed'],
+  [34, A320Failure.AngleOfAttackVaneJammed2, 'AOA vane 2 jammed'],
+  [34, A320Failure.AngleOfAttackVaneJammed3, 'AOA vane 3 jammed'],
+  [34, A320Failure.GpsSatelliteLoss1, 'GPS 1 satellite loss'],
+  [34, A320Failure.GpsSatelliteLoss2, 'GPS 2 satellite loss'],
 ];
@@ -978,14 +978,14 @@ export class PseudoFWC {
     this.fuelCtrTankModeSelMan.setConsumer(sub.on('fuel_ctr_tk_mode_sel_man'));
     this.engine1ValueSwitch.setConsumer(sub.on('fuel_valve_switch_1'));
     this.engine2ValueSwitch.setConsumer(sub.on('fuel_valve_switch_2'));
-    this.centerFuelPump1Auto.setConsumer(sub.on('fuel_pump_switch_1'));
-    this.centerFuelPump2Auto.setConsumer(sub.on('fuel_pump_switch_4'));
+    this.centerFuelPump1Auto.setConsumer(sub.on('fuel_ctr_tk_1_pump_pb_is_on'));
+    this.centerFuelPump2Auto.setConsumer(sub.on('fuel_ctr_tk_2_pump_pb_is_on'));
     this.leftOuterInnerValve.setConsumer(sub.on('fuel_valve_open_4'));
-    this.leftFuelPump1Auto.setConsumer(sub.on('fuel_pump_switch_2'));
-    this.leftFuelPump2Auto.setConsumer(sub.on('fuel_pump_switch_5'));
+    this.leftFuelPump1Auto.setConsumer(sub.on('fuel_l_tk_1_pump_pb_is_on'));
+    this.leftFuelPump2Auto.setConsumer(sub.on('fuel_l_tk_2_pump_pb_is_on'));
     this.rightOuterInnerValve.setConsumer(sub.on('fuel_valve_open_5'));
-    this.rightFuelPump1Auto.setConsumer(sub.on('fuel_pump_switch_3'));
-    this.rightFuelPump2Auto.setConsumer(sub.on('fuel_pump_switch_6'));
+    this.rightFuelPump1Auto.setConsumer(sub.on('fuel_r_tk_1_pump_pb_is_on'));
+    this.rightFuelPump2Auto.setConsumer(sub.on('fuel_r_tk_2_pump_pb_is_on'));
 
     // Inhibit single chimes for the first two seconds after power-on
     this.auralSingleChimeInhibitTimer.schedule(
@@ -1620,7 +1620,7 @@ export class PseudoFWC {
     /* FUEL */
     const fuelGallonsToKg = SimVar.GetSimVarValue('FUEL WEIGHT PER GALLON', 'kilogram');
     this.centerFuelQuantity.set(SimVar.GetSimVarValue('FUEL TANK CENTER QUANTITY', 'gallons') * fuelGallonsToKg);
-    this.fuelXFeedPBOn.set(SimVar.GetSimVarValue('L:XMLVAR_Momentary_PUSH_OVHD_FUEL_XFEED_Pressed', 'bool'));
+    this.fuelXFeedPBOn.set(SimVar.GetSimVarValue('L:A32NX_OVHD_FUEL_XFEED_PB_IS_ON', 'bool'));
 
     const leftInnerFuelQuantity = SimVar.GetSimVarValue('FUEL TANK LEFT MAIN QUANTITY', 'gallons') * fuelGallonsToKg;
     const rightInnerFuelQuantity = SimVar.GetSimVarValue('FUEL TANK RIGHT MAIN QUANTITY', 'gallons') * fuelGallonsToKg;
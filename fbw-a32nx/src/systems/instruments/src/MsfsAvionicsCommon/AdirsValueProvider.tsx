@@ -26,11 +26,11 @@ export class AdirsValueProvider<T extends AdirsSimVars> {
       .handle((knobPosition) => {
         const inertialSource = getSupplier(this.displaySide, knobPosition);
         this.varProvider.updateSimVarSource('latitude', {
-          name: `L:A32NX_ADIRS_IR_${inertialSource}_LATITUDE`,
+          name: `L:A32NX_ADIRS_IR_${inertialSource}_GPIRS_LATITUDE`,
           type: SimVarValueType.Number,
         });
         this.varProvider.updateSimVarSource('longitude', {
-          name: `L:A32NX_ADIRS_IR_${inertialSource}_LONGITUDE`,
+          name: `L:A32NX_ADIRS_IR_${inertialSource}_GPIRS_LONGITUDE`,
           type: SimVarValueType.Number,
         });
         this.varProvider.updateSimVarSource('vsInert', {
@@ -53,8 +53,8 @@ export enum AdirsVars {
   fpaRaw = 'L:A32NX_ADIRS_IR_1_FLIGHT_PATH_ANGLE',
   daRaw = 'L:A32NX_ADIRS_IR_1_DRIFT_ANGLE',
   mach = 'L:A32NX_ADIRS_ADR_1_MACH',
-  latitude = 'L:A32NX_ADIRS_IR_1_LATITUDE',
-  longitude = 'L:A32NX_ADIRS_IR_1_LONGITUDE',
+  latitude = 'L:A32NX_ADIRS_IR_1_GPIRS_LATITUDE',
+  longitude = 'L:A32NX_ADIRS_IR_1_GPIRS_LONGITUDE',
   irMaintWordRaw = 'L:A32NX_ADIRS_IR_1_MAINT_WORD',
 }
 
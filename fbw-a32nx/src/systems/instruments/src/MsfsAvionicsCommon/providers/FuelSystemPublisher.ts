@@ -14,6 +14,13 @@ import {
 /* eslint-disable camelcase */
 export interface BaseFuelSystemEvents {
   fuel_ctr_tk_mode_sel_man: boolean;
+  /** Whether the overhead tank pump push buttons are on. */
+  fuel_l_tk_1_pump_pb_is_on: boolean;
+  fuel_l_tk_2_pump_pb_is_on: boolean;
+  fuel_ctr_tk_1_pump_pb_is_on: boolean;
+  fuel_ctr_tk_2_pump_pb_is_on: boolean;
+  fuel_r_tk_1_pump_pb_is_on: boolean;
+  fuel_r_tk_2_pump_pb_is_on: boolean;
   /** The valve's switch: */
   fuel_valve_switch: boolean;
   /** The valve's actual continous position, in percent, 0 ... 1 */
@@ -56,7 +63,31 @@ export class FuelSystemPublisher extends SimVarPublisher<FuelSystemEvents> {
     const simvars: [keyof FuelSystemEvents, SimVarPublisherEntry<any>][] = [
       [
         'fuel_ctr_tk_mode_sel_man',
-        { name: 'L:A32NX_OVHD_FUEL_MODESEL_MANUAL', type: SimVarValueType.Number, map: (v) => !!v },
+        { name: 'L:A32NX_OVHD_FUEL_MODE_SEL_PB_IS_AUTO', type: SimVarValueType.Bool, map: (v) => !v },
+      ],
+      [
+        'fuel_l_tk_1_pump_pb_is_on',
+        { name: 'L:A32NX_OVHD_FUEL_L_TK_1_PUMP_PB_IS_ON', type: SimVarValueType.Bool, map: (v) => !!v },
+      ],
+      [
+        'fuel_l_tk_2_pump_pb_is_on',
+        { name: 'L:A32NX_OVHD_FUEL_L_TK_2_PUMP_PB_IS_ON', type: SimVarValueType.Bool, map: (v) => !!v },
+      ],
+      [
+        'fuel_ctr_tk_1_pump_pb_is_on',
+        { name: 'L:A32NX_OVHD_FUEL_CTR_TK_1_PUMP_PB_IS_ON', type: SimVarValueType.Bool, map: (v) => !!v },
+      ],
+      [
+        'fuel_ctr_tk_2_pump_pb_is_on',
+        { name: 'L:A32NX_OVHD_FUEL_CTR_TK_2_PUMP_PB_IS_ON', type: SimVarValueType.Bool, map: (v) => !!v },
+      ],
+      [
+        'fuel_r_tk_1_pump_pb_is_on',
+        { name: 'L:A32NX_OVHD_FUEL_R_TK_1_PUMP_PB_IS_ON', type: SimVarValueType.Bool, map: (v) => !!v },
+      ],
+      [
+        'fuel_r_tk_2_pump_pb_is_on',
+        { name: 'L:A32NX_OVHD_FUEL_R_TK_2_PUMP_PB_IS_ON', type: SimVarValueType.Bool, map: (v) => !!v },
       ],
       [
         'fuel_valve_switch',
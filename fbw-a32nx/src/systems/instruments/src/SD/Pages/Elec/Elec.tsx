@@ -682,9 +682,10 @@ const IntegratedDriveGeneratorTitle = ({ x, y, number }) => {
 
 const IntegratedDriveGeneratorTemperature = ({ x, y, number }) => {
   const [temperature] = useSimVar(`L:A32NX_ELEC_ENG_GEN_${number}_IDG_OIL_OUTLET_TEMPERATURE`, 'Celsius', maxStaleness);
+  const [overheat] = useSimVar(`L:A32NX_ELEC_ENG_GEN_${number}_IDG_OIL_OVERHEAT`, 'Bool', maxStaleness);
   return (
     <SvgGroup x={x} y={y}>
-      <text className="Green Right">{Math.round(temperature)}</text>
+      <text className={`Right ${overheat ? 'Amber' : 'Green'}`}>{Math.round(temperature)}</text>
       <text x={3.75} className="Cyan">
         °C
       </text>
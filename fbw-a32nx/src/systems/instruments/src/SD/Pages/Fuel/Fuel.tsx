@@ -4,7 +4,7 @@
 
 import React, { FC, useEffect, useState } from 'react';
 import { useSimVar, useArinc429Var, usePersistentProperty } from '@flybywiresim/fbw-sdk';
-import { fuelForDisplay, fuelInTanksForDisplay } from '../../Common/FuelFunctions';
+import { fuelForDisplay } from '../../Common/FuelFunctions';
 import { Triangle } from '../../Common/Shapes';
 import { PageTitle } from '../../Common/PageTitle';
 import { EcamPage } from '../../Common/EcamPage';
@@ -12,16 +12,12 @@ import { EcamPage } from '../../Common/EcamPage';
 import './Fuel.scss';
 
 export const FuelPage = () => {
-  const [crossFeedPosition] = useSimVar('FUELSYSTEM VALVE OPEN:3', 'number', 500);
+  const [crossFeedIsOpen] = useSimVar('L:A32NX_FUEL_XFEED_VALVE_IS_OPEN', 'bool', 500);
 
-  const [tankLeftOuter] = useSimVar('FUEL TANK LEFT AUX QUANTITY', 'gallons', 500);
-  const [tankLeftInner] = useSimVar('FUEL TANK LEFT MAIN QUANTITY', 'gallons', 500);
   const [tankCenter] = useSimVar('FUEL TANK CENTER QUANTITY', 'gallons', 500);
-  const [tankRightInner] = useSimVar('FUEL TANK RIGHT MAIN QUANTITY', 'gallons', 500);
-  const [tankRightOuter] = useSimVar('FUEL TANK RIGHT AUX QUANTITY', 'gallons', 500);
-  const [leftOuterInnerValve] = useSimVar('FUELSYSTEM VALVE OPEN:4', 'bool', 500);
-  const [rightOuterInnerValve] = useSimVar('FUELSYSTEM VALVE OPEN:5', 'bool', 500);
-  const [modelSelectManual] = useSimVar('L:A32NX_OVHD_FUEL_MODESEL_MANUAL', 'bool', 500);
+  const [leftOuterInnerValve] = useSimVar('L:A32NX_FUEL_L_TRANSFER_VALVE_IS_OPEN', 'bool', 500);
+  const [rightOuterInnerValve] = useSimVar('L:A32NX_FUEL_R_TRANSFER_VALVE_IS_OPEN', 'bool', 500);
+  const [modeSelectAuto] = useSimVar('L:A32NX_OVHD_FUEL_MODE_SEL_PB_IS_AUTO', 'bool', 500);
   const [autoShutoffRequired] = useSimVar('FUELSYSTEM TRIGGER STATUS:9', 'bool', 500);
 
   const [unit] = usePersistentProperty('CONFIG_USING_METRIC_UNIT', '1');
@@ -32,8 +28,6 @@ export const FuelPage = () => {
   const leftFuelUsed = fuelForDisplay(leftConsumption, unit);
   const rightFuelUsed = fuelForDisplay(rightConsumption, unit);
 
-  const [fuelWeightPerGallon] = useSimVar('FUEL WEIGHT PER GALLON', 'kilogram', 60_000);
-
   const [onGround] = useSimVar('SIM ON GROUND', 'bool', 1000);
 
   return (
@@ -89,12 +83,8 @@ export const FuelPage = () => {
         <Pump x={180} y={215} onBus="DC_2" pumpNumber={5} />
 
         {/* Quantities */}
-        <text className="TankQuantity" x={80} y={285}>
-          {fuelInTanksForDisplay(tankLeftOuter, unit, fuelWeightPerGallon)}
-        </text>
-        <text className="TankQuantity" x={190} y={285}>
-          {fuelInTanksForDisplay(tankLeftInner, unit, fuelWeightPerGallon)}
-        </text>
+        <TankQuantity x={80} y={285} tank="LEFT_AUX" unit={unit} />
+        <TankQuantity x={190} y={285} tank="LEFT_MAIN" unit={unit} />
 
         {leftOuterInnerValve ? <Triangle x={77} y={319} colour="Green" fill={0} orientation={90} /> : null}
 
@@ -108,7 +98,7 @@ export const FuelPage = () => {
         {/* CrossFeed valve */}
         <CrossFeedValve x={300} y={150} />
 
-        {crossFeedPosition === 1 && (
+        {!!crossFeedIsOpen && (
           <>
             {/* Center valve lines */}
             <line className="FlowShape" x1="317" y1="150" x2="440" y2="150" />
@@ -122,7 +112,7 @@ export const FuelPage = () => {
           y={223}
           side="L"
           centreTankLevel={tankCenter}
-          modeSelectManual={!!modelSelectManual}
+          modeSelectManual={!modeSelectAuto}
           autoShutoffRequired={!!autoShutoffRequired}
           onGround={!!onGround}
         />
@@ -131,15 +121,13 @@ export const FuelPage = () => {
           y={223}
           side="R"
           centreTankLevel={tankCenter}
-          modeSelectManual={!!modelSelectManual}
+          modeSelectManual={!modeSelectAuto}
           autoShutoffRequired={!!autoShutoffRequired}
           onGround={!!onGround}
         />
 
         {/* Quantities */}
-        <text className="TankQuantity" x={330} y={315}>
-          {fuelInTanksForDisplay(tankCenter, unit, fuelWeightPerGallon)}
-        </text>
+        <TankQuantity x={330} y={315} tank="CENTER" unit={unit} />
       </>
 
       {/* Right */}
@@ -160,12 +148,8 @@ export const FuelPage = () => {
         <Pump x={425} y={215} onBus="DC_2" pumpNumber={6} />
 
         {/* Quantities */}
-        <text className="TankQuantity" x={472} y={285}>
-          {fuelInTanksForDisplay(tankRightInner, unit, fuelWeightPerGallon)}
-        </text>
-        <text className="TankQuantity" x={580} y={285}>
-          {fuelInTanksForDisplay(tankRightOuter, unit, fuelWeightPerGallon)}
-        </text>
+        <TankQuantity x={472} y={285} tank="RIGHT_MAIN" unit={unit} />
+        <TankQuantity x={580} y={285} tank="RIGHT_AUX" unit={unit} />
         {rightOuterInnerValve && <Triangle x={522} y={319} colour="Green" fill={0} orientation={-90} />}
 
         <text className="UnitTemp" x="510" y="355">
@@ -242,12 +226,44 @@ const FuelFlow = ({ unit }: FuelFlowProps) => {
   );
 };
 
+/** When the gauging is degraded, the last two digits of the quantity are dashed. */
+const quantityForDisplay = (quantity: number, isDegraded: boolean) =>
+  isDegraded ? `${Math.floor(quantity / 100)}--` : quantity;
+
+type TankQuantityProps = {
+  x: number;
+  y: number;
+  tank: string;
+  unit: string;
+};
+
+const TankQuantity = ({ x, y, tank, unit }: TankQuantityProps) => {
+  const quantity = useArinc429Var(`L:A32NX_FUEL_TANK_INDICATED_QUANTITY_${tank}`, 500);
+  const [isDegraded] = useSimVar(`L:A32NX_FUEL_TANK_QUANTITY_DEGRADED_${tank}`, 'bool', 500);
+
+  if (!quantity.isNormalOperation()) {
+    return (
+      <text className="TankQuantity Degraded" x={x} y={y}>
+        XX
+      </text>
+    );
+  }
+
+  return (
+    <text className={`TankQuantity ${isDegraded ? 'Degraded' : ''}`} x={x} y={y}>
+      {quantityForDisplay(fuelForDisplay(quantity.value, unit), !!isDegraded)}
+    </text>
+  );
+};
+
 type FOBProps = {
   unit: string;
 };
 
 const FOB = ({ unit }: FOBProps) => {
-  const [fob] = useSimVar('FUEL TOTAL QUANTITY WEIGHT', 'kg', 1000);
+  const fob = useArinc429Var('L:A32NX_FUEL_ON_BOARD_INDICATED', 1000);
+  const [fqiDegraded] = useSimVar('L:A32NX_FUEL_FQI_DEGRADED', 'bool', 1000);
+  const fobIsValid = fob.isNormalOperation();
 
   return (
     <>
@@ -260,8 +276,8 @@ const FOB = ({ unit }: FOBProps) => {
         :
       </text>
 
-      <text id="FobValue" x={204} y={485}>
-        {fuelForDisplay(fob, unit, 1, 2)}
+      <text id="FobValue" className={fqiDegraded || !fobIsValid ? 'Degraded' : ''} x={204} y={485}>
+        {fobIsValid ? quantityForDisplay(fuelForDisplay(fob.value, unit, 1, 2), !!fqiDegraded) : 'XX'}
       </text>
 
       <text id="FobUnit" x={215} y={487}>
@@ -362,7 +378,8 @@ const CrossFeedValveLine = ({ x, y, position }: EngineValveProps) => {
 };
 
 const CrossFeedValve = ({ x, y }: EngineLpValveProps) => {
-  const [position] = useSimVar('FUELSYSTEM VALVE OPEN:3', 'percent', 500);
+  const [isOpen] = useSimVar('L:A32NX_FUEL_XFEED_VALVE_IS_OPEN', 'bool', 500);
+  const position = isOpen ? 100 : 0;
 
   return (
     <g className={`ThickShape ${position > 0 && position < 100 ? 'ValveAmber' : 'ValveGreen'}`}>
@@ -382,11 +399,21 @@ type PumpProps = {
   tankQuantity?: number;
 };
 
+/** The fuel system pump names by their MSFS pump index. */
+const PUMP_NAMES: Record<number, string> = {
+  1: 'CTR_TK_1',
+  2: 'L_TK_1',
+  3: 'R_TK_1',
+  4: 'CTR_TK_2',
+  5: 'L_TK_2',
+  6: 'R_TK_2',
+};
+
 const Pump = ({ x, y, onBus = 'DC_ESS', pumpNumber, centreTank, tankQuantity }: PumpProps) => {
-  const [active] = useSimVar(`FUELSYSTEM PUMP ACTIVE:${pumpNumber}`, 'bool', 500);
+  const [active] = useSimVar(`L:A32NX_FUEL_${PUMP_NAMES[pumpNumber]}_PUMP_IS_RUNNING`, 'bool', 500);
   const [busIsPowered] = useSimVar(`L:A32NX_ELEC_${onBus}_BUS_IS_POWERED`, 'bool', 1000);
   const [centreTankGreen, setCentreTankGreen] = useState(false);
-  const [pushButton] = useSimVar(`FUELSYSTEM PUMP SWITCH:${pumpNumber}`, 'bool', 500);
+  const [pushButton] = useSimVar(`L:A32NX_OVHD_FUEL_${PUMP_NAMES[pumpNumber]}_PUMP_PB_IS_ON`, 'bool', 500);
   const [simOnGround] = useSimVar('SIM ON GROUND', 'bool', 1000);
   // FIXME add centre tank logic once fuel system implemented
   useEffect(() => {
@@ -676,22 +676,8 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
 
   bool uiFuelTamper = false;
 
-  const double pumpStateLeft          = simData.fuelPumpState[L]->get();
-  const double pumpStateRight         = simData.fuelPumpState[R]->get();
-  const bool   xfrCenterLeftManual    = simData.simVarsDataPtr->data().xfrCenterManual[L] > 1.5;                              // junction 4
-  const bool   xfrCenterRightManual   = simData.simVarsDataPtr->data().xfrCenterManual[R] > 1.5;                              // junction 5
-  const bool   xfrCenterLeftAuto      = simData.simVarsDataPtr->data().xfrValveCenterAuto[L] > 0.0 && !xfrCenterLeftManual;   // valve 11
-  const bool   xfrCenterRightAuto     = simData.simVarsDataPtr->data().xfrValveCenterAuto[R] > 0.0 && !xfrCenterRightManual;  // valve 12
-  const bool   xfrValveCenterLeftOpen = simData.simVarsDataPtr->data().xfrValveCenterOpen[L] > 0.0                            //
-                                      && (xfrCenterLeftAuto || xfrCenterLeftManual);                                          // valve 9
-  const bool xfrValveCenterRightOpen = simData.simVarsDataPtr->data().xfrValveCenterOpen[R] > 0.0                             //
-                                       && (xfrCenterRightAuto || xfrCenterRightManual);                                       // valve 10
-  const double xfrValveOuterLeft1    = simData.simVarsDataPtr->data().xfrValveOuter1[L];                                      // valve 6
-  const double xfrValveOuterRight1   = simData.simVarsDataPtr->data().xfrValveOuter1[R];                                      // valve 7
-  const double xfrValveOuterLeft2    = simData.simVarsDataPtr->data().xfrValveOuter2[L];                                      // valve 4
-  const double xfrValveOuterRight2   = simData.simVarsDataPtr->data().xfrValveOuter2[R];                                      // valve 5
-  const double lineLeftToCenterFlow  = simData.simVarsDataPtr->data().lineToCenterFlow[L];
-  const double lineRightToCenterFlow = simData.simVarsDataPtr->data().lineToCenterFlow[R];
+  const double pumpStateLeft  = simData.fuelPumpState[L]->get();
+  const double pumpStateRight = simData.fuelPumpState[R]->get();
 
   const double engine1PreFF = simData.enginePreFF[L]->get();
   const double engine2PreFF = simData.enginePreFF[R]->get();
@@ -702,11 +688,11 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
   /// weight of one gallon of fuel in pounds
   const double weightLbsPerGallon = simData.simVarsDataPtr->data().fuelWeightPerGallon;
 
-  double fuelLeftPre     = simData.fuelLeftPre->get();
-  double fuelRightPre    = simData.fuelRightPre->get();
-  double fuelAuxLeftPre  = simData.fuelAuxLeftPre->get();
-  double fuelAuxRightPre = simData.fuelAuxRightPre->get();
-  double fuelCenterPre   = simData.fuelCenterPre->get();
+  const double fuelLeftPre     = simData.fuelLeftPre->get();
+  const double fuelRightPre    = simData.fuelRightPre->get();
+  const double fuelAuxLeftPre  = simData.fuelAuxLeftPre->get();
+  const double fuelAuxRightPre = simData.fuelAuxRightPre->get();
+  const double fuelCenterPre   = simData.fuelCenterPre->get();
 
   const double leftQuantity     = simData.simVarsDataPtr->data().fuelTankQuantityLeft * weightLbsPerGallon;      // Pounds
   const double rightQuantity    = simData.simVarsDataPtr->data().fuelTankQuantityRight * weightLbsPerGallon;     // Pounds
@@ -721,15 +707,6 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
   const EngineState engine1State = static_cast<EngineState>(simData.engineState[L]->get());
   const EngineState engine2State = static_cast<EngineState>(simData.engineState[R]->get());
 
-  const double xFeedValve  = simData.simVarsDataPtr->data().xFeedValve;
-  const double leftPump1   = simData.simVarsDataPtr->data().fuelPump1[L];
-  const double rightPump1  = simData.simVarsDataPtr->data().fuelPump1[R];
-  const double leftPump2   = simData.simVarsDataPtr->data().fuelPump2[L];
-  const double rightPump2  = simData.simVarsDataPtr->data().fuelPump2[R];
-  const double apuNpercent = simData.apuRpmPercent->get();
-
-  int isTankClosed = 0;
-
   /// Delta time for this update in hours
   const double deltaTimeHours = deltaTimeSeconds / 3600;
 
@@ -812,144 +789,23 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
     simData.fuelAuxRightPre->set(rightAuxQuantity);  // in Pounds
     simData.fuelCenterPre->set(centerQuantity);      // in Pounds
   } else {
-    if (uiFuelTamper == 1) {
-      fuelLeftPre     = leftQuantity;      // Pounds
-      fuelRightPre    = rightQuantity;     // Pounds
-      fuelAuxLeftPre  = leftAuxQuantity;   // Pounds
-      fuelAuxRightPre = rightAuxQuantity;  // Pounds
-      fuelCenterPre   = centerQuantity;    // Pounds
-    }
-    //-----------------------------------------------------------
-    // Cross-feed Logic
-    // isTankClosed = 0, x-feed valve closed
-    // isTankClosed = 1, left tank does not supply fuel
-    // isTankClosed = 2, right tank does not supply fuel
-    // isTankClosed = 3, left & right tanks do not supply fuel
-    // isTankClosed = 4, both tanks supply fuel
-    if (xFeedValve > 0.0) {
-      if (leftPump1 == 0 && leftPump2 == 0 && rightPump1 == 0 && rightPump2 == 0)
-        isTankClosed = 3;
-      else if (leftPump1 == 0 && leftPump2 == 0)
-        isTankClosed = 1;
-      else if (rightPump1 == 0 && rightPump2 == 0)
-        isTankClosed = 2;
-      else
-        isTankClosed = 4;
-    }
-
-    double xfrCenterToLeft  = 0;
-    double xfrCenterToRight = 0;
-    double xfrAuxLeft       = 0;
-    double xfrAuxRight      = 0;
-
-    double fuelFlowRateChange   = 0;
-    double previousFuelFlowRate = 0;
-    double fuelBurn1            = 0;
-    double fuelBurn2            = 0;
-    double apuBurn1             = 0;
-    double apuBurn2             = 0;
-
-    //--------------------------------------------
-    // Left Engine and Wing routine
-    if (fuelLeftPre > 0) {
-      // Cycle Fuel Burn for Engine 1
-      if (aircraftDevelopmentStateVar != 2) {
-        fuelFlowRateChange   = (engine1FF - engine1PreFF) / deltaTimeHours;
-        previousFuelFlowRate = engine1PreFF;
-        fuelBurn1            = (fuelFlowRateChange * pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
-      }
-      // Fuel transfer routine for Left Wing
-      if (xfrValveOuterLeft1 > 0.0 || xfrValveOuterLeft2 > 0.0) {
-        xfrAuxLeft = fuelAuxLeftPre - leftAuxQuantity;
-      }
-    } else {
-      fuelBurn1   = 0;
-      fuelLeftPre = 0;
-    }
+    // The tank quantities are changed by the fuel system in the systems wasm, which feeds the engines and the APU
+    // and transfers the fuel between the tanks. Here the engine fuel used is accumulated and the quantities are
+    // kept as the reference for detecting in-game UI fuel tampering.
+    double fuelBurn1 = 0;
+    double fuelBurn2 = 0;
 
     //--------------------------------------------
-    // Right Engine and Wing routine
-    if (fuelRightPre > 0) {
-      // Cycle Fuel Burn for Engine 2
-      if (aircraftDevelopmentStateVar != 2) {
-        fuelFlowRateChange   = (engine2FF - engine2PreFF) / deltaTimeHours;
-        previousFuelFlowRate = engine2PreFF;
-        fuelBurn2            = (fuelFlowRateChange * pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
-      }
-      // Fuel transfer routine for Right Wing
-      if (xfrValveOuterRight1 > 0.0 || xfrValveOuterRight2 > 0.0) {
-        xfrAuxRight = fuelAuxRightPre - rightAuxQuantity;
-      }
-    } else {
-      fuelBurn2    = 0;
-      fuelRightPre = 0;
-    }
-
-    /// apu fuel consumption for this frame in pounds
-    double apuFuelConsumption = simData.simVarsDataPtr->data().apuFuelConsumption * weightLbsPerGallon * deltaTimeHours;
-
-    // check if APU is actually running instead of just the ASU which doesn't consume fuel
-    if (apuNpercent <= 0.0) {
-      apuFuelConsumption = 0.0;
+    // Cycle Fuel Burn for Engine 1 and 2
+    if (aircraftDevelopmentStateVar != 2) {
+      fuelBurn1 = ((engine1FF - engine1PreFF) * deltaTimeHours / 2) + (engine1PreFF * deltaTimeHours);  // KG
+      fuelBurn2 = ((engine2FF - engine2PreFF) * deltaTimeHours / 2) + (engine2PreFF * deltaTimeHours);  // KG
     }
 
-    apuBurn1 = apuFuelConsumption;
-    apuBurn2 = 0;
-
     //--------------------------------------------
     // Fuel used accumulators
-    double fuelUsedLeft  = simData.engineFuelUsed[L]->get() + fuelBurn1;
-    double fuelUsedRight = simData.engineFuelUsed[R]->get() + fuelBurn2;
-
-    //--------------------------------------------
-    // Cross-feed fuel burn routine
-    // If fuel pumps for a given tank are closed,
-    // all fuel will be burnt on the other tank
-    switch (isTankClosed) {
-      case 1:
-        fuelBurn2 = fuelBurn1 + fuelBurn2;
-        fuelBurn1 = 0;
-        apuBurn1  = 0;
-        apuBurn2  = apuFuelConsumption;
-        break;
-      case 2:
-        fuelBurn1 = fuelBurn1 + fuelBurn2;
-        fuelBurn2 = 0;
-        break;
-      case 3:
-        fuelBurn1 = 0;
-        fuelBurn2 = 0;
-        apuBurn1  = apuFuelConsumption * 0.5;
-        apuBurn2  = apuFuelConsumption * 0.5;
-        break;
-      case 4:
-        apuBurn1 = apuFuelConsumption * 0.5;
-        apuBurn2 = apuFuelConsumption * 0.5;
-        break;
-      default:
-        break;
-    }
-
-    //--------------------------------------------
-    // Center Tank transfer routine
-    double lineFlowRatio = 0;
-    if (xfrValveCenterLeftOpen && xfrValveCenterRightOpen) {
-      if (lineLeftToCenterFlow < 0.1 && lineRightToCenterFlow < 0.1)
-        lineFlowRatio = 0.5;
-      else
-        lineFlowRatio = lineLeftToCenterFlow / (lineLeftToCenterFlow + lineRightToCenterFlow);
-
-      xfrCenterToLeft  = (fuelCenterPre - centerQuantity) * lineFlowRatio;
-      xfrCenterToRight = (fuelCenterPre - centerQuantity) * (1 - lineFlowRatio);
-    } else if (xfrValveCenterLeftOpen)
-      xfrCenterToLeft = fuelCenterPre - centerQuantity;
-    else if (xfrValveCenterRightOpen)
-      xfrCenterToRight = fuelCenterPre - centerQuantity;
-
-    //--------------------------------------------
-    // Final Fuel levels for left and right inner tanks
-    const double fuelLeft  = (fuelLeftPre - (fuelBurn1 * Fadec::KGS_TO_LBS)) + xfrAuxLeft + xfrCenterToLeft - apuBurn1;     // Pounds
-    const double fuelRight = (fuelRightPre - (fuelBurn2 * Fadec::KGS_TO_LBS)) + xfrAuxRight + xfrCenterToRight - apuBurn2;  // Pounds
+    const double fuelUsedLeft  = simData.engineFuelUsed[L]->get() + fuelBurn1;
+    const double fuelUsedRight = simData.engineFuelUsed[R]->get() + fuelBurn2;
 
     //--------------------------------------------
     // Setting new pre-cycle conditions
@@ -959,16 +815,11 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
     simData.engineFuelUsed[L]->set(fuelUsedLeft);
     simData.engineFuelUsed[R]->set(fuelUsedRight);
 
-    simData.fuelAuxLeftPre->set(leftAuxQuantity);
-    simData.fuelAuxRightPre->set(rightAuxQuantity);
-    simData.fuelCenterPre->set(centerQuantity);
-
-    simData.fuelLeftPre->set(fuelLeft);    // in Pounds
-    simData.fuelRightPre->set(fuelRight);  // in Pounds
-
-    simData.fuelFeedTankDataPtr->data().fuelLeftMain  = (fuelLeft / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->data().fuelRightMain = (fuelRight / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->writeDataToSim();
+    simData.fuelLeftPre->set(leftQuantity);          // in Pounds
+    simData.fuelRightPre->set(rightQuantity);        // in Pounds
+    simData.fuelAuxLeftPre->set(leftAuxQuantity);    // in Pounds
+    simData.fuelAuxRightPre->set(rightAuxQuantity);  // in Pounds
+    simData.fuelCenterPre->set(centerQuantity);      // in Pounds
   }
 
   //--------------------------------------------
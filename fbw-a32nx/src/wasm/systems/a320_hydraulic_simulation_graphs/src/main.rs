@@ -511,6 +511,7 @@ impl Aircraft for A320SimpleMainElecHydraulicsTestAircraft {
                 None,
                 &self.circuit_controller,
                 self.pneumatics.pressure(),
+                context.ambient_temperature(),
             );
         }
     }
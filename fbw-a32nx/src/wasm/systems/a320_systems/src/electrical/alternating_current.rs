@@ -6,17 +6,22 @@ use std::time::Duration;
 use systems::{
     apu::ApuGenerator,
     electrical::{
-        AlternatingCurrentElectricalSystem, Contactor, ElectricalBus, Electricity,
+        AlternatingCurrentElectricalSystem, CircuitBreaker, Contactor, ElectricalBus, Electricity,
         EmergencyGenerator, ExternalPowerSource, IntegratedDriveGenerator, TransformerRectifier,
     },
     engine::Engine,
     shared::{
         AuxiliaryPowerUnitElectrical, DelayedTrueLogicGate, ElectricalBusType,
-        EngineFirePushButtons,
+        EngineFirePushButtons, FuelTemperature,
     },
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, power::kilowatt};
+use uom::si::{electric_current::ampere, f64::*, power::kilowatt};
+
+pub(crate) const BLUE_ELEC_PUMP_SUPPLY_BUS_TYPE: ElectricalBusType =
+    ElectricalBusType::Sub("BLUE_ELEC_PUMP");
+pub(crate) const YELLOW_ELEC_PUMP_SUPPLY_BUS_TYPE: ElectricalBusType =
+    ElectricalBusType::Sub("YELLOW_ELEC_PUMP");
 
 pub(super) struct A320AlternatingCurrentElectrical {
     main_power_sources: A320MainPowerSources,
@@ -36,8 +41,12 @@ pub(super) struct A320AlternatingCurrentElectrical {
     ac_stat_inv_bus: ElectricalBus,
     ac_gnd_flt_service_bus: ElectricalBus,
     ext_pwr_to_ac_gnd_flt_service_bus_and_tr_2_contactor: Contactor,
+    blue_elec_pump_circuit_breaker: CircuitBreaker,
+    yellow_elec_pump_circuit_breaker: CircuitBreaker,
 }
 impl A320AlternatingCurrentElectrical {
+    const ELEC_PUMP_CIRCUIT_BREAKER_RATED_CURRENT: f64 = 50.;
+
     pub fn new(context: &mut InitContext) -> Self {
         A320AlternatingCurrentElectrical {
             main_power_sources: A320MainPowerSources::new(context),
@@ -66,6 +75,18 @@ impl A320AlternatingCurrentElectrical {
                 ElectricalBusType::AlternatingCurrentGndFltService,
             ),
             ext_pwr_to_ac_gnd_flt_service_bus_and_tr_2_contactor: Contactor::new(context, "12XN"),
+            blue_elec_pump_circuit_breaker: CircuitBreaker::new_three_phase(
+                context,
+                "BLUE_ELEC_PUMP",
+                BLUE_ELEC_PUMP_SUPPLY_BUS_TYPE,
+                ElectricCurrent::new::<ampere>(Self::ELEC_PUMP_CIRCUIT_BREAKER_RATED_CURRENT),
+            ),
+            yellow_elec_pump_circuit_breaker: CircuitBreaker::new_three_phase(
+                context,
+                "YELLOW_ELEC_PUMP",
+                YELLOW_ELEC_PUMP_SUPPLY_BUS_TYPE,
+                ElectricCurrent::new::<ampere>(Self::ELEC_PUMP_CIRCUIT_BREAKER_RATED_CURRENT),
+            ),
         }
     }
 
@@ -79,6 +100,7 @@ impl A320AlternatingCurrentElectrical {
         apu: &impl AuxiliaryPowerUnitElectrical,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         engines: [&impl Engine; 2],
+        fuel: &impl FuelTemperature,
     ) {
         self.main_power_sources.update(
             context,
@@ -89,6 +111,7 @@ impl A320AlternatingCurrentElectrical {
             apu,
             engine_fire_push_buttons,
             engines,
+            fuel,
         );
 
         self.main_power_sources
@@ -132,6 +155,12 @@ impl A320AlternatingCurrentElectrical {
             &self.ac_gnd_flt_service_bus,
         );
 
+        electricity.flow(&self.ac_bus_1, &self.blue_elec_pump_circuit_breaker);
+        electricity.flow(
+            &self.ac_gnd_flt_service_bus,
+            &self.yellow_elec_pump_circuit_breaker,
+        );
+
         electricity.flow(&self.ac_bus_1, &self.tr_1);
         electricity.transform_in(&self.tr_1);
 
@@ -275,6 +304,15 @@ impl A320AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn gen_drive_has_fault(&self, number: usize) -> bool {
+        self.main_power_sources.gen_drive_has_fault(number)
+    }
+
+    pub fn gen_drive_heat_rejected_to_fuel(&self, number: usize) -> Power {
+        self.main_power_sources
+            .gen_drive_heat_rejected_to_fuel(number)
+    }
+
     pub fn emergency_generator_contactor_is_closed(&self) -> bool {
         self.emergency_gen_contactor.is_closed()
     }
@@ -333,6 +371,9 @@ impl SimulationElement for A320AlternatingCurrentElectrical {
         self.ext_pwr_to_ac_gnd_flt_service_bus_and_tr_2_contactor
             .accept(visitor);
 
+        self.blue_elec_pump_circuit_breaker.accept(visitor);
+        self.yellow_elec_pump_circuit_breaker.accept(visitor);
+
         visitor.visit(self);
     }
 }
@@ -382,13 +423,24 @@ impl A320MainPowerSources {
         apu: &impl AuxiliaryPowerUnitElectrical,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         engines: [&impl Engine; 2],
+        fuel: &impl FuelTemperature,
     ) {
-        self.engine_1_gen
-            .update(context, engines[0], overhead, engine_fire_push_buttons);
+        self.engine_1_gen.update(
+            context,
+            engines[0],
+            overhead,
+            engine_fire_push_buttons,
+            fuel.engine_feed_fuel_temperature(1),
+        );
         electricity.supplied_by(&self.engine_1_gen);
 
-        self.engine_2_gen
-            .update(context, engines[1], overhead, engine_fire_push_buttons);
+        self.engine_2_gen.update(
+            context,
+            engines[1],
+            overhead,
+            engine_fire_push_buttons,
+            fuel.engine_feed_fuel_temperature(2),
+        );
         electricity.supplied_by(&self.engine_2_gen);
 
         electricity.supplied_by(apu.generator(1));
@@ -463,6 +515,22 @@ impl A320MainPowerSources {
     pub fn gen_contactor_open(&self, number: usize) -> bool {
         self.engine_generator_contactors[number - 1].is_open()
     }
+
+    pub fn gen_drive_has_fault(&self, number: usize) -> bool {
+        if number == 1 {
+            self.engine_1_gen.drive_has_fault()
+        } else {
+            self.engine_2_gen.drive_has_fault()
+        }
+    }
+
+    pub fn gen_drive_heat_rejected_to_fuel(&self, number: usize) -> Power {
+        if number == 1 {
+            self.engine_1_gen.heat_rejected_to_fuel()
+        } else {
+            self.engine_2_gen.heat_rejected_to_fuel()
+        }
+    }
 }
 impl SimulationElement for A320MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -7,7 +7,7 @@ use systems::simulation::InitContext;
 use systems::{
     electrical::{
         Battery, BatteryChargeLimiter, Contactor, ElectricalBus, Electricity, EmergencyElectrical,
-        EmergencyGenerator, StaticInverter,
+        EmergencyGenerator, Feeders, StaticInverter,
     },
     shared::{
         ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical, ContactorSignal, ElectricalBusType,
@@ -15,6 +15,7 @@ use systems::{
     },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
+use uom::si::{electrical_resistance::ohm, f64::ElectricalResistance};
 
 pub(crate) const APU_START_MOTOR_BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
 
@@ -47,8 +48,12 @@ pub(super) struct A320DirectCurrentElectrical {
     dc_gnd_flt_service_bus: ElectricalBus,
     tr_2_to_dc_gnd_flt_service_bus_contactor: Contactor,
     dc_bus_2_to_dc_gnd_flt_service_bus_contactor: Contactor,
+    feeders: Feeders,
 }
 impl A320DirectCurrentElectrical {
+    const TR_FEEDER_RESISTANCE_OHM: f64 = 0.002;
+    const BATTERY_FEEDER_RESISTANCE_OHM: f64 = 0.003;
+
     pub fn new(context: &mut InitContext) -> Self {
         A320DirectCurrentElectrical {
             dc_bus_1: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
@@ -85,6 +90,7 @@ impl A320DirectCurrentElectrical {
             ),
             tr_2_to_dc_gnd_flt_service_bus_contactor: Contactor::new(context, "3PX"),
             dc_bus_2_to_dc_gnd_flt_service_bus_contactor: Contactor::new(context, "8PN"),
+            feeders: Feeders::new(context),
         }
     }
 
@@ -125,8 +131,19 @@ impl A320DirectCurrentElectrical {
         );
         electricity.flow(ac_state.tr_ess(), &self.tr_ess_contactor);
 
-        electricity.flow(&self.tr_1_contactor, &self.dc_bus_1);
-        electricity.flow(&self.tr_2_contactor, &self.dc_bus_2);
+        let tr_feeder_resistance = ElectricalResistance::new::<ohm>(Self::TR_FEEDER_RESISTANCE_OHM);
+        self.feeders.flow(
+            electricity,
+            &self.tr_1_contactor,
+            &self.dc_bus_1,
+            tr_feeder_resistance,
+        );
+        self.feeders.flow(
+            electricity,
+            &self.tr_2_contactor,
+            &self.dc_bus_2,
+            tr_feeder_resistance,
+        );
 
         self.dc_bus_2_to_dc_gnd_flt_service_bus_contactor
             .close_when(
@@ -178,7 +195,12 @@ impl A320DirectCurrentElectrical {
         );
         self.battery_1_contactor
             .close_when(self.battery_1_charge_limiter.should_close_contactor());
-        electricity.flow(&self.dc_bat_bus, &self.battery_1_contactor);
+        self.feeders.flow(
+            electricity,
+            &self.dc_bat_bus,
+            &self.battery_1_contactor,
+            ElectricalResistance::new::<ohm>(Self::BATTERY_FEEDER_RESISTANCE_OHM),
+        );
         electricity.flow(&self.battery_1_contactor, &self.hot_bus_1);
         electricity.flow(&self.hot_bus_1, &self.battery_1);
 
@@ -199,7 +221,12 @@ impl A320DirectCurrentElectrical {
         );
         self.battery_2_contactor
             .close_when(self.battery_2_charge_limiter.should_close_contactor());
-        electricity.flow(&self.dc_bat_bus, &self.battery_2_contactor);
+        self.feeders.flow(
+            electricity,
+            &self.dc_bat_bus,
+            &self.battery_2_contactor,
+            ElectricalResistance::new::<ohm>(Self::BATTERY_FEEDER_RESISTANCE_OHM),
+        );
         electricity.flow(&self.battery_2_contactor, &self.hot_bus_2);
         electricity.flow(&self.hot_bus_2, &self.battery_2);
 
@@ -231,7 +258,12 @@ impl A320DirectCurrentElectrical {
         electricity.flow(&self.dc_bat_bus, &self.dc_bat_bus_to_dc_ess_bus_contactor);
 
         electricity.flow(&self.dc_bat_bus_to_dc_ess_bus_contactor, &self.dc_ess_bus);
-        electricity.flow(&self.tr_ess_contactor, &self.dc_ess_bus);
+        self.feeders.flow(
+            electricity,
+            &self.tr_ess_contactor,
+            &self.dc_ess_bus,
+            tr_feeder_resistance,
+        );
         electricity.flow(&self.hot_bus_2_to_dc_ess_bus_contactor, &self.dc_ess_bus);
 
         self.dc_ess_shed_contactor
@@ -288,6 +320,14 @@ impl A320DirectCurrentElectrical {
             == self.hot_bus_2_to_dc_ess_bus_contactor.is_closed()
     }
 
+    pub fn battery_has_thermal_runaway(&self, number: usize) -> bool {
+        if number == 1 {
+            self.battery_1_charge_limiter.has_thermal_runaway()
+        } else {
+            self.battery_2_charge_limiter.has_thermal_runaway()
+        }
+    }
+
     #[cfg(test)]
     pub fn battery_1(&self) -> &Battery {
         &self.battery_1
@@ -320,6 +360,7 @@ impl SimulationElement for A320DirectCurrentElectrical {
         self.battery_2.accept(visitor);
         self.battery_2_charge_limiter.accept(visitor);
         self.static_inverter.accept(visitor);
+        self.feeders.accept(visitor);
 
         self.dc_bus_1_tie_contactor.accept(visitor);
         self.dc_bus_2_tie_contactor.accept(visitor);
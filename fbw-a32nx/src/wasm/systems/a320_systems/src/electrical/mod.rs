@@ -7,6 +7,9 @@ use self::{
     direct_current::A320DirectCurrentElectrical,
     galley::{MainGalley, SecondaryGalley},
 };
+pub(super) use alternating_current::{
+    BLUE_ELEC_PUMP_SUPPLY_BUS_TYPE, YELLOW_ELEC_PUMP_SUPPLY_BUS_TYPE,
+};
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
 use uom::si::{angular_velocity::revolution_per_minute, f64::*};
@@ -17,9 +20,9 @@ use systems::electrical::Battery;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, Electricity, EmergencyElectrical,
-        EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource, StaticInverter,
-        TransformerRectifier,
+        AlternatingCurrentElectricalSystem, ApuGeneratorPushButtons, BatteryPushButtons,
+        Electricity, EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons,
+        ExternalPowerSource, StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -30,9 +33,11 @@ use systems::{
     shared::{
         AdirsDiscreteOutputs, ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical,
         EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-        EmergencyGeneratorPower, EngineFirePushButtons, LgciuWeightOnWheels,
+        EmergencyGeneratorPower, EngineFirePushButtons, FuelTemperature,
+        GeneratorDriveHeatRejection, LgciuWeightOnWheels,
     },
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
@@ -81,6 +86,7 @@ impl A320Electrical {
         gcu: &impl EmergencyGeneratorControlUnit,
         lgciu1: &impl LgciuWeightOnWheels,
         adirs: &impl AdirsDiscreteOutputs,
+        fuel: &impl FuelTemperature,
     ) {
         self.alternating_current.update_main_power_sources(
             context,
@@ -91,6 +97,7 @@ impl A320Electrical {
             apu,
             engine_fire_push_buttons,
             engines,
+            fuel,
         );
 
         self.emergency_elec
@@ -197,6 +204,14 @@ impl A320Electrical {
         self.alternating_current.gen_contactor_open(number)
     }
 
+    pub fn gen_drive_has_fault(&self, number: usize) -> bool {
+        self.alternating_current.gen_drive_has_fault(number)
+    }
+
+    pub fn battery_has_thermal_runaway(&self, number: usize) -> bool {
+        self.direct_current.battery_has_thermal_runaway(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_active()
     }
@@ -213,6 +228,14 @@ impl SimulationElement for A320Electrical {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.galley_is_shed_id, self.galley_is_shed())
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.emergency_elec.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.emergency_elec.restore_state(state);
+    }
 }
 impl EmergencyElectricalState for A320Electrical {
     fn is_in_emergency_elec(&self) -> bool {
@@ -224,6 +247,12 @@ impl EmergencyGeneratorPower for A320Electrical {
         self.emergency_gen.generated_power()
     }
 }
+impl GeneratorDriveHeatRejection for A320Electrical {
+    fn generator_drive_heat_rejected_to_fuel(&self, engine_number: usize) -> Power {
+        self.alternating_current
+            .gen_drive_heat_rejected_to_fuel(engine_number)
+    }
+}
 
 trait A320DirectCurrentElectricalSystem {
     fn static_inverter(&self) -> &StaticInverter;
@@ -286,6 +315,18 @@ impl A320ElectricalOverheadPanel {
             .for_each(|(index, gen)| {
                 gen.set_fault(electrical.gen_contactor_open(index + 1) && gen.is_on());
             });
+
+        self.idgs
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, drive)| drive.set_fault(electrical.gen_drive_has_fault(index + 1)));
+
+        self.batteries
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, battery)| {
+                battery.set_fault(electrical.battery_has_thermal_runaway(index + 1))
+            });
     }
 
     fn generator_is_on(&self, number: usize) -> bool {
@@ -333,6 +374,11 @@ impl EngineGeneratorPushButtons for A320ElectricalOverheadPanel {
         self.idgs[number - 1].is_released()
     }
 }
+impl ApuGeneratorPushButtons for A320ElectricalOverheadPanel {
+    fn apu_gen_push_button_is_on(&self, _: usize) -> bool {
+        self.apu_gen.is_on()
+    }
+}
 impl BatteryPushButtons for A320ElectricalOverheadPanel {
     fn bat_is_auto(&self, number: usize) -> bool {
         self.batteries[number - 1].is_auto()
@@ -439,8 +485,9 @@ mod a320_electrical_circuit_tests {
         },
         failures::FailureType,
         shared::{
-            ApuAvailable, ContactorSignal, ControllerSignal, ElectricalBusType, ElectricalBuses,
-            EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2, PotentialOrigin,
+            ApuAvailable, ConsumePower, ContactorSignal, ControllerSignal, ElectricalBusType,
+            ElectricalBuses, EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2,
+            HydraulicColor, PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -453,7 +500,7 @@ mod a320_electrical_circuit_tests {
         electric_potential::volt,
         frequency::hertz,
         length::foot,
-        power::watt,
+        power::{kilowatt, watt},
         ratio::{percent, ratio},
         velocity::knot,
     };
@@ -1863,6 +1910,35 @@ mod a320_electrical_circuit_tests {
         assert!(test_bed.apu_start_motor_is_powered());
     }
 
+    #[test]
+    fn dc_bat_bus_potential_remains_normal_during_apu_start_without_feeder_resistance() {
+        let mut test_bed = test_bed_with()
+            .bat_auto(1)
+            .bat_auto(2)
+            .command_closing_of_start_contactors()
+            .apu_start_motor_consuming(Power::new::<kilowatt>(12.))
+            .and()
+            .run_for_start_contactor_test();
+
+        assert!(test_bed.apu_start_motor_is_powered());
+        assert!(test_bed.dc_bat_bus_potential_normal());
+    }
+
+    #[test]
+    fn dc_bat_bus_potential_drops_during_apu_start_with_feeder_resistance() {
+        let mut test_bed = test_bed_with()
+            .feeder_resistance_enabled()
+            .bat_auto(1)
+            .bat_auto(2)
+            .command_closing_of_start_contactors()
+            .apu_start_motor_consuming(Power::new::<kilowatt>(12.))
+            .and()
+            .run_for_start_contactor_test();
+
+        assert!(test_bed.apu_start_motor_is_powered());
+        assert!(!test_bed.dc_bat_bus_potential_normal());
+    }
+
     #[test]
     fn when_apu_start_with_both_batteries_auto_and_closing_not_commanded_start_contactors_remain_open_and_motor_unpowered(
     ) {
@@ -2054,6 +2130,28 @@ mod a320_electrical_circuit_tests {
         assert!(test_bed.gen_has_fault(number));
     }
 
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn when_idg_oil_leaks_idg_push_button_has_fault(#[case] number: usize) {
+        let mut test_bed = test_bed_with()
+            .running_engine(number)
+            .and()
+            .failed_idg_oil(number)
+            .run();
+
+        assert!(test_bed.idg_has_fault(number));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn when_idg_normal_idg_push_button_does_not_have_fault(#[case] number: usize) {
+        let mut test_bed = test_bed_with().running_engine(number).run();
+
+        assert!(!test_bed.idg_has_fault(number));
+    }
+
     fn test_bed_with() -> A320ElectricalTestBed {
         test_bed()
     }
@@ -2066,6 +2164,7 @@ mod a320_electrical_circuit_tests {
         gen: TestApuGenerator,
         is_available: bool,
         start_motor_is_powered: bool,
+        start_motor_power: Power,
         should_close_start_contactor: bool,
     }
     impl TestApu {
@@ -2074,10 +2173,15 @@ mod a320_electrical_circuit_tests {
                 gen: TestApuGenerator::new(context),
                 is_available: false,
                 start_motor_is_powered: false,
+                start_motor_power: Power::new::<watt>(0.),
                 should_close_start_contactor: false,
             }
         }
 
+        fn set_start_motor_power(&mut self, power: Power) {
+            self.start_motor_power = power;
+        }
+
         fn set_available(&mut self, available: bool) {
             self.gen.set_available(available);
             self.is_available = available;
@@ -2100,6 +2204,12 @@ mod a320_electrical_circuit_tests {
         fn receive_power(&mut self, buses: &impl ElectricalBuses) {
             self.start_motor_is_powered = buses.is_powered(ElectricalBusType::Sub("49-42-00"));
         }
+
+        fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, power: &mut T) {
+            if self.start_motor_is_powered {
+                power.consume_from_bus(ElectricalBusType::Sub("49-42-00"), self.start_motor_power);
+            }
+        }
     }
     impl AuxiliaryPowerUnitElectrical for TestApu {
         type Generator = TestApuGenerator;
@@ -2141,7 +2251,14 @@ mod a320_electrical_circuit_tests {
         }
     }
     impl ApuGenerator for TestApuGenerator {
-        fn update(&mut self, _n: Ratio, _is_emergency_shutdown: bool) {}
+        fn update(
+            &mut self,
+            _: &UpdateContext,
+            _n: Ratio,
+            _is_emergency_shutdown: bool,
+            _push_button_is_on: bool,
+        ) {
+        }
 
         fn output_within_normal_parameters(&self) -> bool {
             self.is_available
@@ -2425,6 +2542,27 @@ mod a320_electrical_circuit_tests {
         }
     }
 
+    struct TestFuel {
+        temperature: ThermodynamicTemperature,
+    }
+    impl TestFuel {
+        fn new(temperature: ThermodynamicTemperature) -> Self {
+            Self { temperature }
+        }
+    }
+    impl FuelTemperature for TestFuel {
+        fn hydraulic_heat_exchanger_fuel_temperature(
+            &self,
+            _color: HydraulicColor,
+        ) -> ThermodynamicTemperature {
+            self.temperature
+        }
+
+        fn engine_feed_fuel_temperature(&self, _engine_number: usize) -> ThermodynamicTemperature {
+            self.temperature
+        }
+    }
+
     struct A320ElectricalTestAircraft {
         engines: [TestEngine; 2],
         ext_pwr: ExternalPowerSource,
@@ -2477,6 +2615,10 @@ mod a320_electrical_circuit_tests {
             self.apu.command_closing_of_start_contactors();
         }
 
+        fn set_apu_start_motor_power(&mut self, power: Power) {
+            self.apu.set_start_motor_power(power);
+        }
+
         fn apu_start_motor_is_powered(&self) -> bool {
             self.apu.start_motor_is_powered()
         }
@@ -2547,6 +2689,7 @@ mod a320_electrical_circuit_tests {
                 &self.hydraulics,
                 &TestLandingGear::new(context.is_on_ground()),
                 &TestAdirs::new(context.indicated_airspeed()),
+                &TestFuel::new(context.ambient_temperature()),
             );
             self.overhead
                 .update_after_electrical(&self.elec, electricity);
@@ -2654,6 +2797,12 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn failed_idg_oil(mut self, number: usize) -> Self {
+            self.test_bed
+                .fail(FailureType::GeneratorDriveOilLeak(number));
+            self
+        }
+
         fn running_emergency_generator(mut self) -> Self {
             self.command(|a| a.running_emergency_generator());
             self.run_waiting_for(Duration::from_secs(100))
@@ -2750,6 +2899,20 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn apu_start_motor_consuming(mut self, power: Power) -> Self {
+            self.command(|a| a.set_apu_start_motor_power(power));
+            self
+        }
+
+        fn feeder_resistance_enabled(mut self) -> Self {
+            self.write_by_name("ELEC_FEEDER_RESISTANCE_ENABLED", true);
+            self
+        }
+
+        fn dc_bat_bus_potential_normal(&mut self) -> bool {
+            self.read_by_name("ELEC_DC_BAT_BUS_POTENTIAL_NORMAL")
+        }
+
         fn apu_start_contactors_closed(&mut self) -> bool {
             self.read_by_name("ELEC_CONTACTOR_10KA_AND_5KA_IS_CLOSED")
         }
@@ -2856,6 +3019,10 @@ mod a320_electrical_circuit_tests {
             self.read_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_HAS_FAULT", number))
         }
 
+        fn idg_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_ELEC_IDG_{}_PB_HAS_FAULT", number))
+        }
+
         fn rat_and_emer_gen_has_fault(&mut self) -> bool {
             self.read_by_name("OVHD_EMER_ELEC_RAT_AND_EMER_GEN_HAS_FAULT")
         }
@@ -0,0 +1,291 @@
+use systems::air_conditioning::{
+    acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, ZoneType,
+};
+use systems::failures::FailureType;
+use systems::shared::{
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
+    HydraulicColor, LgciuId, ProximityDetectorId,
+};
+
+/// The failures which can be activated through the simulator, keyed by the
+/// identifier the flyPad uses to refer to them.
+pub fn identifiers() -> Vec<(u64, FailureType)> {
+    vec![
+        (
+            21_000,
+            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelOne)),
+        ),
+        (
+            21_001,
+            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelTwo)),
+        ),
+        (
+            21_002,
+            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelOne)),
+        ),
+        (
+            21_003,
+            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelTwo)),
+        ),
+        (21_004, FailureType::HotAir(1)),
+        (21_005, FailureType::TrimAirHighPressure),
+        (21_006, FailureType::TrimAirFault(ZoneType::Cockpit)),
+        (21_007, FailureType::TrimAirFault(ZoneType::Cabin(1))),
+        (21_008, FailureType::TrimAirFault(ZoneType::Cabin(2))),
+        (21_009, FailureType::TrimAirOverheat(ZoneType::Cockpit)),
+        (21_010, FailureType::TrimAirOverheat(ZoneType::Cabin(1))),
+        (21_011, FailureType::TrimAirOverheat(ZoneType::Cabin(2))),
+        (21_012, FailureType::CabinFan(1)),
+        (21_013, FailureType::CabinFan(2)),
+        (21_014, FailureType::GalleyFans),
+        (21_015, FailureType::CpcFault(CpcId::Cpc1)),
+        (21_016, FailureType::CpcFault(CpcId::Cpc2)),
+        (21_017, FailureType::OutflowValveFault),
+        (21_018, FailureType::SafetyValveFault),
+        (21_019, FailureType::RapidDecompression),
+        (24_000, FailureType::TransformerRectifier(1)),
+        (24_001, FailureType::TransformerRectifier(2)),
+        (24_002, FailureType::TransformerRectifier(3)),
+        (24_004, FailureType::StaticInverter),
+        (24_020, FailureType::Generator(1)),
+        (24_021, FailureType::Generator(2)),
+        (24_030, FailureType::ApuGenerator(1)),
+        (24_040, FailureType::GeneratorFeeder(1)),
+        (24_041, FailureType::GeneratorFeeder(2)),
+        (24_050, FailureType::ApuGeneratorFeeder(1)),
+        (24_060, FailureType::GeneratorDriveOilLeak(1)),
+        (24_061, FailureType::GeneratorDriveOilLeak(2)),
+        (
+            24_100,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+        ),
+        (
+            24_101,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+        ),
+        (
+            24_102,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+        ),
+        (
+            24_103,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+        ),
+        (
+            24_104,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentStaticInverter),
+        ),
+        (
+            24_105,
+            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+        ),
+        (
+            24_106,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+        ),
+        (
+            24_107,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+        ),
+        (
+            24_108,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+        ),
+        (
+            24_109,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssentialShed),
+        ),
+        (
+            24_110,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentBattery),
+        ),
+        (
+            24_111,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+        ),
+        (
+            24_112,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+        ),
+        (
+            24_113,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+        ),
+        (24_200, FailureType::CircuitBreaker("BLUE_ELEC_PUMP")),
+        (24_201, FailureType::CircuitBreaker("YELLOW_ELEC_PUMP")),
+        (28_000, FailureType::FuelPump(1)),
+        (28_001, FailureType::FuelPump(2)),
+        (28_002, FailureType::FuelPump(3)),
+        (28_003, FailureType::FuelPump(4)),
+        (28_004, FailureType::FuelPump(5)),
+        (28_005, FailureType::FuelPump(6)),
+        (28_010, FailureType::FuelQuantityProbe(1)),
+        (28_011, FailureType::FuelQuantityProbe(2)),
+        (28_012, FailureType::FuelQuantityProbe(3)),
+        (28_013, FailureType::FuelQuantityProbe(4)),
+        (28_014, FailureType::FuelQuantityProbe(5)),
+        (28_020, FailureType::FuelQuantityIndicationChannel(1)),
+        (28_021, FailureType::FuelQuantityIndicationChannel(2)),
+        (28_030, FailureType::FuelDensitometer),
+        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+        (
+            29_005,
+            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_006,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+        ),
+        (
+            29_007,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+        ),
+        (
+            29_008,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_009,
+            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+        ),
+        (
+            29_010,
+            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
+        ),
+        (
+            29_011,
+            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
+        ),
+        (
+            29_012,
+            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
+        ),
+        (
+            29_013,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Green),
+        ),
+        (
+            29_014,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::Blue),
+        ),
+        (
+            29_015,
+            FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Yellow),
+        ),
+        (
+            29_016,
+            FailureType::ElecPumpWear(AirbusElectricPumpId::Yellow),
+        ),
+        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+        (
+            32_004,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+        ),
+        (
+            32_005,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+        ),
+        (
+            32_006,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+        ),
+        (
+            32_007,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+        ),
+        (
+            32_008,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+        ),
+        (
+            32_009,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+        ),
+        (
+            32_010,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+        ),
+        (
+            32_011,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+        ),
+        (
+            32_012,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+        ),
+        (
+            32_013,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+        ),
+        (
+            32_014,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+        ),
+        (
+            32_015,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+        ),
+        (
+            32_020,
+            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_021,
+            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_022,
+            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_023,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_024,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_025,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_100,
+            FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
+        ),
+        (
+            32_101,
+            FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
+        ),
+        (32_150, FailureType::BrakeAccumulatorGasLeak),
+        (34_000, FailureType::RadioAltimeter(1)),
+        (34_001, FailureType::RadioAltimeter(2)),
+        (34_100, FailureType::Adiru(1)),
+        (34_101, FailureType::Adiru(2)),
+        (34_102, FailureType::Adiru(3)),
+        (34_110, FailureType::Adr(1)),
+        (34_111, FailureType::Adr(2)),
+        (34_112, FailureType::Adr(3)),
+        (34_120, FailureType::Ir(1)),
+        (34_121, FailureType::Ir(2)),
+        (34_122, FailureType::Ir(3)),
+        (34_130, FailureType::PitotProbeBlocked(1)),
+        (34_131, FailureType::PitotProbeBlocked(2)),
+        (34_132, FailureType::PitotProbeBlocked(3)),
+        (34_140, FailureType::StaticPortBlocked(1)),
+        (34_141, FailureType::StaticPortBlocked(2)),
+        (34_142, FailureType::StaticPortBlocked(3)),
+        (34_150, FailureType::AngleOfAttackVaneJammed(1)),
+        (34_151, FailureType::AngleOfAttackVaneJammed(2)),
+        (34_152, FailureType::AngleOfAttackVaneJammed(3)),
+        (34_200, FailureType::GpsSatelliteLoss(1)),
+        (34_201, FailureType::GpsSatelliteLoss(2)),
+    ]
+}
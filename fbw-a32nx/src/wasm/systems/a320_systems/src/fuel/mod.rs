@@ -1,11 +1,33 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Tank quantities are read from MSFS, after which fuel is fed to the engines and the APU and
+// transferred between the tanks by the pumps and valves below. The resulting quantities are
+// written back. The FADEC only tracks the engine fuel used and no longer changes the tanks.
+
+use std::time::Duration;
 
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelSystem, FuelTank},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    accept_iterable,
+    engine::Engine,
+    failures::{Failure, FailureType},
+    fuel::{
+        FuelCG, FuelInfo, FuelPayload, FuelQuantityIndicationSystem, FuelSystem, FuelTank,
+        FUEL_GALLONS_TO_KG,
+    },
+    overhead::{AutoManFaultPushButton, OnOffFaultPushButton, OnOffPushButton},
+    shared::{
+        ConsumePower, ElectricalBusType, ElectricalBuses, EngineFirePushButtons, EngineFuelFlow,
+        FuelTemperature, GeneratorDriveHeatRejection, HydraulicColor, HydraulicHeatRejection,
+    },
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    angle::degree, f64::*, mass::kilogram, power::watt, volume::gallon,
+    volume_rate::gallon_per_minute,
 };
-use uom::si::f64::*;
 
 #[cfg(test)]
 mod test;
@@ -18,6 +40,7 @@ pub trait FuelLevel {
     fn center_tank_has_fuel(&self) -> bool;
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum A320FuelTankType {
     Center,
     LeftInner,
@@ -25,6 +48,23 @@ pub enum A320FuelTankType {
     RightInner,
     RightOuter,
 }
+impl A320FuelTankType {
+    fn inner(side: usize) -> Self {
+        match side {
+            0 => A320FuelTankType::LeftInner,
+            1 => A320FuelTankType::RightInner,
+            i => panic!("Invalid wing side {}.", i),
+        }
+    }
+
+    fn outer(side: usize) -> Self {
+        match side {
+            0 => A320FuelTankType::LeftOuter,
+            1 => A320FuelTankType::RightOuter,
+            i => panic!("Invalid wing side {}.", i),
+        }
+    }
+}
 
 impl From<A320FuelTankType> for usize {
     fn from(value: A320FuelTankType) -> Self {
@@ -44,50 +84,623 @@ impl From<usize> for A320FuelTankType {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum A320FuelPumpId {
+    LeftTank1,
+    LeftTank2,
+    CenterTank1,
+    CenterTank2,
+    RightTank1,
+    RightTank2,
+}
+impl A320FuelPumpId {
+    const ALL: [A320FuelPumpId; 6] = [
+        A320FuelPumpId::LeftTank1,
+        A320FuelPumpId::LeftTank2,
+        A320FuelPumpId::CenterTank1,
+        A320FuelPumpId::CenterTank2,
+        A320FuelPumpId::RightTank1,
+        A320FuelPumpId::RightTank2,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            A320FuelPumpId::LeftTank1 => "L_TK_1",
+            A320FuelPumpId::LeftTank2 => "L_TK_2",
+            A320FuelPumpId::CenterTank1 => "CTR_TK_1",
+            A320FuelPumpId::CenterTank2 => "CTR_TK_2",
+            A320FuelPumpId::RightTank1 => "R_TK_1",
+            A320FuelPumpId::RightTank2 => "R_TK_2",
+        }
+    }
+
+    fn number(self) -> usize {
+        self as usize + 1
+    }
+
+    fn powered_by(self) -> ElectricalBusType {
+        match self {
+            A320FuelPumpId::LeftTank1
+            | A320FuelPumpId::CenterTank1
+            | A320FuelPumpId::RightTank1 => ElectricalBusType::AlternatingCurrent(1),
+            A320FuelPumpId::LeftTank2
+            | A320FuelPumpId::CenterTank2
+            | A320FuelPumpId::RightTank2 => ElectricalBusType::AlternatingCurrent(2),
+        }
+    }
+
+    fn tank(self) -> A320FuelTankType {
+        match self {
+            A320FuelPumpId::LeftTank1 | A320FuelPumpId::LeftTank2 => A320FuelTankType::LeftInner,
+            A320FuelPumpId::CenterTank1 | A320FuelPumpId::CenterTank2 => A320FuelTankType::Center,
+            A320FuelPumpId::RightTank1 | A320FuelPumpId::RightTank2 => A320FuelTankType::RightInner,
+        }
+    }
+
+    fn is_center_tank_pump(self) -> bool {
+        self.tank() == A320FuelTankType::Center
+    }
+
+    /// The pumps delivering into the feed line of the engine on the given side.
+    fn wing_tank_pumps(side: usize) -> [A320FuelPumpId; 2] {
+        match side {
+            0 => [A320FuelPumpId::LeftTank1, A320FuelPumpId::LeftTank2],
+            1 => [A320FuelPumpId::RightTank1, A320FuelPumpId::RightTank2],
+            i => panic!("Invalid wing side {}.", i),
+        }
+    }
+
+    fn center_tank_pump(side: usize) -> A320FuelPumpId {
+        match side {
+            0 => A320FuelPumpId::CenterTank1,
+            1 => A320FuelPumpId::CenterTank2,
+            i => panic!("Invalid wing side {}.", i),
+        }
+    }
+}
+
+pub struct A320FuelOverheadPanel {
+    tank_pumps: [OnOffFaultPushButton; 6],
+    mode_select: AutoManFaultPushButton,
+    crossfeed: OnOffPushButton,
+}
+impl A320FuelOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        A320FuelOverheadPanel {
+            tank_pumps: A320FuelPumpId::ALL.map(|id| {
+                OnOffFaultPushButton::new_on(context, &format!("FUEL_{}_PUMP", id.name()))
+            }),
+            mode_select: AutoManFaultPushButton::new_auto(context, "FUEL_MODE_SEL"),
+            crossfeed: OnOffPushButton::new_off(context, "FUEL_XFEED"),
+        }
+    }
+
+    pub fn update(&mut self, fuel: &A320Fuel) {
+        for id in A320FuelPumpId::ALL {
+            self.tank_pumps[id as usize].set_fault(fuel.pump_has_low_pressure(id));
+        }
+        self.mode_select
+            .set_fault(fuel.center_tank_fuel_is_not_used());
+    }
+
+    fn tank_pump_is_on(&self, id: A320FuelPumpId) -> bool {
+        self.tank_pumps[id as usize].is_on()
+    }
+
+    fn mode_select_is_auto(&self) -> bool {
+        self.mode_select.is_auto()
+    }
+
+    fn crossfeed_is_on(&self) -> bool {
+        self.crossfeed.is_on()
+    }
+}
+impl SimulationElement for A320FuelOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.tank_pumps, visitor);
+        self.mode_select.accept(visitor);
+        self.crossfeed.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct FuelPump {
+    is_running_id: VariableIdentifier,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    failure: Failure,
+    is_commanded: bool,
+    is_running: bool,
+}
+impl FuelPump {
+    const POWER_WATT: f64 = 700.;
+
+    fn new(context: &mut InitContext, id: A320FuelPumpId) -> Self {
+        Self {
+            is_running_id: context.get_identifier(format!("FUEL_{}_PUMP_IS_RUNNING", id.name())),
+            powered_by: id.powered_by(),
+            is_powered: false,
+            failure: Failure::new(FailureType::FuelPump(id.number())),
+            is_commanded: false,
+            is_running: false,
+        }
+    }
+
+    fn update(&mut self, is_commanded: bool) {
+        self.is_commanded = is_commanded;
+        self.is_running = is_commanded && self.is_powered && !self.failure.is_active();
+    }
+
+    fn is_commanded(&self) -> bool {
+        self.is_commanded
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_running
+    }
+}
+impl SimulationElement for FuelPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_running {
+            consumption.consume_from_bus(self.powered_by, Power::new::<watt>(Self::POWER_WATT));
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_running_id, self.is_running);
+    }
+}
+
+/// A valve driven by two electrical motors. It stays in its last position
+/// when neither motor is powered.
+struct FuelValve {
+    is_open_id: VariableIdentifier,
+    powered_by: [ElectricalBusType; 2],
+    is_powered: bool,
+    is_open: bool,
+}
+impl FuelValve {
+    fn new(context: &mut InitContext, name: &str, powered_by: [ElectricalBusType; 2]) -> Self {
+        Self {
+            is_open_id: context.get_identifier(format!("FUEL_{}_VALVE_IS_OPEN", name)),
+            powered_by,
+            is_powered: false,
+            is_open: false,
+        }
+    }
+
+    fn update(&mut self, should_open: bool) {
+        if self.is_powered {
+            self.is_open = should_open;
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+impl SimulationElement for FuelValve {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = self.powered_by.iter().any(|bus| buses.is_powered(*bus));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_open_id, self.is_open);
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_open);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_open = state.read();
+    }
+}
+
 pub struct A320Fuel {
+    slats_fppu_angle_id: VariableIdentifier,
+    slats_fppu_angle: Angle,
+    engine_master_switch_ids: [VariableIdentifier; 2],
+    engine_master_switches_on: [bool; 2],
+    apu_fuel_line_flow_id: VariableIdentifier,
+    apu_fuel_flow: VolumeRate,
+
     fuel_system: FuelSystem<5>,
+    quantity_indication: FuelQuantityIndicationSystem<5>,
+    pumps: [FuelPump; 6],
+    engine_lp_valves: [FuelValve; 2],
+    crossfeed_valve: FuelValve,
+    transfer_valves: [FuelValve; 2],
+    transfer_valves_latched_open: [bool; 2],
+
+    engines_were_running: [bool; 2],
+    center_tank_pumps_engine_start_run_time: Duration,
 }
 impl A320Fuel {
     pub const A320_FUEL: [FuelInfo<'static>; 5] = [
         FuelInfo {
-            fuel_tank_id: "FUEL TANK CENTER QUANTITY",
+            fuel_tank_id: "FUEL_TANK_QUANTITY_CENTER",
             position: (-4.5, 0., 1.),
             total_capacity_gallons: 2179.,
         },
         FuelInfo {
-            fuel_tank_id: "FUEL TANK LEFT MAIN QUANTITY",
+            fuel_tank_id: "FUEL_TANK_QUANTITY_LEFT_MAIN",
             position: (-8., -13., 2.),
             total_capacity_gallons: 1816.,
         },
         FuelInfo {
-            fuel_tank_id: "FUEL TANK LEFT AUX QUANTITY",
+            fuel_tank_id: "FUEL_TANK_QUANTITY_LEFT_AUX",
             position: (-16.9, -27., 3.),
             total_capacity_gallons: 228.,
         },
         FuelInfo {
-            fuel_tank_id: "FUEL TANK RIGHT MAIN QUANTITY",
+            fuel_tank_id: "FUEL_TANK_QUANTITY_RIGHT_MAIN",
             position: (-8., 13., 2.),
             total_capacity_gallons: 1816.,
         },
         FuelInfo {
-            fuel_tank_id: "FUEL TANK RIGHT AUX QUANTITY",
+            fuel_tank_id: "FUEL_TANK_QUANTITY_RIGHT_AUX",
             position: (-16.9, 27., 3.),
             total_capacity_gallons: 228.,
         },
     ];
 
+    const APU_FUEL_LINE_ID: u8 = 18;
+
+    const INNER_TANK_LOW_LEVEL_KG: f64 = 750.;
+    const OUTER_TO_INNER_TRANSFER_FLOW_KG_PER_S: f64 = 1.5;
+
+    // The center tank pumps keep running for two minutes after each engine start,
+    // even with the slats extended, to check their operation.
+    const CENTER_TANK_PUMPS_ENGINE_START_RUN_TIME: Duration = Duration::from_secs(120);
+    const SLATS_RETRACTED_MAX_FPPU_ANGLE_DEGREES: f64 = 6.;
+
+    const CENTER_TANK_UNUSED_FUEL_THRESHOLD_KG: f64 = 250.;
+    const WING_TANK_LOW_FUEL_THRESHOLD_KG: f64 = 5000.;
+
     pub fn new(context: &mut InitContext) -> Self {
         let fuel_tanks = Self::A320_FUEL.map(|f| {
             FuelTank::new(
                 context,
                 f.fuel_tank_id,
                 Vector3::new(f.position.0, f.position.1, f.position.2),
-                false,
+                true,
             )
         });
         A320Fuel {
+            slats_fppu_angle_id: context.get_identifier("SLATS_FPPU_ANGLE".to_owned()),
+            slats_fppu_angle: Angle::default(),
+            engine_master_switch_ids: [1, 2]
+                .map(|id| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{}", id))),
+            engine_master_switches_on: [false; 2],
+            apu_fuel_line_flow_id: context.get_identifier(format!(
+                "FUELSYSTEM LINE FUEL FLOW:{}",
+                Self::APU_FUEL_LINE_ID
+            )),
+            apu_fuel_flow: VolumeRate::default(),
+
             fuel_system: FuelSystem::new(context, fuel_tanks),
+            // The densitometer is in the left inner tank.
+            quantity_indication: FuelQuantityIndicationSystem::new(
+                context,
+                Self::A320_FUEL,
+                A320FuelTankType::LeftInner.into(),
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            pumps: A320FuelPumpId::ALL.map(|id| FuelPump::new(context, id)),
+            engine_lp_valves: [1, 2].map(|id| {
+                FuelValve::new(
+                    context,
+                    &format!("ENG_{}_LP", id),
+                    [
+                        ElectricalBusType::DirectCurrentHot(1),
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                )
+            }),
+            crossfeed_valve: FuelValve::new(
+                context,
+                "XFEED",
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            transfer_valves: ["L", "R"].map(|side| {
+                FuelValve::new(
+                    context,
+                    &format!("{}_TRANSFER", side),
+                    [
+                        ElectricalBusType::DirectCurrentEssential,
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                )
+            }),
+            transfer_valves_latched_open: [false; 2],
+
+            engines_were_running: [false; 2],
+            center_tank_pumps_engine_start_run_time: Duration::default(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A320FuelOverheadPanel,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        engines: [&(impl Engine + EngineFuelFlow); 2],
+        hydraulic: &impl HydraulicHeatRejection,
+        generator_drives: &impl GeneratorDriveHeatRejection,
+    ) {
+        self.update_center_tank_pumps_engine_start_run(context, engines);
+        self.update_pumps(overhead);
+
+        for (index, valve) in self.engine_lp_valves.iter_mut().enumerate() {
+            valve.update(
+                self.engine_master_switches_on[index]
+                    && !engine_fire_push_buttons.is_released(index + 1),
+            );
+        }
+        self.crossfeed_valve.update(overhead.crossfeed_is_on());
+        self.update_transfer_valves(context);
+
+        if !self.fuel_system.has_unlimited_fuel() {
+            self.transfer_outer_to_inner_tanks(context);
+
+            let mut demands = [0, 1].map(|index| {
+                if self.engine_lp_valves[index].is_open() {
+                    engines[index].fuel_flow() * context.delta_as_time()
+                } else {
+                    Mass::default()
+                }
+            });
+            // The APU is supplied by the left feed line, upstream of the engine 1 LP valve.
+            demands[0] += Mass::new::<kilogram>(
+                (self.apu_fuel_flow * context.delta_as_time()).get::<gallon>() * FUEL_GALLONS_TO_KG,
+            );
+            self.feed_engines(demands);
         }
+
+        self.update_temperatures(context, hydraulic, generator_drives);
+        self.quantity_indication.update(context, &self.fuel_system);
+    }
+
+    /// The green and blue hydraulic heat exchangers are in the left inner tank and the yellow
+    /// one in the right inner tank. The IDG cooling fuel returns to the outer tanks.
+    fn update_temperatures(
+        &mut self,
+        context: &UpdateContext,
+        hydraulic: &impl HydraulicHeatRejection,
+        generator_drives: &impl GeneratorDriveHeatRejection,
+    ) {
+        let mut heat_rejected = [Power::default(); 5];
+        heat_rejected[usize::from(A320FuelTankType::LeftInner)] = hydraulic
+            .heat_rejected_to_fuel(HydraulicColor::Green)
+            + hydraulic.heat_rejected_to_fuel(HydraulicColor::Blue);
+        heat_rejected[usize::from(A320FuelTankType::RightInner)] =
+            hydraulic.heat_rejected_to_fuel(HydraulicColor::Yellow);
+        heat_rejected[usize::from(A320FuelTankType::LeftOuter)] =
+            generator_drives.generator_drive_heat_rejected_to_fuel(1);
+        heat_rejected[usize::from(A320FuelTankType::RightOuter)] =
+            generator_drives.generator_drive_heat_rejected_to_fuel(2);
+
+        self.fuel_system.update_temperatures(context, heat_rejected);
+    }
+
+    fn tank_temperature(&self, tank: A320FuelTankType) -> ThermodynamicTemperature {
+        self.fuel_system.tank_temperature(tank.into())
+    }
+
+    fn update_center_tank_pumps_engine_start_run(
+        &mut self,
+        context: &UpdateContext,
+        engines: [&impl Engine; 2],
+    ) {
+        self.center_tank_pumps_engine_start_run_time = self
+            .center_tank_pumps_engine_start_run_time
+            .saturating_sub(context.delta());
+
+        for (was_running, engine) in self.engines_were_running.iter_mut().zip(engines) {
+            let is_running = engine.is_above_minimum_idle();
+            if is_running && !*was_running {
+                self.center_tank_pumps_engine_start_run_time =
+                    Self::CENTER_TANK_PUMPS_ENGINE_START_RUN_TIME;
+            }
+            *was_running = is_running;
+        }
+    }
+
+    fn update_pumps(&mut self, overhead: &A320FuelOverheadPanel) {
+        let center_tank_pumps_should_run = !overhead.mode_select_is_auto()
+            || (self.center_tank_has_fuel()
+                && (self.slats_are_retracted()
+                    || self.center_tank_pumps_engine_start_run_time > Duration::ZERO));
+
+        for id in A320FuelPumpId::ALL {
+            self.pumps[id as usize].update(
+                overhead.tank_pump_is_on(id)
+                    && (!id.is_center_tank_pump() || center_tank_pumps_should_run),
+            );
+        }
+    }
+
+    /// The transfer valves open when the inner tank reaches its low level and stay
+    /// latched open until the aircraft is on ground with both engines shut down.
+    fn update_transfer_valves(&mut self, context: &UpdateContext) {
+        let engines_are_shut_down = self.engine_lp_valves.iter().all(|valve| !valve.is_open());
+        let inner_tanks_are_low = [0, 1].map(|side| {
+            self.tank_quantity(A320FuelTankType::inner(side))
+                < Mass::new::<kilogram>(Self::INNER_TANK_LOW_LEVEL_KG)
+        });
+
+        for ((valve, is_latched_open), inner_tank_is_low) in self
+            .transfer_valves
+            .iter_mut()
+            .zip(self.transfer_valves_latched_open.iter_mut())
+            .zip(inner_tanks_are_low)
+        {
+            if inner_tank_is_low {
+                *is_latched_open = true;
+            } else if context.is_on_ground() && engines_are_shut_down {
+                *is_latched_open = false;
+            }
+
+            valve.update(*is_latched_open);
+        }
+    }
+
+    fn transfer_outer_to_inner_tanks(&mut self, context: &UpdateContext) {
+        for side in [0, 1] {
+            if !self.transfer_valves[side].is_open() {
+                continue;
+            }
+
+            let outer = A320FuelTankType::outer(side);
+            let inner = A320FuelTankType::inner(side);
+            let inner_space =
+                (Self::tank_capacity(inner) - self.tank_quantity(inner)).max(Mass::default());
+            let transferred = Mass::new::<kilogram>(
+                Self::OUTER_TO_INNER_TRANSFER_FLOW_KG_PER_S * context.delta_as_secs_f64(),
+            )
+            .min(self.tank_quantity(outer))
+            .min(inner_space);
+
+            self.set_tank_quantity(outer, self.tank_quantity(outer) - transferred);
+            self.set_tank_quantity(inner, self.tank_quantity(inner) + transferred);
+        }
+    }
+
+    fn feed_engines(&mut self, demands: [Mass; 2]) {
+        let not_supplied = if self.crossfeed_valve.is_open() {
+            let total_demand = demands[0] + demands[1];
+            let not_supplied = self.feed_from_pressurised_tanks(total_demand, &[0, 1]);
+
+            if total_demand > Mass::default() {
+                demands.map(|demand| {
+                    not_supplied * (demand.get::<kilogram>() / total_demand.get::<kilogram>())
+                })
+            } else {
+                [Mass::default(); 2]
+            }
+        } else {
+            [
+                self.feed_from_pressurised_tanks(demands[0], &[0]),
+                self.feed_from_pressurised_tanks(demands[1], &[1]),
+            ]
+        };
+
+        // Without pump pressure, each engine suction feeds from its own inner tank.
+        for (side, demand) in not_supplied.iter().enumerate() {
+            self.draw_fuel(*demand, &[A320FuelTankType::inner(side)]);
+        }
+    }
+
+    /// Feeds the demanded fuel from the tanks with running pumps connected to the feed
+    /// lines of the given sides. The center tank pumps deliver a higher pressure than the
+    /// wing tank pumps, thus the wing tanks only supply what the center tank cannot.
+    fn feed_from_pressurised_tanks(&mut self, demand: Mass, sides: &[usize]) -> Mass {
+        let center_tank_is_pressurised = sides
+            .iter()
+            .any(|&side| self.pumps[A320FuelPumpId::center_tank_pump(side) as usize].is_running());
+        let not_supplied = if center_tank_is_pressurised {
+            self.draw_fuel(demand, &[A320FuelTankType::Center])
+        } else {
+            demand
+        };
+
+        let pressurised_wing_tanks: Vec<_> = sides
+            .iter()
+            .filter(|&&side| {
+                A320FuelPumpId::wing_tank_pumps(side)
+                    .iter()
+                    .any(|&id| self.pumps[id as usize].is_running())
+            })
+            .map(|&side| A320FuelTankType::inner(side))
+            .collect();
+
+        self.draw_fuel(not_supplied, &pressurised_wing_tanks)
+    }
+
+    /// Draws the demanded fuel evenly from the given tanks. Whatever a tank running dry
+    /// cannot supply is drawn from the others. Returns the fuel which couldn't be drawn.
+    fn draw_fuel(&mut self, demand: Mass, tanks: &[A320FuelTankType]) -> Mass {
+        let mut not_drawn = demand;
+        let mut tanks_with_fuel: Vec<_> = tanks
+            .iter()
+            .copied()
+            .filter(|&tank| self.tank_quantity(tank) > Mass::default())
+            .collect();
+
+        for _ in 0..tanks.len() {
+            if tanks_with_fuel.is_empty() || not_drawn <= Mass::default() {
+                break;
+            }
+
+            let share = not_drawn / tanks_with_fuel.len() as f64;
+            for &tank in &tanks_with_fuel {
+                let drawn = share.min(self.tank_quantity(tank));
+                self.set_tank_quantity(tank, self.tank_quantity(tank) - drawn);
+                not_drawn -= drawn;
+            }
+
+            tanks_with_fuel.retain(|&tank| self.tank_quantity(tank) > Mass::default());
+        }
+
+        not_drawn.max(Mass::default())
+    }
+
+    fn tank_capacity(tank: A320FuelTankType) -> Mass {
+        Mass::new::<kilogram>(
+            Self::A320_FUEL[usize::from(tank)].total_capacity_gallons * FUEL_GALLONS_TO_KG,
+        )
+    }
+
+    fn tank_quantity(&self, tank: A320FuelTankType) -> Mass {
+        self.fuel_system.tank_mass(tank.into())
+    }
+
+    fn set_tank_quantity(&mut self, tank: A320FuelTankType, quantity: Mass) {
+        self.fuel_system
+            .set_tank_quantity(tank.into(), quantity.max(Mass::default()));
+    }
+
+    fn slats_are_retracted(&self) -> bool {
+        self.slats_fppu_angle.get::<degree>() < Self::SLATS_RETRACTED_MAX_FPPU_ANGLE_DEGREES
+    }
+
+    fn pump_has_low_pressure(&self, id: A320FuelPumpId) -> bool {
+        let pump = &self.pumps[id as usize];
+        pump.is_commanded()
+            && !(pump.is_running() && self.fuel_system.tank_has_fuel(id.tank().into()))
+    }
+
+    /// Indicates the center tank still holds fuel while a wing tank is running low.
+    fn center_tank_fuel_is_not_used(&self) -> bool {
+        let wing_tank_quantity = |side| {
+            self.tank_quantity(A320FuelTankType::inner(side))
+                + self.tank_quantity(A320FuelTankType::outer(side))
+        };
+
+        self.tank_quantity(A320FuelTankType::Center)
+            > Mass::new::<kilogram>(Self::CENTER_TANK_UNUSED_FUEL_THRESHOLD_KG)
+            && (0..2).any(|side| {
+                wing_tank_quantity(side)
+                    < Mass::new::<kilogram>(Self::WING_TANK_LOW_FUEL_THRESHOLD_KG)
+            })
     }
 
     pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
@@ -149,6 +762,23 @@ impl FuelLevel for A320Fuel {
         self.center_tank_has_fuel()
     }
 }
+impl FuelTemperature for A320Fuel {
+    fn hydraulic_heat_exchanger_fuel_temperature(
+        &self,
+        color: HydraulicColor,
+    ) -> ThermodynamicTemperature {
+        match color {
+            HydraulicColor::Green | HydraulicColor::Blue => {
+                self.tank_temperature(A320FuelTankType::LeftInner)
+            }
+            HydraulicColor::Yellow => self.tank_temperature(A320FuelTankType::RightInner),
+        }
+    }
+
+    fn engine_feed_fuel_temperature(&self, engine_number: usize) -> ThermodynamicTemperature {
+        self.tank_temperature(A320FuelTankType::inner(engine_number - 1))
+    }
+}
 impl FuelPayload for A320Fuel {
     fn total_load(&self) -> Mass {
         self.total_load()
@@ -156,6 +786,10 @@ impl FuelPayload for A320Fuel {
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fore_aft_center_of_gravity()
     }
+
+    fn tank_mass(&self, t: usize) -> Mass {
+        self.fuel_system.tank_mass(t)
+    }
 }
 impl FuelCG for A320Fuel {
     fn center_of_gravity(&self) -> Vector3<f64> {
@@ -165,6 +799,47 @@ impl FuelCG for A320Fuel {
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        self.quantity_indication.accept(visitor);
+        accept_iterable!(self.pumps, visitor);
+        accept_iterable!(self.engine_lp_valves, visitor);
+        self.crossfeed_valve.accept(visitor);
+        accept_iterable!(self.transfer_valves, visitor);
+
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.slats_fppu_angle = reader.read(&self.slats_fppu_angle_id);
+        for (id, is_on) in self
+            .engine_master_switch_ids
+            .iter()
+            .zip(self.engine_master_switches_on.iter_mut())
+        {
+            *is_on = reader.read(id);
+        }
+
+        let apu_fuel_flow_gallon_per_hour: f64 = reader.read(&self.apu_fuel_line_flow_id);
+        self.apu_fuel_flow =
+            VolumeRate::new::<gallon_per_minute>(apu_fuel_flow_gallon_per_hour / 60.);
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        for is_latched_open in self.transfer_valves_latched_open {
+            state.write(is_latched_open);
+        }
+        for was_running in self.engines_were_running {
+            state.write(was_running);
+        }
+        state.write(self.center_tank_pumps_engine_start_run_time);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        for is_latched_open in self.transfer_valves_latched_open.iter_mut() {
+            *is_latched_open = state.read();
+        }
+        for was_running in self.engines_were_running.iter_mut() {
+            *was_running = state.read();
+        }
+        self.center_tank_pumps_engine_start_run_time = state.read();
+    }
 }
@@ -1,31 +1,142 @@
 use std::time::Duration;
 
 use super::*;
-use crate::systems::simulation::{
-    test::{SimulationTestBed, TestBed, WriteByName},
-    Aircraft, SimulationElement, SimulationElementVisitor,
+use crate::systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    engine::leap_engine::LeapEngine,
+    fuel::FuelType,
+    shared::{arinc429::Arinc429Word, PotentialOrigin},
+    simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElement, SimulationElementVisitor,
+    },
 };
+use uom::si::{power::watt, thermodynamic_temperature::degree_celsius};
+
+struct TestEngineFirePushButtons {
+    is_released: [bool; 2],
+}
+impl TestEngineFirePushButtons {
+    fn new() -> Self {
+        Self {
+            is_released: [false, false],
+        }
+    }
+
+    fn release(&mut self, engine_number: usize) {
+        self.is_released[engine_number - 1] = true;
+    }
+}
+impl EngineFirePushButtons for TestEngineFirePushButtons {
+    fn is_released(&self, engine_number: usize) -> bool {
+        self.is_released[engine_number - 1]
+    }
+}
+
+#[derive(Default)]
+struct TestHeatSources {
+    hydraulic_heat: Power,
+    generator_drive_heat: Power,
+}
+impl HydraulicHeatRejection for TestHeatSources {
+    fn heat_rejected_to_fuel(&self, _color: HydraulicColor) -> Power {
+        self.hydraulic_heat
+    }
+}
+impl GeneratorDriveHeatRejection for TestHeatSources {
+    fn generator_drive_heat_rejected_to_fuel(&self, _engine_number: usize) -> Power {
+        self.generator_drive_heat
+    }
+}
 
 struct FuelTestAircraft {
     fuel: A320Fuel,
+    fuel_overhead: A320FuelOverheadPanel,
+    engine_1: LeapEngine,
+    engine_2: LeapEngine,
+    fire_push_buttons: TestEngineFirePushButtons,
+    heat_sources: TestHeatSources,
+
+    powered_source: TestElectricitySource,
+    buses: [ElectricalBus; 5],
+    is_powered: bool,
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             fuel: A320Fuel::new(context),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
+            engine_1: LeapEngine::new(context, 1),
+            engine_2: LeapEngine::new(context, 2),
+            fire_push_buttons: TestEngineFirePushButtons::new(),
+            heat_sources: TestHeatSources::default(),
+            powered_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: [
+                ElectricalBusType::AlternatingCurrent(1),
+                ElectricalBusType::AlternatingCurrent(2),
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrentHot(1),
+            ]
+            .map(|bus_type| ElectricalBus::new(context, bus_type)),
+            is_powered: false,
         }
     }
 
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
+
+    fn set_powered(&mut self, is_powered: bool) {
+        self.is_powered = is_powered;
+    }
+
+    fn release_fire_push_button(&mut self, engine_number: usize) {
+        self.fire_push_buttons.release(engine_number);
+    }
+
+    fn set_generator_drive_heat(&mut self, heat: Power) {
+        self.heat_sources.generator_drive_heat = heat;
+    }
 }
 
-impl Aircraft for FuelTestAircraft {}
+impl Aircraft for FuelTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _context: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        if self.is_powered {
+            electricity.supplied_by(&self.powered_source);
+            for bus in &self.buses {
+                electricity.flow(&self.powered_source, bus);
+            }
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.fire_push_buttons,
+            [&self.engine_1, &self.engine_2],
+            &self.heat_sources,
+            &self.heat_sources,
+        );
+        self.fuel_overhead.update(&self.fuel);
+    }
+}
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
+        self.engine_1.accept(visitor);
+        self.engine_2.accept(visitor);
+        accept_iterable!(self.buses, visitor);
 
         visitor.visit(self);
     }
@@ -60,11 +171,11 @@ impl FuelTestBed {
     }
 
     fn fuel_low(mut self) -> Self {
-        self.write_by_name("FUEL TANK LEFT MAIN QUANTITY", 324. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK LEFT AUX QUANTITY", 150. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK RIGHT MAIN QUANTITY", 324. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK RIGHT AUX QUANTITY", 150. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK CENTER QUANTITY", 0. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_LEFT_MAIN", 324. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_LEFT_AUX", 150. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_RIGHT_MAIN", 324. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_RIGHT_AUX", 150. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_CENTER", 0. / FUEL_GALLONS_TO_KG);
 
         self.write_by_name("FUEL TOTAL QUANTITY WEIGHT", 1248. / LBS_TO_KG);
 
@@ -72,16 +183,148 @@ impl FuelTestBed {
     }
 
     fn fuel_high(mut self) -> Self {
-        self.write_by_name("FUEL TANK LEFT MAIN QUANTITY", 1600. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK LEFT AUX QUANTITY", 200. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK RIGHT MAIN QUANTITY", 1600. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK RIGHT AUX QUANTITY", 200. / FUEL_GALLONS_TO_KG);
-        self.write_by_name("FUEL TANK CENTER QUANTITY", 0. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_LEFT_MAIN", 1600. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_LEFT_AUX", 200. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_RIGHT_MAIN", 1600. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_RIGHT_AUX", 200. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL_TANK_QUANTITY_CENTER", 0. / FUEL_GALLONS_TO_KG);
         self.write_by_name("FUEL TOTAL QUANTITY WEIGHT", 3600. / LBS_TO_KG);
 
         self
     }
 
+    fn and_powered(mut self) -> Self {
+        self.command(|a| a.set_powered(true));
+
+        self
+    }
+
+    fn with_tank_quantity(mut self, tank_id: &str, quantity_kg: f64) -> Self {
+        self.write_by_name(tank_id, quantity_kg / FUEL_GALLONS_TO_KG);
+
+        self
+    }
+
+    fn with_engines_running(mut self, fuel_flow_kg_per_hour: f64) -> Self {
+        for engine_number in 1..=2 {
+            self.write_by_name(&format!("FUELSYSTEM VALVE SWITCH:{}", engine_number), true);
+            self.write_by_name(&format!("ENGINE_N2:{}", engine_number), 65.);
+            self.write_by_name(
+                &format!("ENGINE_FF:{}", engine_number),
+                fuel_flow_kg_per_hour,
+            );
+        }
+
+        self
+    }
+
+    fn with_apu_running(mut self, fuel_flow_gallon_per_hour: f64) -> Self {
+        self.write_by_name("FUELSYSTEM LINE FUEL FLOW:18", fuel_flow_gallon_per_hour);
+
+        self
+    }
+
+    fn with_slats_extended(mut self) -> Self {
+        self.write_by_name("SLATS_FPPU_ANGLE", 222.27);
+
+        self
+    }
+
+    fn with_crossfeed_on(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_XFEED_PB_IS_ON", true);
+
+        self
+    }
+
+    fn with_left_tank_pumps_off(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_L_TK_1_PUMP_PB_IS_ON", false);
+        self.write_by_name("OVHD_FUEL_L_TK_2_PUMP_PB_IS_ON", false);
+
+        self
+    }
+
+    fn with_fire_push_button_released(mut self, engine_number: usize) -> Self {
+        self.command(|a| a.release_fire_push_button(engine_number));
+
+        self
+    }
+
+    fn with_ambient_temperature(mut self, celsius: f64) -> Self {
+        self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(celsius));
+
+        self
+    }
+
+    fn with_mach_number(mut self, mach: f64) -> Self {
+        self.write_by_name("AIRSPEED MACH", mach);
+
+        self
+    }
+
+    fn with_generator_drive_heat(mut self, watts: f64) -> Self {
+        self.command(|a| a.set_generator_drive_heat(Power::new::<watt>(watts)));
+
+        self
+    }
+
+    fn with_refuel_fuel(mut self, fuel_type: FuelType, temperature_celsius: f64) -> Self {
+        self.write_by_name("REFUEL_FUEL_TYPE", fuel_type);
+        self.write_by_name("REFUEL_FUEL_TEMPERATURE", temperature_celsius);
+
+        self
+    }
+
+    fn run_for(mut self, duration: Duration) -> Self {
+        self.test_bed.run_multiple_frames(duration);
+
+        self
+    }
+
+    /// Runs in one minute steps, for the slow heat transfers of the fuel.
+    fn soak_for(mut self, duration: Duration) -> Self {
+        for _ in 0..duration.as_secs() / MINUTES_TO_SECONDS {
+            self.run_with_delta(Duration::from_secs(MINUTES_TO_SECONDS));
+        }
+
+        self
+    }
+
+    fn tank_quantity(&mut self, tank_id: &str) -> f64 {
+        let quantity_gallons: f64 = self.read_by_name(tank_id);
+        quantity_gallons * FUEL_GALLONS_TO_KG
+    }
+
+    fn is_true(&mut self, name: &str) -> bool {
+        self.read_by_name(name)
+    }
+
+    fn tank_temperature(&mut self, tank_id: &str) -> f64 {
+        let temperature: ThermodynamicTemperature = self.read_by_name(tank_id);
+        temperature.get::<degree_celsius>()
+    }
+
+    fn freezing_point(&mut self) -> f64 {
+        let temperature: ThermodynamicTemperature = self.read_by_name("FUEL_FREEZING_POINT");
+        temperature.get::<degree_celsius>()
+    }
+
+    fn with_pitch_attitude(mut self, degrees: f64) -> Self {
+        self.write_by_name("PLANE PITCH DEGREES", degrees);
+
+        self
+    }
+
+    fn with_bank_attitude(mut self, degrees: f64) -> Self {
+        self.write_by_name("PLANE BANK DEGREES", degrees);
+
+        self
+    }
+
+    fn indicated_quantity(&mut self, id: &str) -> Option<f64> {
+        let quantity: Arinc429Word<f64> = self.read_arinc429_by_name(id);
+        quantity.normal_value()
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
@@ -111,11 +354,11 @@ fn test_bed_with() -> FuelTestBed {
 fn init() {
     let test_bed = test_bed_with().fuel_low();
 
-    assert!(test_bed.contains_variable_with_name("FUEL TANK LEFT MAIN QUANTITY"));
-    assert!(test_bed.contains_variable_with_name("FUEL TANK LEFT AUX QUANTITY"));
-    assert!(test_bed.contains_variable_with_name("FUEL TANK RIGHT MAIN QUANTITY"));
-    assert!(test_bed.contains_variable_with_name("FUEL TANK RIGHT AUX QUANTITY"));
-    assert!(test_bed.contains_variable_with_name("FUEL TANK CENTER QUANTITY"));
+    assert!(test_bed.contains_variable_with_name("FUEL_TANK_QUANTITY_LEFT_MAIN"));
+    assert!(test_bed.contains_variable_with_name("FUEL_TANK_QUANTITY_LEFT_AUX"));
+    assert!(test_bed.contains_variable_with_name("FUEL_TANK_QUANTITY_RIGHT_MAIN"));
+    assert!(test_bed.contains_variable_with_name("FUEL_TANK_QUANTITY_RIGHT_AUX"));
+    assert!(test_bed.contains_variable_with_name("FUEL_TANK_QUANTITY_CENTER"));
     assert!(test_bed.contains_variable_with_name("FUEL TOTAL QUANTITY WEIGHT"));
 }
 
@@ -144,3 +387,372 @@ fn high_fuel() {
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
 }
+
+#[test]
+fn engines_are_fed_from_their_own_inner_tank() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_engines_running(3600.)
+        .run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN") - 2940.).abs() < 1.);
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN") - 2940.).abs() < 1.);
+}
+
+#[test]
+fn apu_is_fed_from_the_left_inner_tank() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_apu_running(600.)
+        .run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    let apu_burn = 10. * FUEL_GALLONS_TO_KG;
+    assert!(
+        (test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN") - (3000. - apu_burn)).abs() < 1.
+    );
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN") - 3000.).abs() < 1.);
+}
+
+#[test]
+fn center_tank_has_priority_with_slats_retracted() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_CENTER", 2000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_engines_running(3600.)
+        .run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_CENTER") - 1880.).abs() < 1.);
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN") - 3000.).abs() < 1.);
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN") - 3000.).abs() < 1.);
+}
+
+#[test]
+fn center_tank_pumps_stop_after_engine_start_run_with_slats_extended() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_slats_extended()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_CENTER", 2000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_engines_running(3600.)
+        .run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.is_true("FUEL_CTR_TK_1_PUMP_IS_RUNNING"));
+
+    test_bed = test_bed.run_for(Duration::from_secs(2 * MINUTES_TO_SECONDS));
+
+    assert!(!test_bed.is_true("FUEL_CTR_TK_1_PUMP_IS_RUNNING"));
+    assert!(!test_bed.is_true("OVHD_FUEL_CTR_TK_1_PUMP_PB_HAS_FAULT"));
+    assert!(test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN") < 3000.);
+}
+
+#[test]
+fn crossfeed_with_left_tank_pumps_off_feeds_both_engines_from_right_tank() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_crossfeed_on()
+        .with_left_tank_pumps_off()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_engines_running(3600.)
+        .run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.is_true("FUEL_XFEED_VALVE_IS_OPEN"));
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN") - 3000.).abs() < 1.);
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN") - 2880.).abs() < 1.);
+}
+
+#[test]
+fn failed_tank_pumps_show_fault_and_cause_imbalance_with_crossfeed_open() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_crossfeed_on()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_engines_running(3600.);
+    test_bed.fail(FailureType::FuelPump(5));
+    test_bed.fail(FailureType::FuelPump(6));
+    test_bed = test_bed.run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.is_true("OVHD_FUEL_R_TK_1_PUMP_PB_HAS_FAULT"));
+    assert!(test_bed.is_true("OVHD_FUEL_R_TK_2_PUMP_PB_HAS_FAULT"));
+    assert!(!test_bed.is_true("OVHD_FUEL_L_TK_1_PUMP_PB_HAS_FAULT"));
+    assert!(
+        test_bed.tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN")
+            - test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN")
+            > 100.
+    );
+}
+
+#[test]
+fn outer_tank_transfers_into_inner_tank_at_low_level() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 700.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_AUX", 600.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_AUX", 600.)
+        .run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(test_bed.is_true("FUEL_L_TRANSFER_VALVE_IS_OPEN"));
+    assert!(!test_bed.is_true("FUEL_R_TRANSFER_VALVE_IS_OPEN"));
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_AUX") - 510.).abs() < 1.);
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN") - 790.).abs() < 1.);
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_RIGHT_AUX") - 600.).abs() < 1.);
+}
+
+#[test]
+fn engine_fire_push_button_closes_lp_valve() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .with_engines_running(3600.)
+        .with_fire_push_button_released(1)
+        .run_for(Duration::from_secs(MINUTES_TO_SECONDS));
+
+    assert!(!test_bed.is_true("FUEL_ENG_1_LP_VALVE_IS_OPEN"));
+    assert!(test_bed.is_true("FUEL_ENG_2_LP_VALVE_IS_OPEN"));
+    assert!((test_bed.tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN") - 3000.).abs() < 1.);
+}
+
+#[test]
+fn mode_select_shows_fault_when_center_tank_fuel_is_not_used() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_CENTER", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 4000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 6000.)
+        .and_run();
+
+    assert!(test_bed.is_true("OVHD_FUEL_MODE_SEL_PB_HAS_FAULT"));
+}
+
+#[test]
+fn unpowered_pumps_do_not_run() {
+    let mut test_bed = test_bed_with()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .and_run();
+
+    assert!(!test_bed.is_true("FUEL_L_TK_1_PUMP_IS_RUNNING"));
+    assert!(test_bed.is_true("OVHD_FUEL_L_TK_1_PUMP_PB_HAS_FAULT"));
+}
+
+#[test]
+fn fuel_starts_at_ambient_temperature() {
+    let mut test_bed = test_bed_with()
+        .with_ambient_temperature(25.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .and_run();
+
+    assert!((test_bed.tank_temperature("FUEL_TANK_TEMPERATURE_LEFT_MAIN") - 25.).abs() < 0.1);
+}
+
+#[test]
+fn fuel_cold_soaks_towards_total_air_temperature_in_cruise() {
+    let mut test_bed = test_bed_with()
+        .with_ambient_temperature(0.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .and_run()
+        .with_ambient_temperature(-56.)
+        .with_mach_number(0.78)
+        .soak_for(Duration::from_secs(8 * 60 * MINUTES_TO_SECONDS));
+
+    let temperature = test_bed.tank_temperature("FUEL_TANK_TEMPERATURE_LEFT_MAIN");
+    assert!(temperature < -20.);
+    // Ram air heating keeps the fuel well above the static air temperature.
+    assert!(temperature > -40.);
+}
+
+#[test]
+fn smaller_fuel_quantity_cools_down_faster() {
+    let mut test_bed = test_bed_with()
+        .with_ambient_temperature(0.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 5000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 1000.)
+        .and_run()
+        .with_ambient_temperature(-40.)
+        .soak_for(Duration::from_secs(60 * MINUTES_TO_SECONDS));
+
+    assert!(
+        test_bed.tank_temperature("FUEL_TANK_TEMPERATURE_RIGHT_MAIN")
+            < test_bed.tank_temperature("FUEL_TANK_TEMPERATURE_LEFT_MAIN")
+    );
+}
+
+#[test]
+fn generator_drive_heat_warms_outer_tank_fuel() {
+    let mut test_bed = test_bed_with()
+        .with_ambient_temperature(-20.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_AUX", 600.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_generator_drive_heat(5000.)
+        .soak_for(Duration::from_secs(60 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_temperature("FUEL_TANK_TEMPERATURE_LEFT_AUX") > -10.);
+    assert!((test_bed.tank_temperature("FUEL_TANK_TEMPERATURE_LEFT_MAIN") + 20.).abs() < 0.1);
+}
+
+#[test]
+fn refuelled_fuel_mixes_with_fuel_in_tank() {
+    let mut test_bed = test_bed_with()
+        .with_ambient_temperature(20.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 2000.)
+        .and_run()
+        .with_refuel_fuel(FuelType::JetA1, -10.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 4000.);
+    test_bed.run_with_delta(Duration::from_millis(10));
+
+    assert!((test_bed.tank_temperature("FUEL_TANK_TEMPERATURE_LEFT_MAIN") - 5.).abs() < 0.5);
+}
+
+#[test]
+fn freezing_point_follows_the_loaded_fuel_type() {
+    let mut test_bed = test_bed_with()
+        .with_refuel_fuel(FuelType::JetA1, 15.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 2000.)
+        .and_run();
+
+    assert!((test_bed.freezing_point() + 47.).abs() < 0.1);
+
+    test_bed = test_bed
+        .with_refuel_fuel(FuelType::JetA, 15.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .and_run();
+
+    // A blend is assumed to freeze at the highest freezing point of its components.
+    assert!((test_bed.freezing_point() + 40.).abs() < 0.1);
+}
+
+#[test]
+fn indicated_quantity_matches_tank_quantity_in_level_attitude() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.)
+        .and_run();
+
+    let indicated = test_bed
+        .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+        .unwrap();
+    assert!((indicated - 3000.).abs() < 1.);
+    assert!(
+        (test_bed
+            .indicated_quantity("FUEL_ON_BOARD_INDICATED")
+            .unwrap()
+            - 6000.)
+            .abs()
+            < 1.
+    );
+    assert!(!test_bed.is_true("FUEL_FQI_DEGRADED"));
+}
+
+#[test]
+fn indicated_quantity_is_invalid_when_unpowered() {
+    let mut test_bed = test_bed_with()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .and_run();
+
+    assert!(test_bed
+        .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+        .is_none());
+    assert!(test_bed
+        .indicated_quantity("FUEL_ON_BOARD_INDICATED")
+        .is_none());
+}
+
+#[test]
+fn nearly_empty_tank_indicates_low_in_pitched_attitude() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_pitch_attitude(-10.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 300.)
+        .and_run();
+
+    let indicated = test_bed
+        .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+        .unwrap();
+    assert!(indicated < 300.);
+    assert!(indicated > 290.);
+}
+
+#[test]
+fn attitude_error_is_limited_to_a_few_percent_of_the_tank_quantity() {
+    for quantity in [200., 5400.] {
+        let mut test_bed = test_bed_with()
+            .and_powered()
+            .with_bank_attitude(30.)
+            .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", quantity)
+            .and_run();
+
+        let indicated = test_bed
+            .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+            .unwrap();
+        assert!(indicated < quantity - 0.02 * quantity);
+        assert!(indicated >= quantity - 0.03 * quantity);
+    }
+}
+
+#[test]
+fn failed_probe_degrades_tank_quantity() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_RIGHT_MAIN", 3000.);
+    test_bed.fail(FailureType::FuelQuantityProbe(2));
+    test_bed = test_bed.and_run();
+
+    assert!(test_bed.is_true("FUEL_FQI_DEGRADED"));
+    assert!(test_bed.is_true("FUEL_TANK_QUANTITY_DEGRADED_LEFT_MAIN"));
+    assert!(!test_bed.is_true("FUEL_TANK_QUANTITY_DEGRADED_RIGHT_MAIN"));
+    assert!(
+        (test_bed
+            .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+            .unwrap()
+            - 3000.)
+            .abs()
+            > 50.
+    );
+}
+
+#[test]
+fn one_failed_channel_does_not_affect_indication() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.);
+    test_bed.fail(FailureType::FuelQuantityIndicationChannel(1));
+    test_bed = test_bed.and_run();
+
+    assert!(test_bed
+        .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+        .is_some());
+
+    test_bed.fail(FailureType::FuelQuantityIndicationChannel(2));
+    test_bed = test_bed.and_run();
+
+    assert!(test_bed
+        .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+        .is_none());
+}
+
+#[test]
+fn failed_densitometer_uses_nominal_fuel_density() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_ambient_temperature(15.)
+        .with_tank_quantity("FUEL_TANK_QUANTITY_LEFT_MAIN", 3000.);
+    test_bed.fail(FailureType::FuelDensitometer);
+    test_bed = test_bed.and_run();
+
+    let indicated = test_bed
+        .indicated_quantity("FUEL_TANK_INDICATED_QUANTITY_LEFT_MAIN")
+        .unwrap();
+    assert!((indicated - 3000.).abs() > 50.);
+    assert!(!test_bed.is_true("FUEL_FQI_DEGRADED"));
+}
@@ -12,6 +12,7 @@ use uom::si::{
     mass::kilogram,
     pressure::psi,
     ratio::{percent, ratio},
+    torque::newton_meter,
     velocity::knot,
     volume::{cubic_inch, gallon, liter},
     volume_rate::gallon_per_second,
@@ -25,11 +26,13 @@ use systems::{
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
             BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
+            BrakeHeatSinkCharacteristics, BrakeTemperatures,
         },
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
         electrical_generator::{GeneratorControlUnit, HydraulicGeneratorMotor},
         flap_slat::FlapSlatAssembly,
+        ground_service::{GroundServicePanel, HydraulicGroundCart},
         landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
         linear_actuator::{
             Actuator, BoundedLinearLength, ElectroHydrostaticPowered, HydraulicAssemblyController,
@@ -64,16 +67,19 @@ use systems::{
         AirbusEngineDrivenPumpId, DelayedFalseLogicGate, DelayedPulseTrueLogicGate,
         DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
         EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
-        LgciuWeightOnWheels, RamAirTurbineController, ReservoirAirPressure, ReverserPosition,
-        SectionPressure, TrimmableHorizontalStabilizer,
+        EngineFirePushButtons, FuelTemperature, GearWheel, HydraulicColor, HydraulicHeatRejection,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController,
+        ReservoirAirPressure, ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
     },
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, StartState, UpdateContext, VariableIdentifier, Write,
     },
 };
 
+use crate::electrical::{BLUE_ELEC_PUMP_SUPPLY_BUS_TYPE, YELLOW_ELEC_PUMP_SUPPLY_BUS_TYPE};
+
 mod flaps_computer;
 use flaps_computer::SlatFlapComplex;
 
@@ -1484,12 +1490,17 @@ pub(super) struct A320Hydraulic {
     ram_air_turbine: RamAirTurbine,
     ram_air_turbine_controller: A320RamAirTurbineController,
 
+    green_ground_service_panel: GroundServicePanel,
+    blue_ground_service_panel: GroundServicePanel,
+    yellow_ground_service_panel: GroundServicePanel,
+
     power_transfer_unit: PowerTransferUnit,
     power_transfer_unit_controller: A320PowerTransferUnitController,
 
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A320BrakingForce,
+    brake_temperatures: BrakeTemperatures,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1552,15 +1563,13 @@ impl A320Hydraulic {
     const ELECTRIC_PUMP_MAX_CURRENT_AMPERE: f64 = 45.;
     const BLUE_ELEC_PUMP_CONTROL_POWER_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentEssential;
-    const BLUE_ELEC_PUMP_SUPPLY_POWER_BUS: ElectricalBusType =
-        ElectricalBusType::AlternatingCurrent(1);
+    const BLUE_ELEC_PUMP_SUPPLY_POWER_BUS: ElectricalBusType = BLUE_ELEC_PUMP_SUPPLY_BUS_TYPE;
 
     const YELLOW_ELEC_PUMP_CONTROL_POWER_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrent(2);
     const YELLOW_ELEC_PUMP_CONTROL_FROM_CARGO_DOOR_OPERATION_POWER_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentGndFltService;
-    const YELLOW_ELEC_PUMP_SUPPLY_POWER_BUS: ElectricalBusType =
-        ElectricalBusType::AlternatingCurrentGndFltService;
+    const YELLOW_ELEC_PUMP_SUPPLY_POWER_BUS: ElectricalBusType = YELLOW_ELEC_PUMP_SUPPLY_BUS_TYPE;
 
     const YELLOW_EDP_CONTROL_POWER_BUS1: ElectricalBusType = ElectricalBusType::DirectCurrent(2);
     const YELLOW_EDP_CONTROL_POWER_BUS2: ElectricalBusType =
@@ -1686,6 +1695,22 @@ impl A320Hydraulic {
                 Self::RAT_CONTROL_SOLENOID2_POWER_BUS,
             ),
 
+            green_ground_service_panel: GroundServicePanel::new(
+                context,
+                HydraulicColor::Green,
+                HydraulicGroundCart::default_cart(),
+            ),
+            blue_ground_service_panel: GroundServicePanel::new(
+                context,
+                HydraulicColor::Blue,
+                HydraulicGroundCart::default_cart(),
+            ),
+            yellow_ground_service_panel: GroundServicePanel::new(
+                context,
+                HydraulicColor::Yellow,
+                HydraulicGroundCart::default_cart(),
+            ),
+
             power_transfer_unit: PowerTransferUnit::new(
                 context,
                 &A320PowerTransferUnitCharacteristics::new_randomized(),
@@ -1715,6 +1740,17 @@ impl A320Hydraulic {
             ),
 
             braking_force: A320BrakingForce::new(context),
+            brake_temperatures: BrakeTemperatures::new(
+                context,
+                &[1, 2],
+                &[3, 4],
+                BrakeHeatSinkCharacteristics::new(
+                    Mass::new::<kilogram>(90.),
+                    Torque::new::<newton_meter>(45000.),
+                    Length::new::<meter>(0.58),
+                ),
+                true,
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -1825,6 +1861,7 @@ impl A320Hydraulic {
         emergency_elec: &(impl EmergencyElectricalState + EmergencyGeneratorPower),
         reservoir_pneumatics: &impl ReservoirAirPressure,
         adirs: &impl AdirsDiscreteOutputs,
+        fuel: &impl FuelTemperature,
     ) {
         self.core_hydraulic_updater.update(context);
 
@@ -1858,6 +1895,7 @@ impl A320Hydraulic {
                 lgcius.lgciu1(),
                 lgcius.lgciu2(),
                 reservoir_pneumatics,
+                fuel,
             );
         }
 
@@ -2128,6 +2166,15 @@ impl A320Hydraulic {
             engine2,
         );
 
+        self.brake_temperatures.update(
+            context,
+            self.braking_circuit_norm.left_brake_pressure()
+                + self.braking_circuit_altn.left_brake_pressure(),
+            self.braking_circuit_norm.right_brake_pressure()
+                + self.braking_circuit_altn.right_brake_pressure(),
+            lgciu1.main_down_and_locked(),
+        );
+
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
 
@@ -2312,6 +2359,7 @@ impl A320Hydraulic {
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
         reservoir_pneumatics: &impl ReservoirAirPressure,
+        fuel: &impl FuelTemperature,
     ) {
         // First update what is currently consumed and given back by each actuator
         // Todo: might have to split the actuator volumes by expected number of loops
@@ -2411,6 +2459,22 @@ impl A320Hydraulic {
             &self.ram_air_turbine_controller,
         );
 
+        self.green_ground_service_panel.update(
+            context,
+            self.green_circuit.system_section(),
+            self.green_circuit.reservoir(),
+        );
+        self.blue_ground_service_panel.update(
+            context,
+            self.blue_circuit.system_section(),
+            self.blue_circuit.reservoir(),
+        );
+        self.yellow_ground_service_panel.update(
+            context,
+            self.yellow_circuit.system_section(),
+            self.yellow_circuit.reservoir(),
+        );
+
         self.green_circuit_controller.update(
             context,
             engine_fire_push_buttons,
@@ -2420,11 +2484,12 @@ impl A320Hydraulic {
         self.green_circuit.update(
             context,
             &mut [&mut self.engine_driven_pump_1],
-            None::<&mut ElectricPump>,
+            Some(&mut self.green_ground_service_panel.connected_to(None)),
             None::<&mut ElectricPump>,
             Some(&self.power_transfer_unit),
             &self.green_circuit_controller,
             reservoir_pneumatics.green_reservoir_pressure(),
+            fuel.hydraulic_heat_exchanger_fuel_temperature(HydraulicColor::Green),
         );
 
         self.yellow_circuit_controller.update(
@@ -2436,11 +2501,16 @@ impl A320Hydraulic {
         self.yellow_circuit.update(
             context,
             &mut [&mut self.engine_driven_pump_2],
-            Some(&mut self.yellow_electric_pump),
+            Some(
+                &mut self
+                    .yellow_ground_service_panel
+                    .connected_to(Some(&mut self.yellow_electric_pump)),
+            ),
             None::<&mut ElectricPump>,
             Some(&self.power_transfer_unit),
             &self.yellow_circuit_controller,
             reservoir_pneumatics.yellow_reservoir_pressure(),
+            fuel.hydraulic_heat_exchanger_fuel_temperature(HydraulicColor::Yellow),
         );
 
         self.blue_circuit_controller.update(
@@ -2452,11 +2522,16 @@ impl A320Hydraulic {
         self.blue_circuit.update(
             context,
             &mut [&mut self.blue_electric_pump],
-            Some(&mut self.ram_air_turbine),
+            Some(
+                &mut self
+                    .blue_ground_service_panel
+                    .connected_to(Some(&mut self.ram_air_turbine)),
+            ),
             None::<&mut ElectricPump>,
             None,
             &self.blue_circuit_controller,
             reservoir_pneumatics.blue_reservoir_pressure(),
+            fuel.hydraulic_heat_exchanger_fuel_temperature(HydraulicColor::Blue),
         );
 
         self.braking_circuit_norm.update(
@@ -2546,6 +2621,15 @@ impl A320Hydraulic {
         &self.gear_system
     }
 }
+impl HydraulicHeatRejection for A320Hydraulic {
+    fn heat_rejected_to_fuel(&self, color: HydraulicColor) -> Power {
+        match color {
+            HydraulicColor::Green => self.green_circuit.heat_rejected_to_fuel(),
+            HydraulicColor::Blue => self.blue_circuit.heat_rejected_to_fuel(),
+            HydraulicColor::Yellow => self.yellow_circuit.heat_rejected_to_fuel(),
+        }
+    }
+}
 impl SimulationElement for A320Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.engine_driven_pump_1.accept(visitor);
@@ -2572,6 +2656,10 @@ impl SimulationElement for A320Hydraulic {
         self.ram_air_turbine.accept(visitor);
         self.ram_air_turbine_controller.accept(visitor);
 
+        self.green_ground_service_panel.accept(visitor);
+        self.blue_ground_service_panel.accept(visitor);
+        self.yellow_ground_service_panel.accept(visitor);
+
         self.power_transfer_unit.accept(visitor);
         self.power_transfer_unit_controller.accept(visitor);
 
@@ -2584,6 +2672,7 @@ impl SimulationElement for A320Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_temperatures.accept(visitor);
 
         self.emergency_gen.accept(visitor);
         self.nose_steering.accept(visitor);
@@ -2636,6 +2725,16 @@ impl SimulationElement for A320Hydraulic {
                 && !self.ptu_high_pitch_sound_active.output(),
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.gcu.save_state(state);
+        self.ptu_high_pitch_sound_active.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.gcu.restore_state(state);
+        self.ptu_high_pitch_sound_active.restore_state(state);
+    }
 }
 impl EmergencyGeneratorControlUnit for A320Hydraulic {
     fn max_allowed_power(&self) -> Power {
@@ -5979,8 +6078,8 @@ mod tests {
         use super::*;
         use systems::{
             electrical::{
-                test::TestElectricitySource, ElectricalBus, Electricity, ElectricitySource,
-                ExternalPowerSource,
+                test::TestElectricitySource, CircuitBreaker, ElectricalBus, Electricity,
+                ElectricitySource, ExternalPowerSource,
             },
             engine::{leap_engine::LeapEngine, EngineFireOverheadPanel},
             failures::FailureType,
@@ -6094,6 +6193,30 @@ mod tests {
             }
         }
 
+        struct A320TestFuel {
+            temperature: ThermodynamicTemperature,
+        }
+        impl A320TestFuel {
+            fn new(temperature: ThermodynamicTemperature) -> Self {
+                Self { temperature }
+            }
+        }
+        impl FuelTemperature for A320TestFuel {
+            fn hydraulic_heat_exchanger_fuel_temperature(
+                &self,
+                _color: HydraulicColor,
+            ) -> ThermodynamicTemperature {
+                self.temperature
+            }
+
+            fn engine_feed_fuel_temperature(
+                &self,
+                _engine_number: usize,
+            ) -> ThermodynamicTemperature {
+                self.temperature
+            }
+        }
+
         struct A320TestElectrical {
             airspeed: Velocity,
             all_ac_lost: bool,
@@ -6158,6 +6281,8 @@ mod tests {
             dc_ess_bus: ElectricalBus,
             dc_hot_1_bus: ElectricalBus,
             dc_hot_2_bus: ElectricalBus,
+            blue_elec_pump_circuit_breaker: CircuitBreaker,
+            yellow_elec_pump_circuit_breaker: CircuitBreaker,
 
             // Electric buses states to be able to kill them dynamically
             is_ac_ground_service_powered: bool,
@@ -6220,6 +6345,18 @@ mod tests {
                         context,
                         ElectricalBusType::DirectCurrentHot(2),
                     ),
+                    blue_elec_pump_circuit_breaker: CircuitBreaker::new_three_phase(
+                        context,
+                        "BLUE_ELEC_PUMP",
+                        BLUE_ELEC_PUMP_SUPPLY_BUS_TYPE,
+                        ElectricCurrent::new::<ampere>(50.),
+                    ),
+                    yellow_elec_pump_circuit_breaker: CircuitBreaker::new_three_phase(
+                        context,
+                        "YELLOW_ELEC_PUMP",
+                        YELLOW_ELEC_PUMP_SUPPLY_BUS_TYPE,
+                        ElectricCurrent::new::<ampere>(50.),
+                    ),
                     is_ac_ground_service_powered: true,
                     is_dc_ground_service_powered: true,
                     is_ac_1_powered: true,
@@ -6418,6 +6555,12 @@ mod tests {
                 if self.is_dc_hot_2_powered {
                     electricity.flow(&self.powered_source_ac, &self.dc_hot_2_bus);
                 }
+
+                electricity.flow(&self.ac_1_bus, &self.blue_elec_pump_circuit_breaker);
+                electricity.flow(
+                    &self.ac_ground_service_bus,
+                    &self.yellow_elec_pump_circuit_breaker,
+                );
             }
 
             fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -6444,6 +6587,7 @@ mod tests {
                     &self.electrical,
                     &self.pneumatics,
                     &self.adirus,
+                    &A320TestFuel::new(context.ambient_temperature()),
                 );
 
                 self.overhead.update(&self.hydraulics);
@@ -6462,6 +6606,8 @@ mod tests {
                 self.emergency_electrical_overhead.accept(visitor);
                 self.electrical.accept(visitor);
                 self.ext_pwr.accept(visitor);
+                self.blue_elec_pump_circuit_breaker.accept(visitor);
+                self.yellow_elec_pump_circuit_breaker.accept(visitor);
 
                 visitor.visit(self);
             }
@@ -6746,6 +6892,26 @@ mod tests {
                 self.query(|a| a.is_blue_leak_meas_valve_commanded_open())
             }
 
+            fn connect_green_ground_cart(mut self, is_on: bool) -> Self {
+                self.write_by_name("HYD_GREEN_GROUND_CART_CONNECTED", true);
+                self.write_by_name("HYD_GREEN_GROUND_CART_ON", is_on);
+                self
+            }
+
+            fn set_green_reservoir_hand_pump(mut self, is_active: bool) -> Self {
+                self.write_by_name("HYD_GREEN_RESERVOIR_HAND_PUMP_ACTIVE", is_active);
+                self
+            }
+
+            fn is_green_reservoir_overfilled(&mut self) -> bool {
+                self.read_by_name("HYD_GREEN_RESERVOIR_OVERFILL")
+            }
+
+            fn select_green_ground_cart_fill(mut self) -> Self {
+                self.write_by_name("HYD_GREEN_GROUND_CART_FILL_SELECTED", true);
+                self
+            }
+
             fn green_leak_meas_valve_closed(mut self) -> Self {
                 self.write_by_name("OVHD_HYD_LEAK_MEASUREMENT_G_PB_IS_AUTO", false);
                 self
@@ -7118,6 +7284,11 @@ mod tests {
                 self
             }
 
+            fn pull_yellow_elec_pump_circuit_breaker(mut self, is_pulled: bool) -> Self {
+                self.write_by_name("ELEC_CB_YELLOW_ELEC_PUMP_IS_PULLED", is_pulled);
+                self
+            }
+
             fn dc_bus_2_lost(mut self) -> Self {
                 self.command(|a| a.set_dc_bus_2_is_powered(false));
                 self
@@ -8205,6 +8376,30 @@ mod tests {
             assert!(!test_bed.is_yellow_epump_press_low());
         }
 
+        #[test]
+        fn yellow_epump_stops_when_its_circuit_breaker_is_pulled() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.is_yellow_pressure_switch_pressurised());
+
+            test_bed = test_bed
+                .pull_yellow_elec_pump_circuit_breaker(true)
+                .run_waiting_for(Duration::from_secs(25));
+
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            test_bed = test_bed
+                .pull_yellow_elec_pump_circuit_breaker(false)
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.is_yellow_pressure_switch_pressurised());
+        }
+
         #[test]
         fn blue_epump_press_low_at_pump_on() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10620,6 +10815,127 @@ mod tests {
             assert!(test_bed.yellow_pressure().get::<psi>() < 2000.);
         }
 
+        #[test]
+        fn green_ground_cart_pressurises_green_circuit_with_engines_off() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .connect_green_ground_cart(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+            assert!(test_bed.green_pressure().get::<psi>() < 500.);
+
+            test_bed = test_bed
+                .connect_green_ground_cart(true)
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.green_pressure().get::<psi>() > 2500.);
+            assert!(test_bed.yellow_pressure().get::<psi>() < 500.);
+            assert!(test_bed.blue_pressure().get::<psi>() < 500.);
+        }
+
+        #[test]
+        fn green_ground_cart_fills_green_reservoir() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .run_one_tick();
+
+            let initial_volume = test_bed.get_green_reservoir_volume();
+
+            test_bed = test_bed
+                .connect_green_ground_cart(false)
+                .select_green_ground_cart_fill()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(
+                test_bed.get_green_reservoir_volume() > initial_volume + Volume::new::<gallon>(0.5)
+            );
+            assert!(test_bed.get_green_reservoir_volume() <= Volume::new::<liter>(18.));
+        }
+
+        #[test]
+        fn green_reservoir_hand_pump_can_overfill_reservoir() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            assert!(!test_bed.is_green_reservoir_overfilled());
+
+            test_bed = test_bed
+                .set_green_reservoir_hand_pump(true)
+                .run_waiting_for(Duration::from_secs_f64(300.));
+
+            assert!(test_bed.is_green_reservoir_overfilled());
+            assert!(test_bed.get_green_reservoir_volume() <= Volume::new::<liter>(23.));
+        }
+
+        #[test]
+        fn green_reservoir_level_is_restored_from_snapshot() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(10.));
+            test_bed.unfail(FailureType::ReservoirLeak(HydraulicColor::Green));
+
+            let leaked_volume = test_bed.get_green_reservoir_volume();
+            let snapshot = test_bed.save_snapshot();
+
+            test_bed = test_bed
+                .set_green_reservoir_hand_pump(true)
+                .run_waiting_for(Duration::from_secs_f64(60.));
+            assert!(test_bed.get_green_reservoir_volume() > leaked_volume);
+
+            assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+            test_bed = test_bed.set_green_reservoir_hand_pump(false).run_one_tick();
+
+            assert!(
+                (test_bed.get_green_reservoir_volume() - leaked_volume)
+                    .get::<gallon>()
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn worn_yellow_epump_takes_longer_to_build_pressure() {
+            let mut new_pump_test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .run_one_tick();
+
+            let mut worn_pump_test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .run_one_tick();
+            worn_pump_test_bed.fail(FailureType::ElecPumpWear(AirbusElectricPumpId::Yellow));
+
+            new_pump_test_bed = new_pump_test_bed
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(1.));
+            worn_pump_test_bed = worn_pump_test_bed
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(worn_pump_test_bed.yellow_pressure() < new_pump_test_bed.yellow_pressure());
+        }
+
         #[test]
         fn low_air_press_fault_causes_ptu_fault() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11445,6 +11761,29 @@ mod tests {
             assert!(test_bed.is_all_doors_really_up());
         }
 
+        #[test]
+        fn restoring_snapshot_restores_gear_position() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+            let snapshot = test_bed.save_snapshot();
+
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+            assert!(test_bed.is_all_gears_really_down());
+
+            assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+            test_bed = test_bed.set_gear_lever_up().run_one_tick();
+
+            assert!(test_bed.is_all_gears_really_up());
+            assert!(test_bed.is_all_doors_really_up());
+        }
+
         #[test]
         fn empty_green_reservoir_causes_yellow_overheat_if_ptu_on() {
             let mut test_bed = test_bed_in_flight_with()
@@ -3,6 +3,7 @@ extern crate systems;
 mod air_conditioning;
 mod airframe;
 mod electrical;
+pub mod failures;
 mod fuel;
 pub mod hydraulic;
 mod navigation;
@@ -12,7 +13,7 @@ mod power_consumption;
 
 use self::{
     air_conditioning::A320AirConditioning,
-    fuel::A320Fuel,
+    fuel::{A320Fuel, A320FuelOverheadPanel},
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
 };
@@ -22,9 +23,11 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
-use navigation::{A320AirDataInertialReferenceSystemBuilder, A320RadioAltimeters};
+use navigation::{
+    A320AirDataInertialReferenceSystemBuilder, A320MultiModeReceivers, A320RadioAltimeters,
+};
 use power_consumption::A320PowerConsumption;
-use systems::enhanced_gpwc::EnhancedGroundProximityWarningComputer;
+use systems::enhanced_gpwc::{EnhancedGroundProximityWarningComputer, LandingFlapsPositionBits};
 use systems::simulation::InitContext;
 use uom::si::{f64::Length, length::nautical_mile};
 
@@ -60,6 +63,7 @@ pub struct A320 {
     payload: A320Payload,
     airframe: A320Airframe,
     fuel: A320Fuel,
+    fuel_overhead: A320FuelOverheadPanel,
     engine_1: LeapEngine,
     engine_2: LeapEngine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
@@ -73,6 +77,7 @@ pub struct A320 {
     landing_gear: LandingGear,
     pneumatic: A320Pneumatic,
     radio_altimeters: A320RadioAltimeters,
+    multi_mode_receivers: A320MultiModeReceivers,
     egpwc: EnhancedGroundProximityWarningComputer,
     reverse_thrust: ReverserForce,
 }
@@ -98,6 +103,7 @@ impl A320 {
             payload: A320Payload::new(context),
             airframe: A320Airframe::new(context),
             fuel: A320Fuel::new(context),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
             engine_1: LeapEngine::new(context, 1),
             engine_2: LeapEngine::new(context, 2),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
@@ -115,6 +121,7 @@ impl A320 {
             landing_gear: LandingGear::new(context, false),
             pneumatic: A320Pneumatic::new(context),
             radio_altimeters: A320RadioAltimeters::new(context),
+            multi_mode_receivers: A320MultiModeReceivers::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
                 ElectricalBusType::DirectCurrent(1),
@@ -127,6 +134,7 @@ impl A320 {
                     Length::new::<nautical_mile>(320.0),
                 ],
                 0,
+                LandingFlapsPositionBits::new(21, 22),
             ),
             reverse_thrust: ReverserForce::new(context),
         }
@@ -150,6 +158,7 @@ impl Aircraft for A320 {
             self.electrical_overhead.apu_generator_is_on()
                 && !(self.electrical_overhead.external_power_is_on()
                     && self.electrical_overhead.external_power_is_available()),
+            &self.electrical_overhead,
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.left_inner_tank_has_fuel_remaining(),
         );
@@ -167,6 +176,7 @@ impl Aircraft for A320 {
             &self.hydraulic,
             self.lgcius.lgciu1(),
             &self.adirs,
+            &self.fuel,
         );
 
         self.electrical_overhead
@@ -194,7 +204,18 @@ impl Aircraft for A320 {
             self.ext_pwr.output_potential().is_powered(),
         );
 
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.engine_fire_overhead,
+            [&self.engine_1, &self.engine_2],
+            &self.hydraulic,
+            &self.electrical,
+        );
+        self.fuel_overhead.update(&self.fuel);
+
         self.radio_altimeters.update(context);
+        self.multi_mode_receivers.update(context);
 
         self.hydraulic.update(
             context,
@@ -208,6 +229,7 @@ impl Aircraft for A320 {
             &self.electrical,
             &self.pneumatic,
             &self.adirs,
+            &self.fuel,
         );
 
         self.reverse_thrust.update(
@@ -224,7 +246,14 @@ impl Aircraft for A320 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
+        self.adirs.update(
+            context,
+            &self.adirs_overhead,
+            [
+                self.multi_mode_receivers.mmr_1(),
+                self.multi_mode_receivers.mmr_2(),
+            ],
+        );
         self.adirs_overhead.update(context, &self.adirs);
 
         self.power_consumption.update(context);
@@ -251,7 +280,14 @@ impl Aircraft for A320 {
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.multi_mode_receivers.mmr_1(),
+            self.radio_altimeters.radio_altimeter_1(),
+            self.radio_altimeters.radio_altimeter_2(),
+            self.lgcius.lgciu1(),
+        );
     }
 }
 impl SimulationElement for A320 {
@@ -268,6 +304,7 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.engine_1.accept(visitor);
         self.engine_2.accept(visitor);
@@ -277,6 +314,7 @@ impl SimulationElement for A320 {
         self.ext_pwr.accept(visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.multi_mode_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
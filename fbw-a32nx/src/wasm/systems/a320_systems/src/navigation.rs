@@ -5,6 +5,7 @@ use systems::navigation::adirs::{
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
+use systems::navigation::gps::MultiModeReceiver;
 use systems::navigation::radio_altimeter::AntennaInstallation;
 use systems::shared::{ElectricalBusType, MachNumber};
 use systems::simulation::{
@@ -43,6 +44,46 @@ impl A320AirDataInertialReferenceSystemBuilder {
     }
 }
 
+pub struct A320MultiModeReceivers {
+    mmr_1: MultiModeReceiver,
+    mmr_2: MultiModeReceiver,
+}
+
+impl A320MultiModeReceivers {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            mmr_1: MultiModeReceiver::new(
+                context,
+                1,
+                ElectricalBusType::AlternatingCurrentEssential,
+            ),
+            mmr_2: MultiModeReceiver::new(context, 2, ElectricalBusType::AlternatingCurrent(2)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.mmr_1.update(context);
+        self.mmr_2.update(context);
+    }
+
+    pub fn mmr_1(&self) -> &MultiModeReceiver {
+        &self.mmr_1
+    }
+
+    pub fn mmr_2(&self) -> &MultiModeReceiver {
+        &self.mmr_2
+    }
+}
+
+impl SimulationElement for A320MultiModeReceivers {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.mmr_1.accept(visitor);
+        self.mmr_2.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct A320RadioAltimeters {
     radio_altimeter_1: A320RadioAltimeter,
     radio_altimeter_2: A320RadioAltimeter,
@@ -91,6 +132,14 @@ impl A320RadioAltimeters {
         self.radio_altimeter_1.update(context);
         self.radio_altimeter_2.update(context);
     }
+
+    pub fn radio_altimeter_1(&self) -> &Ala52BRadioAltimeter {
+        &self.radio_altimeter_1.radio_altimeter
+    }
+
+    pub fn radio_altimeter_2(&self) -> &Ala52BRadioAltimeter {
+        &self.radio_altimeter_2.radio_altimeter
+    }
 }
 
 impl SimulationElement for A320RadioAltimeters {
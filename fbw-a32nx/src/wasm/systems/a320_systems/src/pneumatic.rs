@@ -1914,7 +1914,7 @@ pub mod tests {
         pneumatic::{
             BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
             CrossBleedValveSelectorMode, EngineState, PneumaticContainer, PneumaticValveSignal,
-            TargetPressureTemperatureSignal, WingAntiIcePushButtonMode,
+            TargetPressureTemperatureSignal, VariableVolumeContainer, WingAntiIcePushButtonMode,
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
@@ -1940,6 +1940,7 @@ pub mod tests {
         ratio::ratio,
         thermodynamic_temperature::degree_celsius,
         velocity::{foot_per_minute, knot},
+        volume::gallon,
     };
 
     use crate::air_conditioning::{A320AirConditioningSystem, A320PressurizationOverheadPanel};
@@ -3877,6 +3878,36 @@ pub mod tests {
         assert!(test_bed.yellow_hydraulic_reservoir_pressure() > Pressure::new::<psi>(35.));
     }
 
+    #[test]
+    fn restoring_snapshot_restores_hydraulic_reservoirs_pressure() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .stop_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .set_bleed_air_running()
+            .and_stabilize();
+
+        let pressure_before_green = test_bed.green_hydraulic_reservoir_pressure();
+        let snapshot = test_bed.save_snapshot();
+
+        test_bed.command(|a| {
+            *a.pneumatic.green_hydraulic_reservoir_with_valve.container() =
+                VariableVolumeContainer::new(
+                    Volume::new::<gallon>(2.5),
+                    Pressure::new::<psi>(14.7),
+                    ThermodynamicTemperature::new::<degree_celsius>(15.),
+                )
+        });
+        assert!(test_bed.green_hydraulic_reservoir_pressure() < pressure_before_green);
+
+        assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+
+        assert_eq!(
+            test_bed.green_hydraulic_reservoir_pressure(),
+            pressure_before_green
+        );
+    }
+
     #[test]
     fn hydraulic_reservoirs_is_pressurized_by_left_system() {
         let mut test_bed = test_bed_with()
@@ -1,346 +1,342 @@
 use systems::simulation::InitContext;
 use systems::{
-    electrical::consumption::{FlightPhasePowerConsumer, PowerConsumerFlightPhase},
+    electrical::consumption::{
+        PowerConsumerCatalog, PowerConsumerCondition, PowerConsumerDefinition,
+    },
     shared::ElectricalBusType,
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, power::watt};
 
-/// This type provides an aggregated form of power consumption.
-/// We haven't yet implemented all power consumers and thus need something to
-/// consume power, as otherwise electrical load is nearly 0.
+const GALLEY_IS_POWERED: PowerConsumerCondition =
+    PowerConsumerCondition::VariableIsOff("ELEC_GALLEY_IS_SHED");
+
+/// Probe and window heating is automatically on when an engine runs or the aircraft is in flight.
+const HEATING_IS_ON: PowerConsumerCondition = PowerConsumerCondition::AnyOf(&[
+    PowerConsumerCondition::InFlight,
+    PowerConsumerCondition::VariableIsOn("ENGINE_STATE:1"),
+    PowerConsumerCondition::VariableIsOn("ENGINE_STATE:2"),
+]);
+
+/// The power consumers which are not yet modelled as part of the systems they belong to.
+/// Equipment which is modelled elsewhere, such as the hydraulic electric pumps and
+/// the fuel pumps, consumes power by itself and is thus not part of this list.
+const A320_POWER_CONSUMERS: &[PowerConsumerDefinition] = &[
+    // AC BUS 1
+    PowerConsumerDefinition::new(
+        "GALLEY_FWD",
+        ElectricalBusType::AlternatingCurrent(1),
+        18000.,
+    )
+    .duty_cycle(0.8)
+    .when(GALLEY_IS_POWERED),
+    PowerConsumerDefinition::new(
+        "GALLEY_FWD_CHILLER",
+        ElectricalBusType::AlternatingCurrent(1),
+        2500.,
+    )
+    .when(GALLEY_IS_POWERED),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION_FAN_1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1900.,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS_VENTILATION_BLOWER",
+        ElectricalBusType::AlternatingCurrent(1),
+        800.,
+    ),
+    PowerConsumerDefinition::new(
+        "WINDOW_HEAT_CAPT",
+        ElectricalBusType::AlternatingCurrent(1),
+        1200.,
+    )
+    .duty_cycle(0.6)
+    .when(HEATING_IS_ON),
+    PowerConsumerDefinition::new("FMGC_1", ElectricalBusType::AlternatingCurrent(1), 120.),
+    PowerConsumerDefinition::new(
+        "LANDING_LIGHTS",
+        ElectricalBusType::AlternatingCurrent(1),
+        1200.,
+    )
+    .when(PowerConsumerCondition::VariableIsOn("LIGHT LANDING")),
+    PowerConsumerDefinition::new(
+        "TAXI_LIGHTS",
+        ElectricalBusType::AlternatingCurrent(1),
+        250.,
+    )
+    .when(PowerConsumerCondition::VariableIsOn("LIGHT TAXI")),
+    PowerConsumerDefinition::new(
+        "STROBE_LIGHTS",
+        ElectricalBusType::AlternatingCurrent(1),
+        200.,
+    )
+    .when(PowerConsumerCondition::VariableIsOn("LIGHT STROBE")),
+    PowerConsumerDefinition::new("NAV_LIGHTS", ElectricalBusType::AlternatingCurrent(1), 150.)
+        .when(PowerConsumerCondition::VariableIsOn("LIGHT NAV")),
+    // AC BUS 2
+    PowerConsumerDefinition::new(
+        "GALLEY_AFT",
+        ElectricalBusType::AlternatingCurrent(2),
+        16000.,
+    )
+    .duty_cycle(0.8)
+    .when(GALLEY_IS_POWERED),
+    PowerConsumerDefinition::new(
+        "GALLEY_AFT_CHILLER",
+        ElectricalBusType::AlternatingCurrent(2),
+        2500.,
+    )
+    .when(GALLEY_IS_POWERED),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION_FAN_2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1900.,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS_VENTILATION_EXTRACT",
+        ElectricalBusType::AlternatingCurrent(2),
+        800.,
+    ),
+    PowerConsumerDefinition::new(
+        "WINDOW_HEAT_FO",
+        ElectricalBusType::AlternatingCurrent(2),
+        1200.,
+    )
+    .duty_cycle(0.6)
+    .when(HEATING_IS_ON),
+    PowerConsumerDefinition::new(
+        "PROBE_HEAT_FO",
+        ElectricalBusType::AlternatingCurrent(2),
+        300.,
+    )
+    .when(HEATING_IS_ON),
+    PowerConsumerDefinition::new(
+        "WEATHER_RADAR",
+        ElectricalBusType::AlternatingCurrent(2),
+        400.,
+    )
+    .when(PowerConsumerCondition::InFlight),
+    PowerConsumerDefinition::new("FMGC_2", ElectricalBusType::AlternatingCurrent(2), 120.),
+    PowerConsumerDefinition::new("ADIRU_2", ElectricalBusType::AlternatingCurrent(2), 140.),
+    PowerConsumerDefinition::new("DU_FO_PFD", ElectricalBusType::AlternatingCurrent(2), 90.),
+    PowerConsumerDefinition::new("DU_FO_ND", ElectricalBusType::AlternatingCurrent(2), 90.),
+    PowerConsumerDefinition::new(
+        "DU_LOWER_ECAM",
+        ElectricalBusType::AlternatingCurrent(2),
+        90.,
+    ),
+    PowerConsumerDefinition::new(
+        "LOGO_LIGHTS",
+        ElectricalBusType::AlternatingCurrent(2),
+        300.,
+    )
+    .when(PowerConsumerCondition::VariableIsOn("LIGHT LOGO")),
+    PowerConsumerDefinition::new(
+        "WING_LIGHTS",
+        ElectricalBusType::AlternatingCurrent(2),
+        300.,
+    )
+    .when(PowerConsumerCondition::VariableIsOn("LIGHT WING")),
+    PowerConsumerDefinition::new(
+        "BEACON_LIGHTS",
+        ElectricalBusType::AlternatingCurrent(2),
+        100.,
+    )
+    .when(PowerConsumerCondition::VariableIsOn("LIGHT BEACON")),
+    // AC ESS BUS
+    PowerConsumerDefinition::new(
+        "DU_CAPT_PFD",
+        ElectricalBusType::AlternatingCurrentEssential,
+        90.,
+    ),
+    PowerConsumerDefinition::new(
+        "DU_CAPT_ND",
+        ElectricalBusType::AlternatingCurrentEssential,
+        90.,
+    ),
+    PowerConsumerDefinition::new(
+        "DU_UPPER_ECAM",
+        ElectricalBusType::AlternatingCurrentEssential,
+        90.,
+    ),
+    PowerConsumerDefinition::new(
+        "ADIRU_1",
+        ElectricalBusType::AlternatingCurrentEssential,
+        140.,
+    ),
+    PowerConsumerDefinition::new("DMC_1", ElectricalBusType::AlternatingCurrentEssential, 60.),
+    PowerConsumerDefinition::new("FWC_1", ElectricalBusType::AlternatingCurrentEssential, 60.),
+    PowerConsumerDefinition::new(
+        "PROBE_HEAT_CAPT",
+        ElectricalBusType::AlternatingCurrentEssential,
+        300.,
+    )
+    .when(HEATING_IS_ON),
+    // AC ESS SHED BUS
+    PowerConsumerDefinition::new(
+        "PROBE_HEAT_STBY",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        260.,
+    )
+    .when(HEATING_IS_ON),
+    PowerConsumerDefinition::new(
+        "ADIRU_3",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        140.,
+    ),
+    PowerConsumerDefinition::new(
+        "CIDS_1",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        150.,
+    ),
+    PowerConsumerDefinition::new(
+        "ATC_1",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        70.,
+    ),
+    PowerConsumerDefinition::new(
+        "GPWC",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        50.,
+    ),
+    // AC STAT INV BUS
+    PowerConsumerDefinition::new(
+        "CAPT_INTEGRAL_LIGHTING",
+        ElectricalBusType::AlternatingCurrentStaticInverter,
+        135.,
+    ),
+    // AC GND/FLT BUS
+    PowerConsumerDefinition::new(
+        "CABIN_LIGHTING",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        1800.,
+    )
+    .when(GALLEY_IS_POWERED),
+    PowerConsumerDefinition::new(
+        "POTABLE_WATER_HEATERS",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        1500.,
+    )
+    .duty_cycle(0.5)
+    .when(GALLEY_IS_POWERED),
+    PowerConsumerDefinition::new(
+        "VACUUM_TOILET_SYSTEM",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        1100.,
+    )
+    .duty_cycle(0.3),
+    PowerConsumerDefinition::new(
+        "CABIN_SERVICE_OUTLETS",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        1000.,
+    )
+    .duty_cycle(0.5)
+    .when(PowerConsumerCondition::OnGround),
+    PowerConsumerDefinition::new(
+        "CARGO_COMPARTMENT_LIGHTING",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        300.,
+    )
+    .when(PowerConsumerCondition::OnGround),
+    PowerConsumerDefinition::new(
+        "ENTRANCE_LIGHTING",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        400.,
+    )
+    .when(PowerConsumerCondition::OnGround),
+    // DC BUS 1
+    PowerConsumerDefinition::new("SEC_2", ElectricalBusType::DirectCurrent(1), 60.),
+    PowerConsumerDefinition::new("FCDC_2", ElectricalBusType::DirectCurrent(1), 40.),
+    PowerConsumerDefinition::new("LGCIU_2", ElectricalBusType::DirectCurrent(1), 35.),
+    PowerConsumerDefinition::new("BSCU_CHANNEL_1", ElectricalBusType::DirectCurrent(1), 70.),
+    PowerConsumerDefinition::new(
+        "CABIN_PRESSURE_CONTROLLER_1",
+        ElectricalBusType::DirectCurrent(1),
+        40.,
+    ),
+    PowerConsumerDefinition::new("VHF_3", ElectricalBusType::DirectCurrent(1), 60.),
+    // DC BUS 2
+    PowerConsumerDefinition::new("ELAC_2", ElectricalBusType::DirectCurrent(2), 70.),
+    PowerConsumerDefinition::new("SEC_3", ElectricalBusType::DirectCurrent(2), 60.),
+    PowerConsumerDefinition::new("FAC_2", ElectricalBusType::DirectCurrent(2), 60.),
+    PowerConsumerDefinition::new("BSCU_CHANNEL_2", ElectricalBusType::DirectCurrent(2), 70.),
+    PowerConsumerDefinition::new(
+        "CABIN_PRESSURE_CONTROLLER_2",
+        ElectricalBusType::DirectCurrent(2),
+        40.,
+    ),
+    PowerConsumerDefinition::new("VHF_2", ElectricalBusType::DirectCurrent(2), 60.),
+    PowerConsumerDefinition::new("RMP_2", ElectricalBusType::DirectCurrent(2), 20.),
+    PowerConsumerDefinition::new("SDAC_2", ElectricalBusType::DirectCurrent(2), 50.),
+    // DC ESS BUS
+    PowerConsumerDefinition::new("ELAC_1", ElectricalBusType::DirectCurrentEssential, 40.),
+    PowerConsumerDefinition::new("FAC_1", ElectricalBusType::DirectCurrentEssential, 40.),
+    PowerConsumerDefinition::new("VHF_1", ElectricalBusType::DirectCurrentEssential, 40.),
+    PowerConsumerDefinition::new("RMP_1", ElectricalBusType::DirectCurrentEssential, 20.),
+    PowerConsumerDefinition::new("ECP", ElectricalBusType::DirectCurrentEssential, 20.),
+    // DC ESS SHED BUS
+    PowerConsumerDefinition::new("SEC_1", ElectricalBusType::DirectCurrentEssentialShed, 60.),
+    PowerConsumerDefinition::new(
+        "LGCIU_1",
+        ElectricalBusType::DirectCurrentEssentialShed,
+        35.,
+    ),
+    PowerConsumerDefinition::new("FCDC_1", ElectricalBusType::DirectCurrentEssentialShed, 40.),
+    PowerConsumerDefinition::new("SDAC_1", ElectricalBusType::DirectCurrentEssentialShed, 50.),
+    PowerConsumerDefinition::new(
+        "ACP_CAPT",
+        ElectricalBusType::DirectCurrentEssentialShed,
+        20.,
+    ),
+    // DC BAT BUS
+    PowerConsumerDefinition::new(
+        "FIRE_DETECTION_UNITS",
+        ElectricalBusType::DirectCurrentBattery,
+        28.,
+    ),
+    // DC HOT BUS 1
+    PowerConsumerDefinition::new(
+        "APU_FIRE_PROTECTION",
+        ElectricalBusType::DirectCurrentHot(1),
+        11.,
+    ),
+    PowerConsumerDefinition::new("CIDS_STANDBY", ElectricalBusType::DirectCurrentHot(1), 4.),
+    // DC HOT BUS 2
+    PowerConsumerDefinition::new(
+        "ENG_1_FIRE_PROTECTION",
+        ElectricalBusType::DirectCurrentHot(2),
+        12.,
+    ),
+    PowerConsumerDefinition::new(
+        "ENG_2_FIRE_PROTECTION",
+        ElectricalBusType::DirectCurrentHot(2),
+        12.,
+    ),
+    // DC GND/FLT BUS
+    PowerConsumerDefinition::new(
+        "SERVICE_INTERPHONE",
+        ElectricalBusType::DirectCurrentGndFltService,
+        84.,
+    ),
+];
+
 pub(super) struct A320PowerConsumption {
-    ac_bus_1_consumer: FlightPhasePowerConsumer,
-    ac_bus_2_consumer: FlightPhasePowerConsumer,
-    ac_ess_bus_consumer: FlightPhasePowerConsumer,
-    ac_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    ac_stat_inv_bus_consumer: FlightPhasePowerConsumer,
-    ac_gnd_flt_service_consumer: FlightPhasePowerConsumer,
-    dc_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_ess_bus_consumer: FlightPhasePowerConsumer,
-    dc_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    dc_bat_bus_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_gnd_flt_service_consumer: FlightPhasePowerConsumer,
+    catalog: PowerConsumerCatalog,
 }
 impl A320PowerConsumption {
     pub fn new(context: &mut InitContext) -> Self {
-        // The watts in this function are all provided by komp.
         Self {
-            ac_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26816.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(30350.1),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(33797.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(39032.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(30733.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(30243.1),
-                ),
-            ]),
-            ac_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26960.2),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(21735.8),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(25183.),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(29777.4),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(22119.),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(24475.8),
-                ),
-            ]),
-            ac_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(455.7),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(715.7),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(715.7)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(715.7)),
-            ]),
-            ac_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(560.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(823.5),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(823.5)),
-            ]),
-            ac_stat_inv_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentStaticInverter,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(135.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(135.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(135.)),
-            ]),
-            ac_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(4718.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(3663.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(3663.)),
-            ]),
-            dc_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(252.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(308.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(280.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(336.)),
-            ]),
-            dc_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(532.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(448.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(448.)),
-            ]),
-            dc_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(140.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(140.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(140.)),
-            ]),
-            dc_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(224.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(168.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(168.)),
-            ]),
-            dc_bat_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentBattery,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(0.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(28.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(28.)),
-            ]),
-            dc_hot_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentHot(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(108.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(11.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(11.)),
-            ]),
-            dc_hot_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentHot(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(24.3)),
-            ]),
-            dc_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(84.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(112.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(84.)),
-            ]),
+            catalog: PowerConsumerCatalog::new(context, A320_POWER_CONSUMERS),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext) {
-        self.ac_bus_1_consumer.update(context);
-        self.ac_bus_2_consumer.update(context);
-        self.ac_ess_bus_consumer.update(context);
-        self.ac_ess_shed_bus_consumer.update(context);
-        self.ac_stat_inv_bus_consumer.update(context);
-        self.ac_gnd_flt_service_consumer.update(context);
-        self.dc_bus_1_consumer.update(context);
-        self.dc_bus_2_consumer.update(context);
-        self.dc_ess_bus_consumer.update(context);
-        self.dc_ess_shed_bus_consumer.update(context);
-        self.dc_bat_bus_consumer.update(context);
-        self.dc_hot_bus_1_consumer.update(context);
-        self.dc_hot_bus_2_consumer.update(context);
-        self.dc_gnd_flt_service_consumer.update(context);
+        self.catalog.update(context);
     }
 }
 impl SimulationElement for A320PowerConsumption {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.ac_bus_1_consumer.accept(visitor);
-        self.ac_bus_2_consumer.accept(visitor);
-        self.ac_ess_bus_consumer.accept(visitor);
-        self.ac_ess_shed_bus_consumer.accept(visitor);
-        self.ac_stat_inv_bus_consumer.accept(visitor);
-        self.ac_gnd_flt_service_consumer.accept(visitor);
-        self.dc_bus_1_consumer.accept(visitor);
-        self.dc_bus_2_consumer.accept(visitor);
-        self.dc_ess_bus_consumer.accept(visitor);
-        self.dc_ess_shed_bus_consumer.accept(visitor);
-        self.dc_bat_bus_consumer.accept(visitor);
-        self.dc_hot_bus_1_consumer.accept(visitor);
-        self.dc_hot_bus_2_consumer.accept(visitor);
-        self.dc_gnd_flt_service_consumer.accept(visitor);
+        self.catalog.accept(visitor);
 
         visitor.visit(self);
     }
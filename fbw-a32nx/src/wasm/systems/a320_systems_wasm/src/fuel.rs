@@ -0,0 +1,82 @@
+use std::error::Error;
+
+use msfs::sim_connect;
+use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+
+use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
+use systems_wasm::{set_data_on_sim_object, Variable};
+
+pub(super) fn fuel(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    builder.copy(
+        Variable::aircraft("FUEL TANK CENTER QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_QUANTITY_CENTER"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK LEFT MAIN QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_QUANTITY_LEFT_MAIN"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK LEFT AUX QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_QUANTITY_LEFT_AUX"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK RIGHT MAIN QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_QUANTITY_RIGHT_MAIN"),
+    );
+    builder.copy(
+        Variable::aircraft("FUEL TANK RIGHT AUX QUANTITY", "gallons", 0),
+        Variable::aspect("FUEL_TANK_QUANTITY_RIGHT_AUX"),
+    );
+
+    builder.variables_to_object(Box::<Fuel>::default());
+
+    Ok(())
+}
+
+#[sim_connect::data_definition]
+#[derive(Default)]
+struct Fuel {
+    #[name = "FUEL TANK CENTER QUANTITY"]
+    #[unit = "gallons"]
+    center: f64,
+
+    #[name = "FUEL TANK LEFT MAIN QUANTITY"]
+    #[unit = "gallons"]
+    left_main: f64,
+
+    #[name = "FUEL TANK LEFT AUX QUANTITY"]
+    #[unit = "gallons"]
+    left_aux: f64,
+
+    #[name = "FUEL TANK RIGHT MAIN QUANTITY"]
+    #[unit = "gallons"]
+    right_main: f64,
+
+    #[name = "FUEL TANK RIGHT AUX QUANTITY"]
+    #[unit = "gallons"]
+    right_aux: f64,
+}
+
+impl VariablesToObject for Fuel {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::aspect("FUEL_TANK_QUANTITY_CENTER"),
+            Variable::aspect("FUEL_TANK_QUANTITY_LEFT_MAIN"),
+            Variable::aspect("FUEL_TANK_QUANTITY_LEFT_AUX"),
+            Variable::aspect("FUEL_TANK_QUANTITY_RIGHT_MAIN"),
+            Variable::aspect("FUEL_TANK_QUANTITY_RIGHT_AUX"),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+        self.center = values[0];
+        self.left_main = values[1];
+        self.left_aux = values[2];
+        self.right_main = values[3];
+        self.right_aux = values[4];
+
+        ObjectWrite::default()
+    }
+
+    set_data_on_sim_object!();
+}
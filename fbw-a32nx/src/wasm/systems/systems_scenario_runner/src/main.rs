@@ -0,0 +1,112 @@
+//! Runs scripted scenarios against the A320 or A380 systems outside of the
+//! simulator.
+//!
+//! Usage: `systems_scenario_runner <scenario.toml> [--csv <trace.csv>]`
+//!
+//! Prints the result of every assertion in the scenario and exits with a
+//! non-zero code when any assertion fails. When the scenario requests a trace,
+//! it is written as CSV to the given file, or next to the scenario file otherwise.
+//! See [`scenario::Scenario`] for the format of scenario files.
+mod runner;
+mod scenario;
+
+use a320_systems::A320;
+use a380_systems::A380;
+use runner::ScenarioReport;
+use scenario::{AircraftType, Scenario};
+use std::{error::Error, fs::File, path::PathBuf, process::ExitCode};
+use systems::simulation::test::SimulationTestBed;
+
+struct Arguments {
+    scenario: PathBuf,
+    csv: Option<PathBuf>,
+}
+impl Arguments {
+    fn parse(mut args: impl Iterator<Item = String>) -> Option<Self> {
+        let mut scenario = None;
+        let mut csv = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--csv" => csv = Some(PathBuf::from(args.next()?)),
+                _ if scenario.is_none() && !arg.starts_with('-') => {
+                    scenario = Some(PathBuf::from(arg))
+                }
+                _ => return None,
+            }
+        }
+
+        Some(Self {
+            scenario: scenario?,
+            csv,
+        })
+    }
+}
+
+fn run(arguments: &Arguments) -> Result<ScenarioReport, Box<dyn Error>> {
+    let scenario = Scenario::parse(&std::fs::read_to_string(&arguments.scenario)?)?;
+    println!(
+        "Running {} on the {}",
+        arguments.scenario.display(),
+        scenario.aircraft()
+    );
+
+    let report = match scenario.aircraft() {
+        AircraftType::A320 => runner::run(
+            &scenario,
+            SimulationTestBed::new(A320::new),
+            a320_systems::failures::identifiers(),
+        ),
+        AircraftType::A380 => runner::run(
+            &scenario,
+            SimulationTestBed::new(A380::new),
+            a380_systems::failures::identifiers(),
+        ),
+    }?;
+
+    if !report.trace().is_empty() {
+        let path = arguments
+            .csv
+            .clone()
+            .unwrap_or_else(|| arguments.scenario.with_extension("csv"));
+        report.trace().write_csv(&mut File::create(&path)?)?;
+        println!("Trace written to {}", path.display());
+    }
+
+    Ok(report)
+}
+
+fn main() -> ExitCode {
+    let Some(arguments) = Arguments::parse(std::env::args().skip(1)) else {
+        eprintln!("Usage: systems_scenario_runner <scenario.toml> [--csv <trace.csv>]");
+        return ExitCode::from(2);
+    };
+
+    match run(&arguments) {
+        Ok(report) => {
+            for result in report.results() {
+                println!("{}", result);
+            }
+
+            let failed = report
+                .results()
+                .iter()
+                .filter(|result| !result.passed())
+                .count();
+            println!(
+                "{} of {} assertions passed",
+                report.results().len() - failed,
+                report.results().len()
+            );
+
+            if report.passed() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use crate::scenario::{Assertion, Scenario, ScenarioError};
+use std::{collections::HashMap, io, time::Duration};
+use systems::{
+    failures::FailureType,
+    simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    },
+};
+
+/// Runs the scenario on the given test bed. Failures in the scenario are
+/// looked up in the given identifiers, which are the aircraft's failures as
+/// known to the flyPad.
+///
+/// Events are applied before the first tick which starts at or after their
+/// time. Assertions and traces are evaluated after the first tick which ends at
+/// or after their time.
+pub fn run<T: Aircraft>(
+    scenario: &Scenario,
+    mut test_bed: SimulationTestBed<T>,
+    failure_identifiers: Vec<(u64, FailureType)>,
+) -> Result<ScenarioReport, ScenarioError> {
+    let failures: HashMap<u64, FailureType> = failure_identifiers.into_iter().collect();
+    if let Some(identifier) = scenario
+        .failure_identifiers()
+        .find(|identifier| !failures.contains_key(identifier))
+    {
+        return Err(ScenarioError::UnknownFailure(identifier));
+    }
+
+    if let Some(name) = scenario
+        .variable_names()
+        .find(|name| !test_bed.contains_variable_with_name(name))
+    {
+        return Err(ScenarioError::UnknownVariable(name.to_owned()));
+    }
+
+    let mut trace = Trace::new(
+        scenario
+            .trace()
+            .map_or(vec![], |request| request.variables().to_vec()),
+    );
+    let trace_interval = scenario.trace().map(|request| request.interval());
+    let mut next_trace_time = Duration::ZERO;
+
+    let mut events = scenario.events().iter().peekable();
+    let mut assertions = scenario.assertions().iter().peekable();
+    let mut results = vec![];
+
+    let mut time = Duration::ZERO;
+    loop {
+        while let Some(event) = events.next_if(|event| event.at() <= time) {
+            for (name, value) in event.writes() {
+                test_bed.write_by_name(name, value);
+            }
+            for identifier in event.failures() {
+                test_bed.fail(failures[identifier]);
+            }
+            for identifier in event.repairs() {
+                test_bed.unfail(failures[identifier]);
+            }
+        }
+
+        if let Some(interval) = trace_interval {
+            if time >= next_trace_time {
+                trace.record(time, &mut test_bed);
+                next_trace_time = time + interval;
+            }
+        }
+
+        while let Some(assertion) = assertions.next_if(|assertion| assertion.at() <= time) {
+            let value = test_bed.read_by_name(assertion.variable());
+            results.push(AssertionResult::new(assertion, time, value));
+        }
+
+        if time >= scenario.duration() {
+            break;
+        }
+
+        let delta = scenario.time_step().min(scenario.duration() - time);
+        test_bed.run_with_delta(delta);
+        time += delta;
+    }
+
+    Ok(ScenarioReport { results, trace })
+}
+
+pub struct AssertionResult {
+    description: String,
+    time: Duration,
+    value: f64,
+    passed: bool,
+}
+impl AssertionResult {
+    fn new(assertion: &Assertion, time: Duration, value: f64) -> Self {
+        Self {
+            description: assertion.to_string(),
+            time,
+            value,
+            passed: assertion.holds_for(value),
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.passed
+    }
+}
+impl std::fmt::Display for AssertionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at {:.3}s: {} (was {})",
+            if self.passed { "PASS" } else { "FAIL" },
+            self.time.as_secs_f64(),
+            self.description,
+            self.value
+        )
+    }
+}
+
+pub struct ScenarioReport {
+    results: Vec<AssertionResult>,
+    trace: Trace,
+}
+impl ScenarioReport {
+    pub fn results(&self) -> &[AssertionResult] {
+        &self.results
+    }
+
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|result| result.passed())
+    }
+
+    pub fn trace(&self) -> &Trace {
+        &self.trace
+    }
+}
+
+/// The values of the traced variables over time.
+pub struct Trace {
+    variables: Vec<String>,
+    rows: Vec<(Duration, Vec<f64>)>,
+}
+impl Trace {
+    fn new(variables: Vec<String>) -> Self {
+        Self {
+            variables,
+            rows: vec![],
+        }
+    }
+
+    fn record<T: Aircraft>(&mut self, time: Duration, test_bed: &mut SimulationTestBed<T>) {
+        let values = self
+            .variables
+            .iter()
+            .map(|name| test_bed.read_by_name(name))
+            .collect();
+        self.rows.push((time, values));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn write_csv(&self, writer: &mut impl io::Write) -> io::Result<()> {
+        writeln!(writer, "time,{}", self.variables.join(","))?;
+        for (time, values) in &self.rows {
+            let values: Vec<_> = values.iter().map(|value| value.to_string()).collect();
+            writeln!(writer, "{},{}", time.as_secs_f64(), values.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use a320_systems::A320;
+
+    fn run_a320(scenario: &str) -> Result<ScenarioReport, ScenarioError> {
+        run(
+            &Scenario::parse(scenario).unwrap(),
+            SimulationTestBed::new(A320::new),
+            a320_systems::failures::identifiers(),
+        )
+    }
+
+    const HOT_BUS_TRACED: &str = r#"
+        aircraft = "A320"
+        duration = 2.0
+        time_step = 0.5
+
+        [trace]
+        variables = ["ELEC_DC_HOT_1_BUS_IS_POWERED"]
+        interval = 1.0
+    "#;
+
+    #[test]
+    fn passes_when_assertions_hold() {
+        let report = run_a320(&format!(
+            r#"{}
+            [[assertions]]
+            at = 1.0
+            variable = "ELEC_DC_HOT_1_BUS_IS_POWERED"
+            equals = true
+            "#,
+            HOT_BUS_TRACED
+        ))
+        .unwrap();
+
+        assert_eq!(report.results().len(), 1);
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn fails_when_assertions_do_not_hold() {
+        let report = run_a320(&format!(
+            r#"{}
+            [[events]]
+            at = 1.0
+            fail = [24111]
+
+            [[assertions]]
+            at = 2.0
+            variable = "ELEC_DC_HOT_1_BUS_IS_POWERED"
+            equals = true
+            "#,
+            HOT_BUS_TRACED
+        ))
+        .unwrap();
+
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn traces_requested_variables_at_interval() {
+        let report = run_a320(HOT_BUS_TRACED).unwrap();
+
+        let mut csv = vec![];
+        report.trace().write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "time,ELEC_DC_HOT_1_BUS_IS_POWERED\n0,0\n1,1\n2,1\n"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_variables() {
+        assert_eq!(
+            run_a320(
+                r#"
+                aircraft = "A320"
+                duration = 1.0
+
+                [[events]]
+                at = 0.0
+                write = { "NOT_A_VARIABLE" = 1 }
+                "#
+            )
+            .err(),
+            Some(ScenarioError::UnknownVariable("NOT_A_VARIABLE".to_owned()))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_failures() {
+        assert_eq!(
+            run_a320(
+                r#"
+                aircraft = "A320"
+                duration = 1.0
+
+                [[events]]
+                at = 0.0
+                fail = [1]
+                "#
+            )
+            .err(),
+            Some(ScenarioError::UnknownFailure(1))
+        );
+    }
+}
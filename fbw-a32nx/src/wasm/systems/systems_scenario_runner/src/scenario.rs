@@ -0,0 +1,475 @@
+use serde::Deserialize;
+use std::{collections::BTreeMap, error::Error, fmt::Display, time::Duration};
+
+/// A scenario describes a single regression run of an aircraft's systems: which
+/// aircraft to simulate, which variables to write and which failures to
+/// (de)activate at what time, what to assert and which variables to trace.
+///
+/// Scenarios are written in TOML:
+///
+/// ```toml
+/// aircraft = "A320"
+/// duration = 20.0
+///
+/// [trace]
+/// variables = ["ELEC_DC_HOT_1_BUS_IS_POWERED"]
+///
+/// [[events]]
+/// at = 10.0
+/// fail = [24111]
+///
+/// [[assertions]]
+/// at = 20.0
+/// variable = "ELEC_DC_HOT_1_BUS_IS_POWERED"
+/// equals = false
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    aircraft: AircraftType,
+    /// Total simulated time in seconds.
+    duration: f64,
+    /// Duration of a single simulation tick in seconds.
+    #[serde(default = "Scenario::default_time_step")]
+    time_step: f64,
+    #[serde(default)]
+    events: Vec<Event>,
+    #[serde(default)]
+    assertions: Vec<Assertion>,
+    #[serde(default)]
+    trace: Option<TraceRequest>,
+}
+impl Scenario {
+    fn default_time_step() -> f64 {
+        0.05
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ScenarioError> {
+        let mut scenario: Scenario =
+            toml::from_str(text).map_err(|err| ScenarioError::Parse(err.to_string()))?;
+        scenario.validate()?;
+
+        scenario.events.sort_by(|a, b| a.at.total_cmp(&b.at));
+        scenario.assertions.sort_by(|a, b| a.at.total_cmp(&b.at));
+
+        Ok(scenario)
+    }
+
+    fn validate(&self) -> Result<(), ScenarioError> {
+        if !(self.time_step.is_finite() && self.time_step > 0.) {
+            return Err(ScenarioError::InvalidTime("time_step", self.time_step));
+        }
+
+        let times = [("duration", self.duration)]
+            .into_iter()
+            .chain(self.events.iter().map(|event| ("events.at", event.at)))
+            .chain(
+                self.assertions
+                    .iter()
+                    .map(|assertion| ("assertions.at", assertion.at)),
+            )
+            .chain(
+                self.trace
+                    .iter()
+                    .map(|trace| ("trace.interval", trace.interval)),
+            );
+        for (name, time) in times {
+            if !(time.is_finite() && time >= 0.) {
+                return Err(ScenarioError::InvalidTime(name, time));
+            }
+        }
+
+        let moments = self
+            .events
+            .iter()
+            .map(|event| ("events.at", event.at))
+            .chain(
+                self.assertions
+                    .iter()
+                    .map(|assertion| ("assertions.at", assertion.at)),
+            );
+        for (name, time) in moments {
+            if time > self.duration {
+                return Err(ScenarioError::TimeAfterDuration(name, time));
+            }
+        }
+
+        if let Some(assertion) = self.assertions.iter().find(|assertion| {
+            assertion.equals.is_none() && assertion.above.is_none() && assertion.below.is_none()
+        }) {
+            return Err(ScenarioError::EmptyAssertion(assertion.variable.clone()));
+        }
+
+        Ok(())
+    }
+
+    pub fn aircraft(&self) -> AircraftType {
+        self.aircraft
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.duration)
+    }
+
+    pub fn time_step(&self) -> Duration {
+        Duration::from_secs_f64(self.time_step)
+    }
+
+    /// The events ordered by the time at which they occur.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// The assertions ordered by the time at which they are evaluated.
+    pub fn assertions(&self) -> &[Assertion] {
+        &self.assertions
+    }
+
+    pub fn trace(&self) -> Option<&TraceRequest> {
+        self.trace.as_ref()
+    }
+
+    /// All variable names referred to by the scenario.
+    pub fn variable_names(&self) -> impl Iterator<Item = &str> {
+        self.events
+            .iter()
+            .flat_map(|event| event.write.keys())
+            .chain(self.assertions.iter().map(|assertion| &assertion.variable))
+            .chain(self.trace.iter().flat_map(|trace| trace.variables.iter()))
+            .map(|name| name.as_str())
+    }
+
+    /// All failure identifiers referred to by the scenario.
+    pub fn failure_identifiers(&self) -> impl Iterator<Item = u64> + '_ {
+        self.events
+            .iter()
+            .flat_map(|event| event.fail.iter().chain(event.unfail.iter()))
+            .copied()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum AircraftType {
+    A320,
+    A380,
+}
+impl Display for AircraftType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AircraftType::A320 => write!(f, "A320"),
+            AircraftType::A380 => write!(f, "A380"),
+        }
+    }
+}
+
+/// A value as written in a scenario file. Booleans are converted to 1 and 0,
+/// like the simulator does for boolean variables.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum Value {
+    Boolean(bool),
+    Number(f64),
+}
+impl From<Value> for f64 {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Boolean(value) => {
+                if value {
+                    1.
+                } else {
+                    0.
+                }
+            }
+            Value::Number(value) => value,
+        }
+    }
+}
+
+/// Writes variables and activates or deactivates failures at the given time.
+/// Failures are referred to by the identifiers the flyPad uses for them.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Event {
+    at: f64,
+    #[serde(default)]
+    write: BTreeMap<String, Value>,
+    #[serde(default)]
+    fail: Vec<u64>,
+    #[serde(default)]
+    unfail: Vec<u64>,
+}
+impl Event {
+    pub fn at(&self) -> Duration {
+        Duration::from_secs_f64(self.at)
+    }
+
+    pub fn writes(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.write
+            .iter()
+            .map(|(name, value)| (name.as_str(), f64::from(*value)))
+    }
+
+    pub fn failures(&self) -> &[u64] {
+        &self.fail
+    }
+
+    pub fn repairs(&self) -> &[u64] {
+        &self.unfail
+    }
+}
+
+/// Checks the value of a variable at the given time. All of the given
+/// conditions must hold for the assertion to pass.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Assertion {
+    at: f64,
+    variable: String,
+    #[serde(default)]
+    equals: Option<Value>,
+    #[serde(default = "Assertion::default_tolerance")]
+    tolerance: f64,
+    #[serde(default)]
+    above: Option<f64>,
+    #[serde(default)]
+    below: Option<f64>,
+}
+impl Assertion {
+    fn default_tolerance() -> f64 {
+        1e-6
+    }
+
+    pub fn at(&self) -> Duration {
+        Duration::from_secs_f64(self.at)
+    }
+
+    pub fn variable(&self) -> &str {
+        &self.variable
+    }
+
+    pub fn holds_for(&self, value: f64) -> bool {
+        self.equals.map_or(true, |expected| {
+            (value - f64::from(expected)).abs() <= self.tolerance
+        }) && self.above.map_or(true, |limit| value > limit)
+            && self.below.map_or(true, |limit| value < limit)
+    }
+}
+impl Display for Assertion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut conditions = vec![];
+        if let Some(expected) = self.equals {
+            conditions.push(format!(
+                "equals {} (±{})",
+                f64::from(expected),
+                self.tolerance
+            ));
+        }
+        if let Some(limit) = self.above {
+            conditions.push(format!("above {}", limit));
+        }
+        if let Some(limit) = self.below {
+            conditions.push(format!("below {}", limit));
+        }
+
+        write!(f, "{} {}", self.variable, conditions.join(" and "))
+    }
+}
+
+/// The variables to record and how often to record them. Without an interval
+/// the variables are recorded every tick.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TraceRequest {
+    variables: Vec<String>,
+    #[serde(default)]
+    interval: f64,
+}
+impl TraceRequest {
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs_f64(self.interval)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ScenarioError {
+    Parse(String),
+    InvalidTime(&'static str, f64),
+    TimeAfterDuration(&'static str, f64),
+    EmptyAssertion(String),
+    UnknownVariable(String),
+    UnknownFailure(u64),
+}
+impl Display for ScenarioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScenarioError::Parse(message) => write!(f, "invalid scenario: {}", message),
+            ScenarioError::InvalidTime(name, value) => {
+                write!(
+                    f,
+                    "{} must be a non-negative number of seconds, got {}",
+                    name, value
+                )
+            }
+            ScenarioError::TimeAfterDuration(name, value) => {
+                write!(
+                    f,
+                    "{} of {} seconds is after the end of the scenario",
+                    name, value
+                )
+            }
+            ScenarioError::EmptyAssertion(variable) => write!(
+                f,
+                "assertion on {} needs at least one of equals, above or below",
+                variable
+            ),
+            ScenarioError::UnknownVariable(name) => {
+                write!(f, "the aircraft has no variable named {}", name)
+            }
+            ScenarioError::UnknownFailure(identifier) => {
+                write!(
+                    f,
+                    "the aircraft has no failure with identifier {}",
+                    identifier
+                )
+            }
+        }
+    }
+}
+impl Error for ScenarioError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_scenario() {
+        let scenario = Scenario::parse(
+            r#"
+            aircraft = "A380"
+            duration = 10.0
+
+            [[events]]
+            at = 5.0
+            write = { "A" = true, "B" = 2 }
+            fail = [24000]
+
+            [[events]]
+            at = 1.0
+            unfail = [24000]
+
+            [[assertions]]
+            at = 10.0
+            variable = "A"
+            above = 0.5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(scenario.aircraft(), AircraftType::A380);
+        assert_eq!(scenario.time_step(), Duration::from_millis(50));
+        assert_eq!(scenario.events()[0].at(), Duration::from_secs(1));
+        assert_eq!(
+            scenario.events()[1].writes().collect::<Vec<_>>(),
+            vec![("A", 1.), ("B", 2.)]
+        );
+        assert_eq!(
+            scenario.failure_identifiers().collect::<Vec<_>>(),
+            vec![24000, 24000]
+        );
+        assert!(scenario.trace().is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(matches!(
+            Scenario::parse(
+                r#"
+                aircraft = "A320"
+                duration = 10.0
+                speed = 1.0
+                "#
+            ),
+            Err(ScenarioError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_negative_times() {
+        assert_eq!(
+            Scenario::parse(
+                r#"
+                aircraft = "A320"
+                duration = 10.0
+
+                [[events]]
+                at = -1.0
+                "#
+            )
+            .unwrap_err(),
+            ScenarioError::InvalidTime("events.at", -1.)
+        );
+    }
+
+    #[test]
+    fn rejects_times_after_duration() {
+        assert_eq!(
+            Scenario::parse(
+                r#"
+                aircraft = "A320"
+                duration = 10.0
+
+                [[assertions]]
+                at = 10.5
+                variable = "A"
+                equals = true
+                "#
+            )
+            .unwrap_err(),
+            ScenarioError::TimeAfterDuration("assertions.at", 10.5)
+        );
+    }
+
+    #[test]
+    fn rejects_assertion_without_condition() {
+        assert_eq!(
+            Scenario::parse(
+                r#"
+                aircraft = "A320"
+                duration = 10.0
+
+                [[assertions]]
+                at = 1.0
+                variable = "A"
+                "#
+            )
+            .unwrap_err(),
+            ScenarioError::EmptyAssertion("A".to_owned())
+        );
+    }
+
+    #[test]
+    fn assertion_checks_all_conditions() {
+        let scenario = Scenario::parse(
+            r#"
+            aircraft = "A320"
+            duration = 10.0
+
+            [[assertions]]
+            at = 1.0
+            variable = "A"
+            above = 1.0
+            below = 3.0
+            "#,
+        )
+        .unwrap();
+        let assertion = &scenario.assertions()[0];
+
+        assert!(assertion.holds_for(2.));
+        assert!(!assertion.holds_for(1.));
+        assert!(!assertion.holds_for(3.5));
+    }
+}
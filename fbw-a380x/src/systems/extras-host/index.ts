@@ -2,7 +2,12 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import { EventBus, HEventPublisher, InstrumentBackplane } from '@microsoft/msfs-sdk';
-import { FlightDeckBounds, NotificationManager, PilotSeatManager } from '@flybywiresim/fbw-sdk';
+import {
+  FlightDeckBounds,
+  HydraulicPumpWearManager,
+  NotificationManager,
+  PilotSeatManager,
+} from '@flybywiresim/fbw-sdk';
 import { ExtrasSimVarPublisher } from 'extras-host/modules/common/ExtrasSimVarPublisher';
 import { PushbuttonCheck } from 'extras-host/modules/pushbutton_check/PushbuttonCheck';
 import { KeyInterceptor } from './modules/key_interceptor/KeyInterceptor';
@@ -59,6 +64,21 @@ class ExtrasHost extends BaseInstrument {
 
   private readonly pilotSeatManager = new PilotSeatManager(ExtrasHost.flightDeckBounds);
 
+  private readonly hydraulicPumpWearManager = new HydraulicPumpWearManager([
+    'HYD_GREEN_1A_EDPUMP',
+    'HYD_GREEN_1B_EDPUMP',
+    'HYD_GREEN_2A_EDPUMP',
+    'HYD_GREEN_2B_EDPUMP',
+    'HYD_YELLOW_3A_EDPUMP',
+    'HYD_YELLOW_3B_EDPUMP',
+    'HYD_YELLOW_4A_EDPUMP',
+    'HYD_YELLOW_4B_EDPUMP',
+    'HYD_GA_EPUMP',
+    'HYD_GB_EPUMP',
+    'HYD_YA_EPUMP',
+    'HYD_YB_EPUMP',
+  ]);
+
   private readonly telexCheck = new TelexCheck();
 
   /**
@@ -83,6 +103,7 @@ class ExtrasHost extends BaseInstrument {
     this.aircraftSync = new AircraftSync(process.env.AIRCRAFT_PROJECT_PREFIX, this.bus);
 
     this.backplane.addInstrument('PilotSeatManager', this.pilotSeatManager);
+    this.backplane.addInstrument('HydraulicPumpWearManager', this.hydraulicPumpWearManager);
 
     console.log('A380X_EXTRASHOST: Created');
   }
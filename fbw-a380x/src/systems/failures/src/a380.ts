@@ -80,6 +80,12 @@ export const A380Failure = Object.freeze({
   Generator4: 24023,
   ApuGenerator1: 24030,
   ApuGenerator2: 24031,
+  Generator1Feeder: 24040,
+  Generator2Feeder: 24041,
+  Generator3Feeder: 24042,
+  Generator4Feeder: 24043,
+  ApuGenerator1Feeder: 24050,
+  ApuGenerator2Feeder: 24051,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrent3: 24102,
@@ -118,6 +124,21 @@ export const A380Failure = Object.freeze({
   MLGLoopA: 26017,
   MLGLoopB: 26018,
 
+  LeftOuterTankQuantityProbe: 28010,
+  FeedOneTankQuantityProbe: 28011,
+  LeftMidTankQuantityProbe: 28012,
+  LeftInnerTankQuantityProbe: 28013,
+  FeedTwoTankQuantityProbe: 28014,
+  FeedThreeTankQuantityProbe: 28015,
+  RightInnerTankQuantityProbe: 28016,
+  RightMidTankQuantityProbe: 28017,
+  FeedFourTankQuantityProbe: 28018,
+  RightOuterTankQuantityProbe: 28019,
+  TrimTankQuantityProbe: 28020,
+  FuelQuantityIndicationChannel1: 28030,
+  FuelQuantityIndicationChannel2: 28031,
+  FuelDensitometer: 28040,
+
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
   GreenReservoirAirLeak: 29002,
@@ -136,6 +157,18 @@ export const A380Failure = Object.freeze({
   EnginePump3BOHeat: 29015,
   EnginePump4AOHeat: 29016,
   EnginePump4BOHeat: 29017,
+  GreenElecPumpAWear: 29018,
+  GreenElecPumpBWear: 29019,
+  YellowElecPumpAWear: 29020,
+  YellowElecPumpBWear: 29021,
+  EnginePump1AWear: 29022,
+  EnginePump1BWear: 29023,
+  EnginePump2AWear: 29024,
+  EnginePump2BWear: 29025,
+  EnginePump3AWear: 29026,
+  EnginePump3BWear: 29027,
+  EnginePump4AWear: 29028,
+  EnginePump4BWear: 29029,
 
   LeftPfdDisplay: 31000,
   RightPfdDisplay: 31001,
@@ -155,6 +188,26 @@ export const A380Failure = Object.freeze({
   RadioAltimeter3: 34002,
   Transponder1: 34003,
   Transponder2: 34004,
+  Adiru1: 34100,
+  Adiru2: 34101,
+  Adiru3: 34102,
+  Adr1: 34110,
+  Adr2: 34111,
+  Adr3: 34112,
+  Ir1: 34120,
+  Ir2: 34121,
+  Ir3: 34122,
+  PitotProbeBlocked1: 34130,
+  PitotProbeBlocked2: 34131,
+  PitotProbeBlocked3: 34132,
+  StaticPortBlocked1: 34140,
+  StaticPortBlocked2: 34141,
+  StaticPortBlocked3: 34142,
+  AngleOfAttackVaneJammed1: 34150,
+  AngleOfAttackVaneJammed2: 34151,
+  AngleOfAttackVaneJammed3: 34152,
+  GpsSatelliteLoss1: 34200,
+  GpsSatelliteLoss2: 34201,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
@@ -233,6 +286,12 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [24, A380Failure.Generator4, 'Generator 4'],
   [24, A380Failure.ApuGenerator1, 'APU Generator 1'],
   [24, A380Failure.ApuGenerator2, 'APU Generator 2'],
+  [24, A380Failure.Generator1Feeder, 'Generator 1 Feeder'],
+  [24, A380Failure.Generator2Feeder, 'Generator 2 Feeder'],
+  [24, A380Failure.Generator3Feeder, 'Generator 3 Feeder'],
+  [24, A380Failure.Generator4Feeder, 'Generator 4 Feeder'],
+  [24, A380Failure.ApuGenerator1Feeder, 'APU Generator 1 Feeder'],
+  [24, A380Failure.ApuGenerator2Feeder, 'APU Generator 2 Feeder'],
   [24, A380Failure.AlternatingCurrent1, 'AC 1'],
   [24, A380Failure.AlternatingCurrent2, 'AC 2'],
   [24, A380Failure.AlternatingCurrent3, 'AC 3'],
@@ -271,6 +330,21 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [26, A380Failure.MLGLoopA, 'Main Landing Gear Bay Loop A'],
   [26, A380Failure.MLGLoopB, 'Main Landing Gear Bay Loop B'],
 
+  [28, A380Failure.LeftOuterTankQuantityProbe, 'L outer TK quantity probe'],
+  [28, A380Failure.FeedOneTankQuantityProbe, 'Feed 1 TK quantity probe'],
+  [28, A380Failure.LeftMidTankQuantityProbe, 'L mid TK quantity probe'],
+  [28, A380Failure.LeftInnerTankQuantityProbe, 'L inner TK quantity probe'],
+  [28, A380Failure.FeedTwoTankQuantityProbe, 'Feed 2 TK quantity probe'],
+  [28, A380Failure.FeedThreeTankQuantityProbe, 'Feed 3 TK quantity probe'],
+  [28, A380Failure.RightInnerTankQuantityProbe, 'R inner TK quantity probe'],
+  [28, A380Failure.RightMidTankQuantityProbe, 'R mid TK quantity probe'],
+  [28, A380Failure.FeedFourTankQuantityProbe, 'Feed 4 TK quantity probe'],
+  [28, A380Failure.RightOuterTankQuantityProbe, 'R outer TK quantity probe'],
+  [28, A380Failure.TrimTankQuantityProbe, 'Trim TK quantity probe'],
+  [28, A380Failure.FuelQuantityIndicationChannel1, 'Fuel quantity indication channel 1'],
+  [28, A380Failure.FuelQuantityIndicationChannel2, 'Fuel quantity indication channel 2'],
+  [28, A380Failure.FuelDensitometer, 'Fuel densitometer'],
+
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
   [29, A380Failure.GreenReservoirAirLeak, 'Green reservoir air leak'],
@@ -289,6 +363,18 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [29, A380Failure.EnginePump3BOHeat, 'Engine 3 pump B overheat'],
   [29, A380Failure.EnginePump4AOHeat, 'Engine 4 pump A overheat'],
   [29, A380Failure.EnginePump4BOHeat, 'Engine 4 pump B overheat'],
+  [29, A380Failure.GreenElecPumpAWear, 'Green A elec pump worn'],
+  [29, A380Failure.GreenElecPumpBWear, 'Green B elec pump worn'],
+  [29, A380Failure.YellowElecPumpAWear, 'Yellow A elec pump worn'],
+  [29, A380Failure.YellowElecPumpBWear, 'Yellow B elec pump worn'],
+  [29, A380Failure.EnginePump1AWear, 'Engine 1 pump A worn'],
+  [29, A380Failure.EnginePump1BWear, 'Engine 1 pump B worn'],
+  [29, A380Failure.EnginePump2AWear, 'Engine 2 pump A worn'],
+  [29, A380Failure.EnginePump2BWear, 'Engine 2 pump B worn'],
+  [29, A380Failure.EnginePump3AWear, 'Engine 3 pump A worn'],
+  [29, A380Failure.EnginePump3BWear, 'Engine 3 pump B worn'],
+  [29, A380Failure.EnginePump4AWear, 'Engine 4 pump A worn'],
+  [29, A380Failure.EnginePump4BWear, 'Engine 4 pump B worn'],
 
   [31, A380Failure.LeftPfdDisplay, 'Captain PFD display'],
   [31, A380Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -305,4 +391,24 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [34, A380Failure.RadioAltimeter3, 'RA SYS C'],
   [34, A380Failure.Transponder1, 'XPDR 1'],
   [34, A380Failure.Transponder2, 'XPDR 2'],
+  [34, A380Failure.Adiru1, 'ADIRU 1'],
+  [34, A380Failure.Adiru2, 'ADIRU 2'],
+  [34, A380Failure.Adiru3, 'ADIRU 3'],
+  [34, A380Failure.Adr1, 'ADR 1'],
+  [34, A380Failure.Adr2, 'ADR 2'],
+  [34, A380Failure.Adr3, 'ADR 3'],
+  [34, A380Failure.Ir1, 'IR 1'],
+  [34, A380Failure.Ir2, 'IR 2'],
+  [34, A380Failure.Ir3, 'IR 3'],
+  [34, A380Failure.PitotProbeBlocked1, 'Pitot probe 1 blocked'],
+  [34, A380Failure.PitotProbeBlocked2, 'Pitot probe 2 blocked'],
+  [34, A380Failure.PitotProbeBlocked3, 'Pitot probe 3 blocked'],
+  [34, A380Failure.StaticPortBlocked1, 'Static port 1 blocked'],
+  [34, A380Failure.StaticPortBlocked2, 'Static port 2 blocked'],
+  [34, A380Failure.StaticPortBlocked3, 'Static port 3 blocked'],
+  [34, A380Failure.AngleOfAttackVaneJammed1, 'AOA vane 1 jammed'],
+  [34, A380Failure.AngleOfAttackVaneJammed2, 'AOA vane 2 jammed'],
+  [34, A380Failure.AngleOfAttackVaneJammed3, 'AOA vane 3 jammed'],
+  [34, A380Failure.GpsSatelliteLoss1, 'GPS 1 satellite loss'],
+  [34, A380Failure.GpsSatelliteLoss2, 'GPS 2 satellite loss'],
 ];
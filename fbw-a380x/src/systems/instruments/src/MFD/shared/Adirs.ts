@@ -26,17 +26,17 @@ export class ADIRS {
   static mapNotAvailable(displayIndex: number) {
     const inertialReferenceSource = ADIRS.getNdInertialReferenceSource(displayIndex);
     return (
-      !Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_LATITUDE`).isNormalOperation() ||
-      !Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_LONGITUDE`).isNormalOperation()
+      !Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_GPIRS_LATITUDE`).isNormalOperation() ||
+      !Arinc429Word.fromSimVarValue(`L:A32NX_ADIRS_IR_${inertialReferenceSource}_GPIRS_LONGITUDE`).isNormalOperation()
     );
   }
 
   static getLatitude() {
-    return ADIRS.getFromAnyAdiru('IR', 'LATITUDE');
+    return ADIRS.getFromAnyAdiru('IR', 'GPIRS_LATITUDE');
   }
 
   static getLongitude() {
-    return ADIRS.getFromAnyAdiru('IR', 'LONGITUDE');
+    return ADIRS.getFromAnyAdiru('IR', 'GPIRS_LONGITUDE');
   }
 
   static getTrueTrack() {
@@ -8,7 +8,6 @@ import { useInterval } from '@flybywiresim/fbw-sdk';
 export const FuelPage = () => {
   const CROSS_FEED_VALVE_CLOSED_THRESHOLD = 0.1;
   const TRANSFER_VALVE_CLOSED_THRESHOLD = 0.1;
-  const JETTISON_VALVE_CLOSED_THRESHOLD = 0.1;
   const FEED_TANK_LOW_LEVEL_THRESHOLD_KG = 1375;
 
   const [showMore] = useState(false);
@@ -701,11 +700,9 @@ export const FuelPage = () => {
   ];
 
   // Jettison valves
-  const [leftJettisonValveOpen] = useSimVar('FUELSYSTEM VALVE OPEN:57', 'Percent over 100', 1000);
-  const isLeftJettisonValveOpen = leftJettisonValveOpen >= JETTISON_VALVE_CLOSED_THRESHOLD;
-  const [rightJettisonValveOpen] = useSimVar('FUELSYSTEM VALVE OPEN:58', 'Percent over 100', 1000);
-  const isRightJettisonValveOpen = rightJettisonValveOpen >= JETTISON_VALVE_CLOSED_THRESHOLD;
-  const isJettisonActive = false; // TODO
+  const [isLeftJettisonValveOpen] = useSimVar('L:A32NX_FUEL_LEFT_JETTISON_VALVE_IS_OPEN', 'Bool', 1000);
+  const [isRightJettisonValveOpen] = useSimVar('L:A32NX_FUEL_RIGHT_JETTISON_VALVE_IS_OPEN', 'Bool', 1000);
+  const [isJettisonActive] = useSimVar('L:A32NX_FUEL_JETTISON_ACTIVE', 'Bool', 1000);
 
   // Collector cells
   const collectorCell1Weight = 1200;
@@ -1,4 +1,4 @@
-import { Arinc429SignStatusMatrix, Arinc429Word, NXDataStore, UpdateThrottler } from '@flybywiresim/fbw-sdk';
+import { Arinc429Word, NXDataStore, UpdateThrottler } from '@flybywiresim/fbw-sdk';
 import { FmgcFlightPhase } from '@shared/flightphase';
 import { LegacySoundManager, soundList } from 'systems-host/systems/LegacySoundManager';
 import { A380X_DEFAULT_RADIO_AUTO_CALL_OUTS, A380XRadioAutoCallOutFlags } from '../../shared/src/AutoCallOuts';
@@ -22,22 +22,6 @@ export class LegacyGpws {
 
   minimumsState = 0;
 
-  Mode3MaxBaroAlt: number;
-
-  Mode4MaxRAAlt: number;
-
-  Mode2BoundaryLeaveAlt: number;
-
-  Mode2NumTerrain: number;
-
-  Mode2NumFramesInBoundary: number;
-
-  RadioAltRate: number;
-
-  prevRadioAlt: number;
-
-  prevRadioAlt2: number;
-
   modes: ModesType[];
 
   PrevShouldPullUpPlay: boolean;
@@ -46,28 +30,12 @@ export class LegacyGpws {
 
   RetardState: LegacyStateMachine;
 
-  egpwsAlertDiscreteWord1 = Arinc429Word.empty();
-
-  egpwsAlertDiscreteWord2 = Arinc429Word.empty();
-
   // eslint-disable-next-line camelcase
   constructor(private soundManager: LegacySoundManager) {
     this.autoCallOutPins = A380X_DEFAULT_RADIO_AUTO_CALL_OUTS;
 
     this.minimumsState = 0;
 
-    this.Mode3MaxBaroAlt = NaN;
-
-    this.Mode4MaxRAAlt = NaN;
-
-    this.Mode2BoundaryLeaveAlt = NaN;
-    this.Mode2NumTerrain = 0;
-    this.Mode2NumFramesInBoundary = 0;
-
-    this.RadioAltRate = NaN;
-    this.prevRadioAlt = NaN;
-    this.prevRadioAlt2 = NaN;
-
     this.modes = [
       // Mode 1
       {
@@ -108,10 +76,10 @@ export class LegacyGpws {
       },
       // Mode 5, not all warnings are fully implemented
       {
-        // 0: no warning, 1: "glideslope", 2: "hard glideslope" (louder)
+        // 0: no warning, 1: "glideslope"
         current: 0,
         previous: 0,
-        type: [{}, {}, {}],
+        type: [{}, {}],
         onChange: (current) => {
           this.setGlideSlopeWarning(current >= 1);
         },
@@ -126,71 +94,32 @@ export class LegacyGpws {
     this.RetardState.setState('landed');
   }
 
-  gpwsUpdateDiscreteWords() {
-    this.egpwsAlertDiscreteWord1.ssm = Arinc429SignStatusMatrix.NormalOperation;
-    this.egpwsAlertDiscreteWord1.setBitValue(11, this.modes[0].current === 1);
-    this.egpwsAlertDiscreteWord1.setBitValue(12, this.modes[0].current === 2);
-    this.egpwsAlertDiscreteWord1.setBitValue(13, this.modes[1].current === 1);
-    this.egpwsAlertDiscreteWord1.setBitValue(12, this.modes[1].current === 2);
-    this.egpwsAlertDiscreteWord1.setBitValue(14, this.modes[2].current === 1);
-    this.egpwsAlertDiscreteWord1.setBitValue(15, this.modes[3].current === 1);
-    this.egpwsAlertDiscreteWord1.setBitValue(16, this.modes[3].current === 2);
-    this.egpwsAlertDiscreteWord1.setBitValue(17, this.modes[3].current === 3);
-    this.egpwsAlertDiscreteWord1.setBitValue(18, this.modes[4].current === 1);
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_1_DISCRETE_WORD_1',
-      this.egpwsAlertDiscreteWord1.value,
-      this.egpwsAlertDiscreteWord1.ssm,
-    );
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_2_DISCRETE_WORD_1',
-      this.egpwsAlertDiscreteWord1.value,
-      this.egpwsAlertDiscreteWord1.ssm,
-    );
-
-    this.egpwsAlertDiscreteWord2.ssm = Arinc429SignStatusMatrix.NormalOperation;
-    this.egpwsAlertDiscreteWord2.setBitValue(14, false);
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_1_DISCRETE_WORD_2',
-      this.egpwsAlertDiscreteWord2.value,
-      this.egpwsAlertDiscreteWord2.ssm,
-    );
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_2_DISCRETE_WORD_2',
-      this.egpwsAlertDiscreteWord2.value,
-      this.egpwsAlertDiscreteWord2.ssm,
-    );
+  /**
+   * Decodes the alerting modes from the EGPWS alert discrete word computed by the systems.
+   * @param word - Arinc429 value of the EGPWS alert discrete word 1.
+   */
+  gpwsReadDiscreteWord(word: Arinc429Word) {
+    this.modes[0].current = word.bitValueOr(12, false) ? 2 : word.bitValueOr(11, false) ? 1 : 0;
+    this.modes[1].current = word.bitValueOr(13, false) ? 1 : 0;
+    this.modes[2].current = word.bitValueOr(14, false) ? 1 : 0;
+    if (word.bitValueOr(15, false)) {
+      this.modes[3].current = 1;
+    } else if (word.bitValueOr(16, false)) {
+      this.modes[3].current = 2;
+    } else if (word.bitValueOr(17, false)) {
+      this.modes[3].current = 3;
+    } else {
+      this.modes[3].current = 0;
+    }
+    this.modes[4].current = word.bitValueOr(18, false) ? 1 : 0;
   }
 
   setGlideSlopeWarning(state: boolean) {
     SimVar.SetSimVarValue('L:A32NX_GPWS_GS_Warning_Active', 'Bool', state ? 1 : 0); // Still need this for XML
-    this.egpwsAlertDiscreteWord2.setBitValue(11, state);
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_1_DISCRETE_WORD_2',
-      this.egpwsAlertDiscreteWord2.value,
-      this.egpwsAlertDiscreteWord2.ssm,
-    );
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_2_DISCRETE_WORD_2',
-      this.egpwsAlertDiscreteWord2.value,
-      this.egpwsAlertDiscreteWord2.ssm,
-    );
   }
 
   setGpwsWarning(state: boolean) {
     SimVar.SetSimVarValue('L:A32NX_GPWS_Warning_Active', 'Bool', state ? 1 : 0); // Still need this for XML
-    this.egpwsAlertDiscreteWord2.setBitValue(12, state);
-    this.egpwsAlertDiscreteWord2.setBitValue(13, state);
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_1_DISCRETE_WORD_2',
-      this.egpwsAlertDiscreteWord2.value,
-      this.egpwsAlertDiscreteWord2.ssm,
-    );
-    Arinc429Word.toSimVarValue(
-      'L:A32NX_EGPWS_ALERT_2_DISCRETE_WORD_2',
-      this.egpwsAlertDiscreteWord2.value,
-      this.egpwsAlertDiscreteWord2.ssm,
-    );
   }
 
   init() {
@@ -198,8 +127,6 @@ export class LegacyGpws {
 
     this.setGlideSlopeWarning(false);
     this.setGpwsWarning(false);
-    this.egpwsAlertDiscreteWord1.ssm = Arinc429SignStatusMatrix.NormalOperation;
-    this.egpwsAlertDiscreteWord1.setBitValue(12, false);
 
     // eslint-disable-next-line max-len
     NXDataStore.getAndSubscribe(
@@ -213,64 +140,27 @@ export class LegacyGpws {
     const throttledT = this.updateThrottler.canUpdate(deltaTime);
 
     if (throttledT > 0) {
-      this.gpws(throttledT);
+      this.gpws();
     }
   }
 
-  gpws(deltaTime) {
+  gpws() {
     // EGPWS receives ADR1 only
     const baroAlt = Arinc429Word.fromSimVarValue('L:A32NX_ADIRS_ADR_1_BARO_CORRECTED_ALTITUDE_1');
     const radioAlt1 = Arinc429Word.fromSimVarValue('L:A32NX_RA_1_RADIO_ALTITUDE');
     const radioAlt2 = Arinc429Word.fromSimVarValue('L:A32NX_RA_2_RADIO_ALTITUDE');
     const radioAlt = radioAlt1.isFailureWarning() || radioAlt1.isNoComputedData() ? radioAlt2 : radioAlt1;
     const radioAltValid = radioAlt.isNormalOperation();
-    const onGround = SimVar.GetSimVarValue('SIM ON GROUND', 'Bool');
 
     this.UpdateAltState(radioAltValid ? radioAlt.value : NaN);
-    this.differentiateRadioalt(radioAltValid ? radioAlt.value : NaN, deltaTime);
 
     const mda = SimVar.GetSimVarValue('L:AIRLINER_MINIMUM_DESCENT_ALTITUDE', 'feet');
     const dh = SimVar.GetSimVarValue('L:AIRLINER_DECISION_HEIGHT', 'feet');
     const phase = SimVar.GetSimVarValue('L:A32NX_FMGC_FLIGHT_PHASE', 'Enum');
 
-    if (
-      radioAltValid &&
-      radioAlt.value >= 10 &&
-      radioAlt.value <= 2450 &&
-      !SimVar.GetSimVarValue('L:A32NX_GPWS_SYS_OFF', 'Bool')
-    ) {
-      // Activate between 10 - 2450 radio alt unless SYS is off
-      const flapsThreeSelected = SimVar.GetSimVarValue('L:A32NX_SPEEDS_LANDING_CONF3', 'Bool');
-      const FlapPosition = SimVar.GetSimVarValue('L:A32NX_FLAPS_HANDLE_INDEX', 'Number');
-      const FlapsInLandingConfig = flapsThreeSelected ? FlapPosition === 3 : FlapPosition === 4; // fixme should be actual flap angle
-      const vSpeed = Simplane.getVerticalSpeed();
-      const Airspeed = SimVar.GetSimVarValue('AIRSPEED INDICATED', 'Knots');
-      const gearExtended = SimVar.GetSimVarValue('GEAR TOTAL PCT EXTENDED', 'Percent') > 0.9;
-
-      this.updateMaxRA(radioAlt.value, onGround, phase);
-
-      this.GPWSMode1(this.modes[0], radioAlt.value, vSpeed);
-      // Mode 2 is disabled because of an issue with the terrain height simvar which causes false warnings very frequently. See PR#1742 for more info
-      // this.GPWSMode2(this.modes[1], radioAlt, Airspeed, FlapsInLandingConfig, gearExtended);
-      this.GPWSMode3(this.modes[2], radioAlt.value, phase);
-      this.GPWSMode4(this.modes[3], radioAlt.value, Airspeed, FlapsInLandingConfig, gearExtended, phase);
-      this.GPWSMode5(this.modes[4], radioAlt.value);
-    } else {
-      this.modes.forEach((mode) => {
-        mode.current = 0;
-      });
-
-      this.Mode3MaxBaroAlt = NaN;
-      if (onGround || (radioAltValid && radioAlt.value < 10)) {
-        this.Mode4MaxRAAlt = NaN;
-      }
-
-      this.setGlideSlopeWarning(false);
-      this.setGpwsWarning(false);
-    }
-
+    // The alerting modes are computed by the EGPWC in the systems
+    this.gpwsReadDiscreteWord(Arinc429Word.fromSimVarValue('L:A32NX_EGPWS_ALERT_1_DISCRETE_WORD_1'));
     this.GPWSComputeLightsAndCallouts();
-    this.gpwsUpdateDiscreteWords();
 
     if (mda !== 0 || (dh !== -1 && dh !== -2 && phase === FmgcFlightPhase.Approach)) {
       let minimumsDA; // MDA or DH
@@ -288,33 +178,6 @@ export class LegacyGpws {
     }
   }
 
-  /**
-   * Takes the derivative of the radio altimeter. Using central difference, to prevent high frequency noise
-   * @param radioAlt - in feet
-   * @param deltaTime - in milliseconds
-   */
-  differentiateRadioalt(radioAlt, deltaTime) {
-    if (!Number.isNaN(this.prevRadioAlt2) && !Number.isNaN(radioAlt)) {
-      this.RadioAltRate = (radioAlt - this.prevRadioAlt2) / (deltaTime / 1000 / 60) / 2;
-      this.prevRadioAlt2 = this.prevRadioAlt;
-      this.prevRadioAlt = radioAlt;
-    } else if (!Number.isNaN(this.prevRadioAlt) && !Number.isNaN(radioAlt)) {
-      this.prevRadioAlt2 = this.prevRadioAlt;
-      this.prevRadioAlt = radioAlt;
-    } else {
-      this.prevRadioAlt2 = radioAlt;
-    }
-  }
-
-  updateMaxRA(radioAlt, onGround, phase) {
-    // on ground check is to get around the fact that radio alt is set to around 300 while loading
-    if (onGround || phase === FmgcFlightPhase.GoAround) {
-      this.Mode4MaxRAAlt = NaN;
-    } else if (this.Mode4MaxRAAlt < radioAlt || Number.isNaN(this.Mode4MaxRAAlt)) {
-      this.Mode4MaxRAAlt = radioAlt;
-    }
-  }
-
   gpwsMinimums(minimumsDA, minimumsIA) {
     let over100Above = false;
     let overMinimums = false;
@@ -374,195 +237,6 @@ export class LegacyGpws {
     this.setGpwsWarning(illuminateGpwsLight);
   }
 
-  /**
-   * Compute the GPWS Mode 1 state.
-   * @param mode - The mode object which stores the state.
-   * @param radioAlt - Radio altitude in feet
-   * @param vSpeed - Vertical speed, in feet/min, should be inertial vertical speed, not sure if simconnect provides that
-   */
-  GPWSMode1(mode, radioAlt, vSpeed) {
-    const sinkrate = -vSpeed;
-
-    if (sinkrate <= 1000) {
-      mode.current = 0;
-      return;
-    }
-
-    const maxSinkrateAlt = 0.61 * sinkrate - 600;
-    const maxPullUpAlt = sinkrate < 1700 ? 1.3 * sinkrate - 1940 : 0.4 * sinkrate - 410;
-
-    if (radioAlt <= maxPullUpAlt) {
-      mode.current = 2;
-    } else if (radioAlt <= maxSinkrateAlt) {
-      mode.current = 1;
-    } else {
-      mode.current = 0;
-    }
-  }
-
-  /**
-   * Compute the GPWS Mode 2 state.
-   * @param mode - The mode object which stores the state.
-   * @param radioAlt - Radio altitude in feet
-   * @param speed - Airspeed in knots.
-   * @param FlapsInLandingConfig - If flaps is in landing config
-   * @param gearExtended - If the gear is deployed
-   */
-  GPWSMode2(mode, radioAlt, speed, FlapsInLandingConfig, gearExtended) {
-    let IsInBoundary = false;
-    const UpperBoundaryRate =
-      -this.RadioAltRate < 3500 ? 0.7937 * -this.RadioAltRate - 1557.5 : 0.19166 * -this.RadioAltRate + 610;
-    const UpperBoundarySpeed = Math.max(1650, Math.min(2450, 8.8888 * speed - 305.555));
-
-    if (!FlapsInLandingConfig && -this.RadioAltRate > 2000) {
-      if (radioAlt < UpperBoundarySpeed && radioAlt < UpperBoundaryRate) {
-        this.Mode2NumFramesInBoundary += 1;
-      } else {
-        this.Mode2NumFramesInBoundary = 0;
-      }
-    } else if (FlapsInLandingConfig && -this.RadioAltRate > 2000) {
-      if (radioAlt < 775 && radioAlt < UpperBoundaryRate && -this.RadioAltRate < 10000) {
-        this.Mode2NumFramesInBoundary += 1;
-      } else {
-        this.Mode2NumFramesInBoundary = 0;
-      }
-    }
-    // This is to prevent very quick changes in radio alt rate triggering the alarm. The derivative is sadly pretty jittery.
-    if (this.Mode2NumFramesInBoundary > 5) {
-      IsInBoundary = true;
-    }
-
-    if (IsInBoundary) {
-      this.Mode2BoundaryLeaveAlt = -1;
-      if (this.Mode2NumTerrain < 2 || gearExtended) {
-        if (this.soundManager.tryPlaySound(soundList.too_low_terrain)) {
-          // too low terrain is not correct, but no "terrain" call yet
-          this.Mode2NumTerrain += 1;
-        }
-        mode.current = 1;
-      } else if (!gearExtended) {
-        mode.current = 2;
-      }
-    } else if (this.Mode2BoundaryLeaveAlt === -1) {
-      this.Mode2BoundaryLeaveAlt = radioAlt;
-    } else if (this.Mode2BoundaryLeaveAlt + 300 > radioAlt) {
-      mode.current = 1;
-      this.soundManager.tryPlaySound(soundList.too_low_terrain);
-    } else if (this.Mode2BoundaryLeaveAlt + 300 <= radioAlt) {
-      mode.current = 0;
-      this.Mode2NumTerrain = 0;
-      this.Mode2BoundaryLeaveAlt = NaN;
-    }
-  }
-
-  /**
-   * Compute the GPWS Mode 3 state.
-   * @param mode - The mode object which stores the state.
-   * @param radioAlt - Radio altitude in feet
-   * @param phase - Flight phase index
-   * @param FlapsInLandingConfig - If flaps is in landing config
-   * @constructor
-   */
-  GPWSMode3(mode, radioAlt, phase) {
-    if (
-      !(phase === FmgcFlightPhase.Takeoff || phase === FmgcFlightPhase.GoAround) ||
-      radioAlt > 1500 ||
-      radioAlt < 10
-    ) {
-      this.Mode3MaxBaroAlt = NaN;
-      mode.current = 0;
-      return;
-    }
-
-    const baroAlt = SimVar.GetSimVarValue('PLANE ALTITUDE', 'feet');
-
-    const maxAltLoss = 0.09 * radioAlt + 7.1;
-
-    if (baroAlt > this.Mode3MaxBaroAlt || Number.isNaN(this.Mode3MaxBaroAlt)) {
-      this.Mode3MaxBaroAlt = baroAlt;
-      mode.current = 0;
-    } else if (this.Mode3MaxBaroAlt - baroAlt > maxAltLoss) {
-      mode.current = 1;
-    } else {
-      mode.current = 0;
-    }
-  }
-
-  /**
-   * Compute the GPWS Mode 4 state.
-   * @param mode - The mode object which stores the state.
-   * @param radioAlt - Radio altitude in feet
-   * @param speed - Airspeed in knots.
-   * @param FlapsInLandingConfig - If flaps is in landing config
-   * @param gearExtended - If the gear is extended
-   * @param phase - Flight phase index
-   * @constructor
-   */
-  GPWSMode4(mode, radioAlt, speed, FlapsInLandingConfig, gearExtended, phase) {
-    if (radioAlt < 30 || radioAlt > 1000) {
-      mode.current = 0;
-      return;
-    }
-    const FlapModeOff = SimVar.GetSimVarValue('L:A32NX_GPWS_FLAP_OFF', 'Bool');
-
-    // Mode 4 A and B logic
-    if (!gearExtended && phase === FmgcFlightPhase.Approach) {
-      if (speed < 190 && radioAlt < 500) {
-        mode.current = 1;
-      } else if (speed >= 190) {
-        const maxWarnAlt = 8.333 * speed - 1083.333;
-        mode.current = radioAlt < maxWarnAlt ? 3 : 0;
-      }
-    } else if (!FlapsInLandingConfig && !FlapModeOff && phase === FmgcFlightPhase.Approach) {
-      if (speed < 159 && radioAlt < 245) {
-        mode.current = 2;
-      } else if (speed >= 159) {
-        const maxWarnAlt = 8.2967 * speed - 1074.18;
-        mode.current = radioAlt < maxWarnAlt ? 3 : 0;
-      }
-    } else {
-      mode.current = 0;
-    }
-    if (!FlapsInLandingConfig || !gearExtended) {
-      const maxWarnAltSpeed = Math.max(Math.min(8.3333 * speed - 1083.33, 1000), 500);
-      const maxWarnAlt = 0.750751 * this.Mode4MaxRAAlt - 0.750751;
-
-      if (this.Mode4MaxRAAlt > 100 && radioAlt < maxWarnAltSpeed && radioAlt < maxWarnAlt) {
-        mode.current = 3;
-      }
-    }
-  }
-
-  /**
-   * Compute the GPWS Mode 5 state.
-   * @param mode - The mode object which stores the state.
-   * @param - radioAlt Radio altitude in feet
-   * @constructor
-   */
-  GPWSMode5(mode, radioAlt) {
-    if (radioAlt > 1000 || radioAlt < 30 || SimVar.GetSimVarValue('L:A32NX_GPWS_GS_OFF', 'Bool')) {
-      mode.current = 0;
-      return;
-    }
-    if (!SimVar.GetSimVarValue('L:A32NX_RADIO_RECEIVER_GS_IS_VALID', 'number')) {
-      mode.current = 0;
-      return;
-    }
-    const error = SimVar.GetSimVarValue('L:A32NX_RADIO_RECEIVER_GS_DEVIATION', 'number');
-    const dots = -error * 2.5; // According to the FCOM, one dot is approx. 0.4 degrees. 1/0.4 = 2.5
-
-    const minAltForWarning = dots < 2.9 ? -75 * dots + 247.5 : 30;
-    const minAltForHardWarning = dots < 3.8 ? -66.66 * dots + 283.33 : 30;
-
-    if (dots > 2 && radioAlt > minAltForHardWarning && radioAlt < 350) {
-      mode.current = 2;
-    } else if (dots > 1.3 && radioAlt > minAltForWarning) {
-      mode.current = 1;
-    } else {
-      mode.current = 0;
-    }
-  }
-
   UpdateAltState(radioAlt) {
     if (Number.isNaN(radioAlt)) {
       return;
@@ -12,7 +12,9 @@ use systems::{
         VariableFrequencyGenerator,
     },
     engine::Engine,
-    shared::{AuxiliaryPowerUnitElectrical, ElectricalBusType, EngineFirePushButtons},
+    shared::{
+        AuxiliaryPowerUnitElectrical, ElectricalBusType, EngineFirePushButtons, FuelTemperature,
+    },
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 use uom::si::{f64::Power, power::kilowatt};
@@ -77,6 +79,7 @@ impl A380AlternatingCurrentElectrical {
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         engines: [&impl Engine; 4],
         adirs: &impl AdirsDiscreteOutputs,
+        fuel: &impl FuelTemperature,
     ) {
         self.main_power_sources.update(
             context,
@@ -87,6 +90,7 @@ impl A380AlternatingCurrentElectrical {
             engine_fire_push_buttons,
             engines,
             adirs,
+            fuel,
         );
 
         self.main_power_sources
@@ -212,6 +216,15 @@ impl A380AlternatingCurrentElectrical {
         self.main_power_sources.gen_drive_connected(number)
     }
 
+    pub fn gen_drive_has_fault(&self, number: usize) -> bool {
+        self.main_power_sources.gen_drive_has_fault(number)
+    }
+
+    pub fn gen_drive_heat_rejected_to_fuel(&self, number: usize) -> Power {
+        self.main_power_sources
+            .gen_drive_heat_rejected_to_fuel(number)
+    }
+
     pub fn emergency_generator_contactor_is_closed(&self) -> bool {
         self.emergency_gen_contactor.is_closed()
     }
@@ -411,9 +424,16 @@ impl A380MainPowerSources {
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         engines: [&impl Engine; 4],
         adirs: &impl AdirsDiscreteOutputs,
+        fuel: &impl FuelTemperature,
     ) {
-        for (gen, engine) in self.engine_gens.iter_mut().zip(engines) {
-            gen.update(context, engine, overhead, engine_fire_push_buttons);
+        for (number, (gen, engine)) in self.engine_gens.iter_mut().zip(engines).enumerate() {
+            gen.update(
+                context,
+                engine,
+                overhead,
+                engine_fire_push_buttons,
+                fuel.engine_feed_fuel_temperature(number + 1),
+            );
             electricity.supplied_by(gen);
         }
 
@@ -636,6 +656,14 @@ impl A380MainPowerSources {
     fn gen_drive_connected(&self, number: usize) -> bool {
         self.engine_gens[number - 1].is_drive_connected()
     }
+
+    fn gen_drive_has_fault(&self, number: usize) -> bool {
+        self.engine_gens[number - 1].drive_has_fault()
+    }
+
+    fn gen_drive_heat_rejected_to_fuel(&self, number: usize) -> Power {
+        self.engine_gens[number - 1].heat_rejected_to_fuel()
+    }
 }
 impl SimulationElement for A380MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -8,10 +8,11 @@ use systems::electrical::{BatteryChargeRectifierUnit, BatteryPushButtons, Emerge
 use systems::shared::{DelayedFalseLogicGate, RamAirTurbineController};
 use systems::simulation::{InitContext, UpdateContext};
 use systems::{
-    electrical::{Battery, Contactor, ElectricalBus, Electricity, StaticInverter},
+    electrical::{Battery, Contactor, ElectricalBus, Electricity, Feeders, StaticInverter},
     shared::{AuxiliaryPowerUnitElectrical, ContactorSignal, ElectricalBusType},
     simulation::{SimulationElement, SimulationElementVisitor},
 };
+use uom::si::{electrical_resistance::ohm, f64::ElectricalResistance};
 
 pub(crate) const APU_START_MOTOR_BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
 
@@ -55,8 +56,12 @@ pub(super) struct A380DirectCurrentElectrical {
     ess_in_flight_sply2: DelayedFalseLogicGate,
     ess_in_flight_contactor: Contactor,
     dc_ess_subbus: ElectricalBus,
+    feeders: Feeders,
 }
 impl A380DirectCurrentElectrical {
+    const TR_FEEDER_RESISTANCE_OHM: f64 = 0.002;
+    const BATTERY_FEEDER_RESISTANCE_OHM: f64 = 0.003;
+
     pub fn new(context: &mut InitContext) -> Self {
         A380DirectCurrentElectrical {
             // 100PP
@@ -129,6 +134,7 @@ impl A380DirectCurrentElectrical {
                 context,
                 ElectricalBusType::DirectCurrentNamed("108PH"),
             ),
+            feeders: Feeders::new(context),
         }
     }
 
@@ -143,6 +149,10 @@ impl A380DirectCurrentElectrical {
         emergency_config: &EmergencyElectrical,
         tefo_condition: bool,
     ) {
+        let tr_feeder_resistance = ElectricalResistance::new::<ohm>(Self::TR_FEEDER_RESISTANCE_OHM);
+        let battery_feeder_resistance =
+            ElectricalResistance::new::<ohm>(Self::BATTERY_FEEDER_RESISTANCE_OHM);
+
         ac_state.power_tr_1(electricity, &self.tr_1);
         ac_state.power_tr_2(electricity, &self.tr_2);
         ac_state.power_tr_ess(electricity, &self.tr_ess);
@@ -181,7 +191,8 @@ impl A380DirectCurrentElectrical {
 
             contactor.close_when(tr.should_close_line_contactor());
             electricity.flow(tr, contactor);
-            electricity.flow(contactor, dc_bus);
+            self.feeders
+                .flow(electricity, contactor, dc_bus, tr_feeder_resistance);
         }
 
         // TODO: Figure out the exact behavior how the APU BUS is powered
@@ -189,7 +200,12 @@ impl A380DirectCurrentElectrical {
         self.tr_apu_contactor
             .close_when(electricity.is_powered(ac_state.tr_apu()));
         electricity.flow(ac_state.tr_apu(), &self.tr_apu_contactor);
-        electricity.flow(&self.tr_apu_contactor, &self.apu_bat_bus);
+        self.feeders.flow(
+            electricity,
+            &self.tr_apu_contactor,
+            &self.apu_bat_bus,
+            tr_feeder_resistance,
+        );
 
         self.inter_bus_line_contactors[0].close_when(
             self.inter_bus_line_contactors[1].is_open()
@@ -222,7 +238,12 @@ impl A380DirectCurrentElectrical {
         electricity.flow(&self.battery_1_contactor, &self.battery_1);
         electricity.flow(&self.battery_1_emergency_contactor, &self.battery_1);
         electricity.flow(&self.hot_bus_1, &self.battery_1);
-        electricity.flow(&self.battery_1_contactor, &self.dc_bus_1);
+        self.feeders.flow(
+            electricity,
+            &self.battery_1_contactor,
+            &self.dc_bus_1,
+            battery_feeder_resistance,
+        );
         electricity.flow(&self.battery_1_emergency_contactor, &self.dc_ess_bus);
 
         electricity.supplied_by(&self.battery_2);
@@ -231,7 +252,12 @@ impl A380DirectCurrentElectrical {
             .close_when(self.tr_2.should_close_battery_connector());
         electricity.flow(&self.battery_2_contactor, &self.battery_2);
         electricity.flow(&self.hot_bus_2, &self.battery_2);
-        electricity.flow(&self.battery_2_contactor, &self.dc_bus_2);
+        self.feeders.flow(
+            electricity,
+            &self.battery_2_contactor,
+            &self.dc_bus_2,
+            battery_feeder_resistance,
+        );
 
         let emergency_config = !electricity.is_powered(&self.dc_bus_1)
             && emergency_config.is_active()
@@ -242,7 +268,12 @@ impl A380DirectCurrentElectrical {
             .close_when(self.tr_ess.should_close_battery_connector());
         electricity.flow(&self.battery_ess_contactor, &self.battery_ess);
         electricity.flow(&self.hot_bus_ess, &self.battery_ess);
-        electricity.flow(&self.battery_ess_contactor, &self.dc_ess_bus);
+        self.feeders.flow(
+            electricity,
+            &self.battery_ess_contactor,
+            &self.dc_ess_bus,
+            battery_feeder_resistance,
+        );
         electricity.flow(
             &self.battery_ess_contactor,
             &self.battery_1_emergency_contactor,
@@ -256,7 +287,12 @@ impl A380DirectCurrentElectrical {
         );
         electricity.flow(&self.battery_apu_contactor, &self.battery_apu);
         electricity.flow(&self.hot_bus_apu, &self.battery_apu);
-        electricity.flow(&self.battery_apu_contactor, &self.apu_bat_bus);
+        self.feeders.flow(
+            electricity,
+            &self.battery_apu_contactor,
+            &self.apu_bat_bus,
+            battery_feeder_resistance,
+        );
 
         self.apu_start_contactors.close_when(
             electricity.is_powered(&self.apu_bat_bus)
@@ -403,6 +439,7 @@ impl SimulationElement for A380DirectCurrentElectrical {
         self.battery_ess.accept(visitor);
         self.battery_apu.accept(visitor);
         self.static_inverter.accept(visitor);
+        self.feeders.accept(visitor);
 
         self.battery_1_contactor.accept(visitor);
         self.battery_1_emergency_contactor.accept(visitor);
@@ -18,9 +18,10 @@ use std::time::Duration;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-        EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource,
-        GeneratorControlUnit, RamAirTurbine, StaticInverter, TransformerRectifier,
+        AlternatingCurrentElectricalSystem, ApuGeneratorPushButtons, BatteryPushButtons,
+        ElectricalElement, Electricity, EmergencyElectrical, EmergencyGenerator,
+        EngineGeneratorPushButtons, ExternalPowerSource, GeneratorControlUnit, RamAirTurbine,
+        StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -31,10 +32,12 @@ use systems::{
     shared::{
         update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AuxiliaryPowerUnitElectrical,
         ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
-        EmergencyElectricalState, EngineFirePushButtons, LatchedTrueLogicGate, LgciuWeightOnWheels,
+        EmergencyElectricalState, EngineFirePushButtons, FuelTemperature,
+        GeneratorDriveHeatRejection, LatchedTrueLogicGate, LgciuWeightOnWheels,
         RamAirTurbineController,
     },
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
@@ -107,6 +110,7 @@ impl A380Electrical {
         engines: [&impl Engine; 4],
         lgciu1: &impl LgciuWeightOnWheels,
         adirs: &impl AdirsDiscreteOutputs,
+        fuel: &impl FuelTemperature,
     ) {
         self.alternating_current.update_main_power_sources(
             context,
@@ -117,6 +121,7 @@ impl A380Electrical {
             engine_fire_push_buttons,
             engines,
             adirs,
+            fuel,
         );
 
         self.emergency_elec
@@ -296,10 +301,20 @@ impl A380Electrical {
         self.alternating_current.gen_drive_connected(number)
     }
 
+    pub fn gen_drive_has_fault(&self, number: usize) -> bool {
+        self.alternating_current.gen_drive_has_fault(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_in_emergency_elec()
     }
 }
+impl GeneratorDriveHeatRejection for A380Electrical {
+    fn generator_drive_heat_rejected_to_fuel(&self, engine_number: usize) -> Power {
+        self.alternating_current
+            .gen_drive_heat_rejected_to_fuel(engine_number)
+    }
+}
 impl SimulationElement for A380Electrical {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.alternating_current.accept(visitor);
@@ -314,6 +329,20 @@ impl SimulationElement for A380Electrical {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.galley_is_shed_id, self.galley_is_shed())
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.emergency_elec.save_state(state);
+        self.gcu.save_state(state);
+        self.tefo_condition.save_state(state);
+        self.emer_config.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.emergency_elec.restore_state(state);
+        self.gcu.restore_state(state);
+        self.tefo_condition.restore_state(state);
+        self.emer_config.restore_state(state);
+    }
 }
 
 trait A380DirectCurrentElectricalSystem {
@@ -383,7 +412,8 @@ impl A380ElectricalOverheadPanel {
             });
 
         self.idgs.iter_mut().enumerate().for_each(|(index, drive)| {
-            drive.set_disconnected(!electrical.gen_drive_connected(index + 1))
+            drive.set_disconnected(!electrical.gen_drive_connected(index + 1));
+            drive.set_fault(electrical.gen_drive_has_fault(index + 1));
         });
     }
 
@@ -428,6 +458,11 @@ impl EngineGeneratorPushButtons for A380ElectricalOverheadPanel {
         self.idgs[number - 1].is_released()
     }
 }
+impl ApuGeneratorPushButtons for A380ElectricalOverheadPanel {
+    fn apu_gen_push_button_is_on(&self, number: usize) -> bool {
+        self.apu_gens[number - 1].is_on()
+    }
+}
 impl BatteryPushButtons for A380ElectricalOverheadPanel {
     fn bat_is_auto(&self, number: usize) -> bool {
         self.batteries[number - 1].is_auto()
@@ -547,6 +582,18 @@ impl SimulationElement for A380RamAirTurbineController {
         self.is_solenoid_1_powered = buses.is_powered(self.solenoid_1_bus);
         self.is_solenoid_2_powered = buses.is_powered(self.solenoid_2_bus);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_solenoid_1_powered);
+        state.write(self.is_solenoid_2_powered);
+        state.write(self.should_deploy);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_solenoid_1_powered = state.read();
+        self.is_solenoid_2_powered = state.read();
+        self.should_deploy = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -580,7 +627,7 @@ mod a380_electrical_circuit_tests {
         shared::{
             ApuAvailable, ApuMaster, ApuStart, ContactorSignal, ControllerSignal,
             ElectricalBusType, ElectricalBuses, EngineCorrectedN1, EngineCorrectedN2,
-            EngineUncorrectedN2, PotentialOrigin,
+            EngineUncorrectedN2, HydraulicColor, PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -3115,7 +3162,14 @@ mod a380_electrical_circuit_tests {
         }
     }
     impl ApuGenerator for TestApuGenerator {
-        fn update(&mut self, _n: Ratio, _is_emergency_shutdown: bool) {}
+        fn update(
+            &mut self,
+            _: &UpdateContext,
+            _n: Ratio,
+            _is_emergency_shutdown: bool,
+            _push_button_is_on: bool,
+        ) {
+        }
 
         fn output_within_normal_parameters(&self) -> bool {
             self.is_available
@@ -3384,6 +3438,27 @@ mod a380_electrical_circuit_tests {
         }
     }
 
+    struct TestFuel {
+        temperature: ThermodynamicTemperature,
+    }
+    impl TestFuel {
+        fn new(temperature: ThermodynamicTemperature) -> Self {
+            Self { temperature }
+        }
+    }
+    impl FuelTemperature for TestFuel {
+        fn hydraulic_heat_exchanger_fuel_temperature(
+            &self,
+            _color: HydraulicColor,
+        ) -> ThermodynamicTemperature {
+            self.temperature
+        }
+
+        fn engine_feed_fuel_temperature(&self, _engine_number: usize) -> ThermodynamicTemperature {
+            self.temperature
+        }
+    }
+
     struct A380ElectricalTestAircraft {
         engines: [TestEngine; 4],
         ext_pwrs: [ExternalPowerSource; 4],
@@ -3525,6 +3600,7 @@ mod a380_electrical_circuit_tests {
                 ],
                 &TestLandingGear::new(context),
                 &TestAdirs::new(context.indicated_airspeed()),
+                &TestFuel::new(context.ambient_temperature()),
             );
             self.overhead
                 .update_after_electrical(&self.elec, electricity);
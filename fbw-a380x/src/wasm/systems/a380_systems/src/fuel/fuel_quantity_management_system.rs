@@ -8,17 +8,21 @@ use systems::{
     pneumatic::EngineState,
     shared::{ElectricalBusType, ElectricalBuses},
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, Read, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
         UpdateContext, VariableIdentifier, Write,
     },
 };
 use uom::si::{
-    f64::{Mass, Velocity},
+    f64::{Mass, Power, Velocity},
     mass::kilogram,
     velocity::knot,
 };
 
-use super::A380FuelTankType;
+use super::{
+    jettison::{JettisonApplication, JettisonPanel},
+    A380FuelTankType,
+};
 
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -320,7 +324,7 @@ impl RefuelApplication {
     }
 
     fn calculate_trim_fuel(
-        &mut self,
+        &self,
         total_desired_fuel: Mass,
         zero_fuel_weight: Mass,
         zero_fuel_weight_cg_percent_mac: f64,
@@ -597,13 +601,378 @@ impl RefuelDriver {
 }
 impl SimulationElement for RefuelDriver {}
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum TrimTransfer {
+    Idle,
+    Forward,
+    Aft,
+}
+
+pub struct InFlightTransferApplication {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    tank_capacities: [Mass; 11],
+
+    feed_tank_transfer_active_ids: [VariableIdentifier; 4],
+    feed_tank_transfer_active: [bool; 4],
+
+    outer_tank_transfer_active_ids: [VariableIdentifier; 2],
+    outer_tank_transfer_active: [bool; 2],
+
+    trim_tank_target_quantity_id: VariableIdentifier,
+    trim_tank_target_quantity: Mass,
+
+    trim_tank_forward_transfer_active_id: VariableIdentifier,
+    trim_tank_aft_transfer_active_id: VariableIdentifier,
+    trim_transfer: TrimTransfer,
+}
+impl InFlightTransferApplication {
+    const FEED_TANKS: [A380FuelTankType; 4] = [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ];
+    const SIDE_FEED_TANKS: [[A380FuelTankType; 2]; 2] = [
+        [A380FuelTankType::FeedOne, A380FuelTankType::FeedTwo],
+        [A380FuelTankType::FeedThree, A380FuelTankType::FeedFour],
+    ];
+    // Inner tanks are emptied first, followed by the mid tanks
+    const SIDE_WING_TANKS: [[A380FuelTankType; 2]; 2] = [
+        [A380FuelTankType::LeftInner, A380FuelTankType::LeftMid],
+        [A380FuelTankType::RightInner, A380FuelTankType::RightMid],
+    ];
+    const OUTER_TANKS: [A380FuelTankType; 2] =
+        [A380FuelTankType::LeftOuter, A380FuelTankType::RightOuter];
+
+    const WING_TRANSFER_RATE_KG_PER_SEC: f64 = 10.;
+    const TRIM_TRANSFER_RATE_KG_PER_SEC: f64 = 4.;
+    const FEED_TANK_TRANSFER_START_DEFICIT_KG: f64 = 1000.;
+    const FEED_TANK_TRANSFER_STOP_DEFICIT_KG: f64 = 1.;
+    // Outer tank fuel is kept as long as possible for wing load alleviation
+    const OUTER_TANK_TRANSFER_FEED_TANK_LEVEL_KG: f64 = 4000.;
+    const TRIM_TANK_TARGET_HYSTERESIS_KG: f64 = 500.;
+
+    pub fn new(
+        context: &mut InitContext,
+        powered_by: ElectricalBusType,
+        tank_capacities: [Mass; 11],
+    ) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+            tank_capacities,
+
+            feed_tank_transfer_active_ids: [1, 2, 3, 4]
+                .map(|id| context.get_identifier(format!("FUEL_FEED_TANK_{id}_TRANSFER_ACTIVE"))),
+            feed_tank_transfer_active: [false; 4],
+
+            outer_tank_transfer_active_ids: ["LEFT", "RIGHT"].map(|side| {
+                context.get_identifier(format!("FUEL_{side}_OUTER_TANK_TRANSFER_ACTIVE"))
+            }),
+            outer_tank_transfer_active: [false; 2],
+
+            trim_tank_target_quantity_id: context
+                .get_identifier("FUEL_TRIM_TANK_TARGET_QUANTITY".to_owned()),
+            trim_tank_target_quantity: Mass::default(),
+
+            trim_tank_forward_transfer_active_id: context
+                .get_identifier("FUEL_TRIM_TANK_FWD_TRANSFER_ACTIVE".to_owned()),
+            trim_tank_aft_transfer_active_id: context
+                .get_identifier("FUEL_TRIM_TANK_AFT_TRANSFER_ACTIVE".to_owned()),
+            trim_transfer: TrimTransfer::Idle,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11>,
+        trim_tank_target_quantity: Mass,
+        jettison_is_active: bool,
+    ) {
+        self.trim_tank_target_quantity = trim_tank_target_quantity;
+
+        if !self.is_powered || context.is_on_ground() || fuel_system.has_unlimited_fuel() {
+            self.feed_tank_transfer_active = [false; 4];
+            self.outer_tank_transfer_active = [false; 2];
+            self.trim_transfer = TrimTransfer::Idle;
+            return;
+        }
+
+        self.update_feed_tank_transfer_requests(fuel_system);
+        self.update_outer_tank_transfer_requests(fuel_system);
+        if jettison_is_active {
+            // The trim tank is emptied by the jettison, CG control resumes once it is completed
+            self.trim_transfer = TrimTransfer::Idle;
+        } else {
+            self.update_trim_transfer_request(fuel_system);
+        }
+
+        let delta = context.delta_as_secs_f64();
+        self.transfer_wing_fuel_to_feed_tanks(fuel_system, delta);
+        self.transfer_trim_fuel(fuel_system, delta);
+    }
+
+    fn feed_tank_deficit(&self, fuel_system: &FuelSystem<11>, tank: A380FuelTankType) -> Mass {
+        (self.tank_capacities[tank as usize] - fuel_system.tank_mass(tank as usize))
+            .max(Mass::default())
+    }
+
+    fn update_feed_tank_transfer_requests(&mut self, fuel_system: &FuelSystem<11>) {
+        for (index, tank) in Self::FEED_TANKS.iter().enumerate() {
+            let deficit = self.feed_tank_deficit(fuel_system, *tank);
+
+            if deficit > Mass::new::<kilogram>(Self::FEED_TANK_TRANSFER_START_DEFICIT_KG) {
+                self.feed_tank_transfer_active[index] = true;
+            } else if deficit < Mass::new::<kilogram>(Self::FEED_TANK_TRANSFER_STOP_DEFICIT_KG) {
+                self.feed_tank_transfer_active[index] = false;
+            }
+        }
+    }
+
+    fn update_outer_tank_transfer_requests(&mut self, fuel_system: &FuelSystem<11>) {
+        for ((is_active, outer_tank), feed_tanks) in self
+            .outer_tank_transfer_active
+            .iter_mut()
+            .zip(Self::OUTER_TANKS)
+            .zip(Self::SIDE_FEED_TANKS)
+        {
+            if !fuel_system.tank_has_fuel(outer_tank as usize) {
+                *is_active = false;
+            } else if feed_tanks.iter().any(|tank| {
+                fuel_system.tank_mass(*tank as usize)
+                    < Mass::new::<kilogram>(Self::OUTER_TANK_TRANSFER_FEED_TANK_LEVEL_KG)
+            }) {
+                *is_active = true;
+            }
+        }
+    }
+
+    fn update_trim_transfer_request(&mut self, fuel_system: &FuelSystem<11>) {
+        let trim_quantity = fuel_system.tank_mass(A380FuelTankType::Trim as usize);
+        let hysteresis = Mass::new::<kilogram>(Self::TRIM_TANK_TARGET_HYSTERESIS_KG);
+        let wing_tanks_have_fuel = Self::SIDE_WING_TANKS
+            .iter()
+            .flatten()
+            .any(|tank| fuel_system.tank_has_fuel(*tank as usize));
+
+        self.trim_transfer = match self.trim_transfer {
+            TrimTransfer::Forward if trim_quantity <= self.trim_tank_target_quantity => {
+                TrimTransfer::Idle
+            }
+            TrimTransfer::Aft
+                if trim_quantity >= self.trim_tank_target_quantity || !wing_tanks_have_fuel =>
+            {
+                TrimTransfer::Idle
+            }
+            TrimTransfer::Idle if trim_quantity > self.trim_tank_target_quantity + hysteresis => {
+                TrimTransfer::Forward
+            }
+            TrimTransfer::Idle
+                if trim_quantity < self.trim_tank_target_quantity - hysteresis
+                    && wing_tanks_have_fuel =>
+            {
+                TrimTransfer::Aft
+            }
+            state => state,
+        };
+    }
+
+    fn transfer_wing_fuel_to_feed_tanks(&self, fuel_system: &mut FuelSystem<11>, delta: f64) {
+        let max_transfer = Mass::new::<kilogram>(Self::WING_TRANSFER_RATE_KG_PER_SEC * delta);
+
+        for side in [0, 1] {
+            let requesting_feed_tanks: Vec<A380FuelTankType> = Self::SIDE_FEED_TANKS[side]
+                .iter()
+                .zip(&self.feed_tank_transfer_active[2 * side..2 * side + 2])
+                .filter(|(_, is_active)| **is_active)
+                .map(|(tank, _)| *tank)
+                .collect();
+
+            let transferred = self.transfer_fuel(
+                fuel_system,
+                &Self::SIDE_WING_TANKS[side],
+                &requesting_feed_tanks,
+                max_transfer,
+            );
+
+            if self.outer_tank_transfer_active[side] {
+                self.transfer_fuel(
+                    fuel_system,
+                    &[Self::OUTER_TANKS[side]],
+                    &Self::SIDE_FEED_TANKS[side],
+                    max_transfer - transferred,
+                );
+            }
+        }
+    }
+
+    fn transfer_trim_fuel(&self, fuel_system: &mut FuelSystem<11>, delta: f64) {
+        let max_transfer = Mass::new::<kilogram>(Self::TRIM_TRANSFER_RATE_KG_PER_SEC * delta);
+        let trim_quantity = fuel_system.tank_mass(A380FuelTankType::Trim as usize);
+
+        match self.trim_transfer {
+            TrimTransfer::Forward => {
+                self.transfer_fuel(
+                    fuel_system,
+                    &[A380FuelTankType::Trim],
+                    &Self::FEED_TANKS,
+                    max_transfer.min(trim_quantity - self.trim_tank_target_quantity),
+                );
+            }
+            TrimTransfer::Aft => {
+                // Both wings supply half of the aft transfer to keep the lateral balance
+                let side_transfer =
+                    max_transfer.min(self.trim_tank_target_quantity - trim_quantity) / 2.;
+                for wing_tanks in Self::SIDE_WING_TANKS {
+                    self.transfer_fuel(
+                        fuel_system,
+                        &wing_tanks,
+                        &[A380FuelTankType::Trim],
+                        side_transfer,
+                    );
+                }
+            }
+            TrimTransfer::Idle => {}
+        }
+    }
+
+    /// Moves up to `max_quantity` of fuel, emptying the sources in the given order and filling
+    /// the destinations evenly up to their capacity. Returns the transferred quantity.
+    fn transfer_fuel(
+        &self,
+        fuel_system: &mut FuelSystem<11>,
+        sources: &[A380FuelTankType],
+        destinations: &[A380FuelTankType],
+        max_quantity: Mass,
+    ) -> Mass {
+        let available = sources.iter().fold(Mass::default(), |sum, tank| {
+            sum + fuel_system.tank_mass(*tank as usize)
+        });
+
+        let mut destination_rooms: Vec<(A380FuelTankType, Mass)> = destinations
+            .iter()
+            .map(|tank| {
+                (
+                    *tank,
+                    (self.tank_capacities[*tank as usize] - fuel_system.tank_mass(*tank as usize))
+                        .max(Mass::default()),
+                )
+            })
+            .collect();
+        let room = destination_rooms
+            .iter()
+            .fold(Mass::default(), |sum, (_, room)| sum + *room);
+
+        let quantity = max_quantity.min(available).min(room);
+        if quantity <= Mass::default() {
+            return Mass::default();
+        }
+
+        let mut remaining = quantity;
+        for tank in sources {
+            let tank_quantity = fuel_system.tank_mass(*tank as usize);
+            let drawn = tank_quantity.min(remaining);
+            fuel_system.set_tank_quantity(*tank as usize, tank_quantity - drawn);
+            remaining -= drawn;
+        }
+
+        // Fill the tanks with the least room first, so that any share they cannot take is
+        // spread over the remaining destinations.
+        destination_rooms
+            .sort_by(|(_, a), (_, b)| a.get::<kilogram>().total_cmp(&b.get::<kilogram>()));
+        let mut remaining = quantity;
+        let destination_count = destination_rooms.len();
+        for (index, (tank, room)) in destination_rooms.into_iter().enumerate() {
+            let added = (remaining / (destination_count - index) as f64).min(room);
+            fuel_system
+                .set_tank_quantity(tank as usize, fuel_system.tank_mass(tank as usize) + added);
+            remaining -= added;
+        }
+
+        quantity
+    }
+}
+impl SimulationElement for InFlightTransferApplication {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, is_active) in self
+            .feed_tank_transfer_active_ids
+            .iter()
+            .zip(self.feed_tank_transfer_active)
+        {
+            writer.write(id, is_active);
+        }
+        for (id, is_active) in self
+            .outer_tank_transfer_active_ids
+            .iter()
+            .zip(self.outer_tank_transfer_active)
+        {
+            writer.write(id, is_active);
+        }
+        writer.write(
+            &self.trim_tank_target_quantity_id,
+            self.trim_tank_target_quantity.get::<kilogram>(),
+        );
+        writer.write(
+            &self.trim_tank_forward_transfer_active_id,
+            self.trim_transfer == TrimTransfer::Forward,
+        );
+        writer.write(
+            &self.trim_tank_aft_transfer_active_id,
+            self.trim_transfer == TrimTransfer::Aft,
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        for is_active in self.feed_tank_transfer_active {
+            state.write(is_active);
+        }
+        for is_active in self.outer_tank_transfer_active {
+            state.write(is_active);
+        }
+        state.write(self.trim_transfer == TrimTransfer::Forward);
+        state.write(self.trim_transfer == TrimTransfer::Aft);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        for is_active in self.feed_tank_transfer_active.iter_mut() {
+            *is_active = state.read();
+        }
+        for is_active in self.outer_tank_transfer_active.iter_mut() {
+            *is_active = state.read();
+        }
+        let is_forward: bool = state.read();
+        let is_aft: bool = state.read();
+        self.trim_transfer = if is_forward {
+            TrimTransfer::Forward
+        } else if is_aft {
+            TrimTransfer::Aft
+        } else {
+            TrimTransfer::Idle
+        };
+    }
+}
+
 pub struct A380FuelQuantityManagementSystem {
     fuel_system: FuelSystem<11>,
     refuel_application: RefuelApplication,
+    in_flight_transfer_application: InFlightTransferApplication,
+    jettison_application: JettisonApplication,
     integrated_refuel_panel: IntegratedRefuelPanel,
+    jettison_panel: JettisonPanel,
 }
 impl A380FuelQuantityManagementSystem {
     pub fn new(context: &mut InitContext, fuel_tanks_info: [FuelInfo; 11]) -> Self {
+        let tank_capacities: [Mass; 11] = std::array::from_fn(|index| {
+            Mass::new::<kilogram>(
+                fuel_tanks_info[index].total_capacity_gallons * fuel::FUEL_GALLONS_TO_KG,
+            )
+        });
         let fuel_tanks = fuel_tanks_info.map(|f| {
             FuelTank::new(
                 context,
@@ -623,10 +992,17 @@ impl A380FuelQuantityManagementSystem {
                 context,
                 ElectricalBusType::DirectCurrentEssential, // 501PP
             ),
+            in_flight_transfer_application: InFlightTransferApplication::new(
+                context,
+                ElectricalBusType::DirectCurrentEssential, // 501PP
+                tank_capacities,
+            ),
+            jettison_application: JettisonApplication::new(context),
             integrated_refuel_panel: IntegratedRefuelPanel::new(
                 context,
                 ElectricalBusType::DirectCurrentEssential, // 501PP
             ),
+            jettison_panel: JettisonPanel::new(context),
         }
     }
 
@@ -636,6 +1012,34 @@ impl A380FuelQuantityManagementSystem {
             &mut self.fuel_system,
             &mut self.integrated_refuel_panel,
         );
+
+        self.jettison_application.update(
+            context,
+            &mut self.fuel_system,
+            &self.jettison_panel,
+            self.integrated_refuel_panel.target_zero_fuel_weight(),
+        );
+
+        // The FQMS uses its own gauged quantities rather than the simulator total
+        let total_quantity = A380FuelTankType::iterator().fold(Mass::default(), |sum, tank| {
+            sum + self.fuel_system.tank_mass(tank as usize)
+        });
+        let trim_tank_target_quantity = self.refuel_application.calculate_trim_fuel(
+            total_quantity,
+            self.integrated_refuel_panel.target_zero_fuel_weight(),
+            self.integrated_refuel_panel
+                .target_zero_fuel_weight_cg_mac(),
+        );
+        self.in_flight_transfer_application.update(
+            context,
+            &mut self.fuel_system,
+            trim_tank_target_quantity,
+            self.jettison_application.is_active(),
+        );
+    }
+
+    pub fn update_temperatures(&mut self, context: &UpdateContext, heat_rejected: [Power; 11]) {
+        self.fuel_system.update_temperatures(context, heat_rejected);
     }
 
     #[allow(dead_code)]
@@ -651,7 +1055,10 @@ impl SimulationElement for A380FuelQuantityManagementSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
         self.refuel_application.accept(visitor);
+        self.in_flight_transfer_application.accept(visitor);
+        self.jettison_application.accept(visitor);
         self.integrated_refuel_panel.accept(visitor);
+        self.jettison_panel.accept(visitor);
         visitor.visit(self);
     }
 }
@@ -0,0 +1,345 @@
+use systems::{
+    accept_iterable,
+    fuel::FuelSystem,
+    overhead::OnOffPushButton,
+    shared::{ElectricalBusType, ElectricalBuses},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    f64::{Mass, MassRate},
+    mass::kilogram,
+    mass_rate::kilogram_per_second,
+};
+
+use super::A380FuelTankType;
+
+pub struct JettisonPanel {
+    arm_pb: OnOffPushButton,
+    active_pb: OnOffPushButton,
+}
+impl JettisonPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            arm_pb: OnOffPushButton::new_off(context, "FUEL_JETTISON_ARM"),
+            active_pb: OnOffPushButton::new_off(context, "FUEL_JETTISON_ACTIVE"),
+        }
+    }
+
+    fn is_armed(&self) -> bool {
+        self.arm_pb.is_on()
+    }
+
+    fn is_active_selected(&self) -> bool {
+        self.active_pb.is_on()
+    }
+}
+impl SimulationElement for JettisonPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.arm_pb.accept(visitor);
+        self.active_pb.accept(visitor);
+        visitor.visit(self);
+    }
+}
+
+struct JettisonPump {
+    is_running_id: VariableIdentifier,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_commanded: bool,
+}
+impl JettisonPump {
+    fn new(context: &mut InitContext, side: &str, powered_by: ElectricalBusType) -> Self {
+        Self {
+            is_running_id: context.get_identifier(format!("FUEL_{side}_JETTISON_PUMP_IS_RUNNING")),
+            powered_by,
+            is_powered: false,
+            is_commanded: false,
+        }
+    }
+
+    fn command(&mut self, is_commanded: bool) {
+        self.is_commanded = is_commanded;
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_commanded && self.is_powered
+    }
+}
+impl SimulationElement for JettisonPump {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_running_id, self.is_running());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Motor operated valve which stays in its last position when unpowered.
+struct JettisonValve {
+    is_open_id: VariableIdentifier,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_open: bool,
+}
+impl JettisonValve {
+    fn new(context: &mut InitContext, side: &str, powered_by: ElectricalBusType) -> Self {
+        Self {
+            is_open_id: context.get_identifier(format!("FUEL_{side}_JETTISON_VALVE_IS_OPEN")),
+            powered_by,
+            is_powered: false,
+            is_open: false,
+        }
+    }
+
+    fn command_open(&mut self, open: bool) {
+        if self.is_powered {
+            self.is_open = open;
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+impl SimulationElement for JettisonValve {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_open_id, self.is_open);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_open);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_open = state.read();
+    }
+}
+
+pub struct JettisonApplication {
+    jettison_active_id: VariableIdentifier,
+    is_active: bool,
+    is_completed: bool,
+
+    target_landing_weight_id: VariableIdentifier,
+    target_landing_weight: Mass,
+
+    flow_rate_id: VariableIdentifier,
+    flow_rate: MassRate,
+
+    pumps: [JettisonPump; 2],
+    valves: [JettisonValve; 2],
+}
+impl JettisonApplication {
+    // Trim tank fuel is jettisoned first, followed by the inner, mid and finally the feed tanks.
+    // The outer tanks are not jettisoned.
+    const SIDE_WING_TANKS: [[A380FuelTankType; 2]; 2] = [
+        [A380FuelTankType::LeftInner, A380FuelTankType::LeftMid],
+        [A380FuelTankType::RightInner, A380FuelTankType::RightMid],
+    ];
+    const SIDE_FEED_TANKS: [[A380FuelTankType; 2]; 2] = [
+        [A380FuelTankType::FeedOne, A380FuelTankType::FeedTwo],
+        [A380FuelTankType::FeedThree, A380FuelTankType::FeedFour],
+    ];
+
+    const SIDE_JETTISON_RATE_KG_PER_SEC: f64 = 20.;
+    const FEED_TANK_MINIMUM_QUANTITY_KG: f64 = 4500.;
+    // Maximum landing weight, used when no target landing weight has been entered
+    const DEFAULT_TARGET_LANDING_WEIGHT_KG: f64 = 386000.;
+    const STOP_CONDITION_TOLERANCE_KG: f64 = 1.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            jettison_active_id: context.get_identifier("FUEL_JETTISON_ACTIVE".to_owned()),
+            is_active: false,
+            is_completed: false,
+
+            target_landing_weight_id: context
+                .get_identifier("FUEL_JETTISON_TARGET_LANDING_WEIGHT".to_owned()),
+            target_landing_weight: Mass::default(),
+
+            flow_rate_id: context.get_identifier("FUEL_JETTISON_FLOW_RATE".to_owned()),
+            flow_rate: MassRate::default(),
+
+            pumps: [
+                JettisonPump::new(context, "LEFT", ElectricalBusType::AlternatingCurrent(2)),
+                JettisonPump::new(context, "RIGHT", ElectricalBusType::AlternatingCurrent(3)),
+            ],
+            valves: [
+                JettisonValve::new(context, "LEFT", ElectricalBusType::DirectCurrent(1)),
+                JettisonValve::new(context, "RIGHT", ElectricalBusType::DirectCurrent(2)),
+            ],
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11>,
+        jettison_panel: &JettisonPanel,
+        zero_fuel_weight: Mass,
+    ) {
+        if !jettison_panel.is_active_selected() {
+            self.is_completed = false;
+        }
+
+        // Jettison is inhibited on ground, whatever the panel selection.
+        let is_in_flight = !context.is_on_ground();
+        let is_commanded = is_in_flight
+            && jettison_panel.is_armed()
+            && jettison_panel.is_active_selected()
+            && !self.is_completed;
+        if is_commanded && self.stop_condition_reached(fuel_system, zero_fuel_weight) {
+            self.is_completed = true;
+        }
+        self.is_active = is_commanded && !self.is_completed;
+
+        for valve in self.valves.iter_mut() {
+            valve.command_open(is_in_flight && jettison_panel.is_armed());
+        }
+        for pump in self.pumps.iter_mut() {
+            pump.command(self.is_active);
+        }
+
+        self.flow_rate = MassRate::default();
+        if !self.is_active || fuel_system.has_unlimited_fuel() {
+            return;
+        }
+
+        let delta = context.delta_as_secs_f64();
+        let above_target_weight = (Self::gross_weight(fuel_system, zero_fuel_weight)
+            - self.target_landing_weight())
+        .max(Mass::default());
+        let is_jettisoning =
+            [0, 1].map(|side| self.pumps[side].is_running() && self.valves[side].is_open());
+        let side_count = is_jettisoning.iter().filter(|is_on| **is_on).count();
+        if side_count == 0 || delta <= 0. {
+            return;
+        }
+
+        // The jettisoning sides share the trim tank fuel, so that it empties evenly
+        let trim_share = fuel_system.tank_mass(A380FuelTankType::Trim as usize) / side_count as f64;
+        let mut jettisoned = Mass::default();
+        for side in [0, 1] {
+            if is_jettisoning[side] {
+                let side_quantity =
+                    Mass::new::<kilogram>(Self::SIDE_JETTISON_RATE_KG_PER_SEC * delta)
+                        .min(above_target_weight / side_count as f64);
+                jettisoned +=
+                    Self::jettison_from_side(fuel_system, side, side_quantity, trim_share);
+            }
+        }
+
+        self.flow_rate = MassRate::new::<kilogram_per_second>(jettisoned.get::<kilogram>() / delta);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    fn target_landing_weight(&self) -> Mass {
+        if self.target_landing_weight > Mass::default() {
+            self.target_landing_weight
+        } else {
+            Mass::new::<kilogram>(Self::DEFAULT_TARGET_LANDING_WEIGHT_KG)
+        }
+    }
+
+    fn gross_weight(fuel_system: &FuelSystem<11>, zero_fuel_weight: Mass) -> Mass {
+        A380FuelTankType::iterator().fold(zero_fuel_weight, |sum, tank| {
+            sum + fuel_system.tank_mass(tank as usize)
+        })
+    }
+
+    fn stop_condition_reached(&self, fuel_system: &FuelSystem<11>, zero_fuel_weight: Mass) -> bool {
+        let tolerance = Mass::new::<kilogram>(Self::STOP_CONDITION_TOLERANCE_KG);
+        let feed_tank_minimum = Mass::new::<kilogram>(Self::FEED_TANK_MINIMUM_QUANTITY_KG);
+
+        Self::gross_weight(fuel_system, zero_fuel_weight)
+            <= self.target_landing_weight() + tolerance
+            || Self::SIDE_FEED_TANKS
+                .iter()
+                .flatten()
+                .any(|tank| fuel_system.tank_mass(*tank as usize) <= feed_tank_minimum + tolerance)
+    }
+
+    /// Jettisons up to `quantity` through one side's jettison valve, taking at most `trim_share`
+    /// from the trim tank, and returns the quantity actually jettisoned.
+    fn jettison_from_side(
+        fuel_system: &mut FuelSystem<11>,
+        side: usize,
+        quantity: Mass,
+        trim_share: Mass,
+    ) -> Mass {
+        let feed_tank_minimum = Mass::new::<kilogram>(Self::FEED_TANK_MINIMUM_QUANTITY_KG);
+        let mut remaining = quantity;
+
+        let trim_quantity = fuel_system.tank_mass(A380FuelTankType::Trim as usize);
+        let trim_drawn = trim_share.min(trim_quantity).min(remaining);
+        fuel_system.set_tank_quantity(A380FuelTankType::Trim as usize, trim_quantity - trim_drawn);
+        remaining -= trim_drawn;
+
+        for tank in Self::SIDE_WING_TANKS[side] {
+            let tank_quantity = fuel_system.tank_mass(tank as usize);
+            let drawn = tank_quantity.min(remaining);
+            fuel_system.set_tank_quantity(tank as usize, tank_quantity - drawn);
+            remaining -= drawn;
+        }
+
+        // The feed tanks are drawn evenly down to their minimum quantity, starting with the one
+        // with the least fuel above its minimum so that any share it cannot supply is taken by
+        // the other one.
+        let mut feed_tanks = Self::SIDE_FEED_TANKS[side].map(|tank| {
+            (
+                tank,
+                (fuel_system.tank_mass(tank as usize) - feed_tank_minimum).max(Mass::default()),
+            )
+        });
+        feed_tanks.sort_by(|(_, a), (_, b)| a.get::<kilogram>().total_cmp(&b.get::<kilogram>()));
+        let feed_tank_count = feed_tanks.len();
+        for (index, (tank, available)) in feed_tanks.into_iter().enumerate() {
+            let drawn = (remaining / (feed_tank_count - index) as f64).min(available);
+            fuel_system
+                .set_tank_quantity(tank as usize, fuel_system.tank_mass(tank as usize) - drawn);
+            remaining -= drawn;
+        }
+
+        quantity - remaining
+    }
+}
+impl SimulationElement for JettisonApplication {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pumps, visitor);
+        accept_iterable!(self.valves, visitor);
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.target_landing_weight =
+            Mass::new::<kilogram>(reader.read(&self.target_landing_weight_id));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.jettison_active_id, self.is_active);
+        writer.write(
+            &self.flow_rate_id,
+            self.flow_rate.get::<kilogram_per_second>(),
+        );
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_completed);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_completed = state.read();
+    }
+}
@@ -1,10 +1,16 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Note: The tank quantities are read from MSFS, and written back after the FQMS has refuelled,
+// transferred or jettisoned fuel. Engine fuel burn is still handled by the FADEC.
 
 mod fuel_quantity_management_system;
+mod jettison;
 use fuel_quantity_management_system::A380FuelQuantityManagementSystem;
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelSystem},
+    fuel::{FuelCG, FuelInfo, FuelPayload, FuelQuantityIndicationSystem, FuelSystem},
+    shared::{
+        ElectricalBusType, FuelTemperature, GeneratorDriveHeatRejection, HydraulicColor,
+        HydraulicHeatRejection,
+    },
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 use uom::si::f64::*;
@@ -62,9 +68,17 @@ impl A380FuelTankType {
 
 pub struct A380Fuel {
     fuel_quantity_management_system: A380FuelQuantityManagementSystem,
+    quantity_indication: FuelQuantityIndicationSystem<11>,
 }
 
 impl A380Fuel {
+    const FEED_TANKS: [A380FuelTankType; 4] = [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ];
+
     // TODO: Move to toml cfg
     pub const A380_FUEL: [FuelInfo<'static>; 11] = [
         FuelInfo {
@@ -141,11 +155,54 @@ impl A380Fuel {
                 context,
                 Self::A380_FUEL,
             ),
+            quantity_indication: FuelQuantityIndicationSystem::new(
+                context,
+                Self::A380_FUEL,
+                A380FuelTankType::FeedTwo as usize,
+                [
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
         }
     }
 
-    pub(crate) fn update(&mut self, context: &UpdateContext) {
+    pub(crate) fn update(
+        &mut self,
+        context: &UpdateContext,
+        hydraulic: &impl HydraulicHeatRejection,
+        generator_drives: &impl GeneratorDriveHeatRejection,
+    ) {
         self.fuel_quantity_management_system.update(context);
+        self.update_temperatures(context, hydraulic, generator_drives);
+        self.quantity_indication
+            .update(context, self.fuel_quantity_management_system.fuel_system());
+    }
+
+    /// The green and yellow hydraulic heat exchangers are in feed tanks 2 and 3. The IDG
+    /// cooling fuel of each engine returns to its own feed tank.
+    fn update_temperatures(
+        &mut self,
+        context: &UpdateContext,
+        hydraulic: &impl HydraulicHeatRejection,
+        generator_drives: &impl GeneratorDriveHeatRejection,
+    ) {
+        let mut heat_rejected = [Power::default(); 11];
+        for (number, tank) in Self::FEED_TANKS.iter().enumerate() {
+            heat_rejected[*tank as usize] =
+                generator_drives.generator_drive_heat_rejected_to_fuel(number + 1);
+        }
+        heat_rejected[A380FuelTankType::FeedTwo as usize] +=
+            hydraulic.heat_rejected_to_fuel(HydraulicColor::Green);
+        heat_rejected[A380FuelTankType::FeedThree as usize] +=
+            hydraulic.heat_rejected_to_fuel(HydraulicColor::Yellow);
+
+        self.fuel_quantity_management_system
+            .update_temperatures(context, heat_rejected);
+    }
+
+    fn tank_temperature(&self, tank: A380FuelTankType) -> ThermodynamicTemperature {
+        self.fuel_system().tank_temperature(tank as usize)
     }
 
     fn fuel_system(&self) -> &FuelSystem<11> {
@@ -255,6 +312,22 @@ impl FuelLevel for A380Fuel {
         self.trim_tank_has_fuel()
     }
 }
+impl FuelTemperature for A380Fuel {
+    fn hydraulic_heat_exchanger_fuel_temperature(
+        &self,
+        color: HydraulicColor,
+    ) -> ThermodynamicTemperature {
+        match color {
+            HydraulicColor::Green => self.tank_temperature(A380FuelTankType::FeedTwo),
+            HydraulicColor::Yellow => self.tank_temperature(A380FuelTankType::FeedThree),
+            HydraulicColor::Blue => panic!("NO BLUE CIRCUIT IN A380"),
+        }
+    }
+
+    fn engine_feed_fuel_temperature(&self, engine_number: usize) -> ThermodynamicTemperature {
+        self.tank_temperature(Self::FEED_TANKS[engine_number - 1])
+    }
+}
 impl FuelPayload for A380Fuel {
     fn total_load(&self) -> Mass {
         self.total_load()
@@ -275,6 +348,7 @@ impl FuelCG for A380Fuel {
 impl SimulationElement for A380Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_quantity_management_system.accept(visitor);
+        self.quantity_indication.accept(visitor);
         visitor.visit(self);
     }
 }
@@ -1,25 +1,75 @@
 use std::{collections::HashMap, time::Duration};
 
 use ntest::{assert_false, assert_true};
-use systems::{electrical::Electricity, fuel::RefuelRate, simulation::test::ReadByName};
-use uom::si::mass::kilogram;
+use systems::{
+    accept_iterable,
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    failures::FailureType,
+    fuel::RefuelRate,
+    shared::{arinc429::Arinc429Word, ElectricalBusType, PotentialOrigin},
+    simulation::test::ReadByName,
+};
+use uom::si::{mass::kilogram, power::watt, thermodynamic_temperature::degree_celsius};
 
 use super::*;
 use crate::systems::simulation::{
     test::{SimulationTestBed, TestBed, WriteByName},
     Aircraft, SimulationElement, SimulationElementVisitor,
 };
+
+#[derive(Default)]
+struct TestHeatSources {
+    hydraulic_heat: Power,
+}
+impl HydraulicHeatRejection for TestHeatSources {
+    fn heat_rejected_to_fuel(&self, _color: HydraulicColor) -> Power {
+        self.hydraulic_heat
+    }
+}
+impl GeneratorDriveHeatRejection for TestHeatSources {
+    fn generator_drive_heat_rejected_to_fuel(&self, _engine_number: usize) -> Power {
+        Power::default()
+    }
+}
+
 struct FuelTestAircraft {
     fuel: A380Fuel,
+    heat_sources: TestHeatSources,
+
+    powered_source: TestElectricitySource,
+    buses: [ElectricalBus; 5],
+    is_powered: bool,
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             fuel: A380Fuel::new(context),
+            heat_sources: TestHeatSources::default(),
+            powered_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: [
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrent(1),
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::AlternatingCurrent(2),
+                ElectricalBusType::AlternatingCurrent(3),
+            ]
+            .map(|bus_type| ElectricalBus::new(context, bus_type)),
+            is_powered: false,
         }
     }
 
+    fn set_powered(&mut self, is_powered: bool) {
+        self.is_powered = is_powered;
+    }
+
+    fn set_hydraulic_heat(&mut self, heat: Power) {
+        self.heat_sources.hydraulic_heat = heat;
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
@@ -32,15 +82,26 @@ impl FuelTestAircraft {
 impl Aircraft for FuelTestAircraft {
     fn update_before_power_distribution(
         &mut self,
-        context: &UpdateContext,
-        _electricity: &mut Electricity,
+        _context: &UpdateContext,
+        electricity: &mut Electricity,
     ) {
-        self.fuel.update(context);
+        if self.is_powered {
+            electricity.supplied_by(&self.powered_source);
+            for bus in &self.buses {
+                electricity.flow(&self.powered_source, bus);
+            }
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel
+            .update(context, &self.heat_sources, &self.heat_sources);
     }
 }
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        accept_iterable!(self.buses, visitor);
 
         visitor.visit(self);
     }
@@ -201,6 +262,116 @@ impl FuelTestBed {
         self
     }
 
+    fn and_powered(mut self) -> Self {
+        self.command(|a| a.set_powered(true));
+        self
+    }
+
+    fn on_ground(mut self) -> Self {
+        self.set_on_ground(true);
+        self
+    }
+
+    fn with_tank_quantity(mut self, tank: A380FuelTankType, quantity_kg: f64) -> Self {
+        self.write_by_name(
+            &format!("FUEL_TANK_QUANTITY_{}", tank as usize + 1),
+            quantity_kg / FUEL_GALLONS_TO_KG,
+        );
+        self
+    }
+
+    fn with_feed_tanks(self, quantity_kg: f64) -> Self {
+        self.with_tank_quantity(A380FuelTankType::FeedOne, quantity_kg)
+            .with_tank_quantity(A380FuelTankType::FeedTwo, quantity_kg)
+            .with_tank_quantity(A380FuelTankType::FeedThree, quantity_kg)
+            .with_tank_quantity(A380FuelTankType::FeedFour, quantity_kg)
+    }
+
+    fn with_inner_tanks(self, quantity_kg: f64) -> Self {
+        self.with_tank_quantity(A380FuelTankType::LeftInner, quantity_kg)
+            .with_tank_quantity(A380FuelTankType::RightInner, quantity_kg)
+    }
+
+    fn with_mid_tanks(self, quantity_kg: f64) -> Self {
+        self.with_tank_quantity(A380FuelTankType::LeftMid, quantity_kg)
+            .with_tank_quantity(A380FuelTankType::RightMid, quantity_kg)
+    }
+
+    fn with_outer_tanks(self, quantity_kg: f64) -> Self {
+        self.with_tank_quantity(A380FuelTankType::LeftOuter, quantity_kg)
+            .with_tank_quantity(A380FuelTankType::RightOuter, quantity_kg)
+    }
+
+    fn with_zero_fuel_weight(mut self, zfw_kg: f64, zfw_cg_percent_mac: f64) -> Self {
+        self.write_by_name("AIRFRAME_ZFW_DESIRED", zfw_kg);
+        self.write_by_name("AIRFRAME_ZFW_CG_PERCENT_MAC_DESIRED", zfw_cg_percent_mac);
+        self
+    }
+
+    fn with_jettison_target_landing_weight(mut self, weight_kg: f64) -> Self {
+        self.write_by_name("FUEL_JETTISON_TARGET_LANDING_WEIGHT", weight_kg);
+        self
+    }
+
+    fn with_jettison_armed(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_JETTISON_ARM_PB_IS_ON", true);
+        self
+    }
+
+    fn with_ambient_temperature(mut self, celsius: f64) -> Self {
+        self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(celsius));
+        self
+    }
+
+    fn with_hydraulic_heat(mut self, watts: f64) -> Self {
+        self.command(|a| a.set_hydraulic_heat(Power::new::<watt>(watts)));
+        self
+    }
+
+    fn soak_for(mut self, duration: Duration) -> Self {
+        for _ in 0..duration.as_secs() / MINUTES_TO_SECONDS {
+            self.run_with_delta(Duration::from_secs(MINUTES_TO_SECONDS));
+        }
+        self
+    }
+
+    fn tank_temperature(&mut self, tank: A380FuelTankType) -> f64 {
+        let temperature: ThermodynamicTemperature =
+            self.read_by_name(&format!("FUEL_TANK_TEMPERATURE_{}", tank as usize + 1));
+        temperature.get::<degree_celsius>()
+    }
+
+    fn indicated_quantity(&mut self, tank: A380FuelTankType) -> Option<f64> {
+        let quantity: Arinc429Word<f64> = self.read_arinc429_by_name(&format!(
+            "FUEL_TANK_INDICATED_QUANTITY_{}",
+            tank as usize + 1
+        ));
+        quantity.normal_value()
+    }
+
+    fn with_jettison_active(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_JETTISON_ACTIVE_PB_IS_ON", true);
+        self
+    }
+
+    fn jettison_flow_rate(&mut self) -> f64 {
+        self.read_by_name("FUEL_JETTISON_FLOW_RATE")
+    }
+
+    fn tank_quantity(&self, tank: A380FuelTankType) -> f64 {
+        self.tank_mass(tank as usize).get::<kilogram>()
+    }
+
+    fn total_tank_quantity(&self) -> f64 {
+        A380FuelTankType::iterator()
+            .map(|tank| self.tank_quantity(tank))
+            .sum()
+    }
+
+    fn is_true(&mut self, name: &str) -> bool {
+        self.read_by_name(name)
+    }
+
     fn trigger_instant_refuel(mut self) -> Self {
         self.write_by_name("EFB_REFUEL_RATE_SETTING", RefuelRate::Instant);
         self.write_by_name("REFUEL_STARTED_BY_USR", true);
@@ -561,3 +732,323 @@ fn spawn_high_fuel_load_desired_real_200000_done() {
     assert_fuel_quantity_200000(&test_bed);
     assert_false!(test_bed.refuel_status());
 }
+
+#[test]
+fn in_flight_inner_tanks_transfer_to_feed_tanks() {
+    let test_bed = test_bed_with()
+        .and_powered()
+        .with_feed_tanks(10000.)
+        .with_inner_tanks(10000.)
+        .with_mid_tanks(10000.)
+        .with_outer_tanks(4000.)
+        .and_run();
+    let total_quantity = test_bed.total_tank_quantity();
+
+    let mut test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.tank_quantity(A380FuelTankType::LeftInner) < 10000.);
+    assert!(test_bed.tank_quantity(A380FuelTankType::RightInner) < 10000.);
+    assert!(test_bed.tank_quantity(A380FuelTankType::FeedOne) > 10000.);
+    assert!(test_bed.tank_quantity(A380FuelTankType::FeedFour) > 10000.);
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftMid) - 10000.).abs() < 1.);
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftOuter) - 4000.).abs() < 1.);
+    assert!((test_bed.total_tank_quantity() - total_quantity).abs() < 1.);
+    assert!(test_bed.is_true("FUEL_FEED_TANK_1_TRANSFER_ACTIVE"));
+}
+
+#[test]
+fn in_flight_mid_tanks_transfer_once_inner_tanks_are_empty() {
+    let test_bed = test_bed_with()
+        .and_powered()
+        .with_feed_tanks(10000.)
+        .with_mid_tanks(10000.)
+        .with_outer_tanks(4000.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.tank_quantity(A380FuelTankType::LeftMid) < 10000.);
+    assert!(test_bed.tank_quantity(A380FuelTankType::RightMid) < 10000.);
+    assert!((test_bed.tank_quantity(A380FuelTankType::RightOuter) - 4000.).abs() < 1.);
+}
+
+#[test]
+fn in_flight_feed_tanks_are_not_overfilled() {
+    let test_bed = test_bed_with()
+        .and_powered()
+        .with_feed_tanks(20000.)
+        .with_inner_tanks(30000.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    let feed_one_capacity = 7299.6 * FUEL_GALLONS_TO_KG;
+    assert!((test_bed.tank_quantity(A380FuelTankType::FeedOne) - feed_one_capacity).abs() < 1.);
+    assert!(test_bed.tank_quantity(A380FuelTankType::LeftInner) > 20000.);
+}
+
+#[test]
+fn in_flight_outer_tanks_hold_fuel_until_feed_tank_low_level() {
+    let test_bed = test_bed_with()
+        .and_powered()
+        .with_feed_tanks(5000.)
+        .with_outer_tanks(4000.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftOuter) - 4000.).abs() < 1.);
+
+    let mut test_bed = test_bed
+        .with_tank_quantity(A380FuelTankType::FeedTwo, 3500.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.tank_quantity(A380FuelTankType::LeftOuter) < 4000.);
+    assert!((test_bed.tank_quantity(A380FuelTankType::RightOuter) - 4000.).abs() < 1.);
+    assert!(test_bed.is_true("FUEL_LEFT_OUTER_TANK_TRANSFER_ACTIVE"));
+    assert!(!test_bed.is_true("FUEL_RIGHT_OUTER_TANK_TRANSFER_ACTIVE"));
+}
+
+#[test]
+fn in_flight_trim_tank_transfers_forward_to_target() {
+    // 58 000 kg total fuel at 300 000 kg ZFW and 36.5 % ZFWCG targets 6 000 kg trim fuel
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_feed_tanks(10000.)
+        .with_inner_tanks(5000.)
+        .with_tank_quantity(A380FuelTankType::Trim, 8000.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.tank_quantity(A380FuelTankType::Trim) < 8000.);
+    assert!(test_bed.is_true("FUEL_TRIM_TANK_FWD_TRANSFER_ACTIVE"));
+
+    let mut test_bed = test_bed.run_multiple_frames(Duration::from_secs(15 * MINUTES_TO_SECONDS));
+
+    assert!((test_bed.tank_quantity(A380FuelTankType::Trim) - 6000.).abs() < 1.);
+    assert!(!test_bed.is_true("FUEL_TRIM_TANK_FWD_TRANSFER_ACTIVE"));
+}
+
+#[test]
+fn in_flight_trim_tank_transfers_aft_to_target() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_feed_tanks(10000.)
+        .with_inner_tanks(9000.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.tank_quantity(A380FuelTankType::Trim) > 0.);
+    assert!(test_bed.is_true("FUEL_TRIM_TANK_AFT_TRANSFER_ACTIVE"));
+    assert!(
+        (test_bed.tank_quantity(A380FuelTankType::LeftInner)
+            - test_bed.tank_quantity(A380FuelTankType::RightInner))
+        .abs()
+            < 1.
+    );
+}
+
+#[test]
+fn no_in_flight_transfer_on_ground() {
+    let test_bed = test_bed_with()
+        .on_ground()
+        .and_powered()
+        .with_feed_tanks(10000.)
+        .with_inner_tanks(10000.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftInner) - 10000.).abs() < 1.);
+    assert!((test_bed.tank_quantity(A380FuelTankType::FeedOne) - 10000.).abs() < 1.);
+}
+
+#[test]
+fn no_in_flight_transfer_when_unpowered() {
+    let test_bed = test_bed_with()
+        .with_feed_tanks(10000.)
+        .with_inner_tanks(10000.)
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftInner) - 10000.).abs() < 1.);
+    assert!((test_bed.tank_quantity(A380FuelTankType::FeedOne) - 10000.).abs() < 1.);
+}
+
+#[test]
+fn jettison_needs_arm_and_active_push_buttons() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_feed_tanks(20000.)
+        .with_inner_tanks(20000.)
+        .with_jettison_active()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(!test_bed.is_true("FUEL_JETTISON_ACTIVE"));
+    assert!(!test_bed.is_true("FUEL_LEFT_JETTISON_VALVE_IS_OPEN"));
+    assert!(test_bed.jettison_flow_rate() < 0.01);
+}
+
+#[test]
+fn jettison_reduces_fuel_on_board() {
+    let test_bed = test_bed_with()
+        .and_powered()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_feed_tanks(20000.)
+        .with_inner_tanks(20000.)
+        .with_outer_tanks(4000.)
+        .with_tank_quantity(A380FuelTankType::Trim, 5000.)
+        .and_run();
+    let total_quantity = test_bed.total_tank_quantity();
+
+    let mut test_bed = test_bed
+        .with_jettison_armed()
+        .with_jettison_active()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.is_true("FUEL_JETTISON_ACTIVE"));
+    assert!(test_bed.is_true("FUEL_LEFT_JETTISON_VALVE_IS_OPEN"));
+    assert!(test_bed.is_true("FUEL_RIGHT_JETTISON_PUMP_IS_RUNNING"));
+    assert!(test_bed.jettison_flow_rate() > 30.);
+    assert!(test_bed.total_tank_quantity() < total_quantity - 1500.);
+    assert!(test_bed.tank_quantity(A380FuelTankType::Trim) < 5000.);
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftOuter) - 4000.).abs() < 1.);
+}
+
+#[test]
+fn jettison_stops_at_target_landing_weight() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_jettison_target_landing_weight(420000.)
+        .with_feed_tanks(20000.)
+        .with_inner_tanks(20000.)
+        .with_mid_tanks(10000.)
+        .with_jettison_armed()
+        .with_jettison_active()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    assert!((300000. + test_bed.total_tank_quantity() - 420000.).abs() < 1.);
+    assert!(!test_bed.is_true("FUEL_JETTISON_ACTIVE"));
+    assert!(test_bed.jettison_flow_rate() < 0.01);
+}
+
+#[test]
+fn jettison_stops_at_feed_tank_minimum_quantity() {
+    let mut test_bed = test_bed_with()
+        .and_powered()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_jettison_target_landing_weight(300000.)
+        .with_feed_tanks(8000.)
+        .with_inner_tanks(2000.)
+        .with_jettison_armed()
+        .with_jettison_active()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(30 * MINUTES_TO_SECONDS));
+
+    for tank in [
+        A380FuelTankType::FeedOne,
+        A380FuelTankType::FeedTwo,
+        A380FuelTankType::FeedThree,
+        A380FuelTankType::FeedFour,
+    ] {
+        assert!(test_bed.tank_quantity(tank) > 4499.);
+    }
+    assert!(test_bed.tank_quantity(A380FuelTankType::LeftInner) < 1.);
+    assert!(!test_bed.is_true("FUEL_JETTISON_ACTIVE"));
+}
+
+#[test]
+fn jettison_valves_stay_closed_when_unpowered() {
+    let mut test_bed = test_bed_with()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_feed_tanks(20000.)
+        .with_inner_tanks(20000.)
+        .with_jettison_armed()
+        .with_jettison_active()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(!test_bed.is_true("FUEL_LEFT_JETTISON_VALVE_IS_OPEN"));
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftInner) - 20000.).abs() < 1.);
+}
+
+#[test]
+fn jettison_is_inhibited_on_ground() {
+    let mut test_bed = test_bed_with()
+        .on_ground()
+        .and_powered()
+        .with_zero_fuel_weight(300000., 36.5)
+        .with_feed_tanks(20000.)
+        .with_inner_tanks(20000.)
+        .with_jettison_armed()
+        .with_jettison_active()
+        .and_run()
+        .run_multiple_frames(Duration::from_secs(60));
+
+    assert!(!test_bed.is_true("FUEL_JETTISON_ACTIVE"));
+    assert!(!test_bed.is_true("FUEL_LEFT_JETTISON_VALVE_IS_OPEN"));
+    assert!(!test_bed.is_true("FUEL_RIGHT_JETTISON_PUMP_IS_RUNNING"));
+    assert!(test_bed.jettison_flow_rate() < 0.01);
+    assert!((test_bed.tank_quantity(A380FuelTankType::LeftInner) - 20000.).abs() < 1.);
+}
+
+#[test]
+fn hydraulic_heat_warms_fuel_in_feed_tanks_two_and_three() {
+    let mut test_bed = test_bed_with()
+        .on_ground()
+        .with_ambient_temperature(10.)
+        .with_feed_tanks(5000.)
+        .with_hydraulic_heat(5000.)
+        .soak_for(Duration::from_secs(60 * MINUTES_TO_SECONDS));
+
+    assert!(test_bed.tank_temperature(A380FuelTankType::FeedTwo) > 11.);
+    assert!(test_bed.tank_temperature(A380FuelTankType::FeedThree) > 11.);
+    assert!((test_bed.tank_temperature(A380FuelTankType::FeedOne) - 10.).abs() < 0.1);
+    assert!((test_bed.tank_temperature(A380FuelTankType::FeedFour) - 10.).abs() < 0.1);
+}
+
+#[test]
+fn failed_probe_degrades_feed_tank_quantity_indication() {
+    let mut test_bed = test_bed_with()
+        .on_ground()
+        .and_powered()
+        .with_feed_tanks(10000.);
+    test_bed.fail(FailureType::FuelQuantityProbe(
+        A380FuelTankType::FeedTwo as usize + 1,
+    ));
+    test_bed = test_bed.and_run();
+
+    assert!(test_bed.is_true("FUEL_FQI_DEGRADED"));
+    assert!(
+        (test_bed
+            .indicated_quantity(A380FuelTankType::FeedOne)
+            .unwrap()
+            - 10000.)
+            .abs()
+            < 1.
+    );
+    assert!(
+        (test_bed
+            .indicated_quantity(A380FuelTankType::FeedTwo)
+            .unwrap()
+            - 10000.)
+            .abs()
+            > 100.
+    );
+}
+
+#[test]
+fn fuel_quantity_indication_is_invalid_when_unpowered() {
+    let mut test_bed = test_bed_with()
+        .on_ground()
+        .with_feed_tanks(10000.)
+        .and_run();
+
+    assert!(test_bed
+        .indicated_quantity(A380FuelTankType::FeedOne)
+        .is_none());
+    assert!(!test_bed.is_true("FUEL_FQI_DEGRADED"));
+}
@@ -763,6 +763,14 @@ mod tests {
             self.longitude
         }
 
+        fn gpirs_latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+            self.latitude
+        }
+
+        fn gpirs_longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+            self.longitude
+        }
+
         fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.heading
         }
@@ -779,6 +787,10 @@ mod tests {
             self.altitude
         }
 
+        fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning)
+        }
+
         fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.angle_of_attack
         }
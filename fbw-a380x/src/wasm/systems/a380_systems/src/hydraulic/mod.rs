@@ -10,6 +10,7 @@ use uom::si::{
     mass::kilogram,
     pressure::psi,
     ratio::{percent, ratio},
+    torque::newton_meter,
     velocity::knot,
     volume::{cubic_inch, gallon, liter},
     volume_rate::gallon_per_second,
@@ -20,7 +21,10 @@ use systems::{
     engine::Engine,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
-        brake_circuit::{BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController},
+        brake_circuit::{
+            BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
+            BrakeHeatSinkCharacteristics, BrakeTemperatures,
+        },
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
         flap_slat::FlapSlatAssembly,
@@ -49,8 +53,9 @@ use systems::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
         AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked,
         DelayedFalseLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
-        LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure, SurfacesPositions,
+        EngineFirePushButtons, FuelTemperature, GearWheel, HydraulicColor, HydraulicHeatRejection,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure,
+        SectionPressure, SurfacesPositions,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -1601,6 +1606,7 @@ pub(super) struct A380Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A380BrakingForce,
+    brake_temperatures: BrakeTemperatures,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1894,6 +1900,18 @@ impl A380Hydraulic {
             ),
 
             braking_force: A380BrakingForce::new(context),
+            // No brake fans on the A380, wing gear wheels are numbered first, body gear wheels after
+            brake_temperatures: BrakeTemperatures::new(
+                context,
+                &[1, 2, 5, 6, 9, 10, 13, 14],
+                &[3, 4, 7, 8, 11, 12, 15, 16],
+                BrakeHeatSinkCharacteristics::new(
+                    Mass::new::<kilogram>(140.),
+                    Torque::new::<newton_meter>(84000.),
+                    Length::new::<meter>(0.71),
+                ),
+                false,
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -1998,6 +2016,7 @@ impl A380Hydraulic {
         lgcius: &LandingGearControlInterfaceUnitSet,
         reservoir_pneumatics: &impl ReservoirAirPressure,
         adirs: &(impl AdirsDiscreteOutputs + AdirsMeasurementOutputs),
+        fuel: &impl FuelTemperature,
     ) {
         self.core_hydraulic_updater.update(context);
 
@@ -2024,6 +2043,7 @@ impl A380Hydraulic {
                 lgcius.lgciu1(),
                 lgcius.lgciu2(),
                 reservoir_pneumatics,
+                fuel,
             );
         }
     }
@@ -2342,6 +2362,15 @@ impl A380Hydraulic {
             &self.bypass_pin,
         );
 
+        self.brake_temperatures.update(
+            context,
+            self.braking_circuit_norm.left_brake_pressure()
+                + self.braking_circuit_altn.left_brake_pressure(),
+            self.braking_circuit_norm.right_brake_pressure()
+                + self.braking_circuit_altn.right_brake_pressure(),
+            lgciu1.main_down_and_locked(),
+        );
+
         self.slats_flaps_complex
             .update(context, adirs, &self.flap_system, &self.slat_system);
 
@@ -2637,6 +2666,7 @@ impl A380Hydraulic {
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
         reservoir_pneumatics: &impl ReservoirAirPressure,
+        fuel: &impl FuelTemperature,
     ) {
         // First update what is currently consumed and given back by each actuator
         // Todo: might have to split the actuator volumes by expected number of loops
@@ -2891,6 +2921,7 @@ impl A380Hydraulic {
             None,
             &self.green_circuit_controller,
             reservoir_pneumatics.green_reservoir_pressure(),
+            fuel.hydraulic_heat_exchanger_fuel_temperature(HydraulicColor::Green),
         );
 
         self.yellow_circuit_controller.update(
@@ -2916,6 +2947,7 @@ impl A380Hydraulic {
             None,
             &self.yellow_circuit_controller,
             reservoir_pneumatics.yellow_reservoir_pressure(),
+            fuel.hydraulic_heat_exchanger_fuel_temperature(HydraulicColor::Yellow),
         );
 
         self.braking_circuit_norm.update(
@@ -2970,6 +3002,15 @@ impl CargoDoorLocked for A380Hydraulic {
     }
 }
 
+impl HydraulicHeatRejection for A380Hydraulic {
+    fn heat_rejected_to_fuel(&self, color: HydraulicColor) -> Power {
+        match color {
+            HydraulicColor::Green => self.green_circuit.heat_rejected_to_fuel(),
+            HydraulicColor::Yellow => self.yellow_circuit.heat_rejected_to_fuel(),
+            HydraulicColor::Blue => panic!("NO BLUE CIRCUIT IN A380"),
+        }
+    }
+}
 impl SimulationElement for A380Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.engine_driven_pump_1a.accept(visitor);
@@ -3008,6 +3049,8 @@ impl SimulationElement for A380Hydraulic {
         self.green_electric_pump_b.accept(visitor);
         self.green_electric_pump_b_controller.accept(visitor);
 
+        self.green_auxiliary_pump.accept(visitor);
+
         self.epump_auto_logic.accept(visitor);
 
         self.forward_cargo_door_controller.accept(visitor);
@@ -3027,6 +3070,7 @@ impl SimulationElement for A380Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_temperatures.accept(visitor);
 
         self.nose_steering.accept(visitor);
         self.body_wheel_steering_left.accept(visitor);
@@ -6913,6 +6957,12 @@ mod tests {
             fn longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
+            fn gpirs_latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
+            }
+            fn gpirs_longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
+            }
             fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
@@ -6925,6 +6975,9 @@ mod tests {
             fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
                 Arinc429Word::new(Length::default(), SignStatus::NormalOperation)
             }
+            fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(self.airspeed, SignStatus::NormalOperation)
+            }
             fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
@@ -6962,6 +7015,30 @@ mod tests {
             }
         }
 
+        struct A380TestFuel {
+            temperature: ThermodynamicTemperature,
+        }
+        impl A380TestFuel {
+            fn new(temperature: ThermodynamicTemperature) -> Self {
+                Self { temperature }
+            }
+        }
+        impl FuelTemperature for A380TestFuel {
+            fn hydraulic_heat_exchanger_fuel_temperature(
+                &self,
+                _color: HydraulicColor,
+            ) -> ThermodynamicTemperature {
+                self.temperature
+            }
+
+            fn engine_feed_fuel_temperature(
+                &self,
+                _engine_number: usize,
+            ) -> ThermodynamicTemperature {
+                self.temperature
+            }
+        }
+
         struct A380TestElectrical {
             airspeed: Velocity,
             all_ac_lost: bool,
@@ -7328,6 +7405,7 @@ mod tests {
                     &self.lgcius,
                     &self.pneumatics,
                     &self.adirus,
+                    &A380TestFuel::new(context.ambient_temperature()),
                 );
 
                 self.overhead.update(&self.hydraulics);
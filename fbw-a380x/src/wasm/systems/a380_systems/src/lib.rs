@@ -5,6 +5,7 @@ mod airframe;
 mod avionics_data_communication_network;
 mod control_display_system;
 mod electrical;
+pub mod failures;
 mod fire_and_smoke_protection;
 mod fuel;
 pub mod hydraulic;
@@ -34,7 +35,9 @@ use fire_and_smoke_protection::A380FireAndSmokeProtection;
 use fuel::FuelLevel;
 use hydraulic::{autobrakes::A380AutobrakePanel, A380Hydraulic, A380HydraulicOverheadPanel};
 use icing::Icing;
-use navigation::{A380AirDataInertialReferenceSystemBuilder, A380RadioAltimeters};
+use navigation::{
+    A380AirDataInertialReferenceSystemBuilder, A380MultiModeReceivers, A380RadioAltimeters,
+};
 use payload::A380Payload;
 use power_consumption::A380PowerConsumption;
 use reverser::{A380ReverserController, A380Reversers};
@@ -48,7 +51,7 @@ use systems::{
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::{reverser_thrust::ReverserForce, trent_engine::TrentEngine, EngineFireOverheadPanel},
-    enhanced_gpwc::EnhancedGroundProximityWarningComputer,
+    enhanced_gpwc::{EnhancedGroundProximityWarningComputer, LandingFlapsPositionBits},
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
@@ -91,6 +94,7 @@ pub struct A380 {
     landing_gear: LandingGear,
     pneumatic: A380Pneumatic,
     radio_altimeters: A380RadioAltimeters,
+    multi_mode_receivers: A380MultiModeReceivers,
     cds: A380ControlDisplaySystem,
     egpwc: EnhancedGroundProximityWarningComputer,
     icing_simulation: Icing,
@@ -146,6 +150,7 @@ impl A380 {
             landing_gear: LandingGear::new(context, true),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
+            multi_mode_receivers: A380MultiModeReceivers::new(context),
             cds: A380ControlDisplaySystem::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
@@ -161,6 +166,7 @@ impl A380 {
                     Length::new::<nautical_mile>(640.0),
                 ],
                 3,
+                LandingFlapsPositionBits::new(22, 23),
             ),
 
             icing_simulation: Icing::new(context),
@@ -193,6 +199,7 @@ impl Aircraft for A380 {
                 || self.electrical_overhead.apu_generator_is_on(2))
                 && !(self.electrical_overhead.external_power_is_on(1)
                     && self.electrical_overhead.external_power_is_available(1)),
+            &self.electrical_overhead,
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.feed_one_tank_has_fuel(),
         );
@@ -213,6 +220,7 @@ impl Aircraft for A380 {
             ],
             self.lgcius.lgciu1(),
             &self.adirs,
+            &self.fuel,
         );
 
         self.electrical_overhead
@@ -252,6 +260,7 @@ impl Aircraft for A380 {
         );
 
         self.radio_altimeters.update(context);
+        self.multi_mode_receivers.update(context);
 
         self.hydraulic.update(
             context,
@@ -267,6 +276,7 @@ impl Aircraft for A380 {
             &self.lgcius,
             &self.pneumatic,
             &self.adirs,
+            &self.fuel,
         );
 
         self.pneumatic.update_hydraulic_reservoir_spatial_volumes(
@@ -276,7 +286,14 @@ impl Aircraft for A380 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
+        self.adirs.update(
+            context,
+            &self.adirs_overhead,
+            [
+                self.multi_mode_receivers.mmr_1(),
+                self.multi_mode_receivers.mmr_2(),
+            ],
+        );
         self.adirs_overhead.update(context, &self.adirs);
 
         self.power_consumption.update(context);
@@ -317,8 +334,6 @@ impl Aircraft for A380 {
 
         self.cds.update();
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-
         self.structural_flex.update(
             context,
             [
@@ -333,8 +348,15 @@ impl Aircraft for A380 {
 
         self.icing_simulation.update(context);
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-        self.fuel.update(context);
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.multi_mode_receivers.mmr_1(),
+            self.radio_altimeters.radio_altimeter_1(),
+            self.radio_altimeters.radio_altimeter_2(),
+            self.lgcius.lgciu1(),
+        );
+        self.fuel.update(context, &self.hydraulic, &self.electrical);
 
         self.engine_reverser_control[0].update(
             &self.engine_2,
@@ -385,6 +407,7 @@ impl SimulationElement for A380 {
         accept_iterable!(self.ext_pwrs, visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.multi_mode_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
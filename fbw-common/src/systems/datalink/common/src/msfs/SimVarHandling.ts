@@ -48,8 +48,8 @@ export enum SimVarSources {
   utcMonth = 'E:ZULU MONTH OF YEAR',
   utcDayOfMonth = 'E:ZULU DAY OF MONTH',
   utcSeconds = 'E:ZULU TIME',
-  presentPositionLatitude = 'L:A32NX_ADIRS_IR_1_LATITUDE',
-  presentPositionLongitude = 'L:A32NX_ADIRS_IR_1_LONGITUDE',
+  presentPositionLatitude = 'L:A32NX_ADIRS_IR_1_GPIRS_LATITUDE',
+  presentPositionLongitude = 'L:A32NX_ADIRS_IR_1_GPIRS_LONGITUDE',
   presentAltitude = 'L:A32NX_ADIRS_ADR_1_ALTITUDE',
   presentHeading = 'L:A32NX_ADIRS_IR_1_HEADING',
   presentTrack = 'L:A32NX_ADIRS_IR_1_TRACK',
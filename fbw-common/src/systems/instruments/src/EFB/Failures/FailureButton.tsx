@@ -7,6 +7,7 @@ export interface FailureButtonProps {
   name: string;
   isActive: boolean;
   isChanging: boolean;
+  isArmed?: boolean;
   onClick: MouseEventHandler<HTMLButtonElement>;
   className: string;
   highlightedTerm?: string;
@@ -16,6 +17,7 @@ export const FailureButton: FC<FailureButtonProps> = ({
   name,
   isActive,
   isChanging,
+  isArmed,
   onClick,
   className,
   highlightedTerm,
@@ -23,6 +25,9 @@ export const FailureButton: FC<FailureButtonProps> = ({
   let color = 'border-0';
   if (!isChanging) {
     color = isActive ? 'border-utility-red' : 'border-utility-green';
+    if (isArmed && !isActive) {
+      color = 'border-utility-amber';
+    }
   }
 
   return (
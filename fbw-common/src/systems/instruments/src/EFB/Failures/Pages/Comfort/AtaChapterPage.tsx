@@ -17,12 +17,14 @@ interface AtaChapterPageProps {
 }
 
 export const AtaChapterPage = ({ chapter, failures }: AtaChapterPageProps) => {
-  const { activeFailures, changingFailures, activate, deactivate } = useFailuresOrchestrator();
+  const { activeFailures, changingFailures, armedFailures, activate, deactivate, disarm } = useFailuresOrchestrator();
   const { searchQuery } = useAppSelector((state) => state.failuresPage);
   const filteredFailures = failures.filter((failure) => failure.ata === chapter);
 
   const handleFailureButtonClick = (failureIdentifier: number) => {
-    if (!activeFailures.has(failureIdentifier)) {
+    if (armedFailures.has(failureIdentifier)) {
+      disarm(failureIdentifier);
+    } else if (!activeFailures.has(failureIdentifier)) {
       activate(failureIdentifier);
     } else {
       deactivate(failureIdentifier);
@@ -68,6 +70,7 @@ export const AtaChapterPage = ({ chapter, failures }: AtaChapterPageProps) => {
                 name={failure.name}
                 isActive={activeFailures.has(failure.identifier)}
                 isChanging={changingFailures.has(failure.identifier)}
+                isArmed={armedFailures.has(failure.identifier)}
                 highlightedTerm={getHighlightedTerm(failure.name)}
                 onClick={() => handleFailureButtonClick(failure.identifier)}
                 className={`${index && index % 4 !== 0 && 'ml-4'} ${index >= 4 && 'mt-4'} h-36`}
@@ -15,7 +15,7 @@ interface FailureGroupProps {
 }
 
 const FailureGroup = ({ title, failures }: FailureGroupProps) => {
-  const { activeFailures, changingFailures, activate, deactivate } = useFailuresOrchestrator();
+  const { activeFailures, changingFailures, armedFailures, activate, deactivate, disarm } = useFailuresOrchestrator();
   const { searchQuery } = useAppSelector((state) => state.failuresPage);
 
   const getHighlightedTerm = (failureName: string) => {
@@ -27,7 +27,9 @@ const FailureGroup = ({ title, failures }: FailureGroupProps) => {
   };
 
   const handleFailureButtonClick = (failureIdentifier: number) => {
-    if (!activeFailures.has(failureIdentifier)) {
+    if (armedFailures.has(failureIdentifier)) {
+      disarm(failureIdentifier);
+    } else if (!activeFailures.has(failureIdentifier)) {
       activate(failureIdentifier);
     } else {
       deactivate(failureIdentifier);
@@ -45,6 +47,7 @@ const FailureGroup = ({ title, failures }: FailureGroupProps) => {
             name={failure.name}
             isActive={activeFailures.has(failure.identifier)}
             isChanging={changingFailures.has(failure.identifier)}
+            isArmed={armedFailures.has(failure.identifier)}
             highlightedTerm={getHighlightedTerm(failure.name)}
             onClick={() => handleFailureButtonClick(failure.identifier)}
             className={`${index && index % 4 !== 0 && 'ml-4'} ${index >= 4 && 'mt-4'} h-36`}
@@ -2,14 +2,17 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import React, { PropsWithChildren, useState } from 'react';
-import { Failure, FailuresOrchestrator, useUpdate, FailureDefinition } from '@flybywiresim/fbw-sdk';
+import { Failure, FailureArming, FailuresOrchestrator, useUpdate, FailureDefinition } from '@flybywiresim/fbw-sdk';
 
 interface FailuresOrchestratorContext {
   allFailures: Readonly<Readonly<Failure>[]>;
   activeFailures: Set<number>;
   changingFailures: Set<number>;
+  armedFailures: Set<number>;
   activate(identifier: number): Promise<void>;
   deactivate(identifier: number): Promise<void>;
+  arm(identifier: number, arming: FailureArming): Promise<void>;
+  disarm(identifier: number): Promise<void>;
 }
 
 const createOrchestrator = (failures: FailureDefinition[]) => new FailuresOrchestrator('A32NX', failures);
@@ -18,8 +21,11 @@ const Context = React.createContext<FailuresOrchestratorContext>({
   allFailures: [],
   activeFailures: new Set<number>(),
   changingFailures: new Set<number>(),
+  armedFailures: new Set<number>(),
   activate: () => Promise.resolve(),
   deactivate: () => Promise.resolve(),
+  arm: () => Promise.resolve(),
+  disarm: () => Promise.resolve(),
 });
 
 export interface FailuresOrchestratorProviderProps {
@@ -35,6 +41,7 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
   const [allFailures] = useState(() => orchestrator.getAllFailures());
   const [activeFailures, setActiveFailures] = useState<Set<number>>(() => new Set<number>());
   const [changingFailures, setChangingFailures] = useState<Set<number>>(() => new Set<number>());
+  const [armedFailures, setArmedFailures] = useState<Set<number>>(() => new Set<number>());
 
   useUpdate(() => {
     orchestrator.update();
@@ -48,6 +55,11 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
     if (!areEqual(changingFailures, cf)) {
       setChangingFailures(cf);
     }
+
+    const arf = orchestrator.getArmedFailures();
+    if (!areEqual(armedFailures, arf)) {
+      setArmedFailures(arf);
+    }
   });
 
   return (
@@ -56,8 +68,11 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
         allFailures,
         activeFailures,
         changingFailures,
+        armedFailures,
         activate: (identifier) => orchestrator.activate(identifier),
         deactivate: (identifier) => orchestrator.deactivate(identifier),
+        arm: (identifier, arming) => orchestrator.arm(identifier, arming),
+        disarm: (identifier) => orchestrator.disarm(identifier),
       }}
     >
       {children}
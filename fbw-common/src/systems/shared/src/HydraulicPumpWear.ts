@@ -0,0 +1,69 @@
+// Copyright (c) 2024 FlyByWire Simulations
+// SPDX-License-Identifier: GPL-3.0
+
+import { Instrument, SimVarValueType } from '@microsoft/msfs-sdk';
+import { NXDataStore } from '@flybywiresim/fbw-sdk';
+
+/**
+ * Keeps the health of the hydraulic pumps across sessions.
+ * The health computed by the systems is saved in the persistent storage when it changes, and the saved health
+ * is replayed into the health setting of each pump when the aircraft is loaded.
+ */
+export class HydraulicPumpWearManager implements Instrument {
+  /** Minimum interval in ms between two reads of the pump healths */
+  private static readonly SAVE_INTERVAL_MS = 10_000;
+
+  /** Minimum health change to save, as the health decreases very slowly while the pumps run */
+  private static readonly SAVE_THRESHOLD = 1e-4;
+
+  private readonly savedHealths = new Map<string, number>();
+
+  /** Pumps for which the systems don't yet indicate the replayed health */
+  private readonly pendingPumps = new Set<string>();
+
+  private lastSaveTime = 0;
+
+  /**
+   * @param pumpNames The names of the pumps, as used in their health variables, e.g. `HYD_GREEN_EDPUMP`
+   */
+  constructor(private readonly pumpNames: string[]) {}
+
+  public init(): void {
+    for (const name of this.pumpNames) {
+      const savedHealth = parseFloat(NXDataStore.get(`${name}_HEALTH`, ''));
+      if (savedHealth > 0) {
+        this.savedHealths.set(name, savedHealth);
+        this.pendingPumps.add(name);
+        SimVar.SetSimVarValue(`L:A32NX_${name}_HEALTH_SETTING`, SimVarValueType.Number, savedHealth);
+      }
+    }
+  }
+
+  public onUpdate(): void {
+    const now = Date.now();
+    if (now - this.lastSaveTime < HydraulicPumpWearManager.SAVE_INTERVAL_MS) {
+      return;
+    }
+    this.lastSaveTime = now;
+
+    for (const name of this.pumpNames) {
+      const health = SimVar.GetSimVarValue(`L:A32NX_${name}_HEALTH`, SimVarValueType.Number);
+      const savedHealth = this.savedHealths.get(name);
+      const hasChanged =
+        savedHealth === undefined || Math.abs(health - savedHealth) >= HydraulicPumpWearManager.SAVE_THRESHOLD;
+
+      // The health of a new pump is written until the systems have read the replayed setting, which must not be saved
+      if (this.pendingPumps.has(name)) {
+        if (hasChanged) {
+          continue;
+        }
+        this.pendingPumps.delete(name);
+      }
+
+      if (hasChanged && health > 0) {
+        this.savedHealths.set(name, health);
+        NXDataStore.set(`${name}_HEALTH`, health.toString());
+      }
+    }
+  }
+}
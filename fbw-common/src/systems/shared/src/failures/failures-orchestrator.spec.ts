@@ -1,5 +1,13 @@
-import { FailuresOrchestrator } from '.';
-import { getActivateFailureSimVarName, getDeactivateFailureSimVarName } from './sim-vars';
+import { FailuresOrchestrator, FailureTrigger } from '.';
+import {
+  getActivateFailureSimVarName,
+  getArmFailureSimVarName,
+  getArmFailureTriggerSimVarName,
+  getArmFailureTriggerValueSimVarName,
+  getDeactivateFailureSimVarName,
+  getTriggeredActivateFailureSimVarName,
+  getTriggeredDeactivateFailureSimVarName,
+} from './sim-vars';
 import { flushPromises } from './test-functions';
 
 describe('FailuresOrchestrator', () => {
@@ -40,6 +48,35 @@ describe('FailuresOrchestrator', () => {
       expect(o.isActive(identifier)).toBe(false);
     });
 
+    test('armed when armed', async () => {
+      const o = orchestrator();
+
+      await armFailure(o);
+
+      expect(o.isArmed(identifier)).toBe(true);
+    });
+
+    test('active and no longer armed when activated by its trigger', async () => {
+      const o = orchestrator();
+      await armFailure(o);
+
+      await SimVar.SetSimVarValue(triggeredActivateSimVarName, 'number', identifier);
+      o.update();
+
+      expect(o.isActive(identifier)).toBe(true);
+      expect(o.isArmed(identifier)).toBe(false);
+    });
+
+    test('inactive when deactivated by its trigger', async () => {
+      const o = orchestrator();
+      await activateFailure(o);
+
+      await SimVar.SetSimVarValue(triggeredDeactivateSimVarName, 'number', identifier);
+      o.update();
+
+      expect(o.isActive(identifier)).toBe(false);
+    });
+
     describe('changing', () => {
       test('while failure is activating', async () => {
         const o = orchestrator();
@@ -81,6 +118,9 @@ describe('FailuresOrchestrator', () => {
 const prefix = 'PREFIX';
 const activateSimVarName = getActivateFailureSimVarName(prefix);
 const deactivateSimVarName = getDeactivateFailureSimVarName(prefix);
+const armSimVarName = getArmFailureSimVarName(prefix);
+const triggeredActivateSimVarName = getTriggeredActivateFailureSimVarName(prefix);
+const triggeredDeactivateSimVarName = getTriggeredDeactivateFailureSimVarName(prefix);
 
 const identifier = 123;
 const name = 'test';
@@ -105,3 +145,17 @@ async function activateOrDeactivateFailure(o: FailuresOrchestrator, activate: bo
 
   await promise;
 }
+
+async function armFailure(o: FailuresOrchestrator) {
+  const promise = o.arm(identifier, { trigger: FailureTrigger.AltitudeAbove, triggerValue: 10000 });
+  await flushPromises();
+
+  expect(SimVar.GetSimVarValue(getArmFailureTriggerSimVarName(prefix), 'number')).toBe(FailureTrigger.AltitudeAbove);
+  expect(SimVar.GetSimVarValue(getArmFailureTriggerValueSimVarName(prefix), 'number')).toBe(10000);
+  expect(SimVar.GetSimVarValue(armSimVarName, 'number')).toBe(identifier);
+
+  await SimVar.SetSimVarValue(armSimVarName, 'number', 0);
+  o.update();
+
+  await promise;
+}
@@ -3,8 +3,20 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import { AtaChapterNumber } from '../ata';
-import { QueuedSimVarWriter, SimVarReaderWriter } from './communication';
-import { getActivateFailureSimVarName, getDeactivateFailureSimVarName } from './sim-vars';
+import { QueuedSimVarReader, QueuedSimVarWriter, SimVarReaderWriter } from './communication';
+import {
+  getActivateFailureSimVarName,
+  getArmFailureIntermittentActiveSimVarName,
+  getArmFailureIntermittentInactiveSimVarName,
+  getArmFailureLatencySimVarName,
+  getArmFailureSimVarName,
+  getArmFailureTriggerSimVarName,
+  getArmFailureTriggerValueSimVarName,
+  getDeactivateFailureSimVarName,
+  getDisarmFailureSimVarName,
+  getTriggeredActivateFailureSimVarName,
+  getTriggeredDeactivateFailureSimVarName,
+} from './sim-vars';
 
 export interface Failure {
   ata: AtaChapterNumber;
@@ -14,6 +26,35 @@ export interface Failure {
 
 export type FailureDefinition = [AtaChapterNumber, number, string];
 
+/**
+ * The condition which has to be met for an armed failure to activate.
+ */
+export enum FailureTrigger {
+  /** After the given number of seconds since the failure was armed. */
+  After = 1,
+  /** Above the given indicated altitude in feet. */
+  AltitudeAbove = 2,
+  /** Below the given indicated altitude in feet. */
+  AltitudeBelow = 3,
+  /** Above the given indicated airspeed in knots. */
+  AirspeedAbove = 4,
+  /** Below the given indicated airspeed in knots. */
+  AirspeedBelow = 5,
+  /** When entering the given FWC flight phase. */
+  FlightPhase = 6,
+  /** Randomly, with a mean time between failures of the given number of seconds. */
+  Random = 7,
+}
+
+export interface FailureArming {
+  trigger: FailureTrigger;
+  triggerValue: number;
+  /** The number of seconds between the trigger and the activation of a latent failure. */
+  latencySeconds?: number;
+  /** The number of seconds an intermittent failure is active and inactive in turn. */
+  intermittent?: { activeSeconds: number; inactiveSeconds: number };
+}
+
 /**
  * Orchestrates the activation and deactivation of failures.
  *
@@ -30,6 +71,26 @@ export class FailuresOrchestrator {
 
   private deactivateFailureQueue: QueuedSimVarWriter;
 
+  private armedFailures = new Map<number, FailureArming>();
+
+  private armFailureQueue: QueuedSimVarWriter;
+
+  private disarmFailureQueue: QueuedSimVarWriter;
+
+  private armingParameters: {
+    trigger: SimVarReaderWriter;
+    triggerValue: SimVarReaderWriter;
+    latency: SimVarReaderWriter;
+    intermittentActive: SimVarReaderWriter;
+    intermittentInactive: SimVarReaderWriter;
+  };
+
+  private armingWrites: Promise<void> = Promise.resolve();
+
+  private triggeredActivateFailureReader: QueuedSimVarReader;
+
+  private triggeredDeactivateFailureReader: QueuedSimVarReader;
+
   constructor(simVarPrefix: string, failures: FailureDefinition[]) {
     this.activateFailureQueue = new QueuedSimVarWriter(
       new SimVarReaderWriter(getActivateFailureSimVarName(simVarPrefix)),
@@ -37,18 +98,39 @@ export class FailuresOrchestrator {
     this.deactivateFailureQueue = new QueuedSimVarWriter(
       new SimVarReaderWriter(getDeactivateFailureSimVarName(simVarPrefix)),
     );
+    this.armFailureQueue = new QueuedSimVarWriter(new SimVarReaderWriter(getArmFailureSimVarName(simVarPrefix)));
+    this.disarmFailureQueue = new QueuedSimVarWriter(new SimVarReaderWriter(getDisarmFailureSimVarName(simVarPrefix)));
+    this.armingParameters = {
+      trigger: new SimVarReaderWriter(getArmFailureTriggerSimVarName(simVarPrefix)),
+      triggerValue: new SimVarReaderWriter(getArmFailureTriggerValueSimVarName(simVarPrefix)),
+      latency: new SimVarReaderWriter(getArmFailureLatencySimVarName(simVarPrefix)),
+      intermittentActive: new SimVarReaderWriter(getArmFailureIntermittentActiveSimVarName(simVarPrefix)),
+      intermittentInactive: new SimVarReaderWriter(getArmFailureIntermittentInactiveSimVarName(simVarPrefix)),
+    };
+    this.triggeredActivateFailureReader = new QueuedSimVarReader(
+      new SimVarReaderWriter(getTriggeredActivateFailureSimVarName(simVarPrefix)),
+    );
+    this.triggeredDeactivateFailureReader = new QueuedSimVarReader(
+      new SimVarReaderWriter(getTriggeredDeactivateFailureSimVarName(simVarPrefix)),
+    );
     failures.forEach((failure) => {
       this.failures.push({
         ata: failure[0],
         identifier: failure[1],
         name: failure[2],
       });
+      this.triggeredActivateFailureReader.register(failure[1], () => this.onTriggered(failure[1], true));
+      this.triggeredDeactivateFailureReader.register(failure[1], () => this.onTriggered(failure[1], false));
     });
   }
 
   update() {
     this.activateFailureQueue.update();
     this.deactivateFailureQueue.update();
+    this.armFailureQueue.update();
+    this.disarmFailureQueue.update();
+    this.triggeredActivateFailureReader.update();
+    this.triggeredDeactivateFailureReader.update();
   }
 
   /**
@@ -69,6 +151,33 @@ export class FailuresOrchestrator {
     await this.deactivateFailureQueue.write(identifier);
     this.changingFailures.delete(identifier);
     this.activeFailures.delete(identifier);
+    this.armedFailures.delete(identifier);
+  }
+
+  /**
+   * Arms the failure with the given identifier, such that it activates once the trigger condition is met.
+   * Only failures simulated by the systems WASM module can be armed.
+   */
+  async arm(identifier: number, arming: FailureArming): Promise<void> {
+    this.changingFailures.add(identifier);
+    // The arming parameters are shared by all failures, thus the next failure can only
+    // be armed once the previous one has been consumed.
+    const written = this.armingWrites.then(() => this.writeArming(identifier, arming));
+    this.armingWrites = written;
+    await written;
+    this.changingFailures.delete(identifier);
+    this.armedFailures.set(identifier, arming);
+  }
+
+  /**
+   * Disarms the failure with the given identifier. An intermittent failure which is active is deactivated.
+   */
+  async disarm(identifier: number): Promise<void> {
+    this.changingFailures.add(identifier);
+    await this.disarmFailureQueue.write(identifier);
+    this.changingFailures.delete(identifier);
+    this.armedFailures.delete(identifier);
+    this.activeFailures.delete(identifier);
   }
 
   /**
@@ -78,6 +187,13 @@ export class FailuresOrchestrator {
     return this.activeFailures.has(identifier);
   }
 
+  /**
+   * Determines whether or not the failure with the given identifier is armed.
+   */
+  isArmed(identifier: number): boolean {
+    return this.armedFailures.has(identifier);
+  }
+
   /**
    * Determines whether or not the failure with the given identifier is currently
    * changing its state between active and inactive.
@@ -97,4 +213,31 @@ export class FailuresOrchestrator {
   getChangingFailures(): Set<number> {
     return new Set(this.changingFailures);
   }
+
+  getArmedFailures(): Set<number> {
+    return new Set(this.armedFailures.keys());
+  }
+
+  private async writeArming(identifier: number, arming: FailureArming): Promise<void> {
+    await Promise.all([
+      this.armingParameters.trigger.write(arming.trigger),
+      this.armingParameters.triggerValue.write(arming.triggerValue),
+      this.armingParameters.latency.write(arming.latencySeconds ?? 0),
+      this.armingParameters.intermittentActive.write(arming.intermittent?.activeSeconds ?? 0),
+      this.armingParameters.intermittentInactive.write(arming.intermittent?.inactiveSeconds ?? 0),
+    ]);
+    await this.armFailureQueue.write(identifier);
+  }
+
+  private onTriggered(identifier: number, isActive: boolean) {
+    if (isActive) {
+      this.activeFailures.add(identifier);
+      // Only intermittent failures remain armed once they are activated.
+      if (this.armedFailures.get(identifier)?.intermittent === undefined) {
+        this.armedFailures.delete(identifier);
+      }
+    } else {
+      this.activeFailures.delete(identifier);
+    }
+  }
 }
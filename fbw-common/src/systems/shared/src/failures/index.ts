@@ -2,5 +2,5 @@
 // SPDX-License-Identifier: GPL-3.0
 
 export { FailuresConsumer } from './failures-consumer';
-export { FailuresOrchestrator, FailureDefinition } from './failures-orchestrator';
-export type { Failure } from './failures-orchestrator';
+export { FailuresOrchestrator, FailureDefinition, FailureTrigger } from './failures-orchestrator';
+export type { Failure, FailureArming } from './failures-orchestrator';
@@ -5,3 +5,39 @@ export function getActivateFailureSimVarName(prefix: string) {
 export function getDeactivateFailureSimVarName(prefix: string) {
   return `L:${prefix}_FAILURE_DEACTIVATE`;
 }
+
+export function getArmFailureSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM`;
+}
+
+export function getArmFailureTriggerSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_TRIGGER`;
+}
+
+export function getArmFailureTriggerValueSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_TRIGGER_VALUE`;
+}
+
+export function getArmFailureLatencySimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_LATENCY`;
+}
+
+export function getArmFailureIntermittentActiveSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_INTERMITTENT_ACTIVE`;
+}
+
+export function getArmFailureIntermittentInactiveSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_INTERMITTENT_INACTIVE`;
+}
+
+export function getDisarmFailureSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_DISARM`;
+}
+
+export function getTriggeredActivateFailureSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_TRIGGERED_ACTIVATE`;
+}
+
+export function getTriggeredDeactivateFailureSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_TRIGGERED_DEACTIVATE`;
+}
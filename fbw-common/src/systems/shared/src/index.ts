@@ -11,6 +11,7 @@ export * from './Constants';
 export * from './FbwAircraftSentryClient';
 export * from './FmMessages';
 export * from './GenericDataListenerSync';
+export * from './HydraulicPumpWear';
 export * from './MathUtils';
 export * from './PathVector';
 export * from './PilotSeat';
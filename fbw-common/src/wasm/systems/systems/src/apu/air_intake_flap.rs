@@ -1,6 +1,9 @@
 use crate::{
     shared::{random_number, ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
-    simulation::{SimulationElement, UpdateContext},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        SimulationElement, UpdateContext,
+    },
 };
 use std::time::Duration;
 use uom::si::{f64::*, power::watt, ratio::percent};
@@ -107,6 +110,18 @@ impl SimulationElement for AirIntakeFlap {
             consumption.consume_from_bus(self.powered_by, Power::new::<watt>(20.))
         }
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_powered);
+        state.write(self.open_amount);
+        state.write(self.is_moving);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_powered = state.read();
+        self.open_amount = state.read();
+        self.is_moving = state.read();
+    }
 }
 
 #[cfg(test)]
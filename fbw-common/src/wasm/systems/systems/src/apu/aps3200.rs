@@ -7,16 +7,19 @@ use uom::si::{
 
 use crate::{
     electrical::{
-        ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
-        ProvidePotential,
+        generator_control_unit::GeneratorControlUnit, ElectricalElement,
+        ElectricalElementIdentifier, ElectricalElementIdentifierProvider, ElectricalStateWriter,
+        ElectricitySource, Potential, ProvideFrequency, ProvideLoad, ProvidePotential,
     },
     failures::{Failure, FailureType},
     shared::{
         calculate_towards_target_temperature, random_number, ConsumePower, ControllerSignal,
         ElectricalBusType, ElectricalBuses, PotentialOrigin, PowerConsumptionReport,
     },
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext,
+    },
 };
 
 use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
@@ -77,6 +80,15 @@ impl Turbine for ShutdownAps3200Turbine {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Shutdown);
+        state.write(self.egt);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
 }
 
 struct Starting {
@@ -236,6 +248,18 @@ impl Turbine for Starting {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Starting);
+        state.write(self.since);
+        state.write(self.n);
+        state.write(self.egt);
+        state.write(self.ignore_calculated_egt);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
 }
 
 struct BleedAirUsageEgtDelta {
@@ -278,6 +302,22 @@ impl BleedAirUsageEgtDelta {
         TemperatureInterval::new::<temperature_interval::degree_celsius>(self.current)
     }
 
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.current);
+        state.write(self.target);
+        state.write(self.max);
+        state.write(self.min);
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        Self {
+            current: state.read(),
+            target: state.read(),
+            max: state.read(),
+            min: state.read(),
+        }
+    }
+
     fn delta_per_second(&self) -> f64 {
         // Loosely based on bleed on data provided in a video by Komp.
         // The very much relates to pneumatics and thus could be improved further
@@ -340,6 +380,18 @@ impl ApuGenUsageEgtDelta {
             self.time.as_secs_f64() * self.base_egt_delta_per_second,
         )
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.time);
+        state.write(self.base_egt_delta_per_second);
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        Self {
+            time: state.read(),
+            base_egt_delta_per_second: state.read(),
+        }
+    }
 }
 
 struct Running {
@@ -424,6 +476,19 @@ impl Turbine for Running {
         // TODO: Figure out what value this is supposed to be.
         Pressure::new::<psi>(50.)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Running);
+        state.write(self.egt);
+        state.write(self.base_egt);
+        state.write(self.base_egt_deviation);
+        self.bleed_air_usage.save_state(state);
+        self.apu_gen_usage.save_state(state);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
 }
 
 struct Stopping {
@@ -565,6 +630,48 @@ impl Turbine for Stopping {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Stopping);
+        state.write(self.since);
+        state.write(self.base_temperature);
+        state.write(self.n_factor);
+        state.write(self.egt_delta_at_entry);
+        state.write(self.n);
+        state.write(self.egt);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
+}
+
+/// Restores the turbine in the state saved by [`Turbine::save_state`].
+fn restore_turbine(state: &mut StateReader) -> Box<dyn Turbine> {
+    match state.read() {
+        TurbineState::Shutdown => Box::new(ShutdownAps3200Turbine::new_with_egt(state.read())),
+        TurbineState::Starting => Box::new(Starting {
+            since: state.read(),
+            n: state.read(),
+            egt: state.read(),
+            ignore_calculated_egt: state.read(),
+        }),
+        TurbineState::Running => Box::new(Running {
+            egt: state.read(),
+            base_egt: state.read(),
+            base_egt_deviation: state.read(),
+            bleed_air_usage: BleedAirUsageEgtDelta::restored(state),
+            apu_gen_usage: ApuGenUsageEgtDelta::restored(state),
+        }),
+        TurbineState::Stopping => Box::new(Stopping {
+            since: state.read(),
+            base_temperature: state.read(),
+            n_factor: state.read(),
+            egt_delta_at_entry: state.read(),
+            n: state.read(),
+            egt: state.read(),
+        }),
+    }
 }
 
 fn calculate_towards_ambient_egt(
@@ -590,6 +697,7 @@ pub struct Aps3200ApuGenerator {
     output_potential: ElectricPotential,
     load: Ratio,
     is_emergency_shutdown: bool,
+    gcu: GeneratorControlUnit,
     failure: Failure,
 }
 impl Aps3200ApuGenerator {
@@ -605,6 +713,14 @@ impl Aps3200ApuGenerator {
             output_frequency: Frequency::new::<hertz>(0.),
             load: Ratio::new::<percent>(0.),
             is_emergency_shutdown: false,
+            gcu: GeneratorControlUnit::new(
+                context,
+                &format!("APU_GEN_{}", number),
+                110.0..=120.0,
+                390.0..=410.0,
+                Duration::ZERO,
+                FailureType::ApuGeneratorFeeder(number),
+            ),
             failure: Failure::new(FailureType::ApuGenerator(number)),
         }
     }
@@ -653,13 +769,33 @@ impl Aps3200ApuGenerator {
     fn should_provide_output(&self) -> bool {
         !self.failure.is_active()
             && !self.is_emergency_shutdown
+            && self.gcu.excites_generator()
             && self.n.get::<percent>() >= Aps3200ApuGenerator::APU_GEN_POWERED_N
     }
 }
 impl ApuGenerator for Aps3200ApuGenerator {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    ) {
         self.n = n;
         self.is_emergency_shutdown = is_emergency_shutdown;
+
+        let frequency = if n.get::<percent>() >= Aps3200ApuGenerator::APU_GEN_POWERED_N {
+            self.calculate_frequency(n)
+        } else {
+            Frequency::default()
+        };
+        self.gcu.update(
+            context,
+            push_button_is_on,
+            frequency,
+            self.output_potential,
+            self.load,
+        );
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -702,6 +838,7 @@ impl ElectricitySource for Aps3200ApuGenerator {
 }
 impl SimulationElement for Aps3200ApuGenerator {
     fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
         visitor.visit(self);
     }
@@ -736,6 +873,22 @@ impl SimulationElement for Aps3200ApuGenerator {
             (power_consumption * power_factor_correction / maximum_load) * 100.,
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.n);
+        state.write(self.output_frequency);
+        state.write(self.output_potential);
+        state.write(self.load);
+        state.write(self.is_emergency_shutdown);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.n = state.read();
+        self.output_frequency = state.read();
+        self.output_potential = state.read();
+        self.load = state.read();
+        self.is_emergency_shutdown = state.read();
+    }
 }
 
 pub struct Aps3200StartMotor {
@@ -797,6 +950,16 @@ impl SimulationElement for Aps3200StartMotor {
             consumption.consume_from_bus(self.powered_by, Power::new::<watt>(w));
         }
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_powered);
+        state.write(self.powered_since);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_powered = state.read();
+        self.powered_since = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -993,6 +1156,20 @@ mod apu_generator_tests {
         assert!(test_bed.generator_is_unpowered());
     }
 
+    #[test]
+    fn when_gen_push_button_off_provides_no_output() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, false);
+            electricity.supplied_by(generator);
+        });
+        test_bed.run();
+
+        assert!(!test_bed
+            .query_element_elec(|e, elec| { shared::PowerConsumptionReport::is_powered(elec, e) }));
+    }
+
     #[test]
     fn writes_its_state() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
@@ -1005,6 +1182,7 @@ mod apu_generator_tests {
         assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_FREQUENCY_NORMAL"));
         assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_LOAD"));
         assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_LOAD_NORMAL"));
+        assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_GCU_FAULT_WORD"));
     }
 
     fn apu_generator(context: &mut InitContext) -> Aps3200ApuGenerator {
@@ -1012,16 +1190,16 @@ mod apu_generator_tests {
     }
 
     fn update_above_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Aps3200ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(100.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
     }
 
     fn update_below_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Aps3200ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(0.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(0.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
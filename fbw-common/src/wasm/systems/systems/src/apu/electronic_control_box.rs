@@ -7,7 +7,10 @@ use uom::si::{
 };
 
 use crate::shared::{EngineCorrectedN1, LgciuWeightOnWheels};
-use crate::simulation::{InitContext, SimulatorReader, VariableIdentifier};
+use crate::simulation::{
+    snapshot::{StateReader, StateValue, StateWriter},
+    InitContext, SimulatorReader, VariableIdentifier,
+};
 use crate::{
     pneumatic::PneumaticValveSignal,
     shared::{
@@ -541,6 +544,58 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
             consumption.consume_from_bus(self.powered_by, Power::new::<watt>(105.))
         }
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_powered);
+        state.write(self.turbine_state);
+        state.write(self.master_is_on);
+        state.write(self.master_off_for);
+        state.write(self.start_is_on);
+        state.write(self.start_motor_is_powered);
+        state.write(self.n);
+        state.write(self.n2);
+        state.write(self.bleed_is_on);
+        state.write(self.bleed_air_valve_last_open_time_ago);
+        state.write(self.bleed_air_pressure);
+        state.write(self.fault);
+        state.write(self.fuel_flow);
+        state.write(self.fuel_used);
+        state.write(self.air_intake_flap_open_amount);
+        state.write(self.egt);
+        state.write(self.egt_warning_temperature);
+        state.write(self.n_above_95_duration);
+        state.write(self.fire_button_is_released);
+        state.write(self.engines_on);
+        state.write(self.on_ground);
+        state.write(self.inlet_pressure);
+        state.write(self.aircraft_preset_quick_mode);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_powered = state.read();
+        self.turbine_state = state.read();
+        self.master_is_on = state.read();
+        self.master_off_for = state.read();
+        self.start_is_on = state.read();
+        self.start_motor_is_powered = state.read();
+        self.n = state.read();
+        self.n2 = state.read();
+        self.bleed_is_on = state.read();
+        self.bleed_air_valve_last_open_time_ago = state.read();
+        self.bleed_air_pressure = state.read();
+        self.fault = state.read();
+        self.fuel_flow = state.read();
+        self.fuel_used = state.read();
+        self.air_intake_flap_open_amount = state.read();
+        self.egt = state.read();
+        self.egt_warning_temperature = state.read();
+        self.n_above_95_duration = state.read();
+        self.fire_button_is_released = state.read();
+        self.engines_on = state.read();
+        self.on_ground = state.read();
+        self.inlet_pressure = state.read();
+        self.aircraft_preset_quick_mode = state.read();
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -549,3 +604,22 @@ enum ApuFault {
     FuelLowPressure,
     DcPowerLoss,
 }
+impl StateValue for Option<ApuFault> {
+    fn to_state(self) -> f64 {
+        match self {
+            None => 0.,
+            Some(ApuFault::ApuFire) => 1.,
+            Some(ApuFault::FuelLowPressure) => 2.,
+            Some(ApuFault::DcPowerLoss) => 3.,
+        }
+    }
+
+    fn from_state(value: f64) -> Self {
+        match value as u8 {
+            1 => Some(ApuFault::ApuFire),
+            2 => Some(ApuFault::FuelLowPressure),
+            3 => Some(ApuFault::DcPowerLoss),
+            _ => None,
+        }
+    }
+}
@@ -3,7 +3,10 @@ use self::{
     electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
 };
 use crate::{
-    electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    electrical::{
+        ApuGeneratorPushButtons, ElectricalElement, ElectricitySource, ProvideFrequency,
+        ProvidePotential,
+    },
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
@@ -12,6 +15,7 @@ use crate::{
         LgciuWeightOnWheels,
     },
     simulation::{
+        snapshot::{StateReader, StateValue, StateWriter},
         SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
     },
 };
@@ -142,6 +146,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
         apu_bleed_is_on: bool,
         apu_gen_is_used: bool,
+        generator_buttons: &impl ApuGeneratorPushButtons,
         bleed_air_valve: &mut impl ControllablePneumaticValve,
         has_fuel_remaining: bool,
     ) {
@@ -171,8 +176,13 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         }
 
         let emergency_shutdown = self.is_emergency_shutdown();
-        for gen in &mut self.generators {
-            gen.update(self.ecb.n(), emergency_shutdown);
+        for (index, gen) in self.generators.iter_mut().enumerate() {
+            gen.update(
+                context,
+                self.ecb.n(),
+                emergency_shutdown,
+                generator_buttons.apu_gen_push_button_is_on(index + 1),
+            );
         }
     }
 
@@ -277,6 +287,22 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
             self.air_intake_flap.open_amount(),
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        if let Some(turbine) = &self.turbine {
+            turbine.save_state(state);
+        }
+
+        state.write(self.fuel_pressure_switch.has_fuel_remaining);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        if let Some(turbine) = self.turbine.take() {
+            self.turbine = Some(turbine.restore_state(state));
+        }
+
+        self.fuel_pressure_switch.has_fuel_remaining = state.read();
+    }
 }
 
 pub trait Turbine {
@@ -294,20 +320,52 @@ pub trait Turbine {
     fn egt(&self) -> ThermodynamicTemperature;
     fn state(&self) -> TurbineState;
     fn bleed_air_pressure(&self) -> Pressure;
+
+    /// Saves the state of the turbine, starting with the [`TurbineState`] it is in.
+    fn save_state(&self, state: &mut StateWriter);
+
+    /// Returns the turbine in the state written by [`Turbine::save_state`], which
+    /// isn't necessarily the state the turbine is currently in.
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine>;
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TurbineState {
     Shutdown,
     Starting,
     Running,
     Stopping,
 }
+impl StateValue for TurbineState {
+    fn to_state(self) -> f64 {
+        match self {
+            TurbineState::Shutdown => 0.,
+            TurbineState::Starting => 1.,
+            TurbineState::Running => 2.,
+            TurbineState::Stopping => 3.,
+        }
+    }
+
+    fn from_state(value: f64) -> Self {
+        match value as u8 {
+            1 => TurbineState::Starting,
+            2 => TurbineState::Running,
+            3 => TurbineState::Stopping,
+            _ => TurbineState::Shutdown,
+        }
+    }
+}
 
 pub trait ApuGenerator:
     SimulationElement + ProvidePotential + ProvideFrequency + ElectricalElement + ElectricitySource
 {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool);
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    );
     fn output_within_normal_parameters(&self) -> bool;
 }
 
@@ -475,6 +533,12 @@ mod tests {
         fn bleed_air_pressure(&self) -> Pressure {
             Pressure::new::<psi>(42.)
         }
+
+        fn save_state(&self, _: &mut StateWriter) {}
+
+        fn restore_state(self: Box<Self>, _: &mut StateReader) -> Box<dyn Turbine> {
+            self
+        }
     }
 
     struct TestPneumatic {
@@ -511,6 +575,13 @@ mod tests {
         }
     }
 
+    struct TestApuGeneratorPushButtons;
+    impl ApuGeneratorPushButtons for TestApuGeneratorPushButtons {
+        fn apu_gen_push_button_is_on(&self, _: usize) -> bool {
+            true
+        }
+    }
+
     struct TestLgciu {
         compressed: bool,
     }
@@ -731,6 +802,7 @@ mod tests {
                 &self.apu_fire_overhead,
                 self.apu_bleed.is_on(),
                 self.apu_gen_is_used,
+                &TestApuGeneratorPushButtons,
                 self.pneumatic.bleed_air_valve(),
                 self.has_fuel_remaining,
             );
@@ -3408,5 +3480,47 @@ mod tests {
                     < 1.
             );
         }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn snapshot_of_starting_apu_restores_all_written_variables<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.starting_apu().run(Duration::from_secs(10));
+
+            let differing =
+                test_bed.variables_differing_after_snapshot_round_trip(Duration::from_millis(500));
+
+            assert!(differing.is_empty(), "{:?}", differing);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn snapshot_of_running_apu_restores_all_written_variables<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_with_bleed_air()
+                .and()
+                .power_demand(Power::new::<watt>(50000.))
+                .run(Duration::from_secs(10));
+
+            let differing =
+                test_bed.variables_differing_after_snapshot_round_trip(Duration::from_millis(500));
+
+            assert!(differing.is_empty(), "{:?}", differing);
+        }
     }
 }
@@ -10,9 +10,9 @@ use uom::{
 
 use crate::{
     electrical::{
-        ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
-        ProvidePotential,
+        generator_control_unit::GeneratorControlUnit, ElectricalElement,
+        ElectricalElementIdentifier, ElectricalElementIdentifierProvider, ElectricalStateWriter,
+        ElectricitySource, Potential, ProvideFrequency, ProvideLoad, ProvidePotential,
     },
     failures::{Failure, FailureType},
     shared::{
@@ -20,7 +20,10 @@ use crate::{
         ElectricalBusType, ElectricalBuses, InternationalStandardAtmosphere, PotentialOrigin,
         PowerConsumptionReport,
     },
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext,
+    },
 };
 
 use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
@@ -85,6 +88,15 @@ impl Turbine for ShutdownPw980Turbine {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Shutdown);
+        state.write(self.egt);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
 }
 
 struct Starting {
@@ -328,6 +340,19 @@ impl Turbine for Starting {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Starting);
+        state.write(self.since);
+        state.write(self.n);
+        state.write(self.n2);
+        state.write(self.egt);
+        state.write(self.ignore_calculated_egt);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
 }
 
 struct BleedAirUsageEgtDelta {
@@ -370,6 +395,22 @@ impl BleedAirUsageEgtDelta {
         TemperatureInterval::new::<temperature_interval::degree_celsius>(self.current)
     }
 
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.current);
+        state.write(self.target);
+        state.write(self.max);
+        state.write(self.min);
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        Self {
+            current: state.read(),
+            target: state.read(),
+            max: state.read(),
+            min: state.read(),
+        }
+    }
+
     fn delta_per_second(&self) -> f64 {
         // Fixme: This curve has not been changed from APS3200. It can be improved in the future based on references.
         const BLEED_AIR_DELTA_TEMP_CONST: f64 = 0.46763348242588143;
@@ -430,6 +471,18 @@ impl ApuGenUsageEgtDelta {
             self.time.as_secs_f64() * self.base_egt_delta_per_second,
         )
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.time);
+        state.write(self.base_egt_delta_per_second);
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        Self {
+            time: state.read(),
+            base_egt_delta_per_second: state.read(),
+        }
+    }
 }
 
 struct ApuBleedUsageN2Delta {
@@ -463,6 +516,18 @@ impl ApuBleedUsageN2Delta {
     fn n2_delta(&self) -> Ratio {
         Ratio::new::<percent>(self.time.as_secs_f64() * self.base_n2_delta_per_second)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.time);
+        state.write(self.base_n2_delta_per_second);
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        Self {
+            time: state.read(),
+            base_n2_delta_per_second: state.read(),
+        }
+    }
 }
 
 struct Running {
@@ -567,6 +632,21 @@ impl Turbine for Running {
         Pressure::new::<psi>(22.)
             + InternationalStandardAtmosphere::pressure_at_altitude(Length::ZERO)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Running);
+        state.write(self.egt);
+        state.write(self.base_egt);
+        state.write(self.base_egt_deviation);
+        self.bleed_air_usage.save_state(state);
+        self.apu_gen_usage.save_state(state);
+        state.write(self.n2);
+        self.bleed_air_n2_delta.save_state(state);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
 }
 
 struct Stopping {
@@ -788,6 +868,55 @@ impl Turbine for Stopping {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(TurbineState::Stopping);
+        state.write(self.since);
+        state.write(self.base_temperature);
+        state.write(self.n_factor);
+        state.write(self.n2_factor);
+        state.write(self.egt_delta_at_entry);
+        state.write(self.n);
+        state.write(self.n2);
+        state.write(self.egt);
+    }
+
+    fn restore_state(self: Box<Self>, state: &mut StateReader) -> Box<dyn Turbine> {
+        restore_turbine(state)
+    }
+}
+
+/// Restores the turbine in the state saved by [`Turbine::save_state`].
+fn restore_turbine(state: &mut StateReader) -> Box<dyn Turbine> {
+    match state.read() {
+        TurbineState::Shutdown => Box::new(ShutdownPw980Turbine::new_with_egt(state.read())),
+        TurbineState::Starting => Box::new(Starting {
+            since: state.read(),
+            n: state.read(),
+            n2: state.read(),
+            egt: state.read(),
+            ignore_calculated_egt: state.read(),
+        }),
+        TurbineState::Running => Box::new(Running {
+            egt: state.read(),
+            base_egt: state.read(),
+            base_egt_deviation: state.read(),
+            bleed_air_usage: BleedAirUsageEgtDelta::restored(state),
+            apu_gen_usage: ApuGenUsageEgtDelta::restored(state),
+            n2: state.read(),
+            bleed_air_n2_delta: ApuBleedUsageN2Delta::restored(state),
+        }),
+        TurbineState::Stopping => Box::new(Stopping {
+            since: state.read(),
+            base_temperature: state.read(),
+            n_factor: state.read(),
+            n2_factor: state.read(),
+            egt_delta_at_entry: state.read(),
+            n: state.read(),
+            n2: state.read(),
+            egt: state.read(),
+        }),
+    }
 }
 
 fn calculate_towards_ambient_egt(
@@ -813,6 +942,7 @@ pub struct Pw980ApuGenerator {
     output_potential: ElectricPotential,
     load: Ratio,
     is_emergency_shutdown: bool,
+    gcu: GeneratorControlUnit,
     failure: Failure,
 }
 impl Pw980ApuGenerator {
@@ -828,6 +958,14 @@ impl Pw980ApuGenerator {
             output_frequency: Frequency::default(),
             load: Ratio::default(),
             is_emergency_shutdown: false,
+            gcu: GeneratorControlUnit::new(
+                context,
+                &format!("APU_GEN_{}", number),
+                110.0..=120.0,
+                390.0..=410.0,
+                Duration::ZERO,
+                FailureType::ApuGeneratorFeeder(number),
+            ),
             failure: Failure::new(FailureType::ApuGenerator(number)),
         }
     }
@@ -875,13 +1013,33 @@ impl Pw980ApuGenerator {
     fn should_provide_output(&self) -> bool {
         !self.failure.is_active()
             && !self.is_emergency_shutdown
+            && self.gcu.excites_generator()
             && self.n.get::<percent>() >= Pw980ApuGenerator::APU_GEN_POWERED_N
     }
 }
 impl ApuGenerator for Pw980ApuGenerator {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    ) {
         self.n = n;
         self.is_emergency_shutdown = is_emergency_shutdown;
+
+        let frequency = if n.get::<percent>() >= Pw980ApuGenerator::APU_GEN_POWERED_N {
+            self.calculate_frequency(n)
+        } else {
+            Frequency::default()
+        };
+        self.gcu.update(
+            context,
+            push_button_is_on,
+            frequency,
+            self.output_potential,
+            self.load,
+        );
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -924,6 +1082,7 @@ impl ElectricitySource for Pw980ApuGenerator {
 }
 impl SimulationElement for Pw980ApuGenerator {
     fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
         visitor.visit(self);
     }
@@ -958,6 +1117,22 @@ impl SimulationElement for Pw980ApuGenerator {
             (power_consumption * power_factor_correction / maximum_load) * 100.,
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.n);
+        state.write(self.output_frequency);
+        state.write(self.output_potential);
+        state.write(self.load);
+        state.write(self.is_emergency_shutdown);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.n = state.read();
+        self.output_frequency = state.read();
+        self.output_potential = state.read();
+        self.load = state.read();
+        self.is_emergency_shutdown = state.read();
+    }
 }
 
 pub struct Pw980StartMotor {
@@ -1019,6 +1194,16 @@ impl SimulationElement for Pw980StartMotor {
             consumption.consume_from_bus(self.powered_by, Power::new::<watt>(w));
         }
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_powered);
+        state.write(self.powered_since);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_powered = state.read();
+        self.powered_since = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -1215,6 +1400,20 @@ mod apu_generator_tests {
         assert!(test_bed.generator_is_unpowered());
     }
 
+    #[test]
+    fn when_gen_push_button_off_provides_no_output() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
+
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, false);
+            electricity.supplied_by(generator);
+        });
+        test_bed.run();
+
+        assert!(!test_bed
+            .query_element_elec(|e, elec| { shared::PowerConsumptionReport::is_powered(elec, e) }));
+    }
+
     #[test]
     fn writes_its_state() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(apu_generator));
@@ -1227,6 +1426,7 @@ mod apu_generator_tests {
         assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_FREQUENCY_NORMAL"));
         assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_LOAD"));
         assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_LOAD_NORMAL"));
+        assert!(test_bed.contains_variable_with_name("ELEC_APU_GEN_1_GCU_FAULT_WORD"));
     }
 
     fn apu_generator(context: &mut InitContext) -> Pw980ApuGenerator {
@@ -1234,16 +1434,16 @@ mod apu_generator_tests {
     }
 
     fn update_above_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Pw980ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(100.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
     }
 
     fn update_below_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Pw980ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::default(), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::default(), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
@@ -1,31 +1,61 @@
 use uom::si::{
     electric_charge::ampere_hour, electric_current::ampere, electric_potential::volt,
-    electrical_resistance::ohm, f64::*, time::second,
+    electrical_resistance::ohm, f64::*, ratio::ratio, thermodynamic_temperature::degree_celsius,
+    time::second,
 };
 
 use crate::{
     shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
 
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
     ElectricalStateWriter, ElectricitySource, Potential, PotentialOrigin, ProvideCurrent,
-    ProvidePotential,
+    ProvidePotential, ProvideTemperature,
 };
 
 pub struct Battery {
     number: usize,
     identifier: ElectricalElementIdentifier,
     writer: ElectricalStateWriter,
+    temperature_id: VariableIdentifier,
+    state_of_health_id: VariableIdentifier,
     charge: ElectricCharge,
     input_potential: ElectricPotential,
     output_potential: ElectricPotential,
     current: ElectricCurrent,
+    temperature: Option<ThermodynamicTemperature>,
+    state_of_health: Ratio,
+    is_deeply_discharged: bool,
 }
 impl Battery {
     const RATED_CAPACITY_AMPERE_HOURS: f64 = 23.;
 
+    // Roughly 25 kg of nickel-cadmium cells and their case.
+    const THERMAL_MASS_JOULE_PER_KELVIN: f64 = 25_000.;
+    const HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 3.5;
+    const INTERNAL_RESISTANCE_OHM: f64 = 0.011;
+    // Above this temperature an increasing part of the charging energy is converted into heat
+    // instead of being stored, until all of it is at the upper temperature. As the battery's
+    // potential also decreases with temperature, the charging current is sustained and a hot
+    // battery being charged can enter a thermal runaway.
+    const CHARGE_EFFICIENCY_DECREASE_START_CELSIUS: f64 = 40.;
+    const CHARGE_EFFICIENCY_DECREASE_END_CELSIUS: f64 = 70.;
+    const POTENTIAL_DECREASE_VOLT_PER_KELVIN: f64 = 0.05;
+    // Below this temperature the capacity which can be discharged decreases.
+    const CAPACITY_REDUCTION_START_CELSIUS: f64 = 0.;
+    const CAPACITY_REDUCTION_PER_KELVIN: f64 = 0.015;
+    const MINIMUM_TEMPERATURE_CAPACITY_FACTOR: f64 = 0.3;
+    // Discharging below this charge counts as a deep discharge, which reduces the battery's
+    // state of health. The battery needs to be recharged to count another deep discharge.
+    const DEEP_DISCHARGE_BELOW_AMPERE_HOURS: f64 = 2.3;
+    const DEEP_DISCHARGE_STATE_OF_HEALTH_LOSS: f64 = 0.01;
+    const MINIMUM_STATE_OF_HEALTH: f64 = 0.5;
+
     pub fn full(context: &mut InitContext, number: usize) -> Battery {
         Battery::new(
             context,
@@ -51,15 +81,103 @@ impl Battery {
             number,
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, &format!("BAT_{}", number)),
+            temperature_id: context.get_identifier(format!("ELEC_BAT_{}_TEMPERATURE", number)),
+            state_of_health_id: context
+                .get_identifier(format!("ELEC_BAT_{}_STATE_OF_HEALTH", number)),
             charge,
             input_potential: ElectricPotential::new::<volt>(0.),
             output_potential: Battery::calculate_output_potential_for_charge(charge),
             current: ElectricCurrent::new::<ampere>(0.),
+            temperature: None,
+            state_of_health: Ratio::new::<ratio>(1.),
+            is_deeply_discharged: Battery::is_deep_discharge(charge),
         }
     }
 
     pub fn needs_charging(&self) -> bool {
-        self.charge <= ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS - 3.)
+        Battery::needs_charging_at(self.charge)
+    }
+
+    fn needs_charging_at(charge: ElectricCharge) -> bool {
+        charge <= ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS - 3.)
+    }
+
+    fn is_deep_discharge(charge: ElectricCharge) -> bool {
+        charge < ElectricCharge::new::<ampere_hour>(Battery::DEEP_DISCHARGE_BELOW_AMPERE_HOURS)
+    }
+
+    pub fn state_of_health(&self) -> Ratio {
+        self.state_of_health
+    }
+
+    /// The part of the stored charge which can be discharged, given the battery's
+    /// temperature and state of health.
+    fn capacity_factor(&self) -> f64 {
+        let temperature_factor = (1.
+            - Battery::CAPACITY_REDUCTION_PER_KELVIN
+                * (Battery::CAPACITY_REDUCTION_START_CELSIUS
+                    - self.temperature().get::<degree_celsius>())
+                .max(0.))
+        .max(Battery::MINIMUM_TEMPERATURE_CAPACITY_FACTOR);
+
+        temperature_factor * self.state_of_health.get::<ratio>()
+    }
+
+    /// The part of the charging energy which is converted into heat instead of being stored.
+    fn charge_inefficiency(&self) -> f64 {
+        ((self.temperature().get::<degree_celsius>()
+            - Battery::CHARGE_EFFICIENCY_DECREASE_START_CELSIUS)
+            / (Battery::CHARGE_EFFICIENCY_DECREASE_END_CELSIUS
+                - Battery::CHARGE_EFFICIENCY_DECREASE_START_CELSIUS))
+            .clamp(0., 1.)
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        // The avionics bay isn't simulated, its temperature is assumed to follow the
+        // ambient temperature. This makes a cold soaked aircraft have a cold battery.
+        let surrounding_temperature = context.ambient_temperature().get::<degree_celsius>();
+        let temperature = self
+            .temperature
+            .map_or(surrounding_temperature, |t| t.get::<degree_celsius>());
+
+        let current = self.current.get::<ampere>();
+        let mut heat = current.powi(2) * Battery::INTERNAL_RESISTANCE_OHM;
+        if current > 0. {
+            heat += self.input_potential.get::<volt>() * current * self.charge_inefficiency();
+        }
+        let heat_loss =
+            Battery::HEAT_TRANSFER_WATT_PER_KELVIN * (temperature - surrounding_temperature);
+
+        self.temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(
+            temperature
+                + (heat - heat_loss) * context.delta_as_secs_f64()
+                    / Battery::THERMAL_MASS_JOULE_PER_KELVIN,
+        ));
+    }
+
+    fn update_state_of_health(&mut self) {
+        if !self.is_deeply_discharged && Battery::is_deep_discharge(self.charge) {
+            self.is_deeply_discharged = true;
+            self.state_of_health = (self.state_of_health
+                - Ratio::new::<ratio>(Battery::DEEP_DISCHARGE_STATE_OF_HEALTH_LOSS))
+            .max(Ratio::new::<ratio>(Battery::MINIMUM_STATE_OF_HEALTH));
+        } else if self.is_deeply_discharged && !Battery::needs_charging_at(self.charge) {
+            self.is_deeply_discharged = false;
+        }
+    }
+
+    fn calculate_output_potential(&self) -> ElectricPotential {
+        let potential = Battery::calculate_output_potential_for_charge(self.charge);
+        if potential > ElectricPotential::new::<volt>(0.) {
+            let hot_decrease = Battery::POTENTIAL_DECREASE_VOLT_PER_KELVIN
+                * (self.temperature().get::<degree_celsius>()
+                    - Battery::CHARGE_EFFICIENCY_DECREASE_START_CELSIUS)
+                    .max(0.);
+
+            potential - ElectricPotential::new::<volt>(hot_decrease)
+        } else {
+            potential
+        }
     }
 
     fn is_powered_by_other_potential(&self) -> bool {
@@ -75,7 +193,7 @@ impl Battery {
     fn set_charge(&mut self, charge: ElectricCharge) {
         self.charge = charge;
         self.input_potential = ElectricPotential::new::<volt>(0.);
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.output_potential = self.calculate_output_potential();
     }
 
     #[cfg(test)]
@@ -174,10 +292,23 @@ impl ElectricitySource for Battery {
             Potential::none()
         }
     }
+
+    fn internal_resistance(&self) -> ElectricalResistance {
+        ElectricalResistance::new::<ohm>(Battery::INTERNAL_RESISTANCE_OHM)
+    }
+}
+impl ProvideTemperature for Battery {
+    fn temperature(&self) -> ThermodynamicTemperature {
+        // Until the first update the battery is assumed to be at standard temperature.
+        self.temperature
+            .unwrap_or_else(|| ThermodynamicTemperature::new::<degree_celsius>(15.))
+    }
 }
 impl SimulationElement for Battery {
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_direct(self, writer);
+        writer.write(&self.temperature_id, self.temperature());
+        writer.write(&self.state_of_health_id, self.state_of_health);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
@@ -191,7 +322,9 @@ impl SimulationElement for Battery {
             consumption.consume_from_input(self, power);
 
             let time = Time::new::<second>(context.delta_as_secs_f64());
-            self.charge += ((self.input_potential * self.current) * time) / self.input_potential;
+            self.charge += ((self.input_potential * self.current) * time)
+                * (1. - self.charge_inefficiency())
+                / self.input_potential;
         }
     }
 
@@ -211,11 +344,30 @@ impl SimulationElement for Battery {
 
             if self.output_potential > ElectricPotential::new::<volt>(0.) {
                 let time = Time::new::<second>(context.delta_as_secs_f64());
-                self.charge -= ((consumption * time) / self.output_potential).min(self.charge);
+                self.charge -=
+                    ((consumption * time) / self.output_potential / self.capacity_factor())
+                        .min(self.charge);
             }
         }
 
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.update_temperature(context);
+        self.update_state_of_health();
+        self.output_potential = self.calculate_output_potential();
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.charge);
+        state.write(self.temperature());
+        state.write(self.state_of_health);
+        state.write(self.is_deeply_discharged);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.charge = state.read();
+        self.temperature = Some(state.read());
+        self.state_of_health = state.read();
+        self.is_deeply_discharged = state.read();
+        self.output_potential = self.calculate_output_potential();
     }
 }
 
@@ -332,6 +484,28 @@ mod tests {
             fn potential(&mut self, number: usize) -> ElectricPotential {
                 self.read_by_name(&format!("ELEC_BAT_{}_POTENTIAL", number))
             }
+
+            fn temperature(&mut self, number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE", number))
+            }
+
+            fn state_of_health(&mut self, number: usize) -> Ratio {
+                self.read_by_name(&format!("ELEC_BAT_{}_STATE_OF_HEALTH", number))
+            }
+
+            fn deeply_discharge_battery_1(&mut self) {
+                self.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(0.)));
+                self.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+                self.run_with_delta(Duration::from_secs(1_000));
+            }
+
+            fn recharge_battery_1(&mut self) {
+                self.command(|a| a.power_demand(Power::new::<watt>(0.)));
+                self.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(28.)));
+                for _ in 0..100 {
+                    self.run_with_delta(Duration::from_secs(120));
+                }
+            }
         }
         impl TestBed for BatteryTestBed {
             type Aircraft = TestAircraft;
@@ -396,6 +570,10 @@ mod tests {
                 self.battery_2.charge()
             }
 
+            fn set_battery_1_full_charge(&mut self) {
+                self.battery_1.set_full_charge();
+            }
+
             fn bat_bus_is_powered(&self, electricity: &Electricity) -> bool {
                 electricity.is_powered(&self.bat_bus)
             }
@@ -509,6 +687,26 @@ mod tests {
             assert!(input_potential < test_bed.potential(1));
         }
 
+        #[test]
+        fn restoring_snapshot_restores_charge() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.run();
+            let snapshot = test_bed.save_snapshot();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            test_bed.run_with_delta(Duration::from_secs(600));
+            assert!(
+                test_bed.query(|a| a.battery_1_charge()) < ElectricCharge::new::<ampere_hour>(11.5)
+            );
+
+            assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+
+            assert_eq!(
+                test_bed.query(|a| a.battery_1_charge()),
+                ElectricCharge::new::<ampere_hour>(11.5)
+            );
+        }
+
         #[test]
         fn when_charging_current_is_normal() {
             let mut test_bed = BatteryTestBed::with_empty_batteries();
@@ -668,6 +866,146 @@ mod tests {
             assert!(!test_bed.query_elec(|a, elec| a.bat_bus_is_powered(elec)));
         }
 
+        #[test]
+        fn writes_its_thermal_state() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.run();
+
+            assert!(test_bed.contains_variable_with_name("ELEC_BAT_1_TEMPERATURE"));
+            assert!(test_bed.contains_variable_with_name("ELEC_BAT_1_STATE_OF_HEALTH"));
+        }
+
+        #[test]
+        fn starts_at_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+
+            test_bed.run();
+
+            assert!(
+                (test_bed.temperature(1).get::<degree_celsius>() - -20.).abs() < 0.1,
+                "Battery temperature should start at the ambient temperature."
+            );
+        }
+
+        #[test]
+        fn warms_up_towards_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+            test_bed.run();
+
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.));
+            test_bed.run_with_delta(Duration::from_secs(600));
+
+            let temperature = test_bed.temperature(1).get::<degree_celsius>();
+            assert!(temperature > -20. && temperature < 20.);
+        }
+
+        #[test]
+        fn heats_up_when_discharging_at_a_high_current() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            test_bed.run_with_delta(Duration::from_secs(60));
+
+            assert!(test_bed.temperature(1) > ThermodynamicTemperature::new::<degree_celsius>(0.));
+        }
+
+        #[test]
+        fn cold_battery_has_reduced_capacity() {
+            let mut warm_test_bed = BatteryTestBed::with_full_batteries();
+            warm_test_bed.command(|a| a.power_demand(Power::new::<watt>(140.)));
+            warm_test_bed.run_with_delta(Duration::from_secs(600));
+
+            let mut cold_test_bed = BatteryTestBed::with_full_batteries();
+            cold_test_bed
+                .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.));
+            cold_test_bed.command(|a| a.power_demand(Power::new::<watt>(140.)));
+            cold_test_bed.run_with_delta(Duration::from_secs(600));
+
+            assert!(
+                cold_test_bed.query(|a| a.battery_1_charge())
+                    < warm_test_bed.query(|a| a.battery_1_charge())
+            );
+        }
+
+        #[test]
+        fn hot_battery_has_lower_potential() {
+            let mut normal_test_bed = BatteryTestBed::with_full_batteries();
+            normal_test_bed.run();
+
+            let mut hot_test_bed = BatteryTestBed::with_full_batteries();
+            hot_test_bed
+                .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(60.));
+            hot_test_bed.run();
+
+            assert!(hot_test_bed.potential(1) < normal_test_bed.potential(1));
+        }
+
+        #[test]
+        fn new_battery_is_in_full_health() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.run();
+
+            assert_eq!(test_bed.state_of_health(1), Ratio::new::<ratio>(1.));
+        }
+
+        #[test]
+        fn deep_discharge_reduces_state_of_health() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.deeply_discharge_battery_1();
+
+            assert!(test_bed.state_of_health(1) < Ratio::new::<ratio>(1.));
+        }
+
+        #[test]
+        fn remaining_deeply_discharged_reduces_state_of_health_only_once() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.deeply_discharge_battery_1();
+            let state_of_health = test_bed.state_of_health(1);
+
+            test_bed.run_with_delta(Duration::from_secs(1_000));
+
+            assert_eq!(test_bed.state_of_health(1), state_of_health);
+        }
+
+        #[test]
+        fn every_deep_discharge_after_recharging_reduces_state_of_health() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.deeply_discharge_battery_1();
+            let state_of_health = test_bed.state_of_health(1);
+
+            test_bed.recharge_battery_1();
+            test_bed.deeply_discharge_battery_1();
+
+            assert!(test_bed.state_of_health(1) < state_of_health);
+        }
+
+        #[test]
+        fn degraded_battery_has_reduced_capacity() {
+            let mut new_test_bed = BatteryTestBed::with_full_batteries();
+            new_test_bed.command(|a| a.power_demand(Power::new::<watt>(140.)));
+            new_test_bed.run_with_delta(Duration::from_secs(600));
+
+            let mut degraded_test_bed = BatteryTestBed::with_full_batteries();
+            degraded_test_bed.deeply_discharge_battery_1();
+            degraded_test_bed.recharge_battery_1();
+            degraded_test_bed.command(|a| a.set_battery_1_full_charge());
+            degraded_test_bed.command(|a| a.supply_input_potential(ElectricPotential::default()));
+            degraded_test_bed.command(|a| a.power_demand(Power::new::<watt>(140.)));
+            degraded_test_bed.run_with_delta(Duration::from_secs(600));
+
+            assert!(
+                degraded_test_bed.query(|a| a.battery_1_charge())
+                    < new_test_bed.query(|a| a.battery_1_charge())
+            );
+        }
+
         #[test]
         fn batteries_charge_each_other_until_relatively_equal_charge() {
             let mut test_bed = BatteryTestBed::with_full_and_empty_battery();
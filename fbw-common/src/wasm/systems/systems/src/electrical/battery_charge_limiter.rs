@@ -1,15 +1,21 @@
 use super::{
     AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential,
+    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential, ProvideTemperature,
 };
 use crate::shared::AdirsDiscreteOutputs;
-use crate::simulation::{InitContext, VariableIdentifier};
+use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
+    InitContext, VariableIdentifier,
+};
 use crate::{
     shared::{ApuAvailable, ApuMaster, ApuStart, DelayedTrueLogicGate, LgciuWeightOnWheels},
     simulation::{SimulationElement, SimulatorWriter, UpdateContext, Write},
 };
 use std::time::Duration;
-use uom::si::{electric_current::ampere, electric_potential::volt, f64::*};
+use uom::si::{
+    electric_current::ampere, electric_potential::volt, f64::*,
+    thermodynamic_temperature::degree_celsius,
+};
 
 enum State {
     Off(Off),
@@ -32,7 +38,7 @@ impl State {
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -77,6 +83,63 @@ impl State {
     fn should_close_contactor(&self) -> bool {
         matches!(self, State::Closed(_))
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        match self {
+            State::Off(off) => {
+                state.write(0.);
+                off.bcl_startup_delay.save_state(state);
+            }
+            State::Open(open) => {
+                state.write(1.);
+                open.begin_charging_cycle_delay.save_state(state);
+                state.write(open.open_due_to_discharge_protection);
+                state.write(open.open_due_to_exceeding_emergency_elec_closing_time_allowance);
+                state.write(open.open_due_to_thermal_runaway);
+            }
+            State::Closed(closed) => {
+                state.write(2.);
+                state.write(closed.below_4_ampere_charging_duration);
+                state.write(closed.below_23_volt_duration);
+                state.write(closed.apu_master_sw_pb_on_duration);
+                state.write(closed.had_apu_start);
+                state.write(closed.entered_in_emergency_elec);
+                state.write(closed.thermal_runaway_detected);
+            }
+        }
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        match state.read::<f64>() as u8 {
+            0 => {
+                let mut off = Off::new();
+                off.bcl_startup_delay.restore_state(state);
+                State::Off(off)
+            }
+            2 => {
+                let mut closed = Closed::new(false);
+                closed.below_4_ampere_charging_duration = state.read();
+                closed.below_23_volt_duration = state.read();
+                closed.apu_master_sw_pb_on_duration = state.read();
+                closed.had_apu_start = state.read();
+                closed.entered_in_emergency_elec = state.read();
+                closed.thermal_runaway_detected = state.read();
+                State::Closed(closed)
+            }
+            _ => {
+                let mut open = Open::for_initial_bcl_state();
+                open.begin_charging_cycle_delay.restore_state(state);
+                open.open_due_to_discharge_protection = state.read();
+                open.open_due_to_exceeding_emergency_elec_closing_time_allowance = state.read();
+                open.open_due_to_thermal_runaway = state.read();
+                State::Open(open)
+            }
+        }
+    }
+
+    fn has_thermal_runaway(&self) -> bool {
+        matches!(self, State::Open(open) if open.open_due_to_thermal_runaway)
+    }
 }
 
 pub struct BatteryChargeLimiter {
@@ -104,7 +167,7 @@ impl BatteryChargeLimiter {
         electricity: &Electricity,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -137,6 +200,12 @@ impl BatteryChargeLimiter {
     pub fn should_close_contactor(&self) -> bool {
         self.observer.as_ref().unwrap().should_close_contactor()
     }
+
+    /// Indicates the battery contactor was opened due to a thermal runaway of the battery.
+    /// The contactor remains open until the BAT push button is switched off.
+    pub fn has_thermal_runaway(&self) -> bool {
+        self.observer.as_ref().unwrap().has_thermal_runaway()
+    }
 }
 impl SimulationElement for BatteryChargeLimiter {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -145,6 +214,16 @@ impl SimulationElement for BatteryChargeLimiter {
             self.arrow.should_show_when_contactor_closed(),
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.arrow.save_state(state);
+        self.observer.as_ref().unwrap().save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.arrow.restore_state(state);
+        self.observer = Some(State::restored(state));
+    }
 }
 
 /// The BCL is not powered when the BAT push button is in the OFF
@@ -187,6 +266,7 @@ struct Open {
     begin_charging_cycle_delay: DelayedTrueLogicGate,
     open_due_to_discharge_protection: bool,
     open_due_to_exceeding_emergency_elec_closing_time_allowance: bool,
+    open_due_to_thermal_runaway: bool,
 }
 impl Open {
     const CHARGE_BATTERY_BELOW_VOLTAGE: f64 = 26.5;
@@ -197,6 +277,7 @@ impl Open {
     fn new(
         open_due_to_discharge_protection: bool,
         open_due_to_exceeding_emergency_elec_closing_time_allowance: bool,
+        open_due_to_thermal_runaway: bool,
     ) -> Self {
         Self {
             begin_charging_cycle_delay: DelayedTrueLogicGate::new(Duration::from_millis(
@@ -204,23 +285,28 @@ impl Open {
             )),
             open_due_to_discharge_protection,
             open_due_to_exceeding_emergency_elec_closing_time_allowance,
+            open_due_to_thermal_runaway,
         }
     }
 
     fn for_initial_bcl_state() -> Self {
-        Self::new(false, false)
+        Self::new(false, false, false)
     }
 
     fn from_closed() -> Self {
-        Self::new(false, false)
+        Self::new(false, false, false)
     }
 
     fn due_to_discharge_protection() -> Self {
-        Self::new(true, false)
+        Self::new(true, false, false)
     }
 
     fn due_to_exceeding_emergency_elec_closing_time_allowance() -> Self {
-        Self::new(false, true)
+        Self::new(false, true, false)
+    }
+
+    fn due_to_thermal_runaway() -> Self {
+        Self::new(false, false, true)
     }
 
     fn update_state(
@@ -259,6 +345,7 @@ impl Open {
                 lgciu1,
             )
             && !self.open_due_to_discharge_protection
+            && !self.open_due_to_thermal_runaway
             && (self.should_get_ready_for_apu_start(apu, apu_overhead)
                 || on_ground_at_low_speed_with_unpowered_ac_buses(
                     electricity,
@@ -356,12 +443,14 @@ struct Closed {
     apu_master_sw_pb_on_duration: Duration,
     had_apu_start: bool,
     entered_in_emergency_elec: bool,
+    thermal_runaway_detected: bool,
 }
 impl Closed {
     const BATTERY_CHARGING_OPEN_DELAY_ON_GROUND_SECONDS: u64 = 10;
     const BATTERY_CHARGING_OPEN_DELAY_100_KNOTS_OR_AFTER_APU_START_SECONDS: u64 = 1800;
     const BATTERY_DISCHARGE_PROTECTION_DELAY_SECONDS: u64 = 15;
     const EMER_ELEC_APU_MASTER_MAXIMUM_CLOSED_SECONDS: u64 = 180;
+    const THERMAL_RUNAWAY_CHARGING_TEMPERATURE_CELSIUS: f64 = 60.;
 
     fn new(entered_in_emergency_elec: bool) -> Self {
         Self {
@@ -370,6 +459,7 @@ impl Closed {
             apu_master_sw_pb_on_duration: Duration::from_secs(0),
             entered_in_emergency_elec,
             had_apu_start: false,
+            thermal_runaway_detected: false,
         }
     }
 
@@ -384,13 +474,20 @@ impl Closed {
     fn update_state(
         &mut self,
         context: &UpdateContext,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         apu_overhead: &(impl ApuMaster + ApuStart),
     ) {
         if apu_overhead.start_is_on() {
             self.had_apu_start = true;
         }
 
+        // A battery which is still being charged while overheating is in a thermal runaway.
+        self.thermal_runaway_detected = battery.current() > ElectricCurrent::new::<ampere>(0.)
+            && battery.temperature()
+                > ThermodynamicTemperature::new::<degree_celsius>(
+                    Closed::THERMAL_RUNAWAY_CHARGING_TEMPERATURE_CELSIUS,
+                );
+
         if battery.current() < ElectricCurrent::new::<ampere>(4.) {
             self.below_4_ampere_charging_duration += context.delta();
         } else {
@@ -488,7 +585,7 @@ impl Closed {
         electricity: &Electricity,
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
         apu: &impl ApuAvailable,
@@ -500,6 +597,8 @@ impl Closed {
 
         if !battery_push_buttons.bat_is_auto(battery_number) {
             State::Off(Off::new())
+        } else if self.thermal_runaway_detected {
+            State::Open(Open::due_to_thermal_runaway())
         } else if self.should_open_due_to_discharge_protection(lgciu1) {
             State::Open(Open::due_to_discharge_protection())
         } else if self
@@ -566,6 +665,19 @@ impl ArrowBetweenBatteryAndBatBus {
         self.discharging_above_1_ampere_beyond_time.output()
             || self.charging_above_1_ampere_beyond_time.output()
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.discharging_above_1_ampere_beyond_time
+            .save_state(state);
+        self.charging_above_1_ampere_beyond_time.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.discharging_above_1_ampere_beyond_time
+            .restore_state(state);
+        self.charging_above_1_ampere_beyond_time
+            .restore_state(state);
+    }
 }
 
 #[cfg(test)]
@@ -779,6 +891,15 @@ mod tests {
                 self.query(|a| a.battery_contactor_is_closed())
             }
 
+            fn has_thermal_runaway(&self) -> bool {
+                self.query(|a| a.battery_charge_limiter.has_thermal_runaway())
+            }
+
+            fn battery_surroundings_at(mut self, temperature: ThermodynamicTemperature) -> Self {
+                self.set_ambient_temperature(temperature);
+                self
+            }
+
             fn apu_master_sw_pb_on(mut self) -> Self {
                 self.command(|a| a.set_apu_master_sw_pb_on());
                 self
@@ -1184,6 +1305,43 @@ mod tests {
             test_bed()
         }
 
+        #[test]
+        fn contactor_opens_when_battery_overheats_while_charging() {
+            let test_bed = test_bed_with()
+                .battery_surroundings_at(ThermodynamicTemperature::new::<degree_celsius>(65.))
+                .wait_for_closed_contactor(false)
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.battery_contactor_is_closed());
+            assert!(test_bed.has_thermal_runaway());
+        }
+
+        #[test]
+        fn contactor_remains_open_after_thermal_runaway_until_push_button_cycled() {
+            let mut test_bed = test_bed_with()
+                .battery_surroundings_at(ThermodynamicTemperature::new::<degree_celsius>(65.))
+                .wait_for_closed_contactor(false)
+                .run(Duration::from_secs(1))
+                .battery_surroundings_at(ThermodynamicTemperature::new::<degree_celsius>(15.))
+                .run(Duration::from_secs(60));
+
+            assert!(!test_bed.battery_contactor_is_closed());
+
+            test_bed = test_bed.cycle_battery_push_button();
+
+            assert!(!test_bed.has_thermal_runaway());
+        }
+
+        #[test]
+        fn contactor_does_not_open_for_thermal_runaway_when_battery_is_cool() {
+            let test_bed = test_bed_with()
+                .wait_for_closed_contactor(true)
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.battery_contactor_is_closed());
+            assert!(!test_bed.has_thermal_runaway());
+        }
+
         #[test]
         fn should_show_arrow_when_contactor_closed_while_15_seconds_have_passed_charging_above_1_a()
         {
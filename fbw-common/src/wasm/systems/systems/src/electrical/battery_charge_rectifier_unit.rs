@@ -11,6 +11,7 @@ use crate::{
     failures::{Failure, FailureType},
     shared::{ConsumePower, ElectricalBusType, PotentialOrigin, PowerConsumptionReport},
     simulation::{
+        snapshot::{StateReader, StateValue, StateWriter},
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
     },
 };
@@ -212,6 +213,32 @@ impl SimulationElement for BatteryChargeRectifierUnit {
     fn receive_power(&mut self, buses: &impl crate::shared::ElectricalBuses) {
         self.backup_is_powered = self.battery_pb_is_auto && buses.is_powered(self.battery_hot_bus);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.output_potential);
+        state.write(self.output_current);
+        state.write(self.battery_soc_20);
+        state.write(self.battery_pb_is_auto);
+        state.write(self.backup_is_powered);
+        state.write(self.contactor_closed);
+        state.write(self.loss_of_ac_duration);
+        state.write(self.overcurrent_duration);
+        state.write(self.failed_time);
+        state.write(self.state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.output_potential = state.read();
+        self.output_current = state.read();
+        self.battery_soc_20 = state.read();
+        self.battery_pb_is_auto = state.read();
+        self.backup_is_powered = state.read();
+        self.contactor_closed = state.read();
+        self.loss_of_ac_duration = state.read();
+        self.overcurrent_duration = state.read();
+        self.failed_time = state.read();
+        self.state = state.read();
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -220,6 +247,23 @@ enum State {
     Open,
     Closed,
 }
+impl StateValue for State {
+    fn to_state(self) -> f64 {
+        match self {
+            Self::BatOff => 0.,
+            Self::Open => 1.,
+            Self::Closed => 2.,
+        }
+    }
+
+    fn from_state(value: f64) -> Self {
+        match value as u8 {
+            0 => Self::BatOff,
+            2 => Self::Closed,
+            _ => Self::Open,
+        }
+    }
+}
 impl State {
     const OPEN_BAT_LC_AFTER_AC_LOST_DURATION: Duration = Duration::from_secs(5);
 
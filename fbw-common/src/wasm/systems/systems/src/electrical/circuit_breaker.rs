@@ -0,0 +1,462 @@
+use super::{ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{ElectricalBusType, ElectricalBuses, PowerConsumptionReport},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    electric_current::ampere, electric_potential::volt, f64::*, power::watt, ratio::ratio,
+};
+
+/// Protects the consumers of a bus against overcurrent.
+///
+/// The circuit breaker acts as the bus of the given type: consumers which are powered by
+/// that bus receive their power through the circuit breaker, which itself receives power from
+/// the bus it is flowed from. The current flowing through the breaker is derived from the power
+/// consumed from it. When overloaded the breaker heats up and eventually trips, following an
+/// inverse time characteristic: the higher the overload, the faster it trips.
+///
+/// The breaker opens when pulled, tripped or failed. A tripped breaker is reset by pulling it
+/// and pushing it back in.
+/// ```rust
+/// # use systems::{shared::ElectricalBusType, electrical::{CircuitBreaker, ElectricalBus, Electricity},
+/// # simulation::{InitContext, VariableRegistry, VariableIdentifier}};
+/// # use uom::si::{electric_current::ampere, f64::ElectricCurrent};
+/// # struct SomeVariableRegistry {}
+/// # impl VariableRegistry for SomeVariableRegistry {
+/// #     fn get(&mut self, name: String) -> VariableIdentifier {
+/// #         VariableIdentifier::default()
+/// #     }
+/// # }
+/// # let mut registry = SomeVariableRegistry {};
+/// # let mut electricity = Electricity::new();
+/// # let mut context = InitContext::new(Default::default(), &mut electricity, &mut registry);
+/// let dc_bus = ElectricalBus::new(&mut context, ElectricalBusType::DirectCurrent(1));
+/// let circuit_breaker = CircuitBreaker::new(
+///     &mut context,
+///     "1PC",
+///     ElectricalBusType::Sub("1PC"),
+///     ElectricCurrent::new::<ampere>(10.),
+/// );
+///
+/// electricity.flow(&dc_bus, &circuit_breaker);
+/// ```
+pub struct CircuitBreaker {
+    identifier: ElectricalElementIdentifier,
+    is_pulled_id: VariableIdentifier,
+    is_closed_id: VariableIdentifier,
+    is_tripped_id: VariableIdentifier,
+
+    bus_type: ElectricalBusType,
+    rated_current: ElectricCurrent,
+    phase_factor: f64,
+
+    potential: ElectricPotential,
+    current: ElectricCurrent,
+    thermal_load: f64,
+
+    is_pulled: bool,
+    is_tripped: bool,
+    failure: Failure,
+}
+impl CircuitBreaker {
+    // Up to this overload the breaker never trips.
+    const NO_TRIP_OVERLOAD_RATIO: f64 = 1.15;
+
+    // The breaker trips once the accumulated (overload ratio² - 1) * seconds reaches this value.
+    // This makes it trip after 5 seconds at twice the rated current.
+    const TRIP_THERMAL_LOAD: f64 = 15.;
+
+    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(30);
+
+    /// Creates a circuit breaker for a single phase AC or a DC bus.
+    pub fn new(
+        context: &mut InitContext,
+        id: &'static str,
+        bus_type: ElectricalBusType,
+        rated_current: ElectricCurrent,
+    ) -> Self {
+        Self::new_with_phase_factor(context, id, bus_type, rated_current, 1.)
+    }
+
+    /// Creates a circuit breaker for a three phase AC bus. The rated current is the current per phase.
+    pub fn new_three_phase(
+        context: &mut InitContext,
+        id: &'static str,
+        bus_type: ElectricalBusType,
+        rated_current: ElectricCurrent,
+    ) -> Self {
+        Self::new_with_phase_factor(context, id, bus_type, rated_current, 3_f64.sqrt())
+    }
+
+    fn new_with_phase_factor(
+        context: &mut InitContext,
+        id: &'static str,
+        bus_type: ElectricalBusType,
+        rated_current: ElectricCurrent,
+        phase_factor: f64,
+    ) -> Self {
+        Self {
+            identifier: context.next_electrical_identifier_for_bus(bus_type),
+            is_pulled_id: context.get_identifier(format!("ELEC_CB_{}_IS_PULLED", id)),
+            is_closed_id: context.get_identifier(format!("ELEC_CB_{}_IS_CLOSED", id)),
+            is_tripped_id: context.get_identifier(format!("ELEC_CB_{}_IS_TRIPPED", id)),
+
+            bus_type,
+            rated_current,
+            phase_factor,
+
+            potential: ElectricPotential::new::<volt>(0.),
+            current: ElectricCurrent::new::<ampere>(0.),
+            thermal_load: 0.,
+
+            is_pulled: false,
+            is_tripped: false,
+            failure: Failure::new(FailureType::CircuitBreaker(id)),
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        !self.is_pulled && !self.is_tripped && !self.failure.is_active()
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.is_tripped
+    }
+
+    pub fn current(&self) -> ElectricCurrent {
+        self.current
+    }
+
+    fn update_thermal_load(&mut self, context: &UpdateContext) {
+        let overload_ratio = (self.current / self.rated_current).get::<ratio>();
+
+        if overload_ratio > Self::NO_TRIP_OVERLOAD_RATIO {
+            self.thermal_load += (overload_ratio.powi(2) - 1.) * context.delta_as_secs_f64();
+        } else {
+            self.thermal_load *=
+                (-context.delta_as_secs_f64() / Self::COOLING_TIME_CONSTANT.as_secs_f64()).exp();
+        }
+
+        if self.thermal_load >= Self::TRIP_THERMAL_LOAD {
+            self.is_tripped = true;
+            self.thermal_load = 0.;
+        }
+    }
+}
+impl ElectricalElement for CircuitBreaker {
+    fn input_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn output_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn is_conductive(&self) -> bool {
+        self.is_closed()
+    }
+}
+impl SimulationElement for CircuitBreaker {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_pulled = reader.read(&self.is_pulled_id);
+
+        if self.is_pulled {
+            self.is_tripped = false;
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_closed_id, self.is_closed());
+        writer.write(&self.is_tripped_id, self.is_tripped);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.potential = buses.potential_of(self.bus_type).raw();
+    }
+
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        context: &UpdateContext,
+        report: &T,
+    ) {
+        let consumption = report.total_consumption_from(self);
+
+        self.current = if self.is_closed() && self.potential > ElectricPotential::new::<volt>(0.) {
+            ElectricCurrent::new::<ampere>(
+                consumption.get::<watt>() / (self.potential.get::<volt>() * self.phase_factor),
+            )
+        } else {
+            ElectricCurrent::new::<ampere>(0.)
+        };
+
+        self.update_thermal_load(context);
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.potential);
+        state.write(self.current);
+        state.write(self.thermal_load);
+        state.write(self.is_pulled);
+        state.write(self.is_tripped);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.potential = state.read();
+        self.current = state.read();
+        self.thermal_load = state.read();
+        self.is_pulled = state.read();
+        self.is_tripped = state.read();
+    }
+}
+
+#[cfg(test)]
+mod circuit_breaker_tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::PowerConsumer, test::TestElectricitySource, ElectricalBus, Electricity,
+        },
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    const PROTECTED_BUS: ElectricalBusType = ElectricalBusType::Sub("1PC");
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_bus: ElectricalBus,
+        circuit_breaker: CircuitBreaker,
+        consumer: PowerConsumer,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext, circuit_breaker: CircuitBreaker) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                circuit_breaker,
+                consumer: PowerConsumer::from(PROTECTED_BUS),
+            }
+        }
+
+        fn with_potential(mut self, potential: ElectricPotential) -> Self {
+            self.electricity_source.power_with_potential(potential);
+            self
+        }
+
+        fn power_demand(&mut self, power: Power) {
+            self.consumer.demand(power);
+        }
+
+        fn protected_bus_is_powered(&self, electricity: &Electricity) -> bool {
+            electricity.is_powered(&self.circuit_breaker)
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.dc_bus);
+            electricity.flow(&self.dc_bus, &self.circuit_breaker);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.circuit_breaker.accept(visitor);
+            self.consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(|context| {
+            let circuit_breaker = CircuitBreaker::new(
+                context,
+                "1PC",
+                PROTECTED_BUS,
+                ElectricCurrent::new::<ampere>(10.),
+            );
+            TestAircraft::new(context, circuit_breaker)
+        })
+    }
+
+    fn is_closed(test_bed: &mut SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.read_by_name("ELEC_CB_1PC_IS_CLOSED")
+    }
+
+    fn is_tripped(test_bed: &mut SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.read_by_name("ELEC_CB_1PC_IS_TRIPPED")
+    }
+
+    fn protected_bus_is_powered(test_bed: &SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.query_elec(|a, elec| a.protected_bus_is_powered(elec))
+    }
+
+    #[test]
+    fn is_closed_by_default() {
+        let mut test_bed = test_bed();
+
+        test_bed.run();
+
+        assert!(is_closed(&mut test_bed));
+        assert!(!is_tripped(&mut test_bed));
+        assert!(protected_bus_is_powered(&test_bed));
+    }
+
+    #[test]
+    fn pulled_breaker_unpowers_its_bus() {
+        let mut test_bed = test_bed();
+
+        test_bed.write_by_name("ELEC_CB_1PC_IS_PULLED", true);
+        test_bed.run();
+
+        assert!(!is_closed(&mut test_bed));
+        assert!(!protected_bus_is_powered(&test_bed));
+    }
+
+    #[test]
+    fn measures_current_from_consumption() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(140.)));
+        test_bed.run();
+
+        let current = test_bed.query(|a| a.circuit_breaker.current());
+        assert!((current.get::<ampere>() - 5.).abs() < 0.001);
+    }
+
+    #[test]
+    fn does_not_trip_at_rated_current() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(280.)));
+        test_bed.run_with_delta(Duration::from_secs(3600));
+        test_bed.run();
+
+        assert!(is_closed(&mut test_bed));
+        assert!(protected_bus_is_powered(&test_bed));
+    }
+
+    #[test]
+    fn trips_after_some_time_when_overloaded() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(560.)));
+        for _ in 0..3 {
+            test_bed.run();
+        }
+
+        assert!(is_closed(&mut test_bed));
+
+        for _ in 0..3 {
+            test_bed.run();
+        }
+
+        assert!(is_tripped(&mut test_bed));
+        assert!(!is_closed(&mut test_bed));
+        assert!(!protected_bus_is_powered(&test_bed));
+    }
+
+    #[test]
+    fn trips_faster_with_higher_overload() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(1400.)));
+        test_bed.run();
+
+        assert!(is_tripped(&mut test_bed));
+    }
+
+    #[test]
+    fn short_overload_does_not_trip() {
+        let mut test_bed = test_bed();
+
+        for _ in 0..5 {
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(560.)));
+            for _ in 0..2 {
+                test_bed.run();
+            }
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(140.)));
+            test_bed.run_with_delta(Duration::from_secs(120));
+        }
+
+        assert!(is_closed(&mut test_bed));
+    }
+
+    #[test]
+    fn tripped_breaker_stays_open_until_pulled_and_reset() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(1400.)));
+        test_bed.run();
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(140.)));
+        test_bed.run();
+
+        assert!(is_tripped(&mut test_bed));
+
+        test_bed.write_by_name("ELEC_CB_1PC_IS_PULLED", true);
+        test_bed.run();
+
+        assert!(!is_tripped(&mut test_bed));
+        assert!(!is_closed(&mut test_bed));
+
+        test_bed.write_by_name("ELEC_CB_1PC_IS_PULLED", false);
+        test_bed.run();
+
+        assert!(is_closed(&mut test_bed));
+        assert!(protected_bus_is_powered(&test_bed));
+    }
+
+    #[test]
+    fn failed_breaker_is_open() {
+        let mut test_bed = test_bed();
+
+        test_bed.fail(FailureType::CircuitBreaker("1PC"));
+        test_bed.run();
+
+        assert!(!is_closed(&mut test_bed));
+        assert!(!is_tripped(&mut test_bed));
+        assert!(!protected_bus_is_powered(&test_bed));
+    }
+
+    #[test]
+    fn three_phase_breaker_measures_current_per_phase() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let circuit_breaker = CircuitBreaker::new_three_phase(
+                context,
+                "1PC",
+                PROTECTED_BUS,
+                ElectricCurrent::new::<ampere>(10.),
+            );
+            TestAircraft::new(context, circuit_breaker)
+                .with_potential(ElectricPotential::new::<volt>(115.))
+        });
+
+        test_bed.command(|a| a.power_demand(Power::new::<watt>(115. * 3_f64.sqrt() * 10.)));
+        test_bed.run();
+
+        let current = test_bed.query(|a| a.circuit_breaker.current());
+        assert!((current.get::<ampere>() - 10.).abs() < 0.001);
+    }
+}
@@ -21,9 +21,11 @@ use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
     shared::{random_number, ConsumePower, ElectricalBuses, FwcFlightPhase},
     simulation::{
-        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
+        UpdateContext, Write,
     },
 };
+use fxhash::FxHashMap;
 use num_traits::FromPrimitive;
 use std::time::Duration;
 use uom::si::{f64::*, power::watt};
@@ -48,6 +50,10 @@ impl PowerConsumer {
     pub fn demand(&mut self, power: Power) {
         self.demand = power;
     }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
 }
 impl SimulationElement for PowerConsumer {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -146,6 +152,196 @@ impl From<FwcFlightPhase> for PowerConsumerFlightPhase {
     }
 }
 
+/// The state of the aircraft in which a catalogued power consumer demands power.
+#[derive(Clone, Copy)]
+pub enum PowerConsumerCondition {
+    Always,
+    /// The simulation variable with the given name is non-zero.
+    VariableIsOn(&'static str),
+    /// The simulation variable with the given name is zero.
+    VariableIsOff(&'static str),
+    OnGround,
+    InFlight,
+    AnyOf(&'static [PowerConsumerCondition]),
+    AllOf(&'static [PowerConsumerCondition]),
+}
+impl PowerConsumerCondition {
+    fn is_met(&self, context: &UpdateContext, variable_is_on: &impl Fn(&str) -> bool) -> bool {
+        match self {
+            PowerConsumerCondition::Always => true,
+            PowerConsumerCondition::VariableIsOn(name) => variable_is_on(*name),
+            PowerConsumerCondition::VariableIsOff(name) => !variable_is_on(*name),
+            PowerConsumerCondition::OnGround => context.is_on_ground(),
+            PowerConsumerCondition::InFlight => !context.is_on_ground(),
+            PowerConsumerCondition::AnyOf(conditions) => conditions
+                .iter()
+                .any(|condition| condition.is_met(context, variable_is_on)),
+            PowerConsumerCondition::AllOf(conditions) => conditions
+                .iter()
+                .all(|condition| condition.is_met(context, variable_is_on)),
+        }
+    }
+
+    fn variable_names(&self, names: &mut Vec<&'static str>) {
+        match self {
+            PowerConsumerCondition::VariableIsOn(name)
+            | PowerConsumerCondition::VariableIsOff(name) => names.push(*name),
+            PowerConsumerCondition::AnyOf(conditions)
+            | PowerConsumerCondition::AllOf(conditions) => conditions
+                .iter()
+                .for_each(|condition| condition.variable_names(names)),
+            PowerConsumerCondition::Always
+            | PowerConsumerCondition::OnGround
+            | PowerConsumerCondition::InFlight => {}
+        }
+    }
+}
+
+/// Describes a single piece of equipment which consumes power, such that the power consumption
+/// of an aircraft can be defined as data in a [PowerConsumerCatalog].
+#[derive(Clone, Copy)]
+pub struct PowerConsumerDefinition {
+    name: &'static str,
+    bus: ElectricalBusType,
+    nominal_power_watt: f64,
+    duty_cycle: f64,
+    condition: PowerConsumerCondition,
+}
+impl PowerConsumerDefinition {
+    /// Defines equipment which continuously consumes its nominal power from the given bus.
+    pub const fn new(name: &'static str, bus: ElectricalBusType, nominal_power_watt: f64) -> Self {
+        Self {
+            name,
+            bus,
+            nominal_power_watt,
+            duty_cycle: 1.,
+            condition: PowerConsumerCondition::Always,
+        }
+    }
+
+    /// The fraction of time the equipment consumes its nominal power, such as heaters which
+    /// are switched on and off by their controller. The equipment consumes no power
+    /// for the remainder of the time.
+    pub const fn duty_cycle(mut self, duty_cycle: f64) -> Self {
+        self.duty_cycle = duty_cycle;
+        self
+    }
+
+    /// The equipment only consumes power when the given condition is met.
+    pub const fn when(mut self, condition: PowerConsumerCondition) -> Self {
+        self.condition = condition;
+        self
+    }
+}
+
+/// Consumes power for each piece of equipment in a list of [PowerConsumerDefinition]s.
+/// Whether each piece of equipment is powered is written to the simulation,
+/// making load shedding visible per equipment.
+pub struct PowerConsumerCatalog {
+    consumers: Vec<CataloguedPowerConsumer>,
+    variables: FxHashMap<&'static str, (VariableIdentifier, bool)>,
+    duration: Duration,
+}
+impl PowerConsumerCatalog {
+    const DUTY_CYCLE_PERIOD: Duration = Duration::from_secs(20);
+
+    pub fn new(context: &mut InitContext, definitions: &[PowerConsumerDefinition]) -> Self {
+        let mut names = Vec::new();
+        definitions
+            .iter()
+            .for_each(|definition| definition.condition.variable_names(&mut names));
+
+        Self {
+            consumers: definitions
+                .iter()
+                .map(|definition| CataloguedPowerConsumer::new(context, *definition))
+                .collect(),
+            variables: names
+                .into_iter()
+                .map(|name| (name, (context.get_identifier(name.to_owned()), false)))
+                .collect(),
+            duration: Duration::ZERO,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.duration += context.delta();
+
+        let variables = &self.variables;
+        let variable_is_on = |name: &str| variables.get(name).map_or(false, |(_, is_on)| *is_on);
+        for consumer in &mut self.consumers {
+            consumer.update(context, self.duration, &variable_is_on);
+        }
+    }
+}
+impl SimulationElement for PowerConsumerCatalog {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumers
+            .iter_mut()
+            .for_each(|consumer| consumer.accept(visitor));
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (identifier, is_on) in self.variables.values_mut() {
+            let value: f64 = reader.read(identifier);
+            *is_on = value != 0.;
+        }
+    }
+}
+
+struct CataloguedPowerConsumer {
+    is_powered_id: VariableIdentifier,
+    definition: PowerConsumerDefinition,
+    consumer: PowerConsumer,
+    duty_cycle_offset: Duration,
+}
+impl CataloguedPowerConsumer {
+    fn new(context: &mut InitContext, definition: PowerConsumerDefinition) -> Self {
+        Self {
+            is_powered_id: context
+                .get_identifier(format!("ELEC_CONSUMER_{}_IS_POWERED", definition.name)),
+            definition,
+            consumer: PowerConsumer::from(definition.bus),
+            // Equipment with a duty cycle doesn't all switch on at the same time.
+            duty_cycle_offset: Duration::from_secs(
+                random_number() as u64 % PowerConsumerCatalog::DUTY_CYCLE_PERIOD.as_secs(),
+            ),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        duration: Duration,
+        variable_is_on: &impl Fn(&str) -> bool,
+    ) {
+        let period = PowerConsumerCatalog::DUTY_CYCLE_PERIOD.as_secs_f64();
+        let cycle_position = ((duration + self.duty_cycle_offset).as_secs_f64() % period) / period;
+
+        let demands_power = self.definition.condition.is_met(context, variable_is_on)
+            && cycle_position < self.definition.duty_cycle;
+
+        self.consumer.demand(if demands_power {
+            Power::new::<watt>(self.definition.nominal_power_watt)
+        } else {
+            Power::new::<watt>(0.)
+        });
+    }
+}
+impl SimulationElement for CataloguedPowerConsumer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumer.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_powered_id, self.consumer.is_powered());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -351,4 +547,258 @@ mod tests {
             assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
         }
     }
+
+    #[cfg(test)]
+    mod power_consumer_catalog_tests {
+        use crate::{
+            electrical::{test::TestElectricitySource, ElectricalBus},
+            simulation::{
+                test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+                Aircraft,
+            },
+        };
+
+        use super::*;
+
+        struct PowerConsumerCatalogTestAircraft {
+            electricity_source: TestElectricitySource,
+            apu_generator_consumption: Power,
+            catalog: PowerConsumerCatalog,
+            bus: ElectricalBus,
+        }
+        impl PowerConsumerCatalogTestAircraft {
+            fn new(context: &mut InitContext, definitions: &[PowerConsumerDefinition]) -> Self {
+                Self {
+                    electricity_source: TestElectricitySource::unpowered(
+                        context,
+                        PotentialOrigin::ApuGenerator(1),
+                    ),
+                    apu_generator_consumption: Power::new::<watt>(0.),
+                    catalog: PowerConsumerCatalog::new(context, definitions),
+                    bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                }
+            }
+
+            fn power(&mut self) {
+                self.electricity_source.power()
+            }
+
+            fn apu_generator_consumption(&self) -> Power {
+                self.apu_generator_consumption
+            }
+        }
+        impl Aircraft for PowerConsumerCatalogTestAircraft {
+            fn update_before_power_distribution(
+                &mut self,
+                _: &UpdateContext,
+                electricity: &mut crate::electrical::Electricity,
+            ) {
+                electricity.supplied_by(&self.electricity_source);
+                electricity.flow(&self.electricity_source, &self.bus);
+            }
+
+            fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+                self.catalog.update(context);
+            }
+        }
+        impl SimulationElement for PowerConsumerCatalogTestAircraft {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.catalog.accept(visitor);
+
+                visitor.visit(self);
+            }
+
+            fn process_power_consumption_report<T: PowerConsumptionReport>(
+                &mut self,
+                _: &UpdateContext,
+                report: &T,
+            ) {
+                self.apu_generator_consumption =
+                    report.total_consumption_of(PotentialOrigin::ApuGenerator(1));
+            }
+        }
+
+        const RADIO: PowerConsumerDefinition =
+            PowerConsumerDefinition::new("RADIO", ElectricalBusType::AlternatingCurrent(1), 100.);
+
+        fn test_bed_with(
+            definitions: &[PowerConsumerDefinition],
+        ) -> SimulationTestBed<PowerConsumerCatalogTestAircraft> {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                PowerConsumerCatalogTestAircraft::new(context, definitions)
+            });
+            test_bed.command(|a| a.power());
+
+            test_bed
+        }
+
+        #[test]
+        fn consumes_the_nominal_power_of_equipment() {
+            let mut test_bed = test_bed_with(&[RADIO]);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(100.)
+            );
+        }
+
+        #[test]
+        fn sums_the_power_of_all_equipment() {
+            let mut test_bed = test_bed_with(&[
+                RADIO,
+                PowerConsumerDefinition::new(
+                    "LIGHT",
+                    ElectricalBusType::AlternatingCurrent(1),
+                    50.,
+                ),
+            ]);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(150.)
+            );
+        }
+
+        #[test]
+        fn unpowered_equipment_consumes_nothing() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                PowerConsumerCatalogTestAircraft::new(context, &[RADIO])
+            });
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(0.)
+            );
+        }
+
+        #[test]
+        fn writes_powered_equipment_as_powered() {
+            let mut test_bed = test_bed_with(&[RADIO]);
+
+            test_bed.run();
+
+            let is_powered: bool = test_bed.read_by_name("ELEC_CONSUMER_RADIO_IS_POWERED");
+            assert!(is_powered);
+        }
+
+        #[test]
+        fn writes_unpowered_equipment_as_unpowered() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                PowerConsumerCatalogTestAircraft::new(context, &[RADIO])
+            });
+
+            test_bed.run();
+
+            let is_powered: bool = test_bed.read_by_name("ELEC_CONSUMER_RADIO_IS_POWERED");
+            assert!(!is_powered);
+        }
+
+        #[test]
+        fn equipment_switched_on_consumes_power() {
+            let mut test_bed =
+                test_bed_with(&[RADIO.when(PowerConsumerCondition::VariableIsOn("RADIO_IS_ON"))]);
+            test_bed.write_by_name("RADIO_IS_ON", true);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(100.)
+            );
+        }
+
+        #[test]
+        fn equipment_switched_off_consumes_nothing() {
+            let mut test_bed =
+                test_bed_with(&[RADIO.when(PowerConsumerCondition::VariableIsOn("RADIO_IS_ON"))]);
+            test_bed.write_by_name("RADIO_IS_ON", false);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(0.)
+            );
+        }
+
+        #[test]
+        fn equipment_used_on_ground_consumes_nothing_in_flight() {
+            let mut test_bed = test_bed_with(&[RADIO.when(PowerConsumerCondition::OnGround)]);
+            test_bed.set_on_ground(false);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(0.)
+            );
+        }
+
+        #[test]
+        fn equipment_with_all_conditions_met_consumes_power() {
+            let mut test_bed = test_bed_with(&[RADIO.when(PowerConsumerCondition::AllOf(&[
+                PowerConsumerCondition::InFlight,
+                PowerConsumerCondition::VariableIsOff("RADIO_IS_INHIBITED"),
+            ]))]);
+            test_bed.set_on_ground(false);
+            test_bed.write_by_name("RADIO_IS_INHIBITED", false);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(100.)
+            );
+        }
+
+        #[test]
+        fn equipment_with_any_condition_met_consumes_power() {
+            let mut test_bed = test_bed_with(&[RADIO.when(PowerConsumerCondition::AnyOf(&[
+                PowerConsumerCondition::InFlight,
+                PowerConsumerCondition::VariableIsOn("RADIO_IS_ON"),
+            ]))]);
+            test_bed.set_on_ground(true);
+            test_bed.write_by_name("RADIO_IS_ON", true);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(100.)
+            );
+        }
+
+        #[test]
+        fn equipment_without_duty_cycle_consumes_nothing() {
+            let mut test_bed = test_bed_with(&[RADIO.duty_cycle(0.)]);
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.apu_generator_consumption()),
+                Power::new::<watt>(0.)
+            );
+        }
+
+        #[test]
+        fn equipment_with_duty_cycle_consumes_its_nominal_power_part_of_the_time() {
+            let mut test_bed = test_bed_with(&[RADIO.duty_cycle(0.25)]);
+
+            let mut powered_seconds = 0;
+            for _ in 0..PowerConsumerCatalog::DUTY_CYCLE_PERIOD.as_secs() {
+                test_bed.run_with_delta(Duration::from_secs(1));
+                if test_bed.query(|a| a.apu_generator_consumption()) > Power::new::<watt>(0.) {
+                    powered_seconds += 1;
+                }
+            }
+
+            assert_eq!(powered_seconds, 5);
+        }
+    }
 }
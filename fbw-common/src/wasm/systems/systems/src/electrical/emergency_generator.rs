@@ -1,4 +1,7 @@
-use crate::simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext};
+use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
+    InitContext, SimulationElement, SimulatorWriter, UpdateContext,
+};
 
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
@@ -119,6 +122,22 @@ impl SimulationElement for EmergencyGenerator {
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_alternating(self, writer);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.supplying);
+        state.write(self.output_frequency);
+        state.write(self.output_potential);
+        state.write(self.generated_power);
+        state.write(self.demand);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.supplying = state.read();
+        self.output_frequency = state.read();
+        self.output_potential = state.read();
+        self.generated_power = state.read();
+        self.demand = state.read();
+    }
 }
 
 #[cfg(test)]
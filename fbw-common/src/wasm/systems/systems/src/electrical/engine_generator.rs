@@ -1,13 +1,15 @@
 use super::{
-    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricitySource, EngineGeneratorPushButtons, Potential,
-    PotentialOrigin, ProvideFrequency, ProvideLoad, ProvidePotential,
+    generator_control_unit::GeneratorControlUnit, ElectricalElement, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalStateWriter, ElectricitySource,
+    EngineGeneratorPushButtons, Potential, PotentialOrigin, ProvideFrequency, ProvideLoad,
+    ProvidePotential,
 };
 use crate::{
     engine::Engine,
     failures::{Failure, FailureType},
     shared::{calculate_towards_target_temperature, EngineFirePushButtons, PowerConsumptionReport},
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
@@ -18,6 +20,7 @@ use uom::si::{
     electric_potential::volt,
     f64::*,
     frequency::hertz,
+    power::watt,
     ratio::{percent, ratio},
     thermodynamic_temperature::degree_celsius,
 };
@@ -29,10 +32,19 @@ pub type VariableFrequencyGenerator = EngineGenerator<DirectDrive>;
 
 pub trait EngineGeneratorDrive: SimulationElement {
     fn new_drive(context: &mut InitContext, number: usize) -> Self;
-    fn update_drive(&mut self, context: &UpdateContext, engine: &impl Engine);
+    fn update_drive(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl Engine,
+        load: Ratio,
+        fuel_temperature: ThermodynamicTemperature,
+    );
     fn output_speed(&self) -> AngularVelocity;
+    /// Heat rejected by the drive's oil cooler into the fuel.
+    fn heat_rejected_to_fuel(&self) -> Power;
     fn disconnect(&mut self);
     fn is_connected(&self) -> bool;
+    fn has_fault(&self) -> bool;
 }
 
 pub struct EngineGenerator<Drive: EngineGeneratorDrive> {
@@ -41,12 +53,10 @@ pub struct EngineGenerator<Drive: EngineGeneratorDrive> {
     max_true_power: Power,
     identifier: ElectricalElementIdentifier,
     drive: Drive,
-    activated: bool,
+    gcu: GeneratorControlUnit,
     output_frequency: Frequency,
-    normal_frequency: RangeInclusive<f64>,
     output_potential: ElectricPotential,
     load: Ratio,
-    time_above_threshold: Duration,
     failure: Failure,
 }
 impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
@@ -62,12 +72,17 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
             max_true_power,
             identifier: context.next_electrical_identifier(),
             drive: Drive::new_drive(context, number),
-            activated: true,
+            gcu: GeneratorControlUnit::new(
+                context,
+                &format!("ENG_GEN_{}", number),
+                110.0..=120.0,
+                normal_frequency,
+                INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+                FailureType::GeneratorFeeder(number),
+            ),
             output_frequency: Frequency::new::<hertz>(0.),
-            normal_frequency,
             output_potential: ElectricPotential::new::<volt>(0.),
             load: Ratio::new::<percent>(0.),
-            time_above_threshold: INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
             failure: Failure::new(FailureType::Generator(number)),
         }
     }
@@ -78,45 +93,32 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
         engine: &impl Engine,
         generator_buttons: &impl EngineGeneratorPushButtons,
         fire_buttons: &impl EngineFirePushButtons,
+        fuel_temperature: ThermodynamicTemperature,
     ) {
         if generator_buttons.idg_push_button_is_released(self.number) {
             // The drive cannot be reconnected.
             self.drive.disconnect();
         }
-        self.activated = generator_buttons.engine_gen_push_button_is_on(self.number)
-            && !fire_buttons.is_released(self.number);
-        self.drive.update_drive(context, engine);
-        self.output_frequency = if self.activated {
-            Frequency::new::<hertz>(
-                self.drive.output_speed().get::<revolution_per_minute>() * 4. / 120.,
-            )
-        } else {
-            Frequency::default()
-        };
-        self.update_stable_time(context);
-    }
+        self.drive
+            .update_drive(context, engine, self.load, fuel_temperature);
 
-    // TODO: move to GCU when implemented
-    fn update_stable_time(&mut self, context: &UpdateContext) {
-        if !self.activated {
-            self.time_above_threshold = Duration::ZERO;
-            return;
-        }
+        let drive_frequency = Frequency::new::<hertz>(
+            self.drive.output_speed().get::<revolution_per_minute>() * 4. / 120.,
+        );
+        self.gcu.update(
+            context,
+            generator_buttons.engine_gen_push_button_is_on(self.number)
+                && !fire_buttons.is_released(self.number),
+            drive_frequency,
+            self.output_potential,
+            self.load,
+        );
 
-        let new_time = if self.frequency_normal() {
-            self.time_above_threshold + context.delta()
+        self.output_frequency = if self.gcu.excites_generator() {
+            drive_frequency
         } else {
-            Duration::ZERO
+            Frequency::default()
         };
-
-        self.time_above_threshold = new_time.clamp(
-            Duration::ZERO,
-            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
-        );
-    }
-
-    fn provides_stable_power_output(&self) -> bool {
-        self.time_above_threshold == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -130,15 +132,20 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
     }
 
     fn should_provide_output(&self) -> bool {
-        self.provides_stable_power_output()
-            && self.activated
-            && self.frequency_normal()
-            && !self.failure.is_active()
+        self.gcu.provides_stable_output() && !self.failure.is_active()
     }
 
     pub fn is_drive_connected(&self) -> bool {
         self.drive.is_connected()
     }
+
+    pub fn heat_rejected_to_fuel(&self) -> Power {
+        self.drive.heat_rejected_to_fuel()
+    }
+
+    pub fn drive_has_fault(&self) -> bool {
+        self.drive.has_fault()
+    }
 }
 impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
     fn output_potential(&self) -> Potential {
@@ -152,33 +159,28 @@ impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
         }
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvidePotential for EngineGenerator<Drive> {
     fn potential(&self) -> ElectricPotential {
         self.output_potential
     }
     fn potential_normal(&self) -> bool {
-        let volts = self.output_potential.get::<volt>();
-        (110.0..=120.0).contains(&volts)
+        self.gcu.potential_normal(self.output_potential)
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvideFrequency for EngineGenerator<Drive> {
     fn frequency(&self) -> Frequency {
         self.output_frequency
     }
     fn frequency_normal(&self) -> bool {
-        let hz = self.output_frequency.get::<hertz>();
-        self.normal_frequency.contains(&hz)
+        self.gcu.frequency_normal(self.output_frequency)
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvideLoad for EngineGenerator<Drive> {
     fn load(&self) -> Ratio {
         self.load
     }
     fn load_normal(&self) -> bool {
-        self.load <= Ratio::new::<percent>(100.)
+        self.gcu.load_normal(self.load)
     }
 }
 impl<Drive: EngineGeneratorDrive> ElectricalElement for EngineGenerator<Drive> {
@@ -197,6 +199,7 @@ impl<Drive: EngineGeneratorDrive> ElectricalElement for EngineGenerator<Drive> {
 impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.drive.accept(visitor);
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
 
         visitor.visit(self);
@@ -224,14 +227,32 @@ impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_alternating_with_load(self, writer);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.output_frequency);
+        state.write(self.output_potential);
+        state.write(self.load);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.output_frequency = state.read();
+        self.output_potential = state.read();
+        self.load = state.read();
+    }
 }
 
 pub struct ConstantSpeedDrive {
+    oil_inlet_temperature_id: VariableIdentifier,
+    oil_inlet_temperature: ThermodynamicTemperature,
     oil_outlet_temperature_id: VariableIdentifier,
     oil_outlet_temperature: ThermodynamicTemperature,
+    oil_pressure_is_low_id: VariableIdentifier,
+    oil_pressure_is_low: bool,
+    oil_overheat_id: VariableIdentifier,
     is_connected_id: VariableIdentifier,
     connected: bool,
     output_speed: AngularVelocity,
+    oil_leak: Failure,
 }
 impl ConstantSpeedDrive {
     // Threshold to reach target output speed = 58% of 16645 RPM
@@ -240,21 +261,44 @@ impl ConstantSpeedDrive {
 
     const M: f64 = Self::OUTPUT_SPEED_RPM / Self::ENGINE_GEARBOX_POWER_UP_OUTPUT_THRESHOLD;
 
+    const OIL_OVERHEAT_CELSIUS: f64 = 185.;
+    // Above this temperature the drive disconnects itself to prevent damage.
+    const OIL_AUTOMATIC_DISCONNECT_CELSIUS: f64 = 200.;
+    // Oil pressure monitoring is inhibited while the engine is below this speed.
+    const OIL_PRESSURE_MONITORING_MINIMUM_N2_PERCENT: f64 = 14.;
+    // Heat carried by the oil flow per degree of temperature difference across the cooler.
+    const OIL_HEAT_CAPACITY_RATE_WATT_PER_KELVIN: f64 = 150.;
+
     fn new(context: &mut InitContext, number: usize) -> ConstantSpeedDrive {
         ConstantSpeedDrive {
+            oil_inlet_temperature_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_OIL_INLET_TEMPERATURE", number)),
+            oil_inlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
             oil_outlet_temperature_id: context.get_identifier(format!(
                 "ELEC_ENG_GEN_{}_IDG_OIL_OUTLET_TEMPERATURE",
                 number
             )),
             oil_outlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            oil_pressure_is_low_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_OIL_PRESSURE_IS_LOW", number)),
+            oil_pressure_is_low: false,
+            oil_overheat_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_OIL_OVERHEAT", number)),
             is_connected_id: context
                 .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_IS_CONNECTED", number)),
             connected: true,
             output_speed: AngularVelocity::default(),
+            oil_leak: Failure::new(FailureType::GeneratorDriveOilLeak(number)),
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext, engine: &impl Engine) {
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl Engine,
+        load: Ratio,
+        fuel_temperature: ThermodynamicTemperature,
+    ) {
         self.output_speed = if self.connected {
             (Self::M * engine.gearbox_speed()).min(AngularVelocity::new::<revolution_per_minute>(
                 Self::OUTPUT_SPEED_RPM,
@@ -262,46 +306,94 @@ impl ConstantSpeedDrive {
         } else {
             AngularVelocity::default()
         };
-        self.update_temperature(
-            context,
-            self.get_target_temperature(context, engine.corrected_n2()),
-        );
+
+        self.oil_pressure_is_low = self.connected
+            && self.oil_leak.is_active()
+            && engine.corrected_n2().get::<percent>()
+                >= Self::OIL_PRESSURE_MONITORING_MINIMUM_N2_PERCENT;
+
+        let (target_inlet, target_outlet) =
+            self.get_target_temperatures(engine.corrected_n2(), load, fuel_temperature);
+        self.oil_inlet_temperature =
+            Self::calculate_temperature(context, self.oil_inlet_temperature, target_inlet);
+        self.oil_outlet_temperature =
+            Self::calculate_temperature(context, self.oil_outlet_temperature, target_outlet);
+
+        if self.oil_outlet_temperature.get::<degree_celsius>()
+            > Self::OIL_AUTOMATIC_DISCONNECT_CELSIUS
+        {
+            self.connected = false;
+        }
     }
 
-    fn update_temperature(&mut self, context: &UpdateContext, target: ThermodynamicTemperature) {
+    fn calculate_temperature(
+        context: &UpdateContext,
+        current: ThermodynamicTemperature,
+        target: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
         const IDG_HEATING_COEFFICIENT: f64 = 1.4;
         const IDG_COOLING_COEFFICIENT: f64 = 0.4;
 
-        self.oil_outlet_temperature = calculate_towards_target_temperature(
-            self.oil_outlet_temperature,
+        calculate_towards_target_temperature(
+            current,
             target,
-            if self.oil_outlet_temperature < target {
+            if current < target {
                 IDG_HEATING_COEFFICIENT
             } else {
                 IDG_COOLING_COEFFICIENT
             },
             context.delta(),
-        );
+        )
     }
 
-    fn get_target_temperature(
+    /// The oil heats up while flowing through the drive, by the drive's mechanical losses and
+    /// the generator's electrical losses. It is then cooled by fuel in the fuel cooled oil cooler
+    /// before returning to the drive's inlet. Returns the inlet and outlet temperatures at which
+    /// the heat picked up in the drive equals the heat rejected to the fuel.
+    fn get_target_temperatures(
         &self,
-        context: &UpdateContext,
         corrected_n2: Ratio,
-    ) -> ThermodynamicTemperature {
-        const TEMPERATURE_TO_RPM_FACTOR: f64 = 1.8;
+        load: Ratio,
+        fuel_temperature: ThermodynamicTemperature,
+    ) -> (ThermodynamicTemperature, ThermodynamicTemperature) {
+        // Oil temperature rise through the drive at full output speed without load,
+        // and the additional rise caused by the generator's losses at full load.
+        const NO_LOAD_TEMPERATURE_RISE: f64 = 20.;
+        const FULL_LOAD_TEMPERATURE_RISE: f64 = 25.;
+        // With less oil in the drive, the remaining oil picks up more heat.
+        const OIL_LEAK_TEMPERATURE_RISE_FACTOR: f64 = 4.;
+        const MAXIMUM_COOLER_EFFECTIVENESS: f64 = 0.4;
+        const MINIMUM_COOLER_EFFECTIVENESS: f64 = 0.05;
+        // Fuel flow through the cooler is sufficient for full effectiveness from idle.
+        const FULL_COOLING_N2_PERCENT: f64 = 60.;
 
         if !self.connected {
-            return context.ambient_temperature();
+            return (fuel_temperature, fuel_temperature);
         }
 
-        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
-        let target_idg =
-            corrected_n2.get::<percent>() * TEMPERATURE_TO_RPM_FACTOR + ambient_temperature;
+        let speed_ratio = self.output_speed.get::<revolution_per_minute>() / Self::OUTPUT_SPEED_RPM;
+        // Copper losses increase with the square of the current.
+        let mut temperature_rise = NO_LOAD_TEMPERATURE_RISE * speed_ratio
+            + FULL_LOAD_TEMPERATURE_RISE * load.get::<ratio>().max(0.).powi(2);
+        if self.oil_leak.is_active() {
+            temperature_rise *= OIL_LEAK_TEMPERATURE_RISE_FACTOR;
+        }
 
-        // TODO improve this function with feedback @komp provides.
+        let cooler_effectiveness = MAXIMUM_COOLER_EFFECTIVENESS
+            * (corrected_n2.get::<percent>() / FULL_COOLING_N2_PERCENT)
+                .clamp(MINIMUM_COOLER_EFFECTIVENESS, 1.);
 
-        ThermodynamicTemperature::new::<degree_celsius>(target_idg)
+        let outlet =
+            fuel_temperature.get::<degree_celsius>() + temperature_rise / cooler_effectiveness;
+
+        (
+            ThermodynamicTemperature::new::<degree_celsius>(outlet - temperature_rise),
+            ThermodynamicTemperature::new::<degree_celsius>(outlet),
+        )
+    }
+
+    fn oil_overheat(&self) -> bool {
+        self.oil_outlet_temperature.get::<degree_celsius>() > Self::OIL_OVERHEAT_CELSIUS
     }
 }
 impl EngineGeneratorDrive for ConstantSpeedDrive {
@@ -309,14 +401,30 @@ impl EngineGeneratorDrive for ConstantSpeedDrive {
         Self::new(context, number)
     }
 
-    fn update_drive(&mut self, context: &UpdateContext, engine: &impl Engine) {
-        self.update(context, engine);
+    fn update_drive(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl Engine,
+        load: Ratio,
+        fuel_temperature: ThermodynamicTemperature,
+    ) {
+        self.update(context, engine, load, fuel_temperature);
     }
 
     fn output_speed(&self) -> AngularVelocity {
         self.output_speed
     }
 
+    /// The heat picked up by the oil through the drive is given to the fuel in the cooler.
+    fn heat_rejected_to_fuel(&self) -> Power {
+        Power::new::<watt>(
+            (Self::OIL_HEAT_CAPACITY_RATE_WATT_PER_KELVIN
+                * (self.oil_outlet_temperature.get::<degree_celsius>()
+                    - self.oil_inlet_temperature.get::<degree_celsius>()))
+            .max(0.),
+        )
+    }
+
     fn disconnect(&mut self) {
         self.connected = false;
     }
@@ -324,12 +432,41 @@ impl EngineGeneratorDrive for ConstantSpeedDrive {
     fn is_connected(&self) -> bool {
         self.connected
     }
+
+    fn has_fault(&self) -> bool {
+        self.connected && (self.oil_overheat() || self.oil_pressure_is_low)
+    }
 }
 impl SimulationElement for ConstantSpeedDrive {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.oil_leak.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.oil_inlet_temperature_id, self.oil_inlet_temperature);
         writer.write(&self.oil_outlet_temperature_id, self.oil_outlet_temperature);
+        writer.write(&self.oil_pressure_is_low_id, self.oil_pressure_is_low);
+        writer.write(&self.oil_overheat_id, self.oil_overheat());
         writer.write(&self.is_connected_id, self.connected);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.oil_inlet_temperature);
+        state.write(self.oil_outlet_temperature);
+        state.write(self.oil_pressure_is_low);
+        state.write(self.connected);
+        state.write(self.output_speed);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.oil_inlet_temperature = state.read();
+        self.oil_outlet_temperature = state.read();
+        self.oil_pressure_is_low = state.read();
+        self.connected = state.read();
+        self.output_speed = state.read();
+    }
 }
 
 pub struct DirectDrive {
@@ -338,9 +475,11 @@ pub struct DirectDrive {
     is_connected_id: VariableIdentifier,
     connected: bool,
     output_speed: AngularVelocity,
+    heat_rejected_to_fuel: Power,
 }
 impl DirectDrive {
     const TRANSMISSION_RATIO: f64 = 1.95;
+    const OIL_COOLER_HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 100.;
 
     fn new(context: &mut InitContext, number: usize) -> Self {
         Self {
@@ -353,6 +492,7 @@ impl DirectDrive {
                 .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_IS_CONNECTED", number)),
             connected: true,
             output_speed: AngularVelocity::default(),
+            heat_rejected_to_fuel: Power::default(),
         }
     }
 
@@ -374,18 +514,17 @@ impl DirectDrive {
 
     fn get_target_temperature(
         &self,
-        context: &UpdateContext,
         corrected_n2: Ratio,
+        fuel_temperature: ThermodynamicTemperature,
     ) -> ThermodynamicTemperature {
         const TEMPERATURE_TO_RPM_FACTOR: f64 = 1.8;
 
         if !self.connected {
-            return context.ambient_temperature();
+            return fuel_temperature;
         }
 
-        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
-        let target_idg =
-            corrected_n2.get::<percent>() * TEMPERATURE_TO_RPM_FACTOR + ambient_temperature;
+        let target_idg = corrected_n2.get::<percent>() * TEMPERATURE_TO_RPM_FACTOR
+            + fuel_temperature.get::<degree_celsius>();
 
         // TODO improve this function with feedback @komp provides.
 
@@ -397,7 +536,13 @@ impl EngineGeneratorDrive for DirectDrive {
         Self::new(context, number)
     }
 
-    fn update_drive(&mut self, context: &UpdateContext, engine: &impl Engine) {
+    fn update_drive(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl Engine,
+        _: Ratio,
+        fuel_temperature: ThermodynamicTemperature,
+    ) {
         self.output_speed = if self.connected {
             engine.gearbox_speed() * Self::TRANSMISSION_RATIO
         } else {
@@ -406,14 +551,29 @@ impl EngineGeneratorDrive for DirectDrive {
 
         self.update_temperature(
             context,
-            self.get_target_temperature(context, engine.corrected_n2()),
-        )
+            self.get_target_temperature(engine.corrected_n2(), fuel_temperature),
+        );
+
+        self.heat_rejected_to_fuel = if self.connected {
+            Power::new::<watt>(
+                (Self::OIL_COOLER_HEAT_TRANSFER_WATT_PER_KELVIN
+                    * (self.oil_outlet_temperature.get::<degree_celsius>()
+                        - fuel_temperature.get::<degree_celsius>()))
+                .max(0.),
+            )
+        } else {
+            Power::default()
+        };
     }
 
     fn output_speed(&self) -> AngularVelocity {
         self.output_speed
     }
 
+    fn heat_rejected_to_fuel(&self) -> Power {
+        self.heat_rejected_to_fuel
+    }
+
     fn disconnect(&mut self) {
         self.connected = false;
     }
@@ -421,12 +581,30 @@ impl EngineGeneratorDrive for DirectDrive {
     fn is_connected(&self) -> bool {
         self.connected
     }
+
+    fn has_fault(&self) -> bool {
+        false
+    }
 }
 impl SimulationElement for DirectDrive {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.oil_outlet_temperature_id, self.oil_outlet_temperature);
         writer.write(&self.is_connected_id, self.connected);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.oil_outlet_temperature);
+        state.write(self.connected);
+        state.write(self.output_speed);
+        state.write(self.heat_rejected_to_fuel);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.oil_outlet_temperature = state.read();
+        self.connected = state.read();
+        self.output_speed = state.read();
+        self.heat_rejected_to_fuel = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -626,6 +804,10 @@ mod tests {
                 self.gen_push_button_on = false;
             }
 
+            fn gen_push_button_on(&mut self) {
+                self.gen_push_button_on = true;
+            }
+
             fn release_fire_push_button(&mut self) {
                 self.fire_push_button_released = true;
             }
@@ -673,6 +855,7 @@ mod tests {
                     &TestEngine::new(Ratio::new::<percent>(if self.running { 80. } else { 0. })),
                     &TestOverhead::new(self.gen_push_button_on, self.idg_push_button_released),
                     &TestFireOverhead::new(self.fire_push_button_released),
+                    context.ambient_temperature(),
                 );
                 electricity.supplied_by(&self.engine_gen);
                 electricity.flow(&self.engine_gen, &self.bus);
@@ -923,6 +1106,21 @@ mod tests {
             assert!(test_bed.load() > Ratio::new::<percent>(0.));
         }
 
+        #[test]
+        fn snapshot_of_starting_generator_restores_all_written_variables() {
+            let mut test_bed = EngineGeneratorTestBed::with_shutdown_engine();
+            test_bed.run_without_delta();
+
+            test_bed.command(|a| a.start_engine());
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(50000.)));
+            test_bed.run_with_delta(Duration::from_millis(200));
+
+            let differing =
+                test_bed.variables_differing_after_snapshot_round_trip(Duration::from_millis(200));
+
+            assert!(differing.is_empty(), "{:?}", differing);
+        }
+
         #[test]
         fn when_load_below_maximum_it_is_normal() {
             let mut test_bed = EngineGeneratorTestBed::with_running_engine();
@@ -989,6 +1187,36 @@ mod tests {
             assert!(!test_bed.query(|a| a.generator_output_within_normal_parameters_before_processing_power_consumption_report()));
         }
 
+        #[test]
+        fn when_feeder_fault_provides_no_output() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::GeneratorFeeder(1));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+            assert!(!test_bed.frequency_is_normal());
+        }
+
+        #[test]
+        fn gcu_trip_is_reset_by_switching_gen_push_button_off_and_on() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::GeneratorFeeder(1));
+            test_bed.run();
+            test_bed.unfail(FailureType::GeneratorFeeder(1));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run();
+
+            assert!(test_bed.generator_is_powered());
+        }
+
         #[test]
         fn writes_its_state() {
             let mut test_bed = EngineGeneratorTestBed::with_running_engine();
@@ -1000,6 +1228,7 @@ mod tests {
             assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_FREQUENCY_NORMAL"));
             assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_LOAD"));
             assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_LOAD_NORMAL"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_GCU_FAULT_WORD"));
         }
     }
 
@@ -1126,8 +1355,151 @@ mod tests {
             assert!(!test_bed.query_element(|e| e.is_connected()));
         }
 
+        #[test]
+        fn idg_writes_its_oil_state() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg));
+            test_bed.run();
+
+            assert!(
+                test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_INLET_TEMPERATURE")
+            );
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_PRESSURE_IS_LOW"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_OVERHEAT"));
+        }
+
+        #[test]
+        fn idg_oil_is_cooled_by_fuel_before_returning_to_the_inlet() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(0.5));
+            test_bed.run_with_delta(Duration::from_secs(120));
+
+            assert!(
+                test_bed.query_element(|e| e.oil_inlet_temperature)
+                    < test_bed.query_element(|e| e.oil_outlet_temperature)
+            );
+        }
+
+        #[test]
+        fn loaded_idg_runs_hotter_than_unloaded_idg() {
+            let mut unloaded = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(0.));
+            unloaded.run_with_delta(Duration::from_secs(120));
+
+            let mut loaded = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(1.));
+            loaded.run_with_delta(Duration::from_secs(120));
+
+            assert!(
+                loaded.query_element(|e| e.oil_outlet_temperature)
+                    > unloaded.query_element(|e| e.oil_outlet_temperature)
+            );
+        }
+
+        #[test]
+        fn normally_loaded_idg_has_no_fault() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(1.));
+            test_bed.run_with_delta(Duration::from_secs(300));
+
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+            assert!(test_bed.query_element(|e| e.is_connected()));
+        }
+
+        #[test]
+        fn overloaded_idg_overheats_and_has_fault() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(1.5));
+            test_bed.run_with_delta(Duration::from_secs(300));
+
+            let overheat: bool = test_bed.read_by_name("ELEC_ENG_GEN_1_IDG_OIL_OVERHEAT");
+            assert!(overheat);
+            assert!(test_bed.query_element(|e| e.has_fault()));
+            assert!(test_bed.query_element(|e| e.is_connected()));
+        }
+
+        #[test]
+        fn severely_overheated_idg_disconnects_automatically() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(2.));
+            test_bed.run_with_delta(Duration::from_secs(300));
+
+            assert!(!test_bed.query_element(|e| e.is_connected()));
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+        }
+
+        #[test]
+        fn oil_leak_causes_low_oil_pressure_fault() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(0.3));
+            test_bed.fail(FailureType::GeneratorDriveOilLeak(1));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            let pressure_is_low: bool =
+                test_bed.read_by_name("ELEC_ENG_GEN_1_IDG_OIL_PRESSURE_IS_LOW");
+            assert!(pressure_is_low);
+            assert!(test_bed.query_element(|e| e.has_fault()));
+        }
+
+        #[test]
+        fn oil_pressure_is_not_monitored_while_engine_is_shut_down() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_not_running);
+            test_bed.fail(FailureType::GeneratorDriveOilLeak(1));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+        }
+
+        #[test]
+        fn oil_leak_eventually_overheats_and_disconnects_idg() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(0.3));
+            test_bed.fail(FailureType::GeneratorDriveOilLeak(1));
+            test_bed.run_with_delta(Duration::from_secs(300));
+
+            assert!(!test_bed.query_element(|e| e.is_connected()));
+        }
+
+        #[test]
+        fn loaded_idg_rejects_more_heat_to_fuel_than_unloaded_idg() {
+            let mut unloaded = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(0.));
+            unloaded.run_with_delta(Duration::from_secs(120));
+
+            let mut loaded = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(1.));
+            loaded.run_with_delta(Duration::from_secs(120));
+
+            assert!(unloaded.query_element(|e| e.heat_rejected_to_fuel()) > Power::default());
+            assert!(
+                loaded.query_element(|e| e.heat_rejected_to_fuel())
+                    > unloaded.query_element(|e| e.heat_rejected_to_fuel())
+            );
+        }
+
+        #[test]
+        fn idg_oil_temperature_follows_fuel_temperature() {
+            let mut cold_fuel = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_fuel_temperature(-30.));
+            cold_fuel.run_with_delta(Duration::from_secs(300));
+
+            let mut warm_fuel = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_fuel_temperature(30.));
+            warm_fuel.run_with_delta(Duration::from_secs(300));
+
+            assert!(
+                cold_fuel.query_element(|e| e.oil_outlet_temperature)
+                    < warm_fuel.query_element(|e| e.oil_outlet_temperature)
+            );
+        }
+
         fn engine_not_running(drive: &mut impl EngineGeneratorDrive, context: &UpdateContext) {
-            drive.update_drive(context, &TestEngine::new(Ratio::new::<percent>(0.)))
+            drive.update_drive(
+                context,
+                &TestEngine::new(Ratio::new::<percent>(0.)),
+                Ratio::default(),
+                context.ambient_temperature(),
+            )
         }
 
         fn engine_running_above_threshold<T: EngineGeneratorDrive>(
@@ -1137,7 +1509,36 @@ mod tests {
                 if idg_push_button_is_released {
                     drive.disconnect()
                 }
-                drive.update_drive(context, &TestEngine::new(Ratio::new::<percent>(80.)))
+                drive.update_drive(
+                    context,
+                    &TestEngine::new(Ratio::new::<percent>(80.)),
+                    Ratio::default(),
+                    context.ambient_temperature(),
+                )
+            }
+        }
+
+        fn engine_running_with_load(load: f64) -> impl Fn(&mut ConstantSpeedDrive, &UpdateContext) {
+            move |drive: &mut ConstantSpeedDrive, context: &UpdateContext| {
+                drive.update_drive(
+                    context,
+                    &TestEngine::new(Ratio::new::<percent>(80.)),
+                    Ratio::new::<ratio>(load),
+                    context.ambient_temperature(),
+                )
+            }
+        }
+
+        fn engine_running_with_fuel_temperature(
+            fuel_temperature_celsius: f64,
+        ) -> impl Fn(&mut ConstantSpeedDrive, &UpdateContext) {
+            move |drive: &mut ConstantSpeedDrive, context: &UpdateContext| {
+                drive.update_drive(
+                    context,
+                    &TestEngine::new(Ratio::new::<percent>(80.)),
+                    Ratio::new::<ratio>(0.5),
+                    ThermodynamicTemperature::new::<degree_celsius>(fuel_temperature_celsius),
+                )
             }
         }
     }
@@ -3,6 +3,7 @@ use uom::si::{electric_potential::volt, f64::*, frequency::hertz};
 use crate::{
     shared::PowerConsumptionReport,
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
         VariableIdentifier,
     },
@@ -99,6 +100,18 @@ impl SimulationElement for ExternalPowerSource {
             ElectricPotential::new::<volt>(0.)
         };
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_connected);
+        state.write(self.output_frequency);
+        state.write(self.output_potential);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_connected = state.read();
+        self.output_frequency = state.read();
+        self.output_potential = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,585 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        DelayedTrueLogicGate,
+    },
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::{ops::RangeInclusive, time::Duration};
+use uom::si::{
+    electric_potential::volt,
+    f64::*,
+    frequency::hertz,
+    ratio::{percent, ratio},
+};
+
+/// A protection monitored by the generator control unit. Once the monitored condition
+/// has been present for the protection's delay, the protection trips and remains latched
+/// until the generator push button is switched off.
+struct Protection {
+    fault_word_bit: u8,
+    delay: Duration,
+    detection: DelayedTrueLogicGate,
+    is_tripped: bool,
+}
+impl Protection {
+    fn new(fault_word_bit: u8, delay: Duration) -> Self {
+        Self {
+            fault_word_bit,
+            delay,
+            detection: DelayedTrueLogicGate::new(delay),
+            is_tripped: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, condition: bool) {
+        self.detection.update(context, condition);
+        self.is_tripped |= self.detection.output();
+    }
+
+    fn reset(&mut self) {
+        self.detection = DelayedTrueLogicGate::new(self.delay);
+        self.is_tripped = false;
+    }
+
+    fn is_tripped(&self) -> bool {
+        self.is_tripped
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.detection.save_state(state);
+        state.write(self.is_tripped);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.detection.restore_state(state);
+        self.is_tripped = state.read();
+    }
+}
+
+/// The generator control unit (GCU) regulates and protects an engine or APU generator.
+///
+/// The GCU excites the generator while its GEN push button is on and none of its protections
+/// has tripped. It monitors the generator's output for over and under voltage, over and under
+/// frequency, overload and differential current (a fault in the feeder between the generator
+/// and its line contactor). A tripped protection de-excites the generator and stays latched
+/// until the GEN push button is switched off, after which the generator can be reset by
+/// switching it back on.
+///
+/// Under voltage and under frequency are only monitored once the generator provided stable
+/// output and are inhibited during an underspeed, such that a normal engine or APU shutdown
+/// doesn't latch a fault.
+pub struct GeneratorControlUnit {
+    fault_word_id: VariableIdentifier,
+
+    normal_potential: RangeInclusive<f64>,
+    normal_frequency: RangeInclusive<f64>,
+    stabilization_time: Duration,
+
+    is_on: bool,
+    frequency: Frequency,
+    stable_duration: Duration,
+    under_protections_armed: bool,
+
+    over_voltage: Protection,
+    under_voltage: Protection,
+    over_frequency: Protection,
+    under_frequency: Protection,
+    differential_current: Protection,
+    overload: Protection,
+
+    feeder_fault: Failure,
+}
+impl GeneratorControlUnit {
+    const OVER_VOLTAGE_THRESHOLD_VOLT: f64 = 130.;
+    const UNDER_VOLTAGE_THRESHOLD_VOLT: f64 = 100.;
+    const OVER_FREQUENCY_MARGIN: f64 = 1.05;
+    const UNDERSPEED_MARGIN: f64 = 0.95;
+    const DIFFERENTIAL_CURRENT_THRESHOLD_AMPERE: f64 = 45.;
+    // A fault between the generator and its line contactor draws a current far beyond
+    // the differential current threshold.
+    const FEEDER_FAULT_CURRENT_AMPERE: f64 = 400.;
+    const OVERLOAD_THRESHOLD_PERCENT: f64 = 150.;
+
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        normal_potential: RangeInclusive<f64>,
+        normal_frequency: RangeInclusive<f64>,
+        stabilization_time: Duration,
+        feeder_fault: FailureType,
+    ) -> Self {
+        Self {
+            fault_word_id: context.get_identifier(format!("ELEC_{}_GCU_FAULT_WORD", id)),
+
+            normal_potential,
+            normal_frequency,
+            stabilization_time,
+
+            is_on: true,
+            frequency: Frequency::new::<hertz>(0.),
+            stable_duration: stabilization_time,
+            under_protections_armed: false,
+
+            over_voltage: Protection::new(11, Duration::from_millis(500)),
+            under_voltage: Protection::new(12, Duration::from_millis(4500)),
+            over_frequency: Protection::new(13, Duration::from_secs(1)),
+            under_frequency: Protection::new(14, Duration::from_millis(4500)),
+            differential_current: Protection::new(15, Duration::from_millis(35)),
+            overload: Protection::new(16, Duration::from_secs(5)),
+
+            feeder_fault: Failure::new(feeder_fault),
+        }
+    }
+
+    /// Updates the GCU with the state of the GEN push button and the generator's
+    /// measured frequency, output potential and load.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        push_button_is_on: bool,
+        frequency: Frequency,
+        potential: ElectricPotential,
+        load: Ratio,
+    ) {
+        self.is_on = push_button_is_on;
+        self.frequency = frequency;
+
+        if !self.is_on {
+            self.reset();
+            return;
+        }
+
+        self.update_stable_duration(context);
+
+        if !self.excites_generator() {
+            return;
+        }
+
+        if self.is_underspeed() {
+            self.under_protections_armed = false;
+        } else if self.provides_stable_output() {
+            self.under_protections_armed = true;
+        }
+
+        let volts = potential.get::<volt>();
+        let hz = frequency.get::<hertz>();
+
+        self.over_voltage
+            .update(context, volts > Self::OVER_VOLTAGE_THRESHOLD_VOLT);
+        self.under_voltage.update(
+            context,
+            self.under_protections_armed
+                && self.provides_stable_output()
+                && volts < Self::UNDER_VOLTAGE_THRESHOLD_VOLT,
+        );
+        self.over_frequency.update(
+            context,
+            hz > self.normal_frequency.end() * Self::OVER_FREQUENCY_MARGIN,
+        );
+        self.under_frequency.update(
+            context,
+            self.under_protections_armed
+                && !self.is_underspeed()
+                && hz < *self.normal_frequency.start(),
+        );
+        self.differential_current.update(
+            context,
+            self.differential_current_ampere() > Self::DIFFERENTIAL_CURRENT_THRESHOLD_AMPERE,
+        );
+        self.overload.update(
+            context,
+            load.get::<percent>() > Self::OVERLOAD_THRESHOLD_PERCENT,
+        );
+    }
+
+    fn update_stable_duration(&mut self, context: &UpdateContext) {
+        let new_duration = if self.frequency_normal(self.frequency) {
+            self.stable_duration + context.delta()
+        } else {
+            Duration::ZERO
+        };
+
+        self.stable_duration = new_duration.clamp(Duration::ZERO, self.stabilization_time);
+    }
+
+    fn reset(&mut self) {
+        self.stable_duration = Duration::ZERO;
+        self.under_protections_armed = false;
+        self.protections_mut()
+            .into_iter()
+            .for_each(|protection| protection.reset());
+    }
+
+    fn differential_current_ampere(&self) -> f64 {
+        if self.feeder_fault.is_active() {
+            Self::FEEDER_FAULT_CURRENT_AMPERE
+        } else {
+            0.
+        }
+    }
+
+    fn is_underspeed(&self) -> bool {
+        self.frequency.get::<hertz>() < self.normal_frequency.start() * Self::UNDERSPEED_MARGIN
+    }
+
+    fn protections(&self) -> [&Protection; 6] {
+        [
+            &self.over_voltage,
+            &self.under_voltage,
+            &self.over_frequency,
+            &self.under_frequency,
+            &self.differential_current,
+            &self.overload,
+        ]
+    }
+
+    fn protections_mut(&mut self) -> [&mut Protection; 6] {
+        [
+            &mut self.over_voltage,
+            &mut self.under_voltage,
+            &mut self.over_frequency,
+            &mut self.under_frequency,
+            &mut self.differential_current,
+            &mut self.overload,
+        ]
+    }
+
+    /// Indicates if any of the protections tripped.
+    pub fn has_tripped(&self) -> bool {
+        self.protections()
+            .iter()
+            .any(|protection| protection.is_tripped())
+    }
+
+    /// Indicates if the generator's field is excited, which is the case
+    /// when the GEN push button is on and no protection tripped.
+    pub fn excites_generator(&self) -> bool {
+        self.is_on && !self.has_tripped()
+    }
+
+    /// Indicates if the generator is excited and its frequency has been
+    /// within normal parameters for the stabilization time.
+    pub fn provides_stable_output(&self) -> bool {
+        self.excites_generator()
+            && self.frequency_normal(self.frequency)
+            && self.stable_duration == self.stabilization_time
+    }
+
+    pub fn potential_normal(&self, potential: ElectricPotential) -> bool {
+        self.normal_potential.contains(&potential.get::<volt>())
+    }
+
+    pub fn frequency_normal(&self, frequency: Frequency) -> bool {
+        self.normal_frequency.contains(&frequency.get::<hertz>())
+    }
+
+    pub fn load_normal(&self, load: Ratio) -> bool {
+        load <= Ratio::new::<ratio>(1.)
+    }
+
+    /// The fault word is transmitted to the ECAM and contains the
+    /// latched protection trips:
+    /// - Bit 11: over voltage
+    /// - Bit 12: under voltage
+    /// - Bit 13: over frequency
+    /// - Bit 14: under frequency
+    /// - Bit 15: differential current
+    /// - Bit 16: overload
+    /// - Bit 17: any protection tripped
+    pub fn fault_word(&self) -> Arinc429Word<u32> {
+        let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+        for protection in self.protections() {
+            word.set_bit(protection.fault_word_bit, protection.is_tripped());
+        }
+        word.set_bit(17, self.has_tripped());
+
+        word
+    }
+}
+impl SimulationElement for GeneratorControlUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.feeder_fault.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.fault_word_id, self.fault_word());
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_on);
+        state.write(self.frequency);
+        state.write(self.stable_duration);
+        state.write(self.under_protections_armed);
+        for protection in self.protections() {
+            protection.save_state(state);
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_on = state.read();
+        self.frequency = state.read();
+        self.stable_duration = state.read();
+        self.under_protections_armed = state.read();
+        for protection in self.protections_mut() {
+            protection.restore_state(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed},
+        Aircraft,
+    };
+
+    struct TestAircraft {
+        gcu: GeneratorControlUnit,
+        push_button_is_on: bool,
+        frequency: Frequency,
+        potential: ElectricPotential,
+        load: Ratio,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                gcu: GeneratorControlUnit::new(
+                    context,
+                    "ENG_GEN_1",
+                    110.0..=120.0,
+                    390.0..=410.0,
+                    Duration::from_millis(500),
+                    FailureType::GeneratorFeeder(1),
+                ),
+                push_button_is_on: true,
+                frequency: Frequency::new::<hertz>(400.),
+                potential: ElectricPotential::new::<volt>(115.),
+                load: Ratio::new::<percent>(50.),
+            }
+        }
+
+        fn set_push_button(&mut self, is_on: bool) {
+            self.push_button_is_on = is_on;
+        }
+
+        fn set_frequency(&mut self, hz: f64) {
+            self.frequency = Frequency::new::<hertz>(hz);
+        }
+
+        fn set_potential(&mut self, volts: f64) {
+            self.potential = ElectricPotential::new::<volt>(volts);
+        }
+
+        fn set_load(&mut self, percentage: f64) {
+            self.load = Ratio::new::<percent>(percentage);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.gcu.update(
+                context,
+                self.push_button_is_on,
+                self.frequency,
+                self.potential,
+                self.load,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.gcu.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn excites_generator(test_bed: &SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.query(|a| a.gcu.excites_generator())
+    }
+
+    fn fault_word(test_bed: &mut SimulationTestBed<TestAircraft>) -> Arinc429Word<u32> {
+        test_bed.read_by_name("ELEC_ENG_GEN_1_GCU_FAULT_WORD")
+    }
+
+    #[test]
+    fn excites_generator_with_normal_output() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(excites_generator(&test_bed));
+        assert!(test_bed.query(|a| a.gcu.provides_stable_output()));
+        assert_eq!(fault_word(&mut test_bed).value(), 0);
+    }
+
+    #[test]
+    fn does_not_excite_generator_when_push_button_off() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_push_button(false));
+        test_bed.run();
+
+        assert!(!excites_generator(&test_bed));
+        assert!(!test_bed.query(|a| a.gcu.has_tripped()));
+    }
+
+    #[test]
+    fn over_voltage_trips() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_potential(135.));
+        test_bed.run();
+
+        assert!(!excites_generator(&test_bed));
+        assert!(fault_word(&mut test_bed).get_bit(11));
+        assert!(fault_word(&mut test_bed).get_bit(17));
+    }
+
+    #[test]
+    fn short_over_voltage_does_not_trip() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_potential(135.));
+        test_bed.run_with_delta(Duration::from_millis(400));
+        test_bed.command(|a| a.set_potential(115.));
+        test_bed.run_with_delta(Duration::from_millis(400));
+
+        assert!(excites_generator(&test_bed));
+    }
+
+    #[test]
+    fn under_voltage_trips_after_delay() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.command(|a| a.set_potential(90.));
+        test_bed.run_with_delta(Duration::from_secs(4));
+
+        assert!(excites_generator(&test_bed));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!excites_generator(&test_bed));
+        assert!(fault_word(&mut test_bed).get_bit(12));
+    }
+
+    #[test]
+    fn over_frequency_trips() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_frequency(440.));
+        test_bed.run();
+
+        assert!(!excites_generator(&test_bed));
+        assert!(fault_word(&mut test_bed).get_bit(13));
+    }
+
+    #[test]
+    fn under_frequency_trips_when_not_underspeed() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.command(|a| a.set_frequency(380.));
+        for _ in 0..5 {
+            test_bed.run();
+        }
+
+        assert!(!excites_generator(&test_bed));
+        assert!(fault_word(&mut test_bed).get_bit(14));
+    }
+
+    #[test]
+    fn underspeed_does_not_trip() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.command(|a| {
+            a.set_frequency(0.);
+            a.set_potential(0.);
+        });
+        for _ in 0..10 {
+            test_bed.run();
+        }
+
+        assert!(excites_generator(&test_bed));
+        assert!(!test_bed.query(|a| a.gcu.has_tripped()));
+    }
+
+    #[test]
+    fn overload_trips_after_delay() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_load(160.));
+        for _ in 0..4 {
+            test_bed.run();
+        }
+
+        assert!(excites_generator(&test_bed));
+
+        test_bed.run();
+
+        assert!(!excites_generator(&test_bed));
+        assert!(fault_word(&mut test_bed).get_bit(16));
+    }
+
+    #[test]
+    fn feeder_fault_trips_differential_protection() {
+        let mut test_bed = test_bed();
+
+        test_bed.fail(FailureType::GeneratorFeeder(1));
+        test_bed.run();
+
+        assert!(!excites_generator(&test_bed));
+        assert!(fault_word(&mut test_bed).get_bit(15));
+    }
+
+    #[test]
+    fn trip_is_latched_until_push_button_switched_off() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_potential(135.));
+        test_bed.run();
+        test_bed.command(|a| a.set_potential(0.));
+        test_bed.run();
+
+        assert!(!excites_generator(&test_bed));
+
+        test_bed.command(|a| a.set_push_button(false));
+        test_bed.run();
+
+        assert_eq!(fault_word(&mut test_bed).value(), 0);
+
+        test_bed.command(|a| a.set_push_button(true));
+        test_bed.run();
+
+        assert!(excites_generator(&test_bed));
+    }
+
+    #[test]
+    fn only_provides_stable_output_after_stabilization_time() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_frequency(0.));
+        test_bed.run();
+        test_bed.command(|a| a.set_frequency(400.));
+        test_bed.run_with_delta(Duration::from_millis(499));
+
+        assert!(!test_bed.query(|a| a.gcu.provides_stable_output()));
+
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.query(|a| a.gcu.provides_stable_output()));
+    }
+}
@@ -3,10 +3,13 @@
 mod battery;
 mod battery_charge_limiter;
 mod battery_charge_rectifier_unit;
+mod circuit_breaker;
 pub mod consumption;
 mod emergency_generator;
 mod engine_generator;
 mod external_power_source;
+pub mod generator_control_unit;
+mod network;
 mod ram_air_turbine;
 mod static_inverter;
 mod transformer_rectifier;
@@ -19,7 +22,10 @@ use std::{
 
 use crate::{
     failures::{Failure, FailureType},
-    simulation::{InitContext, VariableIdentifier},
+    simulation::{
+        snapshot::{StateReader, StateValue, StateWriter},
+        InitContext, VariableIdentifier,
+    },
 };
 use crate::{
     shared::{
@@ -27,12 +33,14 @@ use crate::{
         PotentialOrigin, PowerConsumptionReport,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
+        UpdateContext, Write,
     },
 };
 pub use battery::Battery;
 pub use battery_charge_limiter::BatteryChargeLimiter;
 pub use battery_charge_rectifier_unit::BatteryChargeRectifierUnit;
+pub use circuit_breaker::CircuitBreaker;
 pub use emergency_generator::EmergencyGenerator;
 pub use engine_generator::{
     EngineGenerator, IntegratedDriveGenerator, VariableFrequencyGenerator,
@@ -40,9 +48,12 @@ pub use engine_generator::{
 };
 pub use external_power_source::ExternalPowerSource;
 use fxhash::{FxHashMap, FxHashSet};
+use network::{NetworkSolution, ResistiveNetwork};
 pub use static_inverter::StaticInverter;
 pub use transformer_rectifier::TransformerRectifier;
-use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
+use uom::si::{
+    electric_potential::volt, electrical_resistance::ohm, f64::*, power::watt, velocity::knot,
+};
 
 pub use ram_air_turbine::{GeneratorControlUnit, RamAirTurbine};
 
@@ -57,6 +68,10 @@ pub trait EngineGeneratorPushButtons {
     fn idg_push_button_is_released(&self, number: usize) -> bool;
 }
 
+pub trait ApuGeneratorPushButtons {
+    fn apu_gen_push_button_is_on(&self, number: usize) -> bool;
+}
+
 pub trait BatteryPushButtons {
     fn bat_is_auto(&self, number: usize) -> bool;
 }
@@ -107,6 +122,51 @@ impl SimulationElement for Contactor {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.closed_id, self.is_closed());
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.closed);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.closed = state.read();
+    }
+}
+
+/// The feeders of an aircraft's electrical system. Their resistance is only taken into
+/// account when enabled through the `ELEC_FEEDER_RESISTANCE_ENABLED` variable, otherwise
+/// electricity flows through them as through any other conductive element.
+pub struct Feeders {
+    is_enabled_id: VariableIdentifier,
+    is_enabled: bool,
+}
+impl Feeders {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            is_enabled_id: context.get_identifier("ELEC_FEEDER_RESISTANCE_ENABLED".to_owned()),
+            is_enabled: false,
+        }
+    }
+
+    /// Flows electricity from the given output element to the given input element through
+    /// a feeder of the given resistance. See [`Electricity::flow_through_feeder()`].
+    pub fn flow(
+        &self,
+        electricity: &mut Electricity,
+        from_output: &impl ElectricalElement,
+        to_input: &impl ElectricalElement,
+        resistance: ElectricalResistance,
+    ) {
+        if self.is_enabled {
+            electricity.flow_through_feeder(from_output, to_input, resistance);
+        } else {
+            electricity.flow(from_output, to_input);
+        }
+    }
+}
+impl SimulationElement for Feeders {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_enabled = reader.read(&self.is_enabled_id);
+    }
 }
 
 pub struct ElectricalBus {
@@ -179,6 +239,14 @@ impl SimulationElement for ElectricalBus {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.potential = buses.potential_of(self.bus_type).raw();
     }
+
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        _: &UpdateContext,
+        report: &T,
+    ) {
+        self.potential = report.network_potential_of(self);
+    }
 }
 
 pub struct ElectricalStateWriter {
@@ -276,6 +344,10 @@ pub trait ProvideLoad {
     fn load_normal(&self) -> bool;
 }
 
+pub trait ProvideTemperature {
+    fn temperature(&self) -> ThermodynamicTemperature;
+}
+
 /// Determines if and for how long the aircraft is in an emergency electrical situation.
 pub struct EmergencyElectrical {
     is_active_for_duration: Duration,
@@ -310,6 +382,14 @@ impl EmergencyElectrical {
     fn active_duration(&self) -> Duration {
         self.is_active_for_duration
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_active_for_duration);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_active_for_duration = state.read();
+    }
 }
 impl Default for EmergencyElectrical {
     fn default() -> Self {
@@ -332,6 +412,13 @@ pub trait ElectricalElement {
 
 pub trait ElectricitySource: ElectricalElement {
     fn output_potential(&self) -> Potential;
+
+    /// Returns the resistance within the source, which causes the potential it provides
+    /// to drop as it supplies more current. Only taken into account when the source
+    /// is part of a network with feeders.
+    fn internal_resistance(&self) -> ElectricalResistance {
+        ElectricalResistance::new::<ohm>(0.)
+    }
 }
 
 pub trait ElectricityTransformer: ElectricalElement {
@@ -363,6 +450,8 @@ pub struct Electricity {
     next_identifier: ElectricalElementIdentifier,
     buses: FxHashMap<ElectricalBusType, ElectricalElementIdentifier>,
     potential: PotentialCollection,
+    network: ResistiveNetwork,
+    network_solution: NetworkSolution,
     none_potential: RefCell<Potential>,
 }
 impl Electricity {
@@ -371,12 +460,29 @@ impl Electricity {
             next_identifier: ElectricalElementIdentifier::first(),
             buses: Default::default(),
             potential: PotentialCollection::new(),
+            network: Default::default(),
+            network_solution: Default::default(),
             none_potential: RefCell::new(Potential::none()),
         }
     }
 
     pub(super) fn pre_tick(&mut self) {
         self.potential.clear();
+        self.network.clear();
+        self.network_solution = Default::default();
+    }
+
+    /// Saves the potential and power consumption of the elements as they were at the end
+    /// of the last tick. The network itself isn't saved, as it is built anew every tick.
+    pub(crate) fn save_state(&self, state: &mut StateWriter) {
+        self.potential.save_state(state);
+        self.network_solution.save_state(state);
+    }
+
+    pub(crate) fn restore_state(&mut self, state: &mut StateReader) {
+        self.pre_tick();
+        self.potential.restore_state(state);
+        self.network_solution.restore_state(state);
     }
 
     /// Flows electricity from the given output element to the given input element as long
@@ -412,6 +518,52 @@ impl Electricity {
         if from_output.is_conductive() && to_input.is_conductive() {
             self.potential
                 .flow(from_output.output_identifier(), to_input.input_identifier());
+            self.network
+                .connect(from_output.output_identifier(), to_input.input_identifier());
+        }
+    }
+
+    /// Flows electricity from the given output element to the given input element through
+    /// a feeder of the given resistance, as long as both elements are conductive.
+    ///
+    /// Electrical networks containing feeders are solved for the voltage drop across
+    /// their feeders and the [internal resistance](`ElectricitySource::internal_resistance()`)
+    /// of their sources once the power consumption is known. The resulting potential is
+    /// available through [`PowerConsumptionReport::network_potential_of()`] and
+    /// the consumption of each origin is divided according to the current it supplies.
+    /// ```rust
+    /// # use systems::{shared::ElectricalBusType, electrical::{Battery, Contactor, ElectricalBus, Electricity},
+    /// # simulation::{InitContext, VariableRegistry, VariableIdentifier}};
+    /// # use uom::si::{electrical_resistance::ohm, f64::ElectricalResistance};
+    /// # struct SomeVariableRegistry {}
+    /// # impl VariableRegistry for SomeVariableRegistry {
+    /// #     fn get(&mut self, name: String) -> VariableIdentifier {
+    /// #         VariableIdentifier::default()
+    /// #     }
+    /// # }
+    /// # let mut registry = SomeVariableRegistry {};
+    /// # let mut electricity = Electricity::new();
+    /// # let mut context = InitContext::new(Default::default(), &mut electricity, &mut registry);
+    /// let battery = Battery::full(&mut context, 1);
+    /// let contactor = Contactor::new(&mut context, "TEST");
+    ///
+    /// electricity.supplied_by(&battery);
+    /// electricity.flow_through_feeder(&battery, &contactor, ElectricalResistance::new::<ohm>(0.002));
+    /// ```
+    pub fn flow_through_feeder(
+        &mut self,
+        from_output: &impl ElectricalElement,
+        to_input: &impl ElectricalElement,
+        resistance: ElectricalResistance,
+    ) {
+        if from_output.is_conductive() && to_input.is_conductive() {
+            self.potential
+                .flow(from_output.output_identifier(), to_input.input_identifier());
+            self.network.connect_through_feeder(
+                from_output.output_identifier(),
+                to_input.input_identifier(),
+                resistance,
+            );
         }
     }
 
@@ -438,10 +590,11 @@ impl Electricity {
     /// ```
     pub fn supplied_by(&mut self, source: &impl ElectricitySource) {
         let output_identifier = source.output_identifier();
-        self.potential.supplied_by(
-            output_identifier,
-            source.output_potential().include(output_identifier),
-        )
+        let potential = source.output_potential();
+        self.network
+            .supplied_by(output_identifier, &potential, source.internal_resistance());
+        self.potential
+            .supplied_by(output_identifier, potential.include(output_identifier))
     }
 
     /// Transforms electricity within the given transformer.
@@ -475,6 +628,11 @@ impl Electricity {
             None => Potential::none(),
         };
 
+        self.network.supplied_by(
+            output_identifier,
+            &transformed_potential,
+            ElectricalResistance::new::<ohm>(0.),
+        );
         self.potential
             .supplied_by(output_identifier, transformed_potential);
     }
@@ -516,6 +674,23 @@ impl Electricity {
 
         let mut visitor = ConsumePowerInConvertersVisitor::new(context, self);
         element.accept(&mut visitor);
+
+        self.solve_network();
+    }
+
+    fn solve_network(&mut self) {
+        self.network_solution = self.network.solve(&self.potential);
+        for (origin, power) in self.network_solution.consumption_per_origin() {
+            self.potential.set_consumption_of(*origin, *power);
+        }
+    }
+
+    /// Returns the potential of the given element, including the voltage drop caused by
+    /// the resistance of the network the element is part of.
+    pub fn network_potential_of(&self, element: &impl ElectricalElement) -> ElectricPotential {
+        self.network_solution
+            .potential_of(element.output_identifier())
+            .unwrap_or_else(|| self.output_of(element).raw())
     }
 
     pub fn report_consumption_to(
@@ -592,9 +767,18 @@ impl PowerConsumptionReport for Electricity {
         self.potential.total_consumption_of(origin)
     }
 
+    fn total_consumption_from(&self, element: &impl ElectricalElement) -> Power {
+        self.potential
+            .total_consumption_from(element.output_identifier())
+    }
+
     fn is_powered(&self, element: &impl ElectricalElement) -> bool {
         self.is_powered(element)
     }
+
+    fn network_potential_of(&self, element: &impl ElectricalElement) -> ElectricPotential {
+        self.network_potential_of(element)
+    }
 }
 impl Default for Electricity {
     fn default() -> Self {
@@ -803,6 +987,37 @@ impl Potential {
 
         self.origins.symmetric_difference(&set).count() == 0
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.raw);
+
+        let mut origins: Vec<_> = self.origins.iter().copied().collect();
+        origins.sort_by_key(|origin| origin_key(*origin));
+        state.write_count(origins.len());
+        for origin in origins {
+            save_origin(origin, state);
+        }
+
+        let mut elements: Vec<_> = self.elements.iter().copied().collect();
+        elements.sort_by_key(|element| element.0);
+        state.write_count(elements.len());
+        for element in elements {
+            state.write(element);
+        }
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        let mut potential = Self::none();
+        potential.raw = state.read();
+        for _ in 0..state.read_count() {
+            potential.origins.insert(restore_origin(state));
+        }
+        for _ in 0..state.read_count() {
+            potential.elements.insert(state.read());
+        }
+
+        potential
+    }
 }
 impl Default for Potential {
     fn default() -> Self {
@@ -810,23 +1025,70 @@ impl Default for Potential {
     }
 }
 
+/// Saves the origin as two values: its kind and its number.
+fn save_origin(origin: PotentialOrigin, state: &mut StateWriter) {
+    let (kind, number) = origin_key(origin);
+    state.write(kind as f64);
+    state.write(number as f64);
+}
+
+fn restore_origin(state: &mut StateReader) -> PotentialOrigin {
+    let kind = state.read::<f64>() as u8;
+    let number = state.read::<f64>() as usize;
+    match kind {
+        0 => PotentialOrigin::EngineGenerator(number),
+        1 => PotentialOrigin::ApuGenerator(number),
+        2 => PotentialOrigin::External,
+        3 => PotentialOrigin::EmergencyGenerator,
+        4 => PotentialOrigin::Battery(number),
+        5 => PotentialOrigin::TransformerRectifier(number),
+        _ => PotentialOrigin::StaticInverter,
+    }
+}
+
+/// Orders origins, such that saving the same state always results in the same values.
+fn origin_key(origin: PotentialOrigin) -> (u8, usize) {
+    match origin {
+        PotentialOrigin::EngineGenerator(number) => (0, number),
+        PotentialOrigin::ApuGenerator(number) => (1, number),
+        PotentialOrigin::External => (2, 0),
+        PotentialOrigin::EmergencyGenerator => (3, 0),
+        PotentialOrigin::Battery(number) => (4, number),
+        PotentialOrigin::TransformerRectifier(number) => (5, number),
+        PotentialOrigin::StaticInverter => (6, 0),
+    }
+}
+
+impl StateValue for ElectricalElementIdentifier {
+    fn to_state(self) -> f64 {
+        self.0 as f64
+    }
+
+    fn from_state(value: f64) -> Self {
+        Self(value as u32)
+    }
+}
+
 /// Maintains the many to one relationship from electrical elements to their electric potential.
 #[derive(Debug)]
 struct PotentialCollection {
     items: FxHashMap<ElectricalElementIdentifier, Rc<RefCell<Potential>>>,
     consumption_per_origin: FxHashMap<PotentialOrigin, Power>,
+    consumption_per_element: FxHashMap<ElectricalElementIdentifier, Power>,
 }
 impl PotentialCollection {
     fn new() -> Self {
         Self {
             items: Default::default(),
             consumption_per_origin: Default::default(),
+            consumption_per_element: Default::default(),
         }
     }
 
     fn clear(&mut self) {
         self.items.clear();
         self.consumption_per_origin.clear();
+        self.consumption_per_element.clear();
     }
 
     fn flow(
@@ -903,21 +1165,104 @@ impl PotentialCollection {
                 let y = self.consumption_per_origin.entry(*origin).or_default();
                 *y += power / potential.origin_count() as f64;
             }
+
+            *self.consumption_per_element.entry(identifier).or_default() += power;
         }
     }
 
+    fn set_consumption_of(&mut self, origin: PotentialOrigin, power: Power) {
+        self.consumption_per_origin.insert(origin, power);
+    }
+
     fn total_consumption_of(&self, origin: PotentialOrigin) -> Power {
         match self.consumption_per_origin.get(&origin) {
             Some(power) => *power,
             None => Power::new::<watt>(0.),
         }
     }
+
+    fn total_consumption_from(&self, identifier: ElectricalElementIdentifier) -> Power {
+        match self.consumption_per_element.get(&identifier) {
+            Some(power) => *power,
+            None => Power::new::<watt>(0.),
+        }
+    }
+
+    /// Saves every distinct potential once, followed by the elements which point to it.
+    fn save_state(&self, state: &mut StateWriter) {
+        let mut identifiers: Vec<_> = self.items.keys().copied().collect();
+        identifiers.sort_by_key(|identifier| identifier.0);
+
+        let mut groups: Vec<(&Rc<RefCell<Potential>>, Vec<ElectricalElementIdentifier>)> =
+            Vec::new();
+        for identifier in identifiers {
+            let potential = &self.items[&identifier];
+            match groups
+                .iter_mut()
+                .find(|(group, _)| Rc::ptr_eq(group, potential))
+            {
+                Some((_, identifiers)) => identifiers.push(identifier),
+                None => groups.push((potential, vec![identifier])),
+            }
+        }
+
+        state.write_count(groups.len());
+        for (potential, identifiers) in groups {
+            potential.as_ref().borrow().save_state(state);
+            state.write_count(identifiers.len());
+            for identifier in identifiers {
+                state.write(identifier);
+            }
+        }
+
+        let mut consumption_per_origin: Vec<_> = self.consumption_per_origin.iter().collect();
+        consumption_per_origin.sort_by_key(|(origin, _)| origin_key(**origin));
+        state.write_count(consumption_per_origin.len());
+        for (origin, power) in consumption_per_origin {
+            save_origin(*origin, state);
+            state.write(*power);
+        }
+
+        let mut consumption_per_element: Vec<_> = self.consumption_per_element.iter().collect();
+        consumption_per_element.sort_by_key(|(identifier, _)| identifier.0);
+        state.write_count(consumption_per_element.len());
+        for (identifier, power) in consumption_per_element {
+            state.write(*identifier);
+            state.write(*power);
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.clear();
+
+        for _ in 0..state.read_count() {
+            let potential = Rc::new(RefCell::new(Potential::restored(state)));
+            for _ in 0..state.read_count() {
+                self.items.insert(state.read(), Rc::clone(&potential));
+            }
+        }
+
+        for _ in 0..state.read_count() {
+            let origin = restore_origin(state);
+            self.consumption_per_origin.insert(origin, state.read());
+        }
+
+        for _ in 0..state.read_count() {
+            let identifier = state.read();
+            self.consumption_per_element
+                .insert(identifier, state.read());
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use uom::si::{electric_current::ampere, frequency::hertz, ratio::percent};
+    use uom::si::{
+        electric_current::ampere,
+        frequency::hertz,
+        ratio::{percent, ratio},
+    };
 
     struct StubElectricSource {}
     impl ProvideCurrent for StubElectricSource {
@@ -1025,19 +1370,33 @@ mod tests {
 
         struct ElectricalBusTestAircraft {
             bus: ElectricalBus,
+            bus_type: ElectricalBusType,
             battery: BatteryStub,
+            feeder_resistance: Option<ElectricalResistance>,
+            consumption: Power,
         }
         impl ElectricalBusTestAircraft {
             fn new(bus_type: ElectricalBusType, context: &mut InitContext) -> Self {
                 Self {
                     bus: ElectricalBus::new(context, bus_type),
+                    bus_type,
                     battery: BatteryStub::new(context),
+                    feeder_resistance: None,
+                    consumption: Power::new::<watt>(0.),
                 }
             }
 
             fn powered_by_battery_at(&mut self, potential: ElectricPotential) {
                 self.battery.set_potential(potential);
             }
+
+            fn powered_through_feeder_of(&mut self, resistance: ElectricalResistance) {
+                self.feeder_resistance = Some(resistance);
+            }
+
+            fn consume(&mut self, power: Power) {
+                self.consumption = power;
+            }
         }
         impl Aircraft for ElectricalBusTestAircraft {
             fn update_before_power_distribution(
@@ -1046,7 +1405,12 @@ mod tests {
                 electricity: &mut Electricity,
             ) {
                 electricity.supplied_by(&self.battery);
-                electricity.flow(&self.battery, &self.bus);
+                match self.feeder_resistance {
+                    Some(resistance) => {
+                        electricity.flow_through_feeder(&self.battery, &self.bus, resistance)
+                    }
+                    None => electricity.flow(&self.battery, &self.bus),
+                }
             }
         }
         impl SimulationElement for ElectricalBusTestAircraft {
@@ -1054,6 +1418,10 @@ mod tests {
                 self.bus.accept(visitor);
                 visitor.visit(self);
             }
+
+            fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, power: &mut T) {
+                power.consume_from_bus(self.bus_type, self.consumption);
+            }
         }
 
         #[test]
@@ -1090,6 +1458,45 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn bat_bus_potential_drops_under_load_when_powered_through_feeder() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                ElectricalBusTestAircraft::new(ElectricalBusType::DirectCurrentBattery, context)
+            });
+
+            test_bed.command(|a| a.powered_by_battery_at(ElectricPotential::new::<volt>(25.5)));
+            test_bed
+                .command(|a| a.powered_through_feeder_of(ElectricalResistance::new::<ohm>(0.01)));
+            test_bed.command(|a| a.consume(Power::new::<watt>(2000.)));
+            test_bed.run();
+
+            assert!(!ReadByName::<
+                SimulationTestBed<ElectricalBusTestAircraft>,
+                bool,
+            >::read_by_name(
+                &mut test_bed, "ELEC_DC_BAT_BUS_POTENTIAL_NORMAL"
+            ));
+        }
+
+        #[test]
+        fn bat_bus_potential_remains_normal_without_load_when_powered_through_feeder() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                ElectricalBusTestAircraft::new(ElectricalBusType::DirectCurrentBattery, context)
+            });
+
+            test_bed.command(|a| a.powered_by_battery_at(ElectricPotential::new::<volt>(25.5)));
+            test_bed
+                .command(|a| a.powered_through_feeder_of(ElectricalResistance::new::<ohm>(0.01)));
+            test_bed.run();
+
+            assert!(ReadByName::<
+                SimulationTestBed<ElectricalBusTestAircraft>,
+                bool,
+            >::read_by_name(
+                &mut test_bed, "ELEC_DC_BAT_BUS_POTENTIAL_NORMAL"
+            ));
+        }
+
         #[test]
         fn writes_potential_normal_when_bat_bus() {
             let mut test_bed = SimulationTestBed::new(|context| {
@@ -1589,6 +1996,7 @@ mod tests {
             identifier: ElectricalElementIdentifier,
             is_powered: bool,
             is_conductive: bool,
+            internal_resistance: ElectricalResistance,
         }
         impl TestElectricalElement {
             fn new(identifier_provider: &mut impl ElectricalElementIdentifierProvider) -> Self {
@@ -1597,6 +2005,7 @@ mod tests {
                     identifier: identifier_provider.next_electrical_identifier(),
                     is_powered: false,
                     is_conductive: true,
+                    internal_resistance: ElectricalResistance::new::<ohm>(0.),
                 }
             }
 
@@ -1606,6 +2015,7 @@ mod tests {
                     identifier: ElectricalElementIdentifier::first(),
                     is_powered: false,
                     is_conductive: true,
+                    internal_resistance: ElectricalResistance::new::<ohm>(0.),
                 }
             }
 
@@ -1623,6 +2033,11 @@ mod tests {
                 self.number = number;
                 self
             }
+
+            fn with_internal_resistance(mut self, resistance: f64) -> Self {
+                self.internal_resistance = ElectricalResistance::new::<ohm>(resistance);
+                self
+            }
         }
         impl ElectricalElement for TestElectricalElement {
             fn input_identifier(&self) -> ElectricalElementIdentifier {
@@ -1648,6 +2063,10 @@ mod tests {
                     Potential::none()
                 }
             }
+
+            fn internal_resistance(&self) -> ElectricalResistance {
+                self.internal_resistance
+            }
         }
 
         struct TestBus {
@@ -2105,5 +2524,231 @@ mod tests {
                 Power::new::<watt>(200.)
             );
         }
+
+        #[test]
+        fn network_without_feeders_is_not_solved() {
+            let mut electricity = Electricity::new();
+            let generator_1 = TestElectricalElement::new(&mut electricity)
+                .power()
+                .with_internal_resistance(0.1);
+            let generator_2 = TestElectricalElement::new(&mut electricity)
+                .with_number(2)
+                .power()
+                .with_internal_resistance(0.3);
+            electricity.supplied_by(&generator_1);
+            electricity.supplied_by(&generator_2);
+
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            electricity.flow(&generator_1, &bus);
+            electricity.flow(&generator_2, &bus);
+
+            electricity.consume_from_bus(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(4000.),
+            );
+            electricity.solve_network();
+
+            assert_eq!(
+                electricity.network_potential_of(&bus),
+                ElectricPotential::new::<volt>(115.)
+            );
+            assert_eq!(
+                electricity.total_consumption_of(PotentialOrigin::EngineGenerator(1)),
+                Power::new::<watt>(2000.)
+            );
+        }
+
+        #[test]
+        fn feeder_without_load_causes_no_voltage_drop() {
+            let mut electricity = Electricity::new();
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            electricity.supplied_by(&generator);
+
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            electricity.flow_through_feeder(
+                &generator,
+                &bus,
+                ElectricalResistance::new::<ohm>(0.1),
+            );
+
+            electricity.solve_network();
+
+            assert!(
+                (electricity.network_potential_of(&bus) - ElectricPotential::new::<volt>(115.))
+                    .abs()
+                    < ElectricPotential::new::<volt>(0.001)
+            );
+        }
+
+        #[test]
+        fn feeder_resistance_causes_a_voltage_drop_under_load() {
+            let mut electricity = Electricity::new();
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            electricity.supplied_by(&generator);
+
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            electricity.flow_through_feeder(
+                &generator,
+                &bus,
+                ElectricalResistance::new::<ohm>(0.1),
+            );
+
+            electricity.consume_from_bus(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(1150.),
+            );
+            electricity.solve_network();
+
+            assert_eq!(
+                electricity.network_potential_of(&generator),
+                ElectricPotential::new::<volt>(115.)
+            );
+            assert!((electricity.network_potential_of(&bus).get::<volt>() - 113.99).abs() < 0.01);
+        }
+
+        #[test]
+        fn internal_resistance_causes_a_voltage_drop_under_load() {
+            let mut electricity = Electricity::new();
+            let generator = TestElectricalElement::new(&mut electricity)
+                .power()
+                .with_internal_resistance(0.1);
+            electricity.supplied_by(&generator);
+
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            electricity.flow_through_feeder(&generator, &bus, ElectricalResistance::new::<ohm>(0.));
+
+            electricity.consume_from_bus(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(1150.),
+            );
+            electricity.solve_network();
+
+            assert!((electricity.network_potential_of(&bus).get::<volt>() - 113.99).abs() < 0.01);
+        }
+
+        #[test]
+        fn elements_beyond_a_feeder_have_a_lower_potential_under_load() {
+            let mut electricity = Electricity::new();
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            electricity.supplied_by(&generator);
+
+            let bus_1 = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            let bus_2 = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(2));
+            electricity.flow(&generator, &bus_1);
+            electricity.flow_through_feeder(&bus_1, &bus_2, ElectricalResistance::new::<ohm>(0.1));
+
+            electricity.consume_from_bus(
+                ElectricalBusType::AlternatingCurrent(2),
+                Power::new::<watt>(1150.),
+            );
+            electricity.solve_network();
+
+            assert_eq!(
+                electricity.network_potential_of(&bus_1),
+                ElectricPotential::new::<volt>(115.)
+            );
+            assert!(
+                electricity.network_potential_of(&bus_2) < ElectricPotential::new::<volt>(115.)
+            );
+            assert!(electricity.is_powered(&bus_2));
+        }
+
+        #[test]
+        fn consumption_includes_the_losses_in_the_feeders() {
+            let mut electricity = Electricity::new();
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            electricity.supplied_by(&generator);
+
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            electricity.flow_through_feeder(
+                &generator,
+                &bus,
+                ElectricalResistance::new::<ohm>(0.1),
+            );
+
+            electricity.consume_from_bus(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(1150.),
+            );
+            electricity.solve_network();
+
+            assert!(
+                electricity.total_consumption_of(PotentialOrigin::EngineGenerator(1))
+                    > Power::new::<watt>(1150.)
+            );
+        }
+
+        #[test]
+        fn consumption_is_divided_according_to_the_internal_resistance_of_paralleled_sources() {
+            let mut electricity = Electricity::new();
+            let generator_1 = TestElectricalElement::new(&mut electricity)
+                .power()
+                .with_internal_resistance(0.1);
+            let generator_2 = TestElectricalElement::new(&mut electricity)
+                .with_number(2)
+                .power()
+                .with_internal_resistance(0.3);
+            electricity.supplied_by(&generator_1);
+            electricity.supplied_by(&generator_2);
+
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            electricity.flow_through_feeder(
+                &generator_1,
+                &bus,
+                ElectricalResistance::new::<ohm>(0.),
+            );
+            electricity.flow_through_feeder(
+                &generator_2,
+                &bus,
+                ElectricalResistance::new::<ohm>(0.),
+            );
+
+            electricity.consume_from_bus(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(4000.),
+            );
+            electricity.solve_network();
+
+            let consumption_1 =
+                electricity.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+            let consumption_2 =
+                electricity.total_consumption_of(PotentialOrigin::EngineGenerator(2));
+            assert!((consumption_1 / consumption_2).get::<ratio>() - 3. < 0.01);
+            assert!((consumption_1 / consumption_2).get::<ratio>() - 3. > -0.01);
+        }
+
+        #[test]
+        fn consumption_is_divided_according_to_the_feeder_resistance_of_paralleled_sources() {
+            let mut electricity = Electricity::new();
+            let generator_1 = TestElectricalElement::new(&mut electricity).power();
+            let generator_2 = TestElectricalElement::new(&mut electricity)
+                .with_number(2)
+                .power();
+            electricity.supplied_by(&generator_1);
+            electricity.supplied_by(&generator_2);
+
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+            electricity.flow_through_feeder(
+                &generator_1,
+                &bus,
+                ElectricalResistance::new::<ohm>(0.01),
+            );
+            electricity.flow_through_feeder(
+                &generator_2,
+                &bus,
+                ElectricalResistance::new::<ohm>(0.02),
+            );
+
+            electricity.consume_from_bus(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(4000.),
+            );
+            electricity.solve_network();
+
+            assert!(
+                electricity.total_consumption_of(PotentialOrigin::EngineGenerator(1))
+                    > electricity.total_consumption_of(PotentialOrigin::EngineGenerator(2))
+            );
+        }
     }
 }
@@ -0,0 +1,469 @@
+use fxhash::{FxHashMap, FxHashSet};
+use uom::si::{electric_potential::volt, electrical_resistance::ohm, f64::*, power::watt};
+
+use crate::{
+    shared::PotentialOrigin,
+    simulation::snapshot::{StateReader, StateWriter},
+};
+
+use super::{
+    origin_key, restore_origin, save_origin, ElectricalElementIdentifier, Potential,
+    PotentialCollection,
+};
+
+/// Records the topology of the electrical network during a simulation tick, such that the
+/// potential of its elements can be solved once the power consumption is known.
+///
+/// Only networks which contain at least one feeder are solved. Within such a network elements
+/// which are connected without a feeder share the same potential, feeders cause a voltage drop
+/// proportional to the current flowing through them and sources of electricity provide
+/// a potential which drops with the current they supply, according to their internal resistance.
+/// Consumers are considered to consume a constant amount of power, thus drawing more current
+/// as the potential drops.
+#[derive(Debug, Default)]
+pub(super) struct ResistiveNetwork {
+    connections: Vec<Connection>,
+    sources: Vec<Source>,
+}
+impl ResistiveNetwork {
+    const MINIMUM_LOAD_POTENTIAL_VOLT: f64 = 1.;
+    const MAXIMUM_ITERATIONS: usize = 20;
+    const CONVERGED_POTENTIAL_DIFFERENCE_VOLT: f64 = 0.001;
+
+    pub(super) fn clear(&mut self) {
+        self.connections.clear();
+        self.sources.clear();
+    }
+
+    pub(super) fn connect(
+        &mut self,
+        left: ElectricalElementIdentifier,
+        right: ElectricalElementIdentifier,
+    ) {
+        self.connections.push(Connection {
+            left,
+            right,
+            resistance: 0.,
+            is_feeder: false,
+        });
+    }
+
+    pub(super) fn connect_through_feeder(
+        &mut self,
+        left: ElectricalElementIdentifier,
+        right: ElectricalElementIdentifier,
+        resistance: ElectricalResistance,
+    ) {
+        self.connections.push(Connection {
+            left,
+            right,
+            resistance: resistance.get::<ohm>().max(0.),
+            is_feeder: true,
+        });
+    }
+
+    pub(super) fn supplied_by(
+        &mut self,
+        identifier: ElectricalElementIdentifier,
+        potential: &Potential,
+        internal_resistance: ElectricalResistance,
+    ) {
+        if potential.is_powered() {
+            self.sources.push(Source {
+                identifier,
+                origins: potential.origins().copied().collect(),
+                potential: potential.raw().get::<volt>(),
+                resistance: internal_resistance.get::<ohm>().max(0.),
+            });
+        }
+    }
+
+    pub(super) fn solve(&self, potential: &PotentialCollection) -> NetworkSolution {
+        let mut solution = NetworkSolution::default();
+        if !self.connections.iter().any(|c| c.is_feeder) {
+            return solution;
+        }
+
+        // Elements connected without resistance share a node and thus a potential.
+        let mut nodes = DisjointSet::default();
+        // Elements connected in any way form a network which is solved as a whole.
+        let mut networks = DisjointSet::default();
+        for connection in &self.connections {
+            networks.union(connection.left, connection.right);
+            if connection.resistance > 0. {
+                nodes.insert(connection.left);
+                nodes.insert(connection.right);
+            } else {
+                nodes.union(connection.left, connection.right);
+            }
+        }
+        for source in &self.sources {
+            nodes.insert(source.identifier);
+            networks.insert(source.identifier);
+        }
+
+        let solved_networks: FxHashSet<ElectricalElementIdentifier> = self
+            .connections
+            .iter()
+            .filter(|c| c.is_feeder)
+            .map(|c| networks.find(c.left))
+            .collect();
+
+        for network in solved_networks {
+            let elements: Vec<ElectricalElementIdentifier> = networks
+                .elements()
+                .filter(|element| networks.find(*element) == network)
+                .collect();
+
+            self.solve_network(&elements, &nodes, &networks, potential, &mut solution);
+        }
+
+        solution
+    }
+
+    fn solve_network(
+        &self,
+        elements: &[ElectricalElementIdentifier],
+        nodes: &DisjointSet,
+        networks: &DisjointSet,
+        potential: &PotentialCollection,
+        solution: &mut NetworkSolution,
+    ) {
+        let network = networks.find(elements[0]);
+
+        let mut node_indices: FxHashMap<ElectricalElementIdentifier, usize> = FxHashMap::default();
+        for element in elements {
+            let node = nodes.find(*element);
+            let next_index = node_indices.len();
+            node_indices.entry(node).or_insert(next_index);
+        }
+        let node_count = node_indices.len();
+        let index_of = |element: ElectricalElementIdentifier| node_indices[&nodes.find(element)];
+
+        // Only sources which actually supply the network take part. Sources which provide a lower
+        // potential than others are not supplying and possibly being charged instead.
+        let sources: Vec<(usize, &Source)> = self
+            .sources
+            .iter()
+            .filter(|source| {
+                networks.find(source.identifier) == network
+                    && potential
+                        .get(source.identifier)
+                        .map_or(false, |p| p.origins().any(|o| source.origins.contains(o)))
+            })
+            .map(|source| (index_of(source.identifier), source))
+            .collect();
+        if sources.is_empty() {
+            return;
+        }
+
+        let mut fixed_potential: Vec<Option<f64>> = vec![None; node_count];
+        for (index, source) in sources.iter().filter(|(_, s)| s.resistance <= 0.) {
+            fixed_potential[*index] =
+                Some(fixed_potential[*index].map_or(source.potential, |p| p.max(source.potential)));
+        }
+
+        let mut loads = vec![0.; node_count];
+        for element in elements {
+            loads[index_of(*element)] += potential.total_consumption_from(*element).get::<watt>();
+        }
+
+        let feeders: Vec<(usize, usize, f64)> = self
+            .connections
+            .iter()
+            .filter(|c| c.resistance > 0. && networks.find(c.left) == network)
+            .map(|c| (index_of(c.left), index_of(c.right), 1. / c.resistance))
+            .filter(|(left, right, _)| left != right)
+            .collect();
+
+        let initial_potential = sources
+            .iter()
+            .map(|(_, source)| source.potential)
+            .fold(0., f64::max);
+        let mut potentials: Vec<f64> = fixed_potential
+            .iter()
+            .map(|p| p.unwrap_or(initial_potential))
+            .collect();
+
+        for _ in 0..Self::MAXIMUM_ITERATIONS {
+            let next = match Self::solve_node_potentials(
+                &potentials,
+                &fixed_potential,
+                &sources,
+                &feeders,
+                &loads,
+            ) {
+                Some(next) => next,
+                None => return,
+            };
+
+            let converged = next
+                .iter()
+                .zip(&potentials)
+                .all(|(a, b)| (a - b).abs() < Self::CONVERGED_POTENTIAL_DIFFERENCE_VOLT);
+            potentials = next;
+
+            if converged {
+                break;
+            }
+        }
+
+        for element in elements {
+            solution.potentials.insert(
+                *element,
+                ElectricPotential::new::<volt>(potentials[index_of(*element)]),
+            );
+        }
+
+        let mut currents = vec![0.; sources.len()];
+        let mut supplied_currents = vec![0.; node_count];
+        for (current, (index, source)) in currents.iter_mut().zip(&sources) {
+            if source.resistance > 0. {
+                *current = ((source.potential - potentials[*index]) / source.resistance).max(0.);
+                supplied_currents[*index] += *current;
+            }
+        }
+
+        // Sources without internal resistance supply whatever current the remainder
+        // of their node requires, shared equally when multiple are connected to it.
+        let mut required_currents: Vec<f64> = loads
+            .iter()
+            .zip(&potentials)
+            .zip(&supplied_currents)
+            .map(|((load, potential), supplied)| {
+                load / potential.max(Self::MINIMUM_LOAD_POTENTIAL_VOLT) - supplied
+            })
+            .collect();
+        for (left, right, conductance) in &feeders {
+            let current = (potentials[*left] - potentials[*right]) * conductance;
+            required_currents[*left] += current;
+            required_currents[*right] -= current;
+        }
+        for (current, (index, source)) in currents.iter_mut().zip(&sources) {
+            if source.resistance <= 0. {
+                let ideal_source_count = sources
+                    .iter()
+                    .filter(|(i, s)| i == index && s.resistance <= 0.)
+                    .count();
+                *current = required_currents[*index].max(0.) / ideal_source_count as f64;
+            }
+        }
+
+        for (current, (_, source)) in currents.iter().zip(&sources) {
+            let power = source.potential * current / source.origins.len() as f64;
+            for origin in &source.origins {
+                *solution.consumption_per_origin.entry(*origin).or_default() +=
+                    Power::new::<watt>(power);
+            }
+        }
+    }
+
+    /// Solves the potential of the nodes which aren't fixed by a source without internal
+    /// resistance, given the potentials of the previous iteration to linearise the
+    /// constant power consumption of the loads.
+    fn solve_node_potentials(
+        potentials: &[f64],
+        fixed_potential: &[Option<f64>],
+        sources: &[(usize, &Source)],
+        feeders: &[(usize, usize, f64)],
+        loads: &[f64],
+    ) -> Option<Vec<f64>> {
+        let unknowns: Vec<usize> = (0..potentials.len())
+            .filter(|index| fixed_potential[*index].is_none())
+            .collect();
+        let mut unknown_index = vec![None; potentials.len()];
+        for (u, index) in unknowns.iter().enumerate() {
+            unknown_index[*index] = Some(u);
+        }
+
+        let mut matrix = vec![vec![0.; unknowns.len()]; unknowns.len()];
+        let mut rhs = vec![0.; unknowns.len()];
+
+        for (u, index) in unknowns.iter().enumerate() {
+            rhs[u] -= loads[*index] / potentials[*index].max(Self::MINIMUM_LOAD_POTENTIAL_VOLT);
+        }
+
+        for (index, source) in sources.iter().filter(|(_, s)| s.resistance > 0.) {
+            if let Some(u) = unknown_index[*index] {
+                let conductance = 1. / source.resistance;
+                matrix[u][u] += conductance;
+                rhs[u] += conductance * source.potential;
+            }
+        }
+
+        for (left, right, conductance) in feeders {
+            match (unknown_index[*left], unknown_index[*right]) {
+                (Some(l), Some(r)) => {
+                    matrix[l][l] += conductance;
+                    matrix[r][r] += conductance;
+                    matrix[l][r] -= conductance;
+                    matrix[r][l] -= conductance;
+                }
+                (Some(l), None) => {
+                    matrix[l][l] += conductance;
+                    rhs[l] += conductance * fixed_potential[*right].unwrap_or_default();
+                }
+                (None, Some(r)) => {
+                    matrix[r][r] += conductance;
+                    rhs[r] += conductance * fixed_potential[*left].unwrap_or_default();
+                }
+                (None, None) => {}
+            }
+        }
+
+        let solved = solve_linear_system(matrix, rhs)?;
+
+        Some(
+            fixed_potential
+                .iter()
+                .zip(&unknown_index)
+                .map(|(fixed, u)| match (fixed, u) {
+                    (Some(potential), _) => *potential,
+                    (None, Some(u)) => solved[*u].max(0.),
+                    (None, None) => 0.,
+                })
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Connection {
+    left: ElectricalElementIdentifier,
+    right: ElectricalElementIdentifier,
+    resistance: f64,
+    is_feeder: bool,
+}
+
+#[derive(Debug)]
+struct Source {
+    identifier: ElectricalElementIdentifier,
+    origins: Vec<PotentialOrigin>,
+    potential: f64,
+    resistance: f64,
+}
+
+/// The potentials and power consumption per origin of the solved electrical networks.
+#[derive(Debug, Default)]
+pub(super) struct NetworkSolution {
+    potentials: FxHashMap<ElectricalElementIdentifier, ElectricPotential>,
+    consumption_per_origin: FxHashMap<PotentialOrigin, Power>,
+}
+impl NetworkSolution {
+    pub(super) fn potential_of(
+        &self,
+        identifier: ElectricalElementIdentifier,
+    ) -> Option<ElectricPotential> {
+        self.potentials.get(&identifier).copied()
+    }
+
+    pub(super) fn consumption_per_origin(
+        &self,
+    ) -> impl Iterator<Item = (&PotentialOrigin, &Power)> + '_ {
+        self.consumption_per_origin.iter()
+    }
+
+    pub(super) fn save_state(&self, state: &mut StateWriter) {
+        let mut potentials: Vec<_> = self.potentials.iter().collect();
+        potentials.sort_by_key(|(identifier, _)| identifier.0);
+        state.write_count(potentials.len());
+        for (identifier, potential) in potentials {
+            state.write(*identifier);
+            state.write(*potential);
+        }
+
+        let mut consumption_per_origin: Vec<_> = self.consumption_per_origin.iter().collect();
+        consumption_per_origin.sort_by_key(|(origin, _)| origin_key(**origin));
+        state.write_count(consumption_per_origin.len());
+        for (origin, power) in consumption_per_origin {
+            save_origin(*origin, state);
+            state.write(*power);
+        }
+    }
+
+    pub(super) fn restore_state(&mut self, state: &mut StateReader) {
+        self.potentials.clear();
+        for _ in 0..state.read_count() {
+            let identifier = state.read();
+            self.potentials.insert(identifier, state.read());
+        }
+
+        self.consumption_per_origin.clear();
+        for _ in 0..state.read_count() {
+            let origin = restore_origin(state);
+            self.consumption_per_origin.insert(origin, state.read());
+        }
+    }
+}
+
+#[derive(Default)]
+struct DisjointSet {
+    parents: FxHashMap<ElectricalElementIdentifier, ElectricalElementIdentifier>,
+}
+impl DisjointSet {
+    fn insert(&mut self, element: ElectricalElementIdentifier) {
+        self.parents.entry(element).or_insert(element);
+    }
+
+    fn find(&self, element: ElectricalElementIdentifier) -> ElectricalElementIdentifier {
+        let mut root = element;
+        while let Some(parent) = self.parents.get(&root).filter(|parent| **parent != root) {
+            root = *parent;
+        }
+
+        root
+    }
+
+    fn union(&mut self, left: ElectricalElementIdentifier, right: ElectricalElementIdentifier) {
+        self.insert(left);
+        self.insert(right);
+
+        let left_root = self.find(left);
+        let right_root = self.find(right);
+        if left_root != right_root {
+            self.parents.insert(right_root, left_root);
+        }
+    }
+
+    fn elements(&self) -> impl Iterator<Item = ElectricalElementIdentifier> + '_ {
+        self.parents.keys().copied()
+    }
+}
+
+/// Solves the linear system using Gaussian elimination with partial pivoting.
+/// Returns `None` when the system has no unique solution.
+fn solve_linear_system(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let size = rhs.len();
+    for column in 0..size {
+        let pivot = (column..size).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .total_cmp(&matrix[*b][column].abs())
+        })?;
+        if matrix[pivot][column].abs() < f64::EPSILON {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+
+        for row in column + 1..size {
+            let factor = matrix[row][column] / matrix[column][column];
+            if factor != 0. {
+                let pivot_row = matrix[column].clone();
+                for (value, pivot_value) in matrix[row].iter_mut().zip(pivot_row).skip(column) {
+                    *value -= factor * pivot_value;
+                }
+                rhs[row] -= factor * rhs[column];
+            }
+        }
+    }
+
+    let mut solution = vec![0.; size];
+    for row in (0..size).rev() {
+        let sum: f64 = (row + 1..size).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - sum) / matrix[row][row];
+    }
+
+    Some(solution)
+}
@@ -5,6 +5,7 @@ use crate::{
         LgciuWeightOnWheels, RamAirTurbineController,
     },
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
@@ -125,6 +126,16 @@ impl SimulationElement for RamAirTurbine {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.stow_position_id, self.stow_position);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.deployment_commanded);
+        state.write(self.stow_position);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.deployment_commanded = state.read();
+        self.stow_position = state.read();
+    }
 }
 impl AngularSpeedSensor for RamAirTurbine {
     fn speed(&self) -> AngularVelocity {
@@ -194,6 +205,18 @@ impl GeneratorControlUnit {
             Power::default()
         }
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_active);
+        state.write(self.current_speed);
+        state.write(self.manual_generator_on_was_pressed);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_active = state.read();
+        self.current_speed = state.read();
+        self.manual_generator_on_was_pressed = state.read();
+    }
 }
 impl EmergencyGeneratorControlUnit for GeneratorControlUnit {
     fn max_allowed_power(&self) -> Power {
@@ -10,7 +10,10 @@ use super::{
 use crate::{
     failures::{Failure, FailureType},
     shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext,
+    },
 };
 
 pub struct StaticInverter {
@@ -99,6 +102,16 @@ impl SimulationElement for StaticInverter {
             Frequency::new::<hertz>(0.)
         };
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.output_potential);
+        state.write(self.output_frequency);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.output_potential = state.read();
+        self.output_frequency = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -11,6 +11,7 @@ use crate::{
     failures::{Failure, FailureType},
     shared::{ConsumePower, PowerConsumptionReport},
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
     },
 };
@@ -132,6 +133,16 @@ impl SimulationElement for TransformerRectifier {
 
         self.output_current = consumption / self.output_potential;
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.output_potential);
+        state.write(self.output_current);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.output_potential = state.read();
+        self.output_current = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,10 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_second, pressure::psi,
+    ratio::percent,
+};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineFuelFlow, EngineUncorrectedN2},
     simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
 };
 
@@ -12,6 +15,7 @@ pub struct LeapEngine {
     corrected_n1_id: VariableIdentifier,
     corrected_n2_id: VariableIdentifier,
     thrust_id: VariableIdentifier,
+    fuel_flow_id: VariableIdentifier,
 
     corrected_n1: Ratio,
     corrected_n2: Ratio,
@@ -24,6 +28,7 @@ pub struct LeapEngine {
     oil_pressure: Pressure,
 
     net_thrust: Mass,
+    fuel_flow: MassRate,
 }
 impl LeapEngine {
     // According to the Type Certificate Data Sheet of LEAP 1A26
@@ -43,6 +48,7 @@ impl LeapEngine {
             corrected_n2_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
             uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
+            fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
 
             corrected_n1: Ratio::new::<percent>(0.),
             corrected_n2: Ratio::new::<percent>(0.),
@@ -53,6 +59,7 @@ impl LeapEngine {
             oil_pressure: Pressure::new::<psi>(0.),
 
             net_thrust: Mass::default(),
+            fuel_flow: MassRate::default(),
         }
     }
 
@@ -74,6 +81,9 @@ impl SimulationElement for LeapEngine {
         self.corrected_n2 = reader.read(&self.corrected_n2_id);
         self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
         self.net_thrust = reader.read(&self.thrust_id);
+
+        let fuel_flow_kg_per_hour: f64 = reader.read(&self.fuel_flow_id);
+        self.fuel_flow = MassRate::new::<kilogram_per_second>(fuel_flow_kg_per_hour / 3600.);
         self.update_parameters();
     }
 }
@@ -92,6 +102,11 @@ impl EngineUncorrectedN2 for LeapEngine {
         self.uncorrected_n2
     }
 }
+impl EngineFuelFlow for LeapEngine {
+    fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+}
 impl Engine for LeapEngine {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
         self.hydraulic_pump_output_speed
@@ -0,0 +1,588 @@
+use crate::{
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        low_pass_filter::LowPassFilter,
+        DelayedTrueLogicGate,
+    },
+    simulation::UpdateContext,
+};
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Velocity},
+    length::{foot, nautical_mile},
+    velocity::{foot_per_minute, knot},
+};
+
+/// The sensor and cockpit inputs the basic GPWS modes are computed from.
+pub(super) struct GpwsInputs {
+    pub(super) radio_altitude: Arinc429Word<Length>,
+    pub(super) barometric_altitude: Arinc429Word<Length>,
+    pub(super) inertial_vertical_speed: Arinc429Word<Velocity>,
+    pub(super) computed_airspeed: Arinc429Word<Velocity>,
+    /// `None` when no valid glideslope is received.
+    pub(super) glideslope_deviation: Option<Angle>,
+    pub(super) gear_is_down: bool,
+    pub(super) flaps_in_landing_config: bool,
+    /// `None` when the terrain clearance floor isn't operational.
+    pub(super) distance_to_runway: Option<Length>,
+    pub(super) system_off: bool,
+    pub(super) glideslope_mode_off: bool,
+}
+
+/// Computes the basic GPWS alerting modes and the terrain clearance floor (TCF):
+/// - Mode 1: excessive sink rate
+/// - Mode 2: excessive terrain closure rate
+/// - Mode 3: altitude loss after takeoff
+/// - Mode 4: unsafe terrain clearance
+/// - Mode 5: excessive glideslope deviation
+///
+/// Mode 2 is inhibited, see [`GpwsModes::MODE_2_IS_INHIBITED`].
+pub(super) struct GpwsModes {
+    radio_altitude_rate: LowPassFilter<f64>,
+    previous_radio_altitude: Option<Length>,
+    airborne_confirmation: DelayedTrueLogicGate,
+    is_airborne: bool,
+    is_approach: bool,
+    remaining_overflight_duration: Duration,
+    mode_2_boundary_confirmation: DelayedTrueLogicGate,
+    mode_2_time_in_boundary: Duration,
+    mode_2_is_active: bool,
+    mode_2_boundary_exit_altitude: Option<Length>,
+    mode_3_max_altitude: Option<Length>,
+    mode_4_filter_value: Length,
+
+    sink_rate: bool,
+    pull_up: bool,
+    terrain: bool,
+    dont_sink: bool,
+    too_low_gear: bool,
+    too_low_flaps: bool,
+    too_low_terrain: bool,
+    glideslope: bool,
+}
+
+impl GpwsModes {
+    const MINIMUM_RADIO_ALTITUDE_FEET: f64 = 10.;
+    const MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 2450.;
+    const AIR_GROUND_TRANSITION_RADIO_ALTITUDE_FEET: f64 = 25.;
+    const AIRBORNE_MINIMUM_AIRSPEED_KNOTS: f64 = 90.;
+    const AIRBORNE_CONFIRMATION_DURATION: Duration = Duration::from_secs(10);
+    const RADIO_ALTITUDE_RATE_TIME_CONSTANT: Duration = Duration::from_millis(500);
+    const OVERFLIGHT_RADIO_ALTITUDE_RATE_FEET_PER_MINUTE: f64 = -2200. * 60.;
+    const OVERFLIGHT_DURATION: Duration = Duration::from_secs(60);
+    /// Mode 2 is inhibited, as the radio altitude follows the terrain height of the
+    /// simulator, whose sudden changes cause false closure rate warnings very frequently.
+    /// There's also no "TERRAIN" aural to announce the alert yet.
+    pub(super) const MODE_2_IS_INHIBITED: bool = true;
+    const MODE_2_CONFIRMATION_DURATION: Duration = Duration::from_millis(500);
+    // Time for two "TERRAIN" call outs before the "PULL UP" warning.
+    const MODE_2_TERRAIN_DURATION: Duration = Duration::from_millis(1600);
+    const MODE_2_ALTITUDE_GAIN_FEET: f64 = 300.;
+    const TCF_MAXIMUM_DISTANCE_NM: f64 = 15.;
+
+    pub(super) fn new() -> Self {
+        Self {
+            radio_altitude_rate: LowPassFilter::new(Self::RADIO_ALTITUDE_RATE_TIME_CONSTANT),
+            previous_radio_altitude: None,
+            airborne_confirmation: DelayedTrueLogicGate::new(Self::AIRBORNE_CONFIRMATION_DURATION),
+            is_airborne: false,
+            is_approach: false,
+            remaining_overflight_duration: Duration::ZERO,
+            mode_2_boundary_confirmation: DelayedTrueLogicGate::new(
+                Self::MODE_2_CONFIRMATION_DURATION,
+            ),
+            mode_2_time_in_boundary: Duration::ZERO,
+            mode_2_is_active: false,
+            mode_2_boundary_exit_altitude: None,
+            mode_3_max_altitude: None,
+            mode_4_filter_value: Length::default(),
+
+            sink_rate: false,
+            pull_up: false,
+            terrain: false,
+            dont_sink: false,
+            too_low_gear: false,
+            too_low_flaps: false,
+            too_low_terrain: false,
+            glideslope: false,
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, inputs: &GpwsInputs) {
+        let radio_altitude = inputs.radio_altitude.normal_value();
+        let terrain_clearance_floor_is_operational =
+            inputs.distance_to_runway.is_some() && radio_altitude.is_some();
+
+        let unfiltered_radio_altitude_rate =
+            self.update_radio_altitude_rate(context, radio_altitude);
+        self.update_overflight(context, unfiltered_radio_altitude_rate);
+        self.update_mode_4_filter(radio_altitude);
+        self.update_air_ground_state(context, inputs);
+        self.update_approach_takeoff_state(inputs, terrain_clearance_floor_is_operational);
+
+        self.reset_alerts();
+
+        match radio_altitude {
+            Some(radio_altitude)
+                if !inputs.system_off
+                    && radio_altitude.get::<foot>() >= Self::MINIMUM_RADIO_ALTITUDE_FEET
+                    && radio_altitude.get::<foot>() <= Self::MAXIMUM_RADIO_ALTITUDE_FEET =>
+            {
+                let altitude_rate = self.altitude_rate(inputs);
+
+                self.update_mode_1(radio_altitude, altitude_rate);
+                if !Self::MODE_2_IS_INHIBITED {
+                    self.update_mode_2(context, radio_altitude, inputs);
+                }
+                self.update_mode_3(radio_altitude, altitude_rate, inputs);
+                self.update_mode_4(
+                    radio_altitude,
+                    inputs,
+                    terrain_clearance_floor_is_operational,
+                );
+                self.update_mode_5(radio_altitude, inputs);
+                self.update_terrain_clearance_floor(radio_altitude, inputs);
+            }
+            _ => {
+                self.mode_2_boundary_confirmation.update(context, false);
+                self.mode_2_time_in_boundary = Duration::ZERO;
+                self.mode_2_is_active = false;
+                self.mode_2_boundary_exit_altitude = None;
+                self.mode_3_max_altitude = None;
+            }
+        }
+    }
+
+    /// Differentiates the radio altitude in feet per minute. The stored rate is filtered, as
+    /// the radio altitude follows the terrain below the aircraft and thus jitters considerably.
+    /// Returns the unfiltered rate.
+    fn update_radio_altitude_rate(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Option<Length>,
+    ) -> Option<f64> {
+        let rate = match (self.previous_radio_altitude, radio_altitude) {
+            (Some(previous), Some(current)) if context.delta_as_secs_f64() > 0. => {
+                let rate = (current - previous).get::<foot>() / (context.delta_as_secs_f64() / 60.);
+                self.radio_altitude_rate.update(context.delta(), rate);
+
+                Some(rate)
+            }
+            (_, None) => {
+                self.radio_altitude_rate.reset(0.);
+
+                None
+            }
+            _ => None,
+        };
+
+        self.previous_radio_altitude = radio_altitude;
+
+        rate
+    }
+
+    fn update_overflight(&mut self, context: &UpdateContext, radio_altitude_rate: Option<f64>) {
+        if radio_altitude_rate.map_or(false, |rate| {
+            rate < Self::OVERFLIGHT_RADIO_ALTITUDE_RATE_FEET_PER_MINUTE
+        }) {
+            self.remaining_overflight_duration = Self::OVERFLIGHT_DURATION;
+        } else {
+            self.remaining_overflight_duration = self
+                .remaining_overflight_duration
+                .saturating_sub(context.delta());
+        }
+    }
+
+    fn overflight_detected(&self) -> bool {
+        self.remaining_overflight_duration > Duration::ZERO
+    }
+
+    fn update_mode_4_filter(&mut self, radio_altitude: Option<Length>) {
+        if !self.is_airborne || self.is_approach {
+            self.mode_4_filter_value = Length::default();
+        } else if let Some(radio_altitude) = radio_altitude {
+            self.mode_4_filter_value = self.mode_4_filter_value.max(radio_altitude * 0.75);
+        }
+    }
+
+    fn update_air_ground_state(&mut self, context: &UpdateContext, inputs: &GpwsInputs) {
+        let (computed_airspeed, radio_altitude) = match (
+            inputs.computed_airspeed.normal_value(),
+            inputs.radio_altitude.normal_value(),
+        ) {
+            (Some(computed_airspeed), Some(radio_altitude)) => (computed_airspeed, radio_altitude),
+            // Remain in the current state.
+            _ => return,
+        };
+
+        self.airborne_confirmation.update(
+            context,
+            computed_airspeed.get::<knot>() > Self::AIRBORNE_MINIMUM_AIRSPEED_KNOTS
+                && radio_altitude.get::<foot>() > Self::AIR_GROUND_TRANSITION_RADIO_ALTITUDE_FEET,
+        );
+
+        if self.is_airborne {
+            self.is_airborne =
+                radio_altitude.get::<foot>() >= Self::AIR_GROUND_TRANSITION_RADIO_ALTITUDE_FEET;
+        } else {
+            self.is_airborne = self.airborne_confirmation.output();
+        }
+    }
+
+    fn update_approach_takeoff_state(
+        &mut self,
+        inputs: &GpwsInputs,
+        terrain_clearance_floor_is_operational: bool,
+    ) {
+        let computed_airspeed = match inputs.computed_airspeed.normal_value() {
+            Some(computed_airspeed) => computed_airspeed,
+            None => return,
+        };
+
+        if self.is_approach {
+            // Passing below the mode 4B floor without an alert means the aircraft is landing.
+            self.is_approach = !inputs.radio_altitude.normal_value().map_or(false, |ra| {
+                ra.get::<foot>() < 245. && inputs.gear_is_down && inputs.flaps_in_landing_config
+            });
+        } else {
+            self.is_approach = self.mode_4_filter_value
+                > self.mode_4a_upper_boundary(
+                    computed_airspeed,
+                    inputs.flaps_in_landing_config,
+                    terrain_clearance_floor_is_operational,
+                );
+        }
+    }
+
+    fn altitude_rate(&self, inputs: &GpwsInputs) -> Option<Velocity> {
+        inputs.inertial_vertical_speed.normal_value().or_else(|| {
+            self.previous_radio_altitude
+                .map(|_| Velocity::new::<foot_per_minute>(self.radio_altitude_rate.output()))
+        })
+    }
+
+    fn update_mode_1(&mut self, radio_altitude: Length, altitude_rate: Option<Velocity>) {
+        let sink_rate = match altitude_rate {
+            Some(altitude_rate) => -altitude_rate.get::<foot_per_minute>(),
+            None => return,
+        };
+        if sink_rate <= 1000. {
+            return;
+        }
+
+        let max_sink_rate_altitude = 0.61 * sink_rate - 600.;
+        let max_pull_up_altitude = if sink_rate < 1700. {
+            1.3 * sink_rate - 1940.
+        } else {
+            0.4 * sink_rate - 410.
+        };
+
+        let radio_altitude = radio_altitude.get::<foot>();
+        if radio_altitude <= max_pull_up_altitude {
+            self.pull_up = true;
+        } else if radio_altitude <= max_sink_rate_altitude {
+            self.sink_rate = true;
+        }
+    }
+
+    fn update_mode_2(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Length,
+        inputs: &GpwsInputs,
+    ) {
+        let closure_rate = -self.radio_altitude_rate.output();
+        let radio_altitude_feet = radio_altitude.get::<foot>();
+        let upper_boundary_rate = if closure_rate < 3500. {
+            0.7937 * closure_rate - 1557.5
+        } else {
+            0.19166 * closure_rate + 610.
+        };
+
+        let is_in_boundary = closure_rate > 2000.
+            && radio_altitude_feet < upper_boundary_rate
+            && if inputs.flaps_in_landing_config {
+                // Mode 2B
+                radio_altitude_feet < 775. && closure_rate < 10000.
+            } else {
+                // Mode 2A
+                let upper_boundary_speed = inputs
+                    .computed_airspeed
+                    .normal_value()
+                    .map_or(1650., |cas| {
+                        (8.8888 * cas.get::<knot>() - 305.555).clamp(1650., 2450.)
+                    });
+                radio_altitude_feet < upper_boundary_speed
+            };
+
+        self.mode_2_boundary_confirmation
+            .update(context, is_in_boundary);
+
+        if self.mode_2_boundary_confirmation.output() {
+            self.mode_2_is_active = true;
+            self.mode_2_boundary_exit_altitude = None;
+            self.mode_2_time_in_boundary += context.delta();
+
+            if self.mode_2_time_in_boundary < Self::MODE_2_TERRAIN_DURATION || inputs.gear_is_down {
+                self.terrain = true;
+            } else {
+                self.pull_up = true;
+            }
+        } else if self.mode_2_is_active {
+            // After leaving the envelope, the alert continues until altitude has been gained.
+            self.mode_2_time_in_boundary = Duration::ZERO;
+            let exit_altitude = *self
+                .mode_2_boundary_exit_altitude
+                .get_or_insert(radio_altitude);
+
+            if radio_altitude.get::<foot>()
+                < exit_altitude.get::<foot>() + Self::MODE_2_ALTITUDE_GAIN_FEET
+            {
+                self.terrain = true;
+            } else {
+                self.mode_2_is_active = false;
+                self.mode_2_boundary_exit_altitude = None;
+            }
+        }
+    }
+
+    fn update_mode_3(
+        &mut self,
+        radio_altitude: Length,
+        altitude_rate: Option<Velocity>,
+        inputs: &GpwsInputs,
+    ) {
+        let barometric_altitude = match inputs.barometric_altitude.normal_value() {
+            Some(altitude)
+                if !(inputs.gear_is_down && inputs.flaps_in_landing_config)
+                    && !self.is_approach
+                    && radio_altitude.get::<foot>() <= 1500. =>
+            {
+                altitude
+            }
+            _ => {
+                self.mode_3_max_altitude = None;
+                return;
+            }
+        };
+
+        match self.mode_3_max_altitude {
+            Some(max_altitude) if barometric_altitude <= max_altitude => {
+                let max_altitude_loss = 0.09 * radio_altitude.get::<foot>() + 7.1;
+                let is_climbing =
+                    altitude_rate.map_or(false, |rate| rate.get::<foot_per_minute>() > 0.);
+
+                self.dont_sink = !is_climbing
+                    && (max_altitude - barometric_altitude).get::<foot>() > max_altitude_loss;
+            }
+            _ => self.mode_3_max_altitude = Some(barometric_altitude),
+        }
+    }
+
+    fn update_mode_4(
+        &mut self,
+        radio_altitude: Length,
+        inputs: &GpwsInputs,
+        terrain_clearance_floor_is_operational: bool,
+    ) {
+        let radio_altitude_feet = radio_altitude.get::<foot>();
+        let computed_airspeed = match inputs.computed_airspeed.normal_value() {
+            Some(computed_airspeed)
+                if self.is_airborne && (30. ..=1000.).contains(&radio_altitude_feet) =>
+            {
+                computed_airspeed
+            }
+            _ => return,
+        };
+
+        let computed_airspeed_knots = computed_airspeed.get::<knot>();
+        if self.is_approach && !inputs.gear_is_down {
+            // Mode 4A
+            let boundary = self.mode_4a_upper_boundary(
+                computed_airspeed,
+                inputs.flaps_in_landing_config,
+                terrain_clearance_floor_is_operational,
+            );
+
+            if computed_airspeed_knots < 190. && radio_altitude_feet < 500. {
+                self.too_low_gear = true;
+            } else if computed_airspeed_knots >= 190. && radio_altitude < boundary {
+                if inputs.flaps_in_landing_config {
+                    self.too_low_gear = true;
+                } else {
+                    self.too_low_terrain = true;
+                }
+            }
+        } else if self.is_approach && !inputs.flaps_in_landing_config {
+            // Mode 4B
+            let boundary = self.mode_4b_upper_boundary(
+                computed_airspeed,
+                inputs.flaps_in_landing_config,
+                terrain_clearance_floor_is_operational,
+            );
+
+            if computed_airspeed_knots < 159. && radio_altitude_feet < 245. {
+                self.too_low_flaps = true;
+            } else if computed_airspeed_knots >= 159. && radio_altitude < boundary {
+                self.too_low_terrain = true;
+            }
+        } else if !self.is_approach && (!inputs.flaps_in_landing_config || !inputs.gear_is_down) {
+            // Mode 4C
+            let boundary = Length::new::<foot>(
+                (8.3333 * computed_airspeed_knots - 1083.33).clamp(500., 1000.),
+            );
+
+            if radio_altitude < boundary.min(self.mode_4_filter_value) {
+                self.too_low_terrain = true;
+            }
+        }
+    }
+
+    fn mode_4a_upper_boundary(
+        &self,
+        computed_airspeed: Velocity,
+        flaps_in_landing_config: bool,
+        terrain_clearance_floor_is_operational: bool,
+    ) -> Length {
+        let expanded_boundary = if flaps_in_landing_config || terrain_clearance_floor_is_operational
+        {
+            500.
+        } else if self.overflight_detected() {
+            800.
+        } else {
+            1000.
+        };
+
+        Length::new::<foot>(
+            (8.333 * computed_airspeed.get::<knot>() - 1083.33)
+                .min(expanded_boundary)
+                .max(500.),
+        )
+    }
+
+    fn mode_4b_upper_boundary(
+        &self,
+        computed_airspeed: Velocity,
+        flaps_in_landing_config: bool,
+        terrain_clearance_floor_is_operational: bool,
+    ) -> Length {
+        let expanded_boundary = if flaps_in_landing_config || terrain_clearance_floor_is_operational
+        {
+            245.
+        } else if self.overflight_detected() {
+            800.
+        } else {
+            1000.
+        };
+
+        Length::new::<foot>(
+            (8.333 * computed_airspeed.get::<knot>() - 1083.33)
+                .min(expanded_boundary)
+                .max(245.),
+        )
+    }
+
+    fn update_mode_5(&mut self, radio_altitude: Length, inputs: &GpwsInputs) {
+        let radio_altitude_feet = radio_altitude.get::<foot>();
+        let deviation = match inputs.glideslope_deviation {
+            Some(deviation)
+                if !inputs.glideslope_mode_off && (30. ..=1000.).contains(&radio_altitude_feet) =>
+            {
+                deviation
+            }
+            _ => return,
+        };
+
+        // One dot of deviation is approximately 0.4°. Positive dots are below the glideslope.
+        let dots = -deviation.get::<degree>() / 0.4;
+        let min_altitude_for_soft_alert = if dots < 2.9 { -75. * dots + 247.5 } else { 30. };
+        let min_altitude_for_hard_alert = if dots < 3.8 {
+            -66.66 * dots + 283.33
+        } else {
+            30.
+        };
+
+        let hard_alert = dots > 2.
+            && radio_altitude_feet > min_altitude_for_hard_alert
+            && radio_altitude_feet < 350.;
+        let soft_alert = dots > 1.3 && radio_altitude_feet > min_altitude_for_soft_alert;
+
+        self.glideslope = hard_alert || soft_alert;
+    }
+
+    /// The terrain clearance floor alerts when the aircraft descends below a floor which rises
+    /// with the distance to the runway. It is independent of the aircraft configuration.
+    fn update_terrain_clearance_floor(&mut self, radio_altitude: Length, inputs: &GpwsInputs) {
+        if let Some(distance) = inputs.distance_to_runway {
+            if self.is_airborne
+                && distance.get::<nautical_mile>() <= Self::TCF_MAXIMUM_DISTANCE_NM
+                && radio_altitude < Self::terrain_clearance_floor(distance)
+            {
+                self.too_low_terrain = true;
+            }
+        }
+    }
+
+    fn terrain_clearance_floor(distance_to_runway: Length) -> Length {
+        // The floor starts 1 nm from the runway and rises by 100 ft per nm up to 400 ft.
+        Length::new::<foot>(
+            ((distance_to_runway.get::<nautical_mile>() - 1.) * 100.).clamp(0., 400.),
+        )
+    }
+
+    fn reset_alerts(&mut self) {
+        self.sink_rate = false;
+        self.pull_up = false;
+        self.terrain = false;
+        self.dont_sink = false;
+        self.too_low_gear = false;
+        self.too_low_flaps = false;
+        self.too_low_terrain = false;
+        self.glideslope = false;
+    }
+
+    fn has_alert(&self) -> bool {
+        self.sink_rate
+            || self.pull_up
+            || self.terrain
+            || self.dont_sink
+            || self.too_low_gear
+            || self.too_low_flaps
+            || self.too_low_terrain
+    }
+
+    pub(super) fn is_airborne(&self) -> bool {
+        self.is_airborne
+    }
+
+    pub(super) fn is_approach(&self) -> bool {
+        self.is_approach
+    }
+
+    pub(super) fn discrete_word_1(&self) -> Arinc429Word<u32> {
+        let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+
+        word.set_bit(11, self.sink_rate);
+        word.set_bit(12, self.pull_up);
+        word.set_bit(13, self.terrain);
+        word.set_bit(14, self.dont_sink);
+        word.set_bit(15, self.too_low_gear);
+        word.set_bit(16, self.too_low_flaps);
+        word.set_bit(17, self.too_low_terrain);
+        word.set_bit(18, self.glideslope);
+
+        word
+    }
+
+    pub(super) fn discrete_word_2(&self) -> Arinc429Word<u32> {
+        let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+
+        word.set_bit(11, false);
+        word.set_bit(12, self.has_alert());
+        word.set_bit(13, self.pull_up);
+        word.set_bit(14, false);
+
+        word
+    }
+}
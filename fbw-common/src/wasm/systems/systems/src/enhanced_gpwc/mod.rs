@@ -1,23 +1,47 @@
 use crate::{
     accept_iterable,
-    enhanced_gpwc::navigation_display::NavigationDisplay,
+    enhanced_gpwc::{
+        gpws_modes::{GpwsInputs, GpwsModes},
+        navigation_display::NavigationDisplay,
+        reactive_windshear::{ReactiveWindshear, ReactiveWindshearInputs},
+    },
+    navigation::radio_altimeter::RadioAltimeter,
     shared::{
         arinc429::{Arinc429Word, SignStatus},
-        AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, LgciuGearExtension,
+        AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, GpsMeasurementOutputs,
+        LgciuGearExtension,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use std::vec::Vec;
 use uom::si::{
-    angle::degree,
+    angle::{degree, radian},
     f64::{Angle, Length, Velocity},
+    length::nautical_mile,
     velocity::foot_per_minute,
 };
 
+mod gpws_modes;
 pub mod navigation_display;
+mod reactive_windshear;
+
+/// The bits of the SFCC slat/flap actual position word which indicate
+/// the flaps in the CONF 3 and CONF FULL landing positions.
+pub struct LandingFlapsPositionBits {
+    flaps_3: u8,
+    flaps_full: u8,
+}
+impl LandingFlapsPositionBits {
+    pub fn new(flaps_3: u8, flaps_full: u8) -> Self {
+        Self {
+            flaps_3,
+            flaps_full,
+        }
+    }
+}
 
 pub struct EnhancedGroundProximityWarningComputer {
     powered_by: ElectricalBusType,
@@ -37,6 +61,27 @@ pub struct EnhancedGroundProximityWarningComputer {
     navigation_displays: [NavigationDisplay; 2],
     gear_is_down: bool,
     terronnd_rendering_mode: u8,
+    gpws_modes: GpwsModes,
+    reactive_windshear: ReactiveWindshear,
+    landing_flaps_position_bits: LandingFlapsPositionBits,
+    slat_flap_actual_position_word_id: VariableIdentifier,
+    slat_flap_actual_position_word: Arinc429Word<u32>,
+    glideslope_is_valid_id: VariableIdentifier,
+    glideslope_is_valid: bool,
+    glideslope_deviation_id: VariableIdentifier,
+    glideslope_deviation: Angle,
+    gpws_sys_off_id: VariableIdentifier,
+    gpws_sys_off: bool,
+    gpws_terr_off_id: VariableIdentifier,
+    gpws_terr_off: bool,
+    fmgc_nav_accuracy_high_id: VariableIdentifier,
+    fmgc_nav_accuracy_high: bool,
+    gpws_gs_off_id: VariableIdentifier,
+    gpws_gs_off: bool,
+    gpws_flap_off_id: VariableIdentifier,
+    gpws_flap_off: bool,
+    gpws_flaps_3_id: VariableIdentifier,
+    gpws_flaps_3: bool,
     // output variables of the EGPWC
     egpwc_destination_longitude_id: VariableIdentifier,
     egpwc_destination_latitude_id: VariableIdentifier,
@@ -47,14 +92,25 @@ pub struct EnhancedGroundProximityWarningComputer {
     egpwc_present_vertical_speed_id: VariableIdentifier,
     egpwc_gear_is_down_id: VariableIdentifier,
     egpwc_terronnd_rendering_mode: VariableIdentifier,
+    egpws_alert_discrete_word_1_ids: [VariableIdentifier; 2],
+    egpws_alert_discrete_word_2_ids: [VariableIdentifier; 2],
+    gpws_ground_state_id: VariableIdentifier,
+    gpws_approach_state_id: VariableIdentifier,
 }
 
 impl EnhancedGroundProximityWarningComputer {
+    const EARTH_RADIUS_NM: f64 = 3440.065;
+    const SLATS_RETRACTED_BIT: u8 = 12;
+    const FLAPS_RETRACTED_BIT: u8 = 19;
+    const WINDSHEAR_WARNING_BIT: u8 = 15;
+    const WINDSHEAR_CAUTION_BIT: u8 = 17;
+
     pub fn new(
         context: &mut InitContext,
         powered_by: ElectricalBusType,
         range_lookup: Vec<Length>,
         terronnd_rendering_mode: u8,
+        landing_flaps_position_bits: LandingFlapsPositionBits,
     ) -> Self {
         EnhancedGroundProximityWarningComputer {
             powered_by,
@@ -78,6 +134,30 @@ impl EnhancedGroundProximityWarningComputer {
             ],
             gear_is_down: true,
             terronnd_rendering_mode,
+            gpws_modes: GpwsModes::new(),
+            reactive_windshear: ReactiveWindshear::new(),
+            landing_flaps_position_bits,
+            slat_flap_actual_position_word_id: context
+                .get_identifier("SFCC_SLAT_FLAP_ACTUAL_POSITION_WORD".to_owned()),
+            slat_flap_actual_position_word: Arinc429Word::new(0, SignStatus::FailureWarning),
+            glideslope_is_valid_id: context.get_identifier("RADIO_RECEIVER_GS_IS_VALID".to_owned()),
+            glideslope_is_valid: false,
+            glideslope_deviation_id: context
+                .get_identifier("RADIO_RECEIVER_GS_DEVIATION".to_owned()),
+            glideslope_deviation: Angle::default(),
+            gpws_sys_off_id: context.get_identifier("GPWS_SYS_OFF".to_owned()),
+            gpws_sys_off: false,
+            gpws_terr_off_id: context.get_identifier("GPWS_TERR_OFF".to_owned()),
+            gpws_terr_off: false,
+            fmgc_nav_accuracy_high_id: context
+                .get_identifier("FMGC_L_NAV_ACCURACY_HIGH".to_owned()),
+            fmgc_nav_accuracy_high: false,
+            gpws_gs_off_id: context.get_identifier("GPWS_GS_OFF".to_owned()),
+            gpws_gs_off: false,
+            gpws_flap_off_id: context.get_identifier("GPWS_FLAP_OFF".to_owned()),
+            gpws_flap_off: false,
+            gpws_flaps_3_id: context.get_identifier("GPWS_FLAPS3".to_owned()),
+            gpws_flaps_3: false,
             egpwc_destination_longitude_id: context.get_identifier("EGPWC_DEST_LONG".to_owned()),
             egpwc_destination_latitude_id: context.get_identifier("EGPWC_DEST_LAT".to_owned()),
             egpwc_present_latitude_id: context.get_identifier("EGPWC_PRESENT_LAT".to_owned()),
@@ -89,25 +169,151 @@ impl EnhancedGroundProximityWarningComputer {
             egpwc_gear_is_down_id: context.get_identifier("EGPWC_GEAR_IS_DOWN".to_owned()),
             egpwc_terronnd_rendering_mode: context
                 .get_identifier("EGPWC_TERRONND_RENDERING_MODE".to_owned()),
+            egpws_alert_discrete_word_1_ids: [1, 2].map(|number| {
+                context.get_identifier(format!("EGPWS_ALERT_{}_DISCRETE_WORD_1", number))
+            }),
+            egpws_alert_discrete_word_2_ids: [1, 2].map(|number| {
+                context.get_identifier(format!("EGPWS_ALERT_{}_DISCRETE_WORD_2", number))
+            }),
+            gpws_ground_state_id: context.get_identifier("GPWS_GROUND_STATE".to_owned()),
+            gpws_approach_state_id: context.get_identifier("GPWS_APPROACH_STATE".to_owned()),
         }
     }
 
-    fn update_position_data(&mut self, adirs_output: &impl AdirsMeasurementOutputs) {
-        // documentation hints:
-        //   - EGPWC has direct connection to GPS sensor && ADIRS_1
-        //   - uses direct GPS data if ADIRS_1 is unavailable
-        // TODO:
-        //   - implement logic as soon as GPS sensor is available
-        self.latitude = adirs_output.latitude(1);
-        self.longitude = adirs_output.longitude(1);
-        self.altitude = adirs_output.altitude(1);
+    fn update_position_data(
+        &mut self,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        gps: &impl GpsMeasurementOutputs,
+    ) {
+        // The EGPWC has a direct connection to the GPS sensor and ADIRS 1,
+        // where the GPS data is used when ADIRS 1 is unavailable.
+        let adiru_latitude = adirs_output.gpirs_latitude(1);
+        let adiru_longitude = adirs_output.gpirs_longitude(1);
+        if adiru_latitude.is_normal_operation() && adiru_longitude.is_normal_operation() {
+            self.latitude = adiru_latitude;
+            self.longitude = adiru_longitude;
+        } else {
+            self.latitude = gps.latitude();
+            self.longitude = gps.longitude();
+        }
+
+        let adiru_altitude = adirs_output.altitude(1);
+        self.altitude = if adiru_altitude.is_normal_operation() {
+            adiru_altitude
+        } else {
+            gps.altitude()
+        };
+
         self.heading = adirs_output.true_heading(1);
         self.vertical_speed = adirs_output.vertical_speed(1);
     }
 
+    fn flaps_in_landing_config(&self) -> bool {
+        let word = self.slat_flap_actual_position_word;
+        let flaps_3 = word.get_bit(self.landing_flaps_position_bits.flaps_3)
+            && !word.get_bit(self.landing_flaps_position_bits.flaps_full);
+        let flaps_full = word.get_bit(self.landing_flaps_position_bits.flaps_full);
+
+        !word.is_normal_operation()
+            || self.gpws_flap_off
+            || if self.gpws_flaps_3 {
+                flaps_3
+            } else {
+                flaps_full
+            }
+    }
+
+    fn slats_or_flaps_extended(&self) -> bool {
+        let word = self.slat_flap_actual_position_word;
+
+        !word.is_normal_operation()
+            || !word.get_bit(Self::SLATS_RETRACTED_BIT)
+            || !word.get_bit(Self::FLAPS_RETRACTED_BIT)
+    }
+
+    /// The great circle distance from the present position to the destination runway.
+    fn distance_to_destination(&self) -> Option<Length> {
+        let latitude = self.latitude.normal_value()?.get::<radian>();
+        let longitude = self.longitude.normal_value()?.get::<radian>();
+        let destination_latitude = self.destination_latitude.normal_value()?.get::<radian>();
+        let destination_longitude = self.destination_longitude.normal_value()?.get::<radian>();
+
+        let haversine = ((destination_latitude - latitude) / 2.).sin().powi(2)
+            + latitude.cos()
+                * destination_latitude.cos()
+                * ((destination_longitude - longitude) / 2.).sin().powi(2);
+
+        Some(Length::new::<nautical_mile>(
+            2. * Self::EARTH_RADIUS_NM * haversine.sqrt().asin(),
+        ))
+    }
+
+    fn select_radio_altitude(
+        radio_altimeter_1: &impl RadioAltimeter,
+        radio_altimeter_2: &impl RadioAltimeter,
+    ) -> Arinc429Word<Length> {
+        let radio_altitude_1 = radio_altimeter_1.radio_altitude();
+        if radio_altitude_1.is_failure_warning() || radio_altitude_1.is_no_computed_data() {
+            radio_altimeter_2.radio_altitude()
+        } else {
+            radio_altitude_1
+        }
+    }
+
+    fn update_gpws_modes(
+        &mut self,
+        context: &UpdateContext,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        radio_altitude: Arinc429Word<Length>,
+    ) {
+        let inputs = GpwsInputs {
+            radio_altitude,
+            barometric_altitude: adirs_output.altitude(1),
+            inertial_vertical_speed: adirs_output.vertical_speed(1),
+            computed_airspeed: adirs_output.computed_airspeed(1),
+            glideslope_deviation: if self.glideslope_is_valid {
+                Some(self.glideslope_deviation)
+            } else {
+                None
+            },
+            gear_is_down: self.gear_is_down,
+            flaps_in_landing_config: self.flaps_in_landing_config(),
+            // The terrain clearance floor requires an accurate position from the FMGC.
+            distance_to_runway: if self.gpws_terr_off || !self.fmgc_nav_accuracy_high {
+                None
+            } else {
+                self.distance_to_destination()
+            },
+            system_off: self.gpws_sys_off,
+            glideslope_mode_off: self.gpws_gs_off,
+        };
+
+        self.gpws_modes.update(context, &inputs);
+    }
+
+    fn update_reactive_windshear(
+        &mut self,
+        context: &UpdateContext,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        radio_altitude: Arinc429Word<Length>,
+    ) {
+        let inputs = ReactiveWindshearInputs {
+            radio_altitude,
+            computed_airspeed: adirs_output.computed_airspeed(1),
+            angle_of_attack: adirs_output.angle_of_attack(1),
+            slats_or_flaps_extended: self.slats_or_flaps_extended(),
+        };
+
+        self.reactive_windshear.update(context, &inputs);
+    }
+
     pub fn update(
         &mut self,
+        context: &UpdateContext,
         adirs_output: &impl AdirsMeasurementOutputs,
+        gps: &impl GpsMeasurementOutputs,
+        radio_altimeter_1: &impl RadioAltimeter,
+        radio_altimeter_2: &impl RadioAltimeter,
         lgcius: &impl LgciuGearExtension,
     ) {
         if !self.is_powered {
@@ -123,9 +329,15 @@ impl EnhancedGroundProximityWarningComputer {
                 Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning);
 
             self.gear_is_down = false;
+            self.gpws_modes = GpwsModes::new();
+            self.reactive_windshear = ReactiveWindshear::new();
         } else {
-            self.update_position_data(adirs_output);
+            self.update_position_data(adirs_output, gps);
             self.gear_is_down = lgcius.main_down_and_locked();
+
+            let radio_altitude = Self::select_radio_altitude(radio_altimeter_1, radio_altimeter_2);
+            self.update_gpws_modes(context, adirs_output, radio_altitude);
+            self.update_reactive_windshear(context, adirs_output, radio_altitude);
         }
 
         self.navigation_displays.iter_mut().for_each(|display| {
@@ -157,6 +369,16 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             Angle::new::<degree>(destination_lat),
             SignStatus::from(destination_lat_ssm),
         );
+
+        self.slat_flap_actual_position_word = reader.read(&self.slat_flap_actual_position_word_id);
+        self.glideslope_is_valid = reader.read(&self.glideslope_is_valid_id);
+        self.glideslope_deviation = reader.read(&self.glideslope_deviation_id);
+        self.gpws_sys_off = reader.read(&self.gpws_sys_off_id);
+        self.gpws_terr_off = reader.read(&self.gpws_terr_off_id);
+        self.fmgc_nav_accuracy_high = reader.read(&self.fmgc_nav_accuracy_high_id);
+        self.gpws_gs_off = reader.read(&self.gpws_gs_off_id);
+        self.gpws_flap_off = reader.read(&self.gpws_flap_off_id);
+        self.gpws_flaps_3 = reader.read(&self.gpws_flaps_3_id);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -182,6 +404,33 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             &self.egpwc_terronnd_rendering_mode,
             self.terronnd_rendering_mode,
         );
+
+        let (discrete_word_1, discrete_word_2) = if self.is_powered {
+            let mut discrete_word_2 = self.gpws_modes.discrete_word_2();
+            discrete_word_2.set_bit(
+                Self::WINDSHEAR_WARNING_BIT,
+                self.reactive_windshear.warning(),
+            );
+            discrete_word_2.set_bit(
+                Self::WINDSHEAR_CAUTION_BIT,
+                self.reactive_windshear.caution(),
+            );
+
+            (self.gpws_modes.discrete_word_1(), discrete_word_2)
+        } else {
+            (
+                Arinc429Word::new(0, SignStatus::FailureWarning),
+                Arinc429Word::new(0, SignStatus::FailureWarning),
+            )
+        };
+        self.egpws_alert_discrete_word_1_ids
+            .iter()
+            .for_each(|id| writer.write(id, discrete_word_1));
+        self.egpws_alert_discrete_word_2_ids
+            .iter()
+            .for_each(|id| writer.write(id, discrete_word_2));
+        writer.write(&self.gpws_ground_state_id, !self.gpws_modes.is_airborne());
+        writer.write(&self.gpws_approach_state_id, self.gpws_modes.is_approach());
     }
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -202,12 +451,13 @@ mod tests {
         },
     };
     use ntest::assert_about_eq;
+    use std::time::Duration;
     use uom::si::{
         angle::degree,
         electric_potential::volt,
         f64::*,
         length::{foot, nautical_mile},
-        velocity::foot_per_minute,
+        velocity::{foot_per_minute, knot},
     };
 
     struct TestAdirs {
@@ -217,6 +467,7 @@ mod tests {
         heading: Arinc429Word<Angle>,
         vertical_speed: Arinc429Word<Velocity>,
         altitude: Arinc429Word<Length>,
+        computed_airspeed: Arinc429Word<Velocity>,
         angle_of_attack: Arinc429Word<Angle>,
     }
     impl TestAdirs {
@@ -228,6 +479,10 @@ mod tests {
                 heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 vertical_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
                 altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+                computed_airspeed: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
                 angle_of_attack: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
             }
         }
@@ -246,6 +501,8 @@ mod tests {
             );
             self.altitude =
                 Arinc429Word::new(Length::new::<foot>(15000.0), SignStatus::NormalOperation);
+            self.computed_airspeed =
+                Arinc429Word::new(Velocity::new::<knot>(250.0), SignStatus::NormalOperation);
             self.angle_of_attack =
                 Arinc429Word::new(Angle::new::<degree>(0.0), SignStatus::NormalOperation);
         }
@@ -263,6 +520,14 @@ mod tests {
             self.longitude
         }
 
+        fn gpirs_latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+            self.latitude
+        }
+
+        fn gpirs_longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+            self.longitude
+        }
+
         fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.heading
         }
@@ -279,11 +544,76 @@ mod tests {
             self.altitude
         }
 
+        fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            self.computed_airspeed
+        }
+
         fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.angle_of_attack
         }
     }
 
+    struct TestGps {
+        latitude: Arinc429Word<Angle>,
+        longitude: Arinc429Word<Angle>,
+        altitude: Arinc429Word<Length>,
+    }
+    impl TestGps {
+        fn new() -> Self {
+            Self {
+                latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+            }
+        }
+
+        fn acquire(&mut self) {
+            self.latitude =
+                Arinc429Word::new(Angle::new::<degree>(20.4), SignStatus::NormalOperation);
+            self.longitude =
+                Arinc429Word::new(Angle::new::<degree>(30.4), SignStatus::NormalOperation);
+            self.altitude =
+                Arinc429Word::new(Length::new::<foot>(15100.0), SignStatus::NormalOperation);
+        }
+    }
+    impl GpsMeasurementOutputs for TestGps {
+        fn latitude(&self) -> Arinc429Word<Angle> {
+            self.latitude
+        }
+
+        fn longitude(&self) -> Arinc429Word<Angle> {
+            self.longitude
+        }
+
+        fn altitude(&self) -> Arinc429Word<Length> {
+            self.altitude
+        }
+
+        fn horizontal_integrity_limit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::new::<nautical_mile>(0.1), self.latitude.ssm())
+        }
+    }
+
+    struct TestRadioAltimeter {
+        radio_altitude: Arinc429Word<Length>,
+    }
+    impl TestRadioAltimeter {
+        fn new() -> Self {
+            Self {
+                radio_altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+            }
+        }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altitude = Arinc429Word::new(radio_altitude, SignStatus::NormalOperation);
+        }
+    }
+    impl RadioAltimeter for TestRadioAltimeter {
+        fn radio_altitude(&self) -> Arinc429Word<Length> {
+            self.radio_altitude
+        }
+    }
+
     struct TestLgciu {
         gear_down: bool,
     }
@@ -325,6 +655,9 @@ mod tests {
 
     struct EgpwcTestAircraft {
         adirs: TestAdirs,
+        gps: TestGps,
+        radio_altimeter_1: TestRadioAltimeter,
+        radio_altimeter_2: TestRadioAltimeter,
         lgciu: TestLgciu,
         egpwc: EnhancedGroundProximityWarningComputer,
         powered_source_dc: TestElectricitySource,
@@ -335,6 +668,9 @@ mod tests {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 adirs: TestAdirs::new(),
+                gps: TestGps::new(),
+                radio_altimeter_1: TestRadioAltimeter::new(),
+                radio_altimeter_2: TestRadioAltimeter::new(),
                 lgciu: TestLgciu::new(),
                 egpwc: EnhancedGroundProximityWarningComputer::new(
                     context,
@@ -348,6 +684,7 @@ mod tests {
                         Length::new::<nautical_mile>(320.0),
                     ],
                     0,
+                    LandingFlapsPositionBits::new(21, 22),
                 ),
                 powered_source_dc: TestElectricitySource::powered(
                     context,
@@ -358,18 +695,48 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.egpwc.update(&self.adirs, &self.lgciu);
+        fn update(&mut self, context: &UpdateContext) {
+            self.egpwc.update(
+                context,
+                &self.adirs,
+                &self.gps,
+                &self.radio_altimeter_1,
+                &self.radio_altimeter_2,
+                &self.lgciu,
+            );
         }
 
         fn initialize_adiru(&mut self) {
             self.adirs.initialize();
         }
 
+        fn acquire_gps(&mut self) {
+            self.gps.acquire();
+        }
+
         fn gear_down(&mut self) {
             self.lgciu.set_gear_down(true);
         }
 
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altimeter_1.set_radio_altitude(radio_altitude);
+            self.radio_altimeter_2.set_radio_altitude(radio_altitude);
+        }
+
+        fn set_altitude(&mut self, altitude: Length) {
+            self.adirs.altitude = Arinc429Word::new(altitude, SignStatus::NormalOperation);
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.adirs.vertical_speed =
+                Arinc429Word::new(vertical_speed, SignStatus::NormalOperation);
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.adirs.computed_airspeed =
+                Arinc429Word::new(computed_airspeed, SignStatus::NormalOperation);
+        }
+
         fn set_elec_powered(&mut self, is_powered: bool) {
             self.is_elec_powered = is_powered;
         }
@@ -389,8 +756,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for EgpwcTestAircraft {
@@ -625,4 +992,415 @@ mod tests {
         let nd_terr_activate_fo: bool = test_bed.read_by_name("EGPWC_ND_R_TERRAIN_ACTIVE");
         assert!(!nd_terr_activate_fo);
     }
+
+    #[test]
+    fn powered_up_prefers_adiru_position() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.acquire_gps());
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert!(present_lat.is_normal_operation());
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.3);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert!(present_long.is_normal_operation());
+        assert_about_eq!(present_long.value().get::<degree>(), 30.3);
+        let present_altitude: Arinc429Word<Length> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
+        assert!(present_altitude.is_normal_operation());
+        assert_about_eq!(present_altitude.value().get::<foot>(), 15000.0);
+    }
+
+    #[test]
+    fn powered_up_falls_back_to_gps_when_adiru_unavailable() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.acquire_gps());
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert!(present_lat.is_normal_operation());
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.4);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert!(present_long.is_normal_operation());
+        assert_about_eq!(present_long.value().get::<degree>(), 30.4);
+        let present_altitude: Arinc429Word<Length> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
+        assert!(present_altitude.is_normal_operation());
+        assert_about_eq!(present_altitude.value().get::<foot>(), 15100.0);
+        let present_heading: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
+        assert!(present_heading.is_failure_warning());
+    }
+
+    fn alert_discrete_word_1(
+        test_bed: &mut SimulationTestBed<EgpwcTestAircraft>,
+    ) -> Arinc429Word<u32> {
+        test_bed.read_by_name("EGPWS_ALERT_1_DISCRETE_WORD_1")
+    }
+
+    fn alert_discrete_word_2(
+        test_bed: &mut SimulationTestBed<EgpwcTestAircraft>,
+    ) -> Arinc429Word<u32> {
+        test_bed.read_by_name("EGPWS_ALERT_1_DISCRETE_WORD_2")
+    }
+
+    fn slat_flap_actual_position_word(retracted: bool) -> Arinc429Word<u32> {
+        let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+        word.set_bit(12, retracted);
+        word.set_bit(19, retracted);
+
+        word
+    }
+
+    fn powered_test_bed(radio_altitude: Length) -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.write_by_name(
+            "SFCC_SLAT_FLAP_ACTUAL_POSITION_WORD",
+            slat_flap_actual_position_word(true),
+        );
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.set_radio_altitude(radio_altitude));
+
+        test_bed
+    }
+
+    fn airborne_test_bed(radio_altitude: Length) -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = powered_test_bed(radio_altitude);
+        for _ in 0..11 {
+            test_bed.run();
+        }
+
+        let ground_state: bool = test_bed.read_by_name("GPWS_GROUND_STATE");
+        assert!(!ground_state);
+
+        test_bed
+    }
+
+    #[test]
+    fn alert_words_are_failure_warning_when_unpowered() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.run();
+
+        assert!(alert_discrete_word_1(&mut test_bed).is_failure_warning());
+        assert!(alert_discrete_word_2(&mut test_bed).is_failure_warning());
+    }
+
+    #[test]
+    fn no_alerts_in_normal_flight() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(2000.));
+        test_bed.run();
+
+        let word_1 = alert_discrete_word_1(&mut test_bed);
+        assert!(word_1.is_normal_operation());
+        assert_eq!(word_1.value(), 0);
+        assert_eq!(alert_discrete_word_2(&mut test_bed).value(), 0);
+    }
+
+    #[test]
+    fn mode_1_alerts_sink_rate() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(900.));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-2500.)));
+        test_bed.run();
+
+        let word_1 = alert_discrete_word_1(&mut test_bed);
+        assert!(word_1.get_bit(11));
+        assert!(!word_1.get_bit(12));
+        assert!(alert_discrete_word_2(&mut test_bed).get_bit(12));
+    }
+
+    #[test]
+    fn mode_1_alerts_pull_up() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(500.));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-2500.)));
+        test_bed.run();
+
+        assert!(alert_discrete_word_1(&mut test_bed).get_bit(12));
+        assert!(alert_discrete_word_2(&mut test_bed).get_bit(13));
+    }
+
+    #[test]
+    fn mode_1_does_not_alert_at_moderate_sink_rate() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(300.));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-800.)));
+        test_bed.run();
+
+        let word_1 = alert_discrete_word_1(&mut test_bed);
+        assert!(!word_1.get_bit(11));
+        assert!(!word_1.get_bit(12));
+    }
+
+    #[test]
+    fn mode_1_is_inhibited_when_gpws_system_is_off() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(500.));
+        test_bed.write_by_name("GPWS_SYS_OFF", true);
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-2500.)));
+        test_bed.run();
+
+        assert_eq!(alert_discrete_word_1(&mut test_bed).value(), 0);
+    }
+
+    #[test]
+    fn mode_2_is_inhibited_when_closing_terrain() {
+        let mut radio_altitude = Length::new::<foot>(1500.);
+        let mut test_bed = powered_test_bed(radio_altitude);
+        test_bed.command(|a| a.set_vertical_speed(Velocity::default()));
+
+        // Closing the terrain at 6000 ft/min, well within the mode 2 envelope, which would
+        // alert "TERRAIN" then "PULL UP" unless inhibited.
+        for _ in 0..40 {
+            radio_altitude -= Length::new::<foot>(10.);
+            test_bed.command(|a| a.set_radio_altitude(radio_altitude));
+            test_bed.run_with_delta(Duration::from_millis(100));
+        }
+
+        let word_1 = alert_discrete_word_1(&mut test_bed);
+        assert!(!word_1.get_bit(13));
+        assert!(!word_1.get_bit(12));
+    }
+
+    #[test]
+    fn mode_3_alerts_dont_sink_on_altitude_loss_after_takeoff() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(500.));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::default()));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(1000.)));
+        test_bed.run();
+
+        assert!(!alert_discrete_word_1(&mut test_bed).get_bit(14));
+
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(900.)));
+        test_bed.run();
+
+        assert!(alert_discrete_word_1(&mut test_bed).get_bit(14));
+    }
+
+    #[test]
+    fn mode_3_is_inhibited_in_landing_configuration() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(500.));
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_vertical_speed(Velocity::default()));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(1000.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(900.)));
+        test_bed.run();
+
+        assert!(!alert_discrete_word_1(&mut test_bed).get_bit(14));
+    }
+
+    #[test]
+    fn mode_4_alerts_too_low_gear_on_approach() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(1500.));
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(200.)));
+        for _ in 0..11 {
+            test_bed.run();
+        }
+
+        let approach_state: bool = test_bed.read_by_name("GPWS_APPROACH_STATE");
+        assert!(approach_state);
+
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(400.)));
+        test_bed.run();
+
+        assert!(alert_discrete_word_1(&mut test_bed).get_bit(15));
+    }
+
+    #[test]
+    fn mode_4_alerts_too_low_flaps_on_approach() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(1500.));
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(200.)));
+        for _ in 0..11 {
+            test_bed.run();
+        }
+
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let word_1 = alert_discrete_word_1(&mut test_bed);
+        assert!(!word_1.get_bit(15));
+        assert!(word_1.get_bit(16));
+    }
+
+    #[test]
+    fn mode_5_alerts_glideslope_deviation() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(500.));
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.8);
+        test_bed.run();
+
+        assert!(alert_discrete_word_1(&mut test_bed).get_bit(18));
+    }
+
+    #[test]
+    fn mode_5_is_inhibited_when_glideslope_mode_is_off() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(500.));
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.8);
+        test_bed.write_by_name("GPWS_GS_OFF", true);
+        test_bed.run();
+
+        assert!(!alert_discrete_word_1(&mut test_bed).get_bit(18));
+    }
+
+    #[test]
+    fn terrain_clearance_floor_alerts_too_low_terrain_near_runway() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(80.));
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.command(|a| a.gear_down());
+        // 2 nm north of the present position
+        test_bed.write_by_name("FM1_DEST_LAT_SSM", 3);
+        test_bed.write_by_name("FM1_DEST_LAT", 20.3 + 2. / 60.);
+        test_bed.write_by_name("FM1_DEST_LONG_SSM", 3);
+        test_bed.write_by_name("FM1_DEST_LONG", 30.3);
+        test_bed.write_by_name("FMGC_L_NAV_ACCURACY_HIGH", true);
+        for _ in 0..11 {
+            test_bed.run();
+        }
+
+        assert!(alert_discrete_word_1(&mut test_bed).get_bit(17));
+
+        test_bed.write_by_name("GPWS_TERR_OFF", true);
+        test_bed.run();
+
+        assert!(!alert_discrete_word_1(&mut test_bed).get_bit(17));
+    }
+
+    #[test]
+    fn terrain_clearance_floor_is_inhibited_with_low_navigation_accuracy() {
+        let mut test_bed = powered_test_bed(Length::new::<foot>(80.));
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.command(|a| a.gear_down());
+        test_bed.write_by_name("FM1_DEST_LAT_SSM", 3);
+        test_bed.write_by_name("FM1_DEST_LAT", 20.3 + 2. / 60.);
+        test_bed.write_by_name("FM1_DEST_LONG_SSM", 3);
+        test_bed.write_by_name("FM1_DEST_LONG", 30.3);
+        test_bed.write_by_name("FMGC_L_NAV_ACCURACY_HIGH", false);
+        for _ in 0..11 {
+            test_bed.run();
+        }
+
+        assert!(!alert_discrete_word_1(&mut test_bed).get_bit(17));
+    }
+
+    #[test]
+    fn terrain_clearance_floor_does_not_alert_far_from_runway() {
+        let mut test_bed = airborne_test_bed(Length::new::<foot>(80.));
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.command(|a| a.gear_down());
+        test_bed.write_by_name("FM1_DEST_LAT_SSM", 3);
+        test_bed.write_by_name("FM1_DEST_LAT", 60.4);
+        test_bed.write_by_name("FM1_DEST_LONG_SSM", 3);
+        test_bed.write_by_name("FM1_DEST_LONG", 25.3);
+        test_bed.write_by_name("FMGC_L_NAV_ACCURACY_HIGH", true);
+        test_bed.run();
+
+        assert!(!alert_discrete_word_1(&mut test_bed).get_bit(17));
+    }
+
+    fn windshear_test_bed(radio_altitude: Length) -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = powered_test_bed(radio_altitude);
+        test_bed.write_by_name(
+            "SFCC_SLAT_FLAP_ACTUAL_POSITION_WORD",
+            slat_flap_actual_position_word(false),
+        );
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+
+        test_bed
+    }
+
+    #[test]
+    fn reactive_windshear_warning_in_downdraft() {
+        let mut test_bed = windshear_test_bed(Length::new::<foot>(800.));
+        test_bed.write_by_name("AMBIENT WIND Y", -15.);
+        for _ in 0..5 {
+            test_bed.run();
+        }
+
+        let word_2 = alert_discrete_word_2(&mut test_bed);
+        assert!(word_2.get_bit(15));
+        assert!(!word_2.get_bit(17));
+    }
+
+    #[test]
+    fn reactive_windshear_warning_on_increasing_tailwind() {
+        let mut computed_airspeed = Velocity::new::<knot>(150.);
+        let mut test_bed = windshear_test_bed(Length::new::<foot>(800.));
+        for _ in 0..6 {
+            computed_airspeed -= Velocity::new::<knot>(3.);
+            test_bed.command(|a| a.set_computed_airspeed(computed_airspeed));
+            test_bed.run();
+        }
+
+        assert!(alert_discrete_word_2(&mut test_bed).get_bit(15));
+    }
+
+    #[test]
+    fn reactive_windshear_caution_in_updraft() {
+        let mut test_bed = windshear_test_bed(Length::new::<foot>(800.));
+        test_bed.write_by_name("AMBIENT WIND Y", 15.);
+        for _ in 0..5 {
+            test_bed.run();
+        }
+
+        let word_2 = alert_discrete_word_2(&mut test_bed);
+        assert!(!word_2.get_bit(15));
+        assert!(word_2.get_bit(17));
+    }
+
+    #[test]
+    fn reactive_windshear_warning_is_held_after_the_shear() {
+        let mut test_bed = windshear_test_bed(Length::new::<foot>(800.));
+        test_bed.write_by_name("AMBIENT WIND Y", -15.);
+        for _ in 0..5 {
+            test_bed.run();
+        }
+
+        test_bed.write_by_name("AMBIENT WIND Y", 0.);
+        test_bed.run();
+
+        assert!(alert_discrete_word_2(&mut test_bed).get_bit(15));
+
+        for _ in 0..5 {
+            test_bed.run();
+        }
+
+        assert!(!alert_discrete_word_2(&mut test_bed).get_bit(15));
+    }
+
+    #[test]
+    fn reactive_windshear_is_inhibited_in_clean_configuration() {
+        let mut test_bed = windshear_test_bed(Length::new::<foot>(800.));
+        test_bed.write_by_name(
+            "SFCC_SLAT_FLAP_ACTUAL_POSITION_WORD",
+            slat_flap_actual_position_word(true),
+        );
+        test_bed.write_by_name("AMBIENT WIND Y", -15.);
+        for _ in 0..5 {
+            test_bed.run();
+        }
+
+        assert!(!alert_discrete_word_2(&mut test_bed).get_bit(15));
+    }
+
+    #[test]
+    fn reactive_windshear_is_inhibited_above_1500_ft() {
+        let mut test_bed = windshear_test_bed(Length::new::<foot>(2000.));
+        test_bed.write_by_name("AMBIENT WIND Y", -15.);
+        for _ in 0..5 {
+            test_bed.run();
+        }
+
+        assert!(!alert_discrete_word_2(&mut test_bed).get_bit(15));
+    }
 }
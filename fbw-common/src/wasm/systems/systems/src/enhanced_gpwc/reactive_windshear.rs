@@ -0,0 +1,142 @@
+use crate::{
+    shared::{arinc429::Arinc429Word, low_pass_filter::LowPassFilter, DelayedFalseLogicGate},
+    simulation::UpdateContext,
+};
+use std::time::Duration;
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    angle::radian,
+    f64::{Angle, Length, Velocity},
+    length::foot,
+    velocity::{knot, meter_per_second},
+};
+
+/// The sensor inputs the reactive windshear detection is computed from.
+pub(super) struct ReactiveWindshearInputs {
+    pub(super) radio_altitude: Arinc429Word<Length>,
+    pub(super) computed_airspeed: Arinc429Word<Velocity>,
+    pub(super) angle_of_attack: Arinc429Word<Angle>,
+    /// True when the slats or flaps are out of the clean configuration.
+    pub(super) slats_or_flaps_extended: bool,
+}
+
+/// Detects windshear the aircraft is already flying through by computing the windshear hazard
+/// factor F = Wx_dot / g - Wz / V, where Wx_dot is the rate of change of the tailwind along the
+/// flight path and Wz the vertical wind. A positive hazard factor indicates a loss of aircraft
+/// performance (increasing tailwind or downdraft) and leads to a warning, a negative one a gain
+/// of performance (increasing headwind or updraft) and leads to a caution.
+pub(super) struct ReactiveWindshear {
+    previous_computed_airspeed: Option<Velocity>,
+    hazard_factor: LowPassFilter<f64>,
+    warning: DelayedFalseLogicGate,
+    caution: DelayedFalseLogicGate,
+}
+
+impl ReactiveWindshear {
+    const GRAVITY_METER_PER_SECOND_SQUARED: f64 = 9.80665;
+    const HAZARD_FACTOR_TIME_CONSTANT: Duration = Duration::from_millis(1500);
+    const MINIMUM_RADIO_ALTITUDE_FEET: f64 = 50.;
+    const MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 1500.;
+    const MINIMUM_COMPUTED_AIRSPEED_KNOTS: f64 = 60.;
+    const WARNING_HAZARD_FACTOR: f64 = 0.13;
+    const CAUTION_HAZARD_FACTOR: f64 = -0.13;
+    // Below this height the warning threshold is lowered, as there is less
+    // altitude available to recover from the loss of performance.
+    const LOW_ALTITUDE_RADIO_ALTITUDE_FEET: f64 = 500.;
+    const LOW_ALTITUDE_WARNING_HAZARD_FACTOR: f64 = 0.105;
+    const MINIMUM_ALERT_DURATION: Duration = Duration::from_secs(3);
+
+    pub(super) fn new() -> Self {
+        Self {
+            previous_computed_airspeed: None,
+            hazard_factor: LowPassFilter::new(Self::HAZARD_FACTOR_TIME_CONSTANT),
+            warning: DelayedFalseLogicGate::new(Self::MINIMUM_ALERT_DURATION),
+            caution: DelayedFalseLogicGate::new(Self::MINIMUM_ALERT_DURATION),
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, inputs: &ReactiveWindshearInputs) {
+        let computed_airspeed = inputs
+            .computed_airspeed
+            .normal_value()
+            .filter(|airspeed| airspeed.get::<knot>() > Self::MINIMUM_COMPUTED_AIRSPEED_KNOTS);
+
+        let hazard_factor = match (self.previous_computed_airspeed, computed_airspeed) {
+            (Some(previous), Some(current)) if context.delta_as_secs_f64() > 0. => {
+                self.hazard_factor.update(
+                    context.delta(),
+                    Self::hazard_factor(context, inputs, previous, current),
+                );
+
+                Some(self.hazard_factor.output())
+            }
+            _ => {
+                self.hazard_factor.reset(0.);
+
+                None
+            }
+        };
+        self.previous_computed_airspeed = computed_airspeed;
+
+        let radio_altitude = inputs.radio_altitude.normal_value();
+        let is_in_envelope = inputs.slats_or_flaps_extended
+            && radio_altitude.map_or(false, |radio_altitude| {
+                (Self::MINIMUM_RADIO_ALTITUDE_FEET..=Self::MAXIMUM_RADIO_ALTITUDE_FEET)
+                    .contains(&radio_altitude.get::<foot>())
+            });
+        let warning_hazard_factor = if radio_altitude.map_or(false, |radio_altitude| {
+            radio_altitude.get::<foot>() < Self::LOW_ALTITUDE_RADIO_ALTITUDE_FEET
+        }) {
+            Self::LOW_ALTITUDE_WARNING_HAZARD_FACTOR
+        } else {
+            Self::WARNING_HAZARD_FACTOR
+        };
+
+        let warning =
+            is_in_envelope && hazard_factor.map_or(false, |factor| factor > warning_hazard_factor);
+        let caution = is_in_envelope
+            && !warning
+            && hazard_factor.map_or(false, |factor| factor < Self::CAUTION_HAZARD_FACTOR);
+
+        self.warning.update(context, warning);
+        self.caution.update(context, caution);
+    }
+
+    fn hazard_factor(
+        context: &UpdateContext,
+        inputs: &ReactiveWindshearInputs,
+        previous_computed_airspeed: Velocity,
+        computed_airspeed: Velocity,
+    ) -> f64 {
+        let angle_of_attack = inputs
+            .angle_of_attack
+            .normal_value()
+            .unwrap_or_default()
+            .get::<radian>();
+
+        // Inertial acceleration along the flight path, which is rotated from the longitudinal
+        // body axis by the angle of attack.
+        let flight_path_acceleration = context.long_accel().get::<meter_per_second_squared>()
+            * angle_of_attack.cos()
+            - context.vert_accel().get::<meter_per_second_squared>() * angle_of_attack.sin();
+        let airspeed_rate = (computed_airspeed - previous_computed_airspeed)
+            .get::<meter_per_second>()
+            / context.delta_as_secs_f64();
+        let tailwind_rate = flight_path_acceleration - airspeed_rate;
+
+        // The relative wind is the ambient wind minus the aircraft velocity, both in body axes.
+        let vertical_wind = context.local_relative_wind().to_ms_vector()[1]
+            + context.local_velocity().to_ms_vector()[1];
+
+        tailwind_rate / Self::GRAVITY_METER_PER_SECOND_SQUARED
+            - vertical_wind / computed_airspeed.get::<meter_per_second>()
+    }
+
+    pub(super) fn warning(&self) -> bool {
+        self.warning.output()
+    }
+
+    pub(super) fn caution(&self) -> bool {
+        self.caution.output()
+    }
+}
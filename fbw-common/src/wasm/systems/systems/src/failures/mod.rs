@@ -1,3 +1,5 @@
+pub mod trigger;
+
 use crate::air_conditioning::{
     acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, VcmId, ZoneType,
 };
@@ -7,7 +9,10 @@ use crate::shared::{
     AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
     FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
 };
-use crate::simulation::SimulationElement;
+use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
+    SimulationElement,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum FailureType {
@@ -40,18 +45,29 @@ pub enum FailureType {
     // ATA24
     Generator(usize),
     ApuGenerator(usize),
+    GeneratorFeeder(usize),
+    ApuGeneratorFeeder(usize),
+    GeneratorDriveOilLeak(usize),
     TransformerRectifier(usize),
     StaticInverter,
     ElectricalBus(ElectricalBusType),
+    CircuitBreaker(&'static str),
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    // ATA28
+    FuelPump(usize),
+    FuelQuantityProbe(usize),
+    FuelQuantityIndicationChannel(usize),
+    FuelDensitometer,
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
+    EnginePumpWear(AirbusEngineDrivenPumpId),
+    ElecPumpWear(AirbusElectricPumpId),
     // ATA32
     LgciuPowerSupply(LgciuId),
     LgciuInternalError(LgciuId),
@@ -61,6 +77,13 @@ pub enum FailureType {
     BrakeAccumulatorGasLeak,
     // ATA34
     RadioAltimeter(usize),
+    Adiru(usize),
+    Adr(usize),
+    Ir(usize),
+    PitotProbeBlocked(usize),
+    StaticPortBlocked(usize),
+    AngleOfAttackVaneJammed(usize),
+    GpsSatelliteLoss(usize),
 }
 
 pub struct Failure {
@@ -89,6 +112,18 @@ impl SimulationElement for Failure {
             self.is_active = is_active;
         }
     }
+
+    fn simulated_failure(&self) -> Option<FailureType> {
+        Some(self.failure_type)
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_active);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_active = state.read();
+    }
 }
 
 #[cfg(test)]
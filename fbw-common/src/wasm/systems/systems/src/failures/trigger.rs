@@ -0,0 +1,733 @@
+use super::FailureType;
+use crate::{
+    shared::{random_from_range, FwcFlightPhase},
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        InitContext, Read, SimulationElement, SimulatorReader, UpdateContext, VariableIdentifier,
+    },
+};
+use num_traits::FromPrimitive;
+use std::time::Duration;
+use uom::si::f64::{Length, Velocity};
+
+/// The condition which has to be met for an armed failure to trigger.
+#[derive(Clone, Copy, PartialEq)]
+pub enum FailureTrigger {
+    /// Triggers once the given time has passed since the failure was armed.
+    After(Duration),
+    AltitudeAbove(Length),
+    AltitudeBelow(Length),
+    AirspeedAbove(Velocity),
+    AirspeedBelow(Velocity),
+    FlightPhase(FwcFlightPhase),
+    /// Triggers at a random moment, on average once per the given mean time
+    /// between failures.
+    Random(Duration),
+}
+impl FailureTrigger {
+    fn is_met(
+        &self,
+        context: &UpdateContext,
+        entered_flight_phase: Option<FwcFlightPhase>,
+    ) -> bool {
+        match *self {
+            FailureTrigger::After(_) => false,
+            FailureTrigger::AltitudeAbove(altitude) => context.indicated_altitude() > altitude,
+            FailureTrigger::AltitudeBelow(altitude) => context.indicated_altitude() < altitude,
+            FailureTrigger::AirspeedAbove(airspeed) => context.indicated_airspeed() > airspeed,
+            FailureTrigger::AirspeedBelow(airspeed) => context.indicated_airspeed() < airspeed,
+            FailureTrigger::FlightPhase(phase) => entered_flight_phase == Some(phase),
+            FailureTrigger::Random(mean_time_between_failures) => {
+                let probability = 1.
+                    - (-context.delta_as_secs_f64()
+                        / mean_time_between_failures.as_secs_f64().max(f64::EPSILON))
+                    .exp();
+                random_from_range(0., 1.) < probability
+            }
+        }
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        match *self {
+            FailureTrigger::After(duration) => {
+                state.write(0.);
+                state.write(duration);
+            }
+            FailureTrigger::AltitudeAbove(altitude) => {
+                state.write(1.);
+                state.write(altitude);
+            }
+            FailureTrigger::AltitudeBelow(altitude) => {
+                state.write(2.);
+                state.write(altitude);
+            }
+            FailureTrigger::AirspeedAbove(airspeed) => {
+                state.write(3.);
+                state.write(airspeed);
+            }
+            FailureTrigger::AirspeedBelow(airspeed) => {
+                state.write(4.);
+                state.write(airspeed);
+            }
+            FailureTrigger::FlightPhase(phase) => {
+                state.write(5.);
+                state.write(phase as u8 as f64);
+            }
+            FailureTrigger::Random(mean_time_between_failures) => {
+                state.write(6.);
+                state.write(mean_time_between_failures);
+            }
+        }
+    }
+
+    fn restored(state: &mut StateReader) -> Option<Self> {
+        let kind = state.read::<f64>() as u8;
+        match kind {
+            0 => Some(FailureTrigger::After(state.read())),
+            1 => Some(FailureTrigger::AltitudeAbove(state.read())),
+            2 => Some(FailureTrigger::AltitudeBelow(state.read())),
+            3 => Some(FailureTrigger::AirspeedAbove(state.read())),
+            4 => Some(FailureTrigger::AirspeedBelow(state.read())),
+            5 => FromPrimitive::from_f64(state.read()).map(FailureTrigger::FlightPhase),
+            6 => Some(FailureTrigger::Random(state.read())),
+            _ => None,
+        }
+    }
+}
+
+/// Describes how a failure behaves once its trigger condition is met.
+#[derive(Clone, Copy, PartialEq)]
+pub enum FailureActivation {
+    /// The failure becomes active and stays active.
+    Permanent,
+    /// The failure lies dormant for the given time before it becomes active.
+    Latent(Duration),
+    /// The failure alternates between being active and inactive for the given times,
+    /// until it is disarmed.
+    Intermittent {
+        active: Duration,
+        inactive: Duration,
+    },
+}
+impl FailureActivation {
+    fn save_state(&self, state: &mut StateWriter) {
+        let (kind, first, second) = match *self {
+            FailureActivation::Permanent => (0., Duration::ZERO, Duration::ZERO),
+            FailureActivation::Latent(dormancy) => (1., dormancy, Duration::ZERO),
+            FailureActivation::Intermittent { active, inactive } => (2., active, inactive),
+        };
+        state.write(kind);
+        state.write(first);
+        state.write(second);
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        let kind = state.read::<f64>() as u8;
+        let first = state.read();
+        let second = state.read();
+        match kind {
+            1 => FailureActivation::Latent(first),
+            2 => FailureActivation::Intermittent {
+                active: first,
+                inactive: second,
+            },
+            _ => FailureActivation::Permanent,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ArmedFailureState {
+    Armed,
+    Dormant(Duration),
+    Active(Duration),
+    Inactive(Duration),
+}
+impl ArmedFailureState {
+    fn save_state(&self, state: &mut StateWriter) {
+        let (kind, remaining) = match *self {
+            ArmedFailureState::Armed => (0., Duration::ZERO),
+            ArmedFailureState::Dormant(remaining) => (1., remaining),
+            ArmedFailureState::Active(remaining) => (2., remaining),
+            ArmedFailureState::Inactive(remaining) => (3., remaining),
+        };
+        state.write(kind);
+        state.write(remaining);
+    }
+
+    fn restored(state: &mut StateReader) -> Self {
+        let kind = state.read::<f64>() as u8;
+        let remaining = state.read();
+        match kind {
+            1 => ArmedFailureState::Dormant(remaining),
+            2 => ArmedFailureState::Active(remaining),
+            3 => ArmedFailureState::Inactive(remaining),
+            _ => ArmedFailureState::Armed,
+        }
+    }
+}
+
+struct ArmedFailure {
+    failure_type: FailureType,
+    trigger: FailureTrigger,
+    activation: FailureActivation,
+    armed_duration: Duration,
+    state: ArmedFailureState,
+}
+impl ArmedFailure {
+    fn new(
+        failure_type: FailureType,
+        trigger: FailureTrigger,
+        activation: FailureActivation,
+    ) -> Self {
+        Self {
+            failure_type,
+            trigger,
+            activation,
+            armed_duration: Duration::ZERO,
+            state: ArmedFailureState::Armed,
+        }
+    }
+
+    /// Returns the new activity of the failure when it changes.
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        entered_flight_phase: Option<FwcFlightPhase>,
+    ) -> Option<bool> {
+        self.armed_duration += context.delta();
+
+        match self.state {
+            ArmedFailureState::Armed => {
+                let is_triggered = match self.trigger {
+                    FailureTrigger::After(duration) => self.armed_duration >= duration,
+                    trigger => trigger.is_met(context, entered_flight_phase),
+                };
+
+                if is_triggered {
+                    self.trigger_activation()
+                } else {
+                    None
+                }
+            }
+            ArmedFailureState::Dormant(remaining) => {
+                if remaining <= context.delta() {
+                    self.activate()
+                } else {
+                    self.state = ArmedFailureState::Dormant(remaining - context.delta());
+                    None
+                }
+            }
+            ArmedFailureState::Active(remaining) => match self.activation {
+                FailureActivation::Intermittent { inactive, .. }
+                    if remaining <= context.delta() =>
+                {
+                    self.state = ArmedFailureState::Inactive(inactive);
+                    Some(false)
+                }
+                _ => {
+                    self.state =
+                        ArmedFailureState::Active(remaining.saturating_sub(context.delta()));
+                    None
+                }
+            },
+            ArmedFailureState::Inactive(remaining) => {
+                if remaining <= context.delta() {
+                    self.activate()
+                } else {
+                    self.state = ArmedFailureState::Inactive(remaining - context.delta());
+                    None
+                }
+            }
+        }
+    }
+
+    fn trigger_activation(&mut self) -> Option<bool> {
+        match self.activation {
+            FailureActivation::Latent(dormancy) if !dormancy.is_zero() => {
+                self.state = ArmedFailureState::Dormant(dormancy);
+                None
+            }
+            _ => self.activate(),
+        }
+    }
+
+    fn activate(&mut self) -> Option<bool> {
+        self.state = ArmedFailureState::Active(match self.activation {
+            FailureActivation::Intermittent { active, .. } => active,
+            _ => Duration::MAX,
+        });
+
+        Some(true)
+    }
+
+    fn is_active(&self) -> bool {
+        matches!(self.state, ArmedFailureState::Active(_))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_active() && !matches!(self.activation, FailureActivation::Intermittent { .. })
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.trigger.save_state(state);
+        self.activation.save_state(state);
+        state.write(self.armed_duration);
+        self.state.save_state(state);
+    }
+
+    /// Reads the failure written by `save_state`. Returns `None` when the failure type
+    /// or the trigger is unknown, after reading all of the failure's values.
+    fn restored(failure_type: Option<FailureType>, state: &mut StateReader) -> Option<Self> {
+        let trigger = FailureTrigger::restored(state);
+        let activation = FailureActivation::restored(state);
+        let armed_duration = state.read();
+        let failure_state = ArmedFailureState::restored(state);
+
+        Some(Self {
+            failure_type: failure_type?,
+            trigger: trigger?,
+            activation,
+            armed_duration,
+            state: failure_state,
+        })
+    }
+}
+
+/// Keeps track of failures which are armed to activate once their trigger
+/// condition is met, instead of being activated immediately.
+pub struct ArmedFailures {
+    fwc_flight_phase_id: VariableIdentifier,
+    flight_phase: Option<FwcFlightPhase>,
+    previous_flight_phase: Option<FwcFlightPhase>,
+    failures: Vec<ArmedFailure>,
+}
+impl ArmedFailures {
+    pub(crate) fn new(context: &mut InitContext) -> Self {
+        Self {
+            fwc_flight_phase_id: context.get_identifier("FWC_FLIGHT_PHASE".to_owned()),
+            flight_phase: None,
+            previous_flight_phase: None,
+            failures: vec![],
+        }
+    }
+
+    /// Arms the failure, replacing any earlier arming of the same failure.
+    pub(crate) fn arm(
+        &mut self,
+        failure_type: FailureType,
+        trigger: FailureTrigger,
+        activation: FailureActivation,
+    ) {
+        self.disarm(failure_type);
+        self.failures
+            .push(ArmedFailure::new(failure_type, trigger, activation));
+    }
+
+    /// Disarms the failure. Returns true when the failure was armed and
+    /// active at that moment.
+    pub(crate) fn disarm(&mut self, failure_type: FailureType) -> bool {
+        let was_active = self
+            .failures
+            .iter()
+            .any(|failure| failure.failure_type == failure_type && failure.is_active());
+        self.failures
+            .retain(|failure| failure.failure_type != failure_type);
+
+        was_active
+    }
+
+    pub fn is_armed(&self, failure_type: FailureType) -> bool {
+        self.failures
+            .iter()
+            .any(|failure| failure.failure_type == failure_type)
+    }
+
+    /// Returns the failures which have to be activated or deactivated.
+    /// Failures which became permanently active are no longer armed afterwards.
+    pub(crate) fn update(&mut self, context: &UpdateContext) -> Vec<(FailureType, bool)> {
+        // A flight phase trigger is only met when entering the phase, such that a failure
+        // armed during the phase doesn't trigger until the next time the phase is entered.
+        let entered_flight_phase = if self.flight_phase != self.previous_flight_phase {
+            self.flight_phase
+        } else {
+            None
+        };
+        let changes = self
+            .failures
+            .iter_mut()
+            .filter_map(|failure| {
+                failure
+                    .update(context, entered_flight_phase)
+                    .map(|is_active| (failure.failure_type, is_active))
+            })
+            .collect();
+
+        self.failures.retain(|failure| !failure.is_finished());
+
+        changes
+    }
+
+    /// Saves the armed failures and the flight phase, which becomes the previous flight phase
+    /// once the next flight phase is read. A failure is identified by its position within the
+    /// failures the aircraft simulates. Failures the aircraft doesn't simulate are skipped.
+    pub(crate) fn save_state(&self, failure_types: &[FailureType], state: &mut StateWriter) {
+        state.write(self.flight_phase.map_or(0., |phase| phase as u8 as f64));
+
+        let failures: Vec<_> = self
+            .failures
+            .iter()
+            .filter_map(|failure| {
+                failure_types
+                    .iter()
+                    .position(|failure_type| *failure_type == failure.failure_type)
+                    .map(|index| (index, failure))
+            })
+            .collect();
+
+        state.write_count(failures.len());
+        for (index, failure) in failures {
+            state.write(index as f64);
+            failure.save_state(state);
+        }
+    }
+
+    pub(crate) fn restore_state(&mut self, failure_types: &[FailureType], state: &mut StateReader) {
+        self.flight_phase = FromPrimitive::from_f64(state.read());
+        self.previous_flight_phase = self.flight_phase;
+
+        self.failures.clear();
+        for _ in 0..state.read_count() {
+            let failure_type = failure_types.get(state.read::<f64>() as usize).copied();
+            if let Some(failure) = ArmedFailure::restored(failure_type, state) {
+                self.failures.push(failure);
+            }
+        }
+    }
+}
+impl SimulationElement for ArmedFailures {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.previous_flight_phase = self.flight_phase;
+        self.flight_phase = FromPrimitive::from_f64(reader.read(&self.fwc_flight_phase_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        shared::seed_random,
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use uom::si::{length::foot, velocity::knot};
+
+    struct TestAircraft {
+        failure: Failure,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                failure: Failure::new(FailureType::TransformerRectifier(1)),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {}
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct ArmedFailuresTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl ArmedFailuresTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn armed_with(mut self, trigger: FailureTrigger, activation: FailureActivation) -> Self {
+            self.arm_failure(FailureType::TransformerRectifier(1), trigger, activation);
+            self
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            self.run_with_delta(duration);
+        }
+
+        fn is_failed(&self) -> bool {
+            self.query(|a| a.failure.is_active())
+        }
+
+        fn is_armed(&self) -> bool {
+            self.test_bed
+                .is_failure_armed(FailureType::TransformerRectifier(1))
+        }
+    }
+    impl TestBed for ArmedFailuresTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn triggers_after_the_given_time() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::After(Duration::from_secs(10)),
+            FailureActivation::Permanent,
+        );
+
+        test_bed.run_for(Duration::from_secs(9));
+        assert!(!test_bed.is_failed());
+
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(test_bed.is_failed());
+        assert!(!test_bed.is_armed());
+    }
+
+    #[test]
+    fn reports_the_failures_triggered_during_the_last_tick() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::After(Duration::from_secs(1)),
+            FailureActivation::Intermittent {
+                active: Duration::from_secs(2),
+                inactive: Duration::from_secs(3),
+            },
+        );
+
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(
+            test_bed.test_bed.triggered_failures()
+                == [(FailureType::TransformerRectifier(1), true)]
+        );
+
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(test_bed.test_bed.triggered_failures().is_empty());
+
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(
+            test_bed.test_bed.triggered_failures()
+                == [(FailureType::TransformerRectifier(1), false)]
+        );
+    }
+
+    #[test]
+    fn triggers_above_altitude() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::AltitudeAbove(Length::new::<foot>(10000.)),
+            FailureActivation::Permanent,
+        );
+
+        test_bed.set_indicated_altitude(Length::new::<foot>(9000.));
+        test_bed.run();
+        assert!(!test_bed.is_failed());
+
+        test_bed.set_indicated_altitude(Length::new::<foot>(11000.));
+        test_bed.run();
+        assert!(test_bed.is_failed());
+    }
+
+    #[test]
+    fn triggers_below_airspeed() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::AirspeedBelow(Velocity::new::<knot>(100.)),
+            FailureActivation::Permanent,
+        );
+
+        test_bed.run();
+        assert!(!test_bed.is_failed());
+
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(90.));
+        test_bed.run();
+        assert!(test_bed.is_failed());
+    }
+
+    #[test]
+    fn triggers_in_flight_phase() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::FlightPhase(FwcFlightPhase::AtOrAbove1500Feet),
+            FailureActivation::Permanent,
+        );
+
+        test_bed.write_by_name("FWC_FLIGHT_PHASE", FwcFlightPhase::LiftOff as i32 as f64);
+        test_bed.run();
+        assert!(!test_bed.is_failed());
+
+        test_bed.write_by_name(
+            "FWC_FLIGHT_PHASE",
+            FwcFlightPhase::AtOrAbove1500Feet as i32 as f64,
+        );
+        test_bed.run();
+        assert!(test_bed.is_failed());
+    }
+
+    #[test]
+    fn flight_phase_trigger_waits_for_the_phase_to_be_entered_again() {
+        let mut test_bed = ArmedFailuresTestBed::new();
+        test_bed.write_by_name(
+            "FWC_FLIGHT_PHASE",
+            FwcFlightPhase::AtOrAbove1500Feet as i32 as f64,
+        );
+        test_bed.run();
+
+        let mut test_bed = test_bed.armed_with(
+            FailureTrigger::FlightPhase(FwcFlightPhase::AtOrAbove1500Feet),
+            FailureActivation::Permanent,
+        );
+        test_bed.run();
+        assert!(!test_bed.is_failed());
+
+        test_bed.write_by_name(
+            "FWC_FLIGHT_PHASE",
+            FwcFlightPhase::AtOrBelow800Feet as i32 as f64,
+        );
+        test_bed.run();
+        assert!(!test_bed.is_failed());
+
+        test_bed.write_by_name(
+            "FWC_FLIGHT_PHASE",
+            FwcFlightPhase::AtOrAbove1500Feet as i32 as f64,
+        );
+        test_bed.run();
+        assert!(test_bed.is_failed());
+    }
+
+    #[test]
+    fn snapshot_restores_the_flight_phase_the_trigger_waits_to_leave() {
+        let mut test_bed = ArmedFailuresTestBed::new();
+        test_bed.write_by_name(
+            "FWC_FLIGHT_PHASE",
+            FwcFlightPhase::AtOrAbove1500Feet as i32 as f64,
+        );
+        test_bed.run();
+        let mut test_bed = test_bed.armed_with(
+            FailureTrigger::FlightPhase(FwcFlightPhase::AtOrAbove1500Feet),
+            FailureActivation::Permanent,
+        );
+        let snapshot = test_bed.save_snapshot();
+
+        let mut restored_test_bed = ArmedFailuresTestBed::new();
+        restored_test_bed.restore_snapshot(&snapshot).unwrap();
+        restored_test_bed.write_by_name(
+            "FWC_FLIGHT_PHASE",
+            FwcFlightPhase::AtOrAbove1500Feet as i32 as f64,
+        );
+        restored_test_bed.run();
+
+        assert!(!restored_test_bed.is_failed());
+        assert!(restored_test_bed.is_armed());
+    }
+
+    #[test]
+    fn random_trigger_eventually_triggers() {
+        seed_random(42);
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::Random(Duration::from_secs(60)),
+            FailureActivation::Permanent,
+        );
+
+        let mut seconds = 0;
+        while !test_bed.is_failed() && seconds < 3600 {
+            test_bed.run_for(Duration::from_secs(1));
+            seconds += 1;
+        }
+
+        assert!(test_bed.is_failed());
+    }
+
+    #[test]
+    fn latent_failure_activates_after_dormancy() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::After(Duration::from_secs(1)),
+            FailureActivation::Latent(Duration::from_secs(5)),
+        );
+
+        test_bed.run_for(Duration::from_secs(1));
+        test_bed.run_for(Duration::from_secs(4));
+        assert!(!test_bed.is_failed());
+        assert!(test_bed.is_armed());
+
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(test_bed.is_failed());
+    }
+
+    #[test]
+    fn intermittent_failure_alternates_until_disarmed() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::After(Duration::ZERO),
+            FailureActivation::Intermittent {
+                active: Duration::from_secs(2),
+                inactive: Duration::from_secs(3),
+            },
+        );
+
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(test_bed.is_failed());
+
+        test_bed.run_for(Duration::from_secs(2));
+        assert!(!test_bed.is_failed());
+
+        test_bed.run_for(Duration::from_secs(3));
+        assert!(test_bed.is_failed());
+
+        test_bed.disarm_failure(FailureType::TransformerRectifier(1));
+        test_bed.run();
+        assert!(!test_bed.is_failed());
+        assert!(!test_bed.is_armed());
+    }
+
+    #[test]
+    fn deactivating_failure_disarms_it() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::After(Duration::from_secs(10)),
+            FailureActivation::Permanent,
+        );
+
+        test_bed.unfail(FailureType::TransformerRectifier(1));
+        test_bed.run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.is_failed());
+        assert!(!test_bed.is_armed());
+    }
+
+    #[test]
+    fn snapshot_restores_armed_failure_progress() {
+        let mut test_bed = ArmedFailuresTestBed::new().armed_with(
+            FailureTrigger::After(Duration::from_secs(10)),
+            FailureActivation::Latent(Duration::from_secs(5)),
+        );
+
+        test_bed.run_for(Duration::from_secs(4));
+        let snapshot = test_bed.save_snapshot();
+
+        test_bed.run_for(Duration::from_secs(10));
+        test_bed.run_for(Duration::from_secs(10));
+        assert!(test_bed.is_failed());
+        assert!(!test_bed.is_armed());
+
+        test_bed.restore_snapshot(&snapshot).unwrap();
+        assert!(!test_bed.is_failed());
+        assert!(test_bed.is_armed());
+
+        test_bed.run_for(Duration::from_secs(6));
+        test_bed.run_for(Duration::from_secs(4));
+        assert!(!test_bed.is_failed());
+
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(test_bed.is_failed());
+    }
+}
@@ -0,0 +1,281 @@
+use super::{FuelInfo, FuelSystem, FuelType};
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{arinc429::SignStatus, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use uom::si::{angle::radian, f64::Mass, mass::kilogram};
+
+fn quantity_word(quantity: Option<Mass>) -> (f64, SignStatus) {
+    match quantity {
+        Some(quantity) => (quantity.get::<kilogram>(), SignStatus::NormalOperation),
+        None => (0., SignStatus::FailureWarning),
+    }
+}
+
+/// The capacitance probes of a single tank. The probes measure the height of the fuel and the
+/// computer converts it into a volume using the tank geometry at the current attitude. The
+/// correction isn't perfect when the tilted fuel surface reaches the bottom or the top of the
+/// probes, thus the indication is slightly low with a nearly empty or nearly full tank in a
+/// pitched or banked attitude, by at most a few percent of the contained fuel. With a failed
+/// probe, the quantity is interpolated from the remaining probes and is degraded.
+struct TankGauge {
+    indicated_quantity_id: VariableIdentifier,
+    is_degraded_id: VariableIdentifier,
+
+    capacity_gallons: f64,
+    probe_failure: Failure,
+
+    indicated_quantity: Option<Mass>,
+}
+impl TankGauge {
+    const PITCH_ATTITUDE_ERROR_FACTOR: f64 = 0.1;
+    const BANK_ATTITUDE_ERROR_FACTOR: f64 = 0.2;
+    const MAXIMUM_ATTITUDE_ERROR_FACTOR: f64 = 0.03;
+    const DEGRADED_PROBE_ERROR_FACTOR: f64 = 0.04;
+
+    fn new(context: &mut InitContext, info: &FuelInfo, number: usize) -> Self {
+        Self {
+            indicated_quantity_id: context
+                .get_identifier(info.fuel_tank_id.replace("QUANTITY", "INDICATED_QUANTITY")),
+            is_degraded_id: context
+                .get_identifier(info.fuel_tank_id.replace("QUANTITY", "QUANTITY_DEGRADED")),
+            capacity_gallons: info.total_capacity_gallons,
+            probe_failure: Failure::new(FailureType::FuelQuantityProbe(number)),
+            indicated_quantity: None,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_computing: bool,
+        quantity: Mass,
+        actual_density_kg_per_gallon: f64,
+        indicated_density_kg_per_gallon: f64,
+    ) {
+        self.indicated_quantity = if is_computing {
+            let volume = (quantity.get::<kilogram>() / actual_density_kg_per_gallon).max(0.);
+            Some(Mass::new::<kilogram>(
+                self.measured_volume_gallons(context, volume) * indicated_density_kg_per_gallon,
+            ))
+        } else {
+            None
+        };
+    }
+
+    fn measured_volume_gallons(&self, context: &UpdateContext, volume: f64) -> f64 {
+        if self.capacity_gallons <= 0. {
+            return volume;
+        }
+
+        // The error is largest when the tank is nearly empty or nearly full, and vanishes when
+        // the fuel surface crosses all the probes.
+        let fill_ratio = (volume / self.capacity_gallons).min(1.);
+        let surface_position_factor = (2. * fill_ratio - 1.).powi(2);
+        let attitude_factor = (Self::PITCH_ATTITUDE_ERROR_FACTOR
+            * context.pitch().get::<radian>().tan().abs()
+            + Self::BANK_ATTITUDE_ERROR_FACTOR * context.bank().get::<radian>().tan().abs())
+        .min(Self::MAXIMUM_ATTITUDE_ERROR_FACTOR);
+        let attitude_error = volume * attitude_factor * surface_position_factor;
+
+        let probe_error = if self.is_degraded() {
+            volume * Self::DEGRADED_PROBE_ERROR_FACTOR
+        } else {
+            0.
+        };
+
+        (volume - attitude_error + probe_error).max(0.)
+    }
+
+    fn indicated_quantity(&self) -> Option<Mass> {
+        self.indicated_quantity
+    }
+
+    fn is_degraded(&self) -> bool {
+        self.probe_failure.is_active()
+    }
+}
+impl SimulationElement for TankGauge {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.probe_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let (quantity, ssm) = quantity_word(self.indicated_quantity);
+        writer.write_arinc429(&self.indicated_quantity_id, quantity, ssm);
+        writer.write(
+            &self.is_degraded_id,
+            self.indicated_quantity.is_some() && self.is_degraded(),
+        );
+    }
+}
+
+/// A computing channel of the fuel quantity indication computer. The channels are redundant,
+/// one of them is enough to compute the quantities.
+struct FuelQuantityIndicationChannel {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    failure: Failure,
+}
+impl FuelQuantityIndicationChannel {
+    fn new(number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+            failure: Failure::new(FailureType::FuelQuantityIndicationChannel(number)),
+        }
+    }
+
+    fn is_available(&self) -> bool {
+        self.is_powered && !self.failure.is_active()
+    }
+}
+impl SimulationElement for FuelQuantityIndicationChannel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Measures the density of the fuel in which it is immersed. Without a valid measurement, the
+/// computer uses the density of a nominal fuel corrected for the fuel temperature, which
+/// doesn't match the loaded fuel exactly.
+struct Densitometer {
+    tank: usize,
+    failure: Failure,
+}
+impl Densitometer {
+    const MINIMUM_IMMERSION_QUANTITY_KG: f64 = 100.;
+
+    const NOMINAL_DENSITY_KG_PER_GALLON_AT_15_DEGREES_C: f64 = 2.97;
+
+    fn new(tank: usize) -> Self {
+        Self {
+            tank,
+            failure: Failure::new(FailureType::FuelDensitometer),
+        }
+    }
+
+    /// The density of the fuel in the given tank, derived from the density measured in the
+    /// densitometer tank and corrected for the temperature difference between both tanks.
+    fn density_kg_per_gallon<const N: usize>(&self, fuel_system: &FuelSystem<N>, t: usize) -> f64 {
+        let temperature = fuel_system.tank_temperature(self.tank);
+        let density = if !self.failure.is_active()
+            && fuel_system.tank_mass(self.tank).get::<kilogram>()
+                >= Self::MINIMUM_IMMERSION_QUANTITY_KG
+        {
+            fuel_system.fuel_type().density_kg_per_gallon(temperature)
+        } else {
+            Self::NOMINAL_DENSITY_KG_PER_GALLON_AT_15_DEGREES_C
+                * FuelType::density_ratio(temperature)
+        };
+
+        density * FuelType::density_ratio(fuel_system.tank_temperature(t))
+            / FuelType::density_ratio(temperature)
+    }
+}
+impl SimulationElement for Densitometer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Gauges the fuel quantity of each tank independently of the quantities of the [`FuelSystem`],
+/// which are the true quantities. The indicated quantities are the ones to display to the crew
+/// and to use for fuel monitoring, such as leak detection. They are only as accurate as the
+/// probes and the density measurement allow, and are invalid when no channel of the computer
+/// is available.
+pub struct FuelQuantityIndicationSystem<const N: usize> {
+    fuel_on_board_id: VariableIdentifier,
+    is_degraded_id: VariableIdentifier,
+
+    channels: [FuelQuantityIndicationChannel; 2],
+    densitometer: Densitometer,
+    tank_gauges: [TankGauge; N],
+}
+impl<const N: usize> FuelQuantityIndicationSystem<N> {
+    pub fn new(
+        context: &mut InitContext,
+        fuel_tanks_info: [FuelInfo; N],
+        densitometer_tank: usize,
+        channels_powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        let mut number = 0;
+        let tank_gauges = fuel_tanks_info.map(|info| {
+            number += 1;
+            TankGauge::new(context, &info, number)
+        });
+        Self {
+            fuel_on_board_id: context.get_identifier("FUEL_ON_BOARD_INDICATED".to_owned()),
+            is_degraded_id: context.get_identifier("FUEL_FQI_DEGRADED".to_owned()),
+            channels: [1, 2].map(|number| {
+                FuelQuantityIndicationChannel::new(number, channels_powered_by[number - 1])
+            }),
+            densitometer: Densitometer::new(densitometer_tank),
+            tank_gauges,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, fuel_system: &FuelSystem<N>) {
+        let is_computing = self.channels.iter().any(|channel| channel.is_available());
+        for (t, gauge) in self.tank_gauges.iter_mut().enumerate() {
+            gauge.update(
+                context,
+                is_computing,
+                fuel_system.tank_mass(t),
+                fuel_system
+                    .fuel_type()
+                    .density_kg_per_gallon(fuel_system.tank_temperature(t)),
+                self.densitometer.density_kg_per_gallon(fuel_system, t),
+            );
+        }
+    }
+
+    pub fn indicated_tank_quantity(&self, t: usize) -> Option<Mass> {
+        self.tank_gauges[t].indicated_quantity()
+    }
+
+    /// The indicated fuel on board, which is only available when all tanks are gauged.
+    pub fn indicated_fuel_on_board(&self) -> Option<Mass> {
+        self.tank_gauges
+            .iter()
+            .try_fold(Mass::default(), |total, gauge| {
+                Some(total + gauge.indicated_quantity()?)
+            })
+    }
+
+    /// Whether the quantity of at least one tank is computed with a reduced accuracy.
+    pub fn is_degraded(&self) -> bool {
+        self.tank_gauges
+            .iter()
+            .any(|gauge| gauge.indicated_quantity().is_some() && gauge.is_degraded())
+    }
+}
+impl<const N: usize> SimulationElement for FuelQuantityIndicationSystem<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.channels, visitor);
+        self.densitometer.accept(visitor);
+        accept_iterable!(self.tank_gauges, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let (quantity, ssm) = quantity_word(self.indicated_fuel_on_board());
+        writer.write_arinc429(&self.fuel_on_board_id, quantity, ssm);
+        writer.write(&self.is_degraded_id, self.is_degraded());
+    }
+}
@@ -1,10 +1,19 @@
 use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
     InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, VariableIdentifier, Write, Writer,
+    SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
 };
 use nalgebra::Vector3;
 use num_traits::Zero;
-use uom::si::{f64::Mass, mass::kilogram};
+use uom::si::{
+    f64::{Mass, Power, ThermodynamicTemperature},
+    mass::kilogram,
+    power::watt,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+mod gauging;
+pub use gauging::FuelQuantityIndicationSystem;
 
 pub const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 
@@ -26,6 +35,52 @@ impl From<f64> for RefuelRate {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FuelType {
+    JetA1,
+    JetA,
+    JetB,
+}
+read_write_enum!(FuelType);
+impl From<f64> for FuelType {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => FuelType::JetA1,
+            1 => FuelType::JetA,
+            2 => FuelType::JetB,
+            i => panic!("Cannot convert from {} to FuelType.", i),
+        }
+    }
+}
+impl FuelType {
+    const DENSITY_CHANGE_PER_DEGREE_C: f64 = -0.00099;
+
+    /// Maximum freezing point allowed by the fuel's specification.
+    pub fn freezing_point(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(match self {
+            FuelType::JetA1 => -47.,
+            FuelType::JetA => -40.,
+            FuelType::JetB => -50.,
+        })
+    }
+
+    /// Typical density of the fuel at the given temperature.
+    pub fn density_kg_per_gallon(&self, temperature: ThermodynamicTemperature) -> f64 {
+        let density_at_15_degrees_c = match self {
+            FuelType::JetA1 => 3.04,
+            FuelType::JetA => 3.06,
+            FuelType::JetB => 2.89,
+        };
+
+        density_at_15_degrees_c * Self::density_ratio(temperature)
+    }
+
+    /// The ratio of the density of fuel at the given temperature to its density at 15 °C.
+    fn density_ratio(temperature: ThermodynamicTemperature) -> f64 {
+        1. + Self::DENSITY_CHANGE_PER_DEGREE_C * (temperature.get::<degree_celsius>() - 15.)
+    }
+}
+
 pub trait FuelPayload {
     fn total_load(&self) -> Mass;
     fn fore_aft_center_of_gravity(&self) -> f64;
@@ -44,20 +99,43 @@ pub struct FuelInfo<'a> {
     pub total_capacity_gallons: f64,
 }
 
+/// A fuel tank whose quantity is read from, and optionally written back to, the simulator.
+/// The temperature of the fuel in the tank is simulated as a single mixed volume: it exchanges
+/// heat with the tank walls, which are at the total air temperature, picks up the heat rejected
+/// into the tank by the hydraulic and generator drive heat exchangers, and mixes with the fuel
+/// transferred or refuelled into the tank.
 #[derive(Debug)]
 pub struct FuelTank {
     fuel_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
     location: Vector3<f64>,
     quantity: Mass,
     write: bool,
+
+    temperature: Option<ThermodynamicTemperature>,
+    freezing_point: ThermodynamicTemperature,
+    // Quantity at which the temperature was last updated, used to detect fuel entering the tank.
+    temperature_quantity: Mass,
 }
 impl FuelTank {
+    const FUEL_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN: f64 = 2000.;
+    // Tank structure in contact with the fuel, which keeps empty tanks from changing temperature
+    // instantly.
+    const STRUCTURE_THERMAL_MASS_JOULE_PER_KELVIN: f64 = 20000.;
+    // The wetted wall area, and thus the heat transfer with the outside, grows with the quantity.
+    const WALL_HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 100.;
+    const WALL_HEAT_TRANSFER_WATT_PER_KELVIN_PER_KILOGRAM: f64 = 0.15;
+
     pub fn new(context: &mut InitContext, id: &str, location: Vector3<f64>, write: bool) -> Self {
         FuelTank {
             fuel_id: context.get_identifier(id.to_owned()),
+            temperature_id: context.get_identifier(id.replace("QUANTITY", "TEMPERATURE")),
             location,
             quantity: Mass::default(),
             write,
+            temperature: None,
+            freezing_point: FuelType::JetA1.freezing_point(),
+            temperature_quantity: Mass::default(),
         }
     }
 
@@ -72,6 +150,73 @@ impl FuelTank {
     pub fn set_quantity(&mut self, quantity: Mass) {
         self.quantity = quantity;
     }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        // Until the first update the fuel is assumed to be at standard temperature.
+        self.temperature
+            .unwrap_or_else(|| ThermodynamicTemperature::new::<degree_celsius>(15.))
+    }
+
+    pub fn freezing_point(&self) -> ThermodynamicTemperature {
+        self.freezing_point
+    }
+
+    fn initialise_temperature(
+        &mut self,
+        temperature: ThermodynamicTemperature,
+        freezing_point: ThermodynamicTemperature,
+    ) {
+        self.temperature = Some(temperature);
+        self.freezing_point = freezing_point;
+        self.temperature_quantity = self.quantity;
+    }
+
+    /// Quantity of fuel which entered (positive) or left (negative) the tank since the
+    /// last temperature update.
+    fn quantity_change(&self) -> Mass {
+        self.quantity - self.temperature_quantity
+    }
+
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        wall_temperature: ThermodynamicTemperature,
+        incoming_fuel: Option<(ThermodynamicTemperature, ThermodynamicTemperature)>,
+        heat: Power,
+    ) {
+        let previous_quantity = self.temperature_quantity.get::<kilogram>().max(0.);
+        let quantity = self.quantity.get::<kilogram>().max(0.);
+        let mut temperature = self.temperature().get::<kelvin>();
+
+        if let Some((incoming_temperature, incoming_freezing_point)) = incoming_fuel {
+            let incoming_quantity = quantity - previous_quantity;
+            if incoming_quantity > 0. {
+                temperature = (temperature * previous_quantity
+                    + incoming_temperature.get::<kelvin>() * incoming_quantity)
+                    / quantity;
+
+                // Without a measurement of the blend, its freezing point is taken as
+                // the highest one of the mixed fuels.
+                self.freezing_point = if previous_quantity > 0. {
+                    self.freezing_point.max(incoming_freezing_point)
+                } else {
+                    incoming_freezing_point
+                };
+            }
+        }
+
+        let wall_heat = (Self::WALL_HEAT_TRANSFER_WATT_PER_KELVIN
+            + Self::WALL_HEAT_TRANSFER_WATT_PER_KELVIN_PER_KILOGRAM * quantity)
+            * (wall_temperature.get::<kelvin>() - temperature);
+        let thermal_mass = quantity * Self::FUEL_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN
+            + Self::STRUCTURE_THERMAL_MASS_JOULE_PER_KELVIN;
+
+        self.temperature = Some(ThermodynamicTemperature::new::<kelvin>(
+            temperature
+                + (wall_heat + heat.get::<watt>()) * context.delta_as_secs_f64() / thermal_mass,
+        ));
+        self.temperature_quantity = self.quantity;
+    }
 }
 impl SimulationElement for FuelTank {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -90,7 +235,21 @@ impl SimulationElement for FuelTank {
                 },
             );
         }
+        writer.write(&self.temperature_id, self.temperature());
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.temperature());
+        state.write(self.freezing_point);
+        state.write(self.temperature_quantity);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.temperature = Some(state.read());
+        self.freezing_point = state.read();
+        self.temperature_quantity = state.read();
     }
+
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         visitor.visit(self);
     }
@@ -103,6 +262,12 @@ pub struct FuelSystem<const N: usize> {
     fuel_total_weight_id: VariableIdentifier,
     fuel_total_weight: Mass,
 
+    refuel_fuel_type_id: VariableIdentifier,
+    refuel_fuel_type: FuelType,
+    refuel_fuel_temperature_id: VariableIdentifier,
+    refuel_fuel_temperature: ThermodynamicTemperature,
+    freezing_point_id: VariableIdentifier,
+
     fuel_tanks: [FuelTank; N],
 }
 impl<const N: usize> FuelSystem<N> {
@@ -112,14 +277,94 @@ impl<const N: usize> FuelSystem<N> {
             unlimited_fuel: false,
             fuel_total_weight_id: context.get_identifier("FUEL TOTAL QUANTITY WEIGHT".to_owned()),
             fuel_total_weight: Mass::default(),
+            refuel_fuel_type_id: context.get_identifier("REFUEL_FUEL_TYPE".to_owned()),
+            refuel_fuel_type: FuelType::JetA1,
+            refuel_fuel_temperature_id: context
+                .get_identifier("REFUEL_FUEL_TEMPERATURE".to_owned()),
+            refuel_fuel_temperature: ThermodynamicTemperature::default(),
+            freezing_point_id: context.get_identifier("FUEL_FREEZING_POINT".to_owned()),
             fuel_tanks,
         }
     }
 
+    /// Updates the fuel temperature of each tank. It must be called once all quantity
+    /// changes of the frame have been applied to the tanks. Fuel entering a tank comes from
+    /// the tanks which lost fuel during the same update, the excess being fresh fuel from the
+    /// refuel truck. The heat rejected into each tank is given in tank order.
+    pub fn update_temperatures(&mut self, context: &UpdateContext, heat_rejected: [Power; N]) {
+        // The tank walls are at the temperature recovered by the airflow over the wing.
+        const WING_RECOVERY_FACTOR: f64 = 0.9;
+        let mach = f64::from(context.mach_number());
+        let wall_temperature = ThermodynamicTemperature::new::<kelvin>(
+            context.ambient_temperature().get::<kelvin>()
+                * (1. + WING_RECOVERY_FACTOR * 0.2 * mach.powi(2)),
+        );
+
+        let (outgoing_quantity, outgoing_heat, outgoing_freezing_point) = self
+            .fuel_tanks
+            .iter()
+            .filter(|tank| tank.quantity_change() < Mass::default())
+            .fold(
+                (0., 0., None::<ThermodynamicTemperature>),
+                |(quantity, heat, freezing_point), tank| {
+                    let change = -tank.quantity_change().get::<kilogram>();
+                    (
+                        quantity + change,
+                        heat + change * tank.temperature().get::<kelvin>(),
+                        Some(
+                            freezing_point
+                                .map_or(tank.freezing_point(), |f| f.max(tank.freezing_point())),
+                        ),
+                    )
+                },
+            );
+        let incoming_quantity: f64 = self
+            .fuel_tanks
+            .iter()
+            .map(|tank| tank.quantity_change().get::<kilogram>().max(0.))
+            .sum();
+
+        let refuel_freezing_point = self.refuel_fuel_type.freezing_point();
+        let incoming_fuel = if self.unlimited_fuel {
+            None
+        } else if incoming_quantity > outgoing_quantity {
+            // Fuel from other tanks mixes with the fuel supplied by the refuel truck.
+            let refuel_quantity = incoming_quantity - outgoing_quantity;
+            Some((
+                ThermodynamicTemperature::new::<kelvin>(
+                    (outgoing_heat
+                        + refuel_quantity * self.refuel_fuel_temperature.get::<kelvin>())
+                        / incoming_quantity,
+                ),
+                outgoing_freezing_point
+                    .map_or(refuel_freezing_point, |f| f.max(refuel_freezing_point)),
+            ))
+        } else if outgoing_quantity > 0. {
+            Some((
+                ThermodynamicTemperature::new::<kelvin>(outgoing_heat / outgoing_quantity),
+                outgoing_freezing_point.unwrap_or(refuel_freezing_point),
+            ))
+        } else {
+            None
+        };
+
+        for (tank, heat) in self.fuel_tanks.iter_mut().zip(heat_rejected) {
+            if tank.temperature.is_none() {
+                // The fuel loaded before the first update has soaked to the ambient temperature.
+                tank.initialise_temperature(context.ambient_temperature(), refuel_freezing_point);
+            }
+            tank.update_temperature(context, wall_temperature, incoming_fuel, heat);
+        }
+    }
+
     pub fn total_load(&self) -> Mass {
         self.fuel_total_weight
     }
 
+    pub fn has_unlimited_fuel(&self) -> bool {
+        self.unlimited_fuel
+    }
+
     pub fn tank_has_fuel(&self, t: usize) -> bool {
         self.unlimited_fuel || !self.fuel_tanks[t].quantity().is_zero()
     }
@@ -148,6 +393,29 @@ impl<const N: usize> FuelSystem<N> {
     pub fn tank_mass(&self, t: usize) -> Mass {
         self.fuel_tanks[t].quantity()
     }
+
+    pub fn tank_temperature(&self, t: usize) -> ThermodynamicTemperature {
+        self.fuel_tanks[t].temperature()
+    }
+
+    pub fn tank_freezing_point(&self, t: usize) -> ThermodynamicTemperature {
+        self.fuel_tanks[t].freezing_point()
+    }
+
+    /// The type of the fuel loaded into the tanks.
+    pub fn fuel_type(&self) -> FuelType {
+        self.refuel_fuel_type
+    }
+
+    /// The highest freezing point of the fuel loaded in the tanks.
+    pub fn freezing_point(&self) -> ThermodynamicTemperature {
+        self.fuel_tanks
+            .iter()
+            .filter(|tank| !tank.quantity().is_zero())
+            .map(|tank| tank.freezing_point())
+            .reduce(|acc, f| acc.max(f))
+            .unwrap_or_else(|| self.refuel_fuel_type.freezing_point())
+    }
 }
 impl<const N: usize> SimulationElement for FuelSystem<N> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -158,5 +426,11 @@ impl<const N: usize> SimulationElement for FuelSystem<N> {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
         self.fuel_total_weight = reader.read(&self.fuel_total_weight_id);
+        self.refuel_fuel_type = reader.read(&self.refuel_fuel_type_id);
+        self.refuel_fuel_temperature = reader.read(&self.refuel_fuel_temperature_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.freezing_point_id, self.freezing_point());
     }
 }
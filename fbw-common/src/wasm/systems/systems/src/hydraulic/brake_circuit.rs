@@ -5,8 +5,9 @@ use crate::{
         pid::PidController, random_from_normal_distribution, random_from_range, HydraulicColor,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, StartState, UpdateContext,
-        Write,
+        snapshot::{StateReader, StateWriter},
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
+        StartState, UpdateContext, Write,
     },
 };
 
@@ -14,7 +15,10 @@ use std::fmt::Debug;
 use std::time::Duration;
 
 use uom::si::{
-    acceleration::meter_per_second_squared, f64::*, pressure::psi, ratio::ratio, volume::gallon,
+    acceleration::meter_per_second_squared, angular_velocity::radian_per_second, f64::*,
+    length::meter, mass::kilogram, pressure::psi, ratio::ratio,
+    thermodynamic_temperature::degree_celsius, torque::newton_meter, velocity::meter_per_second,
+    volume::gallon,
 };
 
 use super::linear_actuator::Actuator;
@@ -418,6 +422,357 @@ impl SimulationElement for BrakeCircuit {
             writer.write(&self.acc_press_id, self.accumulator_pressure());
         }
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.save_state(state);
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        if let Some(accumulator) = &mut self.accumulator {
+            accumulator.restore_state(state);
+        }
+    }
+}
+
+/// Physical characteristics of a wheel brake heat sink and of the wheel it is fitted to.
+pub struct BrakeHeatSinkCharacteristics {
+    heat_sink_mass: Mass,
+    max_brake_torque: Torque,
+    wheel_radius: Length,
+}
+impl BrakeHeatSinkCharacteristics {
+    pub fn new(heat_sink_mass: Mass, max_brake_torque: Torque, wheel_radius: Length) -> Self {
+        Self {
+            heat_sink_mass,
+            max_brake_torque,
+            wheel_radius,
+        }
+    }
+}
+
+/// Carbon heat sink of one wheel brake.
+/// Energy dissipated by the brake is the brake torque (proportional to brake pressure) times the wheel
+/// angular speed. It heats the heat sink mass, which then cools down towards ambient temperature.
+/// The temperature reported to the cockpit is measured by a probe which lags the heat sink temperature.
+struct BrakeHeatSink {
+    wheel_number: usize,
+
+    temperature_id: VariableIdentifier,
+    reported_temperature_id: VariableIdentifier,
+
+    temperature_celsius: f64,
+    reported_temperature_celsius: LowPassFilter<f64>,
+}
+impl BrakeHeatSink {
+    // Carbon specific heat in the usual brake operating temperature range
+    const HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_K: f64 = 1200.;
+
+    // Pressure at which the brake delivers its maximum torque
+    const PRESSURE_FOR_MAX_BRAKE_TORQUE_PSI: f64 = 3000.;
+
+    const PROBE_TIME_CONSTANT: Duration = Duration::from_secs(60);
+
+    fn new(context: &mut InitContext, wheel_number: usize) -> Self {
+        Self {
+            wheel_number,
+
+            temperature_id: context.get_identifier(format!("BRAKE_TEMPERATURE_{}", wheel_number)),
+            reported_temperature_id: context
+                .get_identifier(format!("REPORTED_BRAKE_TEMPERATURE_{}", wheel_number)),
+
+            temperature_celsius: 0.,
+            reported_temperature_celsius: LowPassFilter::new(Self::PROBE_TIME_CONSTANT),
+        }
+    }
+
+    fn reset_to(&mut self, temperature: ThermodynamicTemperature) {
+        self.temperature_celsius = temperature.get::<degree_celsius>();
+        self.reported_temperature_celsius
+            .reset(temperature.get::<degree_celsius>());
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        characteristics: &BrakeHeatSinkCharacteristics,
+        brake_pressure: Pressure,
+        wheel_speed: AngularVelocity,
+        cooling_rate: f64,
+        fan_running: bool,
+    ) {
+        let brake_torque = characteristics.max_brake_torque
+            * (brake_pressure.get::<psi>() / Self::PRESSURE_FOR_MAX_BRAKE_TORQUE_PSI)
+                .min(1.)
+                .max(0.);
+        let dissipated_energy_joules = brake_torque.get::<newton_meter>()
+            * wheel_speed.get::<radian_per_second>().abs()
+            * context.delta_as_secs_f64();
+
+        self.temperature_celsius += dissipated_energy_joules
+            / (characteristics.heat_sink_mass.get::<kilogram>()
+                * Self::HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_K);
+
+        let ambient_celsius = context.ambient_temperature().get::<degree_celsius>();
+        self.temperature_celsius += (ambient_celsius - self.temperature_celsius)
+            * (1. - (-cooling_rate * context.delta_as_secs_f64()).exp());
+
+        // Fan airflow cools the probe faster than the heat sink, so it then reads lower than actual temperature
+        let probe_target_celsius = if fan_running {
+            ambient_celsius + (self.temperature_celsius - ambient_celsius) / 2.
+        } else {
+            self.temperature_celsius
+        };
+        self.reported_temperature_celsius
+            .update(context.delta(), probe_target_celsius);
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.temperature_celsius)
+    }
+
+    fn reported_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.reported_temperature_celsius.output())
+    }
+}
+impl SimulationElement for BrakeHeatSink {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature());
+        writer.write(&self.reported_temperature_id, self.reported_temperature());
+    }
+}
+
+/// Brake cooling fans. They only run with the cockpit button pressed and main gear down and locked.
+struct BrakeFan {
+    button_pressed_id: VariableIdentifier,
+    running_id: VariableIdentifier,
+
+    is_button_pressed: bool,
+    is_running: bool,
+}
+impl BrakeFan {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            button_pressed_id: context.get_identifier("BRAKE_FAN_BTN_PRESSED".to_owned()),
+            running_id: context.get_identifier("BRAKE_FAN".to_owned()),
+
+            is_button_pressed: false,
+            is_running: false,
+        }
+    }
+
+    fn update(&mut self, main_gear_down_and_locked: bool) {
+        self.is_running = self.is_button_pressed && main_gear_down_and_locked;
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_running
+    }
+}
+impl SimulationElement for BrakeFan {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_button_pressed = reader.read(&self.button_pressed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.running_id, self.is_running);
+    }
+}
+
+/// Thermal model of all braked wheels of an aircraft.
+/// Wheels are given by their number, and receive the brake pressure of the side they are on.
+/// Heat sinks cool down with ambient air, faster with airflow when gear is extended and faster with brake fans if fitted.
+pub struct BrakeTemperatures {
+    brakes_hot_id: VariableIdentifier,
+
+    left_heat_sinks: Vec<BrakeHeatSink>,
+    right_heat_sinks: Vec<BrakeHeatSink>,
+
+    characteristics: BrakeHeatSinkCharacteristics,
+
+    brake_fan: Option<BrakeFan>,
+
+    is_initialised: bool,
+}
+impl BrakeTemperatures {
+    // Heat sink cooling down in still air, gear extended airflow and fans increase the rate from there
+    const NATURAL_COOLING_TIME_CONSTANT_S: f64 = 3600.;
+    const AIRFLOW_COOLING_GAIN_PER_METER_PER_SECOND: f64 = 0.02;
+    const BRAKE_FAN_COOLING_GAIN: f64 = 4.35;
+
+    const BRAKES_HOT_THRESHOLD_DEGREE_CELSIUS: f64 = 300.;
+
+    pub fn new(
+        context: &mut InitContext,
+        left_wheel_numbers: &[usize],
+        right_wheel_numbers: &[usize],
+        characteristics: BrakeHeatSinkCharacteristics,
+        has_brake_fans: bool,
+    ) -> Self {
+        Self {
+            brakes_hot_id: context.get_identifier("BRAKES_HOT".to_owned()),
+
+            left_heat_sinks: left_wheel_numbers
+                .iter()
+                .map(|&wheel_number| BrakeHeatSink::new(context, wheel_number))
+                .collect(),
+            right_heat_sinks: right_wheel_numbers
+                .iter()
+                .map(|&wheel_number| BrakeHeatSink::new(context, wheel_number))
+                .collect(),
+
+            characteristics,
+
+            brake_fan: if has_brake_fans {
+                Some(BrakeFan::new(context))
+            } else {
+                None
+            },
+
+            is_initialised: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        left_brake_pressure: Pressure,
+        right_brake_pressure: Pressure,
+        main_gear_down_and_locked: bool,
+    ) {
+        if !self.is_initialised {
+            for heat_sink in self
+                .left_heat_sinks
+                .iter_mut()
+                .chain(self.right_heat_sinks.iter_mut())
+            {
+                heat_sink.reset_to(context.ambient_temperature());
+            }
+            self.is_initialised = true;
+        }
+
+        if let Some(brake_fan) = &mut self.brake_fan {
+            brake_fan.update(main_gear_down_and_locked);
+        }
+        let fan_running = self.brake_fan_running();
+
+        let wheel_speed = if context.is_on_ground() {
+            AngularVelocity::new::<radian_per_second>(
+                context.ground_speed().get::<meter_per_second>()
+                    / self.characteristics.wheel_radius.get::<meter>(),
+            )
+        } else {
+            AngularVelocity::default()
+        };
+
+        let cooling_rate = self.cooling_rate(context, main_gear_down_and_locked, fan_running);
+
+        for heat_sink in &mut self.left_heat_sinks {
+            heat_sink.update(
+                context,
+                &self.characteristics,
+                left_brake_pressure,
+                wheel_speed,
+                cooling_rate,
+                fan_running,
+            );
+        }
+        for heat_sink in &mut self.right_heat_sinks {
+            heat_sink.update(
+                context,
+                &self.characteristics,
+                right_brake_pressure,
+                wheel_speed,
+                cooling_rate,
+                fan_running,
+            );
+        }
+    }
+
+    fn cooling_rate(
+        &self,
+        context: &UpdateContext,
+        main_gear_down_and_locked: bool,
+        fan_running: bool,
+    ) -> f64 {
+        let airflow_gain = if main_gear_down_and_locked {
+            1. + Self::AIRFLOW_COOLING_GAIN_PER_METER_PER_SECOND
+                * context.true_airspeed().get::<meter_per_second>().abs()
+        } else {
+            1.
+        };
+
+        let fan_gain = if fan_running {
+            Self::BRAKE_FAN_COOLING_GAIN
+        } else {
+            1.
+        };
+
+        airflow_gain * fan_gain / Self::NATURAL_COOLING_TIME_CONSTANT_S
+    }
+
+    fn heat_sinks(&self) -> impl Iterator<Item = &BrakeHeatSink> {
+        self.left_heat_sinks
+            .iter()
+            .chain(self.right_heat_sinks.iter())
+    }
+
+    pub fn max_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(
+            self.heat_sinks()
+                .map(|heat_sink| heat_sink.temperature_celsius)
+                .fold(f64::MIN, f64::max),
+        )
+    }
+
+    pub fn max_reported_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(
+            self.heat_sinks()
+                .map(|heat_sink| heat_sink.reported_temperature_celsius.output())
+                .fold(f64::MIN, f64::max),
+        )
+    }
+
+    pub fn temperature(&self, wheel_number: usize) -> Option<ThermodynamicTemperature> {
+        self.heat_sinks()
+            .find(|heat_sink| heat_sink.wheel_number == wheel_number)
+            .map(BrakeHeatSink::temperature)
+    }
+
+    pub fn reported_temperature(&self, wheel_number: usize) -> Option<ThermodynamicTemperature> {
+        self.heat_sinks()
+            .find(|heat_sink| heat_sink.wheel_number == wheel_number)
+            .map(BrakeHeatSink::reported_temperature)
+    }
+
+    /// Hot brakes are detected from the temperature reported by the brake probes
+    pub fn brakes_hot(&self) -> bool {
+        self.max_reported_temperature().get::<degree_celsius>()
+            > Self::BRAKES_HOT_THRESHOLD_DEGREE_CELSIUS
+    }
+
+    pub fn brake_fan_running(&self) -> bool {
+        self.brake_fan
+            .as_ref()
+            .map_or(false, |brake_fan| brake_fan.is_running())
+    }
+}
+impl SimulationElement for BrakeTemperatures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.left_heat_sinks, visitor);
+        accept_iterable!(self.right_heat_sinks, visitor);
+
+        if let Some(brake_fan) = &mut self.brake_fan {
+            brake_fan.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.brakes_hot_id, self.brakes_hot());
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -664,10 +1019,12 @@ impl Debug for BrakeAccumulatorCharacteristics {
 mod tests {
     use super::*;
 
-    use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestBed};
+    use crate::simulation::test::{
+        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+    };
     use crate::simulation::{Aircraft, UpdateContext};
     use std::time::Duration;
-    use uom::si::{pressure::psi, volume::gallon};
+    use uom::si::{pressure::psi, velocity::knot, volume::gallon};
 
     #[derive(Default)]
     struct TestHydraulicSection {
@@ -1035,6 +1392,319 @@ mod tests {
         assert!(test_bed.query(|a| a.right_brake_pressure()) <= pressure_limit);
     }
 
+    struct TestBrakeTemperaturesAircraft {
+        brake_temperatures: BrakeTemperatures,
+
+        left_brake_pressure: Pressure,
+        right_brake_pressure: Pressure,
+        main_gear_down_and_locked: bool,
+    }
+    impl TestBrakeTemperaturesAircraft {
+        fn new(brake_temperatures: BrakeTemperatures) -> Self {
+            Self {
+                brake_temperatures,
+
+                left_brake_pressure: Pressure::default(),
+                right_brake_pressure: Pressure::default(),
+                main_gear_down_and_locked: true,
+            }
+        }
+
+        fn set_brake_pressures(&mut self, left_pressure: Pressure, right_pressure: Pressure) {
+            self.left_brake_pressure = left_pressure;
+            self.right_brake_pressure = right_pressure;
+        }
+
+        fn set_main_gear_down_and_locked(&mut self, down_and_locked: bool) {
+            self.main_gear_down_and_locked = down_and_locked;
+        }
+    }
+    impl Aircraft for TestBrakeTemperaturesAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.brake_temperatures.update(
+                context,
+                self.left_brake_pressure,
+                self.right_brake_pressure,
+                self.main_gear_down_and_locked,
+            );
+        }
+    }
+    impl SimulationElement for TestBrakeTemperaturesAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.brake_temperatures.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    #[test]
+    fn brakes_start_at_ambient_temperature() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+
+        test_bed.run();
+
+        for wheel_number in 1..=4 {
+            let temperature = test_bed
+                .query(|a| a.brake_temperatures.temperature(wheel_number))
+                .unwrap();
+            assert!((temperature.get::<degree_celsius>() - 15.).abs() < 0.1);
+        }
+        assert!(!test_bed.query(|a| a.brake_temperatures.brakes_hot()));
+    }
+
+    #[test]
+    fn unknown_wheel_has_no_temperature() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+
+        test_bed.run();
+
+        assert!(test_bed
+            .query(|a| a.brake_temperatures.temperature(5))
+            .is_none());
+    }
+
+    #[test]
+    fn braking_rolling_wheels_heats_only_braked_side() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+        test_bed.write_by_name(UpdateContext::GROUND_SPEED_KEY, Velocity::new::<knot>(60.));
+        test_bed
+            .command(|a| a.set_brake_pressures(Pressure::new::<psi>(2000.), Pressure::default()));
+
+        for _ in 0..10 {
+            test_bed.run();
+        }
+
+        for wheel_number in [1, 2] {
+            let temperature = test_bed
+                .query(|a| a.brake_temperatures.temperature(wheel_number))
+                .unwrap();
+            assert!(temperature.get::<degree_celsius>() > 100.);
+        }
+        for wheel_number in [3, 4] {
+            let temperature = test_bed
+                .query(|a| a.brake_temperatures.temperature(wheel_number))
+                .unwrap();
+            assert!(temperature.get::<degree_celsius>() < 16.);
+        }
+    }
+
+    #[test]
+    fn braking_stopped_wheels_does_not_heat_brakes() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+        test_bed.command(|a| {
+            a.set_brake_pressures(Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.))
+        });
+
+        for _ in 0..60 {
+            test_bed.run();
+        }
+
+        assert!(
+            test_bed
+                .query(|a| a.brake_temperatures.max_temperature())
+                .get::<degree_celsius>()
+                < 16.
+        );
+    }
+
+    #[test]
+    fn reported_temperature_lags_heat_sink_temperature() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+        heat_brakes(&mut test_bed);
+
+        let temperature = test_bed.query(|a| a.brake_temperatures.temperature(1).unwrap());
+        let reported_temperature =
+            test_bed.query(|a| a.brake_temperatures.reported_temperature(1).unwrap());
+        assert!(reported_temperature < temperature);
+
+        for _ in 0..300 {
+            test_bed.run();
+        }
+
+        let temperature = test_bed.query(|a| a.brake_temperatures.temperature(1).unwrap());
+        let reported_temperature =
+            test_bed.query(|a| a.brake_temperatures.reported_temperature(1).unwrap());
+        assert!(
+            (temperature.get::<degree_celsius>() - reported_temperature.get::<degree_celsius>())
+                .abs()
+                < 15.
+        );
+    }
+
+    #[test]
+    fn brakes_hot_after_heavy_braking() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+        heat_brakes(&mut test_bed);
+
+        for _ in 0..300 {
+            test_bed.run();
+        }
+
+        let brakes_hot: bool = test_bed.read_by_name("BRAKES_HOT");
+        assert!(test_bed.query(|a| a.brake_temperatures.brakes_hot()));
+        assert!(brakes_hot);
+    }
+
+    #[test]
+    fn brakes_cool_down_to_ambient_temperature() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+        heat_brakes(&mut test_bed);
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(3600));
+        }
+
+        assert!(
+            test_bed
+                .query(|a| a.brake_temperatures.max_temperature())
+                .get::<degree_celsius>()
+                < 16.
+        );
+        assert!(!test_bed.query(|a| a.brake_temperatures.brakes_hot()));
+    }
+
+    #[test]
+    fn gear_down_airflow_cools_brakes_faster() {
+        let mut gear_up_test_bed = brake_temperatures_test_bed(true);
+        let mut gear_down_test_bed = brake_temperatures_test_bed(true);
+
+        for test_bed in [&mut gear_up_test_bed, &mut gear_down_test_bed] {
+            heat_brakes(test_bed);
+            test_bed.set_on_ground(false);
+            test_bed.set_true_airspeed(Velocity::new::<knot>(200.));
+        }
+        gear_up_test_bed.command(|a| a.set_main_gear_down_and_locked(false));
+
+        for _ in 0..600 {
+            gear_up_test_bed.run();
+            gear_down_test_bed.run();
+        }
+
+        assert!(
+            gear_down_test_bed.query(|a| a.brake_temperatures.max_temperature())
+                < gear_up_test_bed.query(|a| a.brake_temperatures.max_temperature())
+        );
+    }
+
+    #[test]
+    fn brake_fan_runs_only_when_pressed_with_gear_down() {
+        let mut test_bed = brake_temperatures_test_bed(true);
+
+        test_bed.run();
+        assert!(!test_bed.query(|a| a.brake_temperatures.brake_fan_running()));
+
+        test_bed.write_by_name("BRAKE_FAN_BTN_PRESSED", true);
+        test_bed.run();
+        let brake_fan: bool = test_bed.read_by_name("BRAKE_FAN");
+        assert!(test_bed.query(|a| a.brake_temperatures.brake_fan_running()));
+        assert!(brake_fan);
+
+        test_bed.command(|a| a.set_main_gear_down_and_locked(false));
+        test_bed.run();
+        assert!(!test_bed.query(|a| a.brake_temperatures.brake_fan_running()));
+    }
+
+    #[test]
+    fn brake_fan_cools_brakes_and_probes_faster() {
+        let mut no_fan_test_bed = brake_temperatures_test_bed(true);
+        let mut fan_test_bed = brake_temperatures_test_bed(true);
+
+        for test_bed in [&mut no_fan_test_bed, &mut fan_test_bed] {
+            heat_brakes(test_bed);
+        }
+        fan_test_bed.write_by_name("BRAKE_FAN_BTN_PRESSED", true);
+
+        for _ in 0..600 {
+            no_fan_test_bed.run();
+            fan_test_bed.run();
+        }
+
+        assert!(
+            fan_test_bed.query(|a| a.brake_temperatures.max_temperature())
+                < no_fan_test_bed.query(|a| a.brake_temperatures.max_temperature())
+        );
+        assert!(
+            fan_test_bed.query(|a| a.brake_temperatures.max_reported_temperature())
+                < fan_test_bed.query(|a| a.brake_temperatures.max_temperature())
+        );
+    }
+
+    #[test]
+    fn brake_fan_button_ignored_without_brake_fans() {
+        let mut test_bed = brake_temperatures_test_bed(false);
+
+        test_bed.write_by_name("BRAKE_FAN_BTN_PRESSED", true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.brake_temperatures.brake_fan_running()));
+        assert!(!test_bed.contains_variable_with_name("BRAKE_FAN"));
+    }
+
+    #[test]
+    fn writes_temperature_of_every_wheel() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            TestBrakeTemperaturesAircraft::new(BrakeTemperatures::new(
+                context,
+                &[1, 2, 5, 6, 9, 10, 13, 14],
+                &[3, 4, 7, 8, 11, 12, 15, 16],
+                brake_heat_sink_characteristics(),
+                false,
+            ))
+        });
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+        test_bed.run();
+
+        for wheel_number in 1..=16 {
+            assert!(test_bed
+                .contains_variable_with_name(&format!("BRAKE_TEMPERATURE_{}", wheel_number)));
+            assert!(test_bed.contains_variable_with_name(&format!(
+                "REPORTED_BRAKE_TEMPERATURE_{}",
+                wheel_number
+            )));
+        }
+        assert!(!test_bed.contains_variable_with_name("BRAKE_TEMPERATURE_17"));
+    }
+
+    fn heat_brakes(test_bed: &mut SimulationTestBed<TestBrakeTemperaturesAircraft>) {
+        test_bed.write_by_name(UpdateContext::GROUND_SPEED_KEY, Velocity::new::<knot>(100.));
+        test_bed.command(|a| {
+            a.set_brake_pressures(Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.))
+        });
+
+        for _ in 0..10 {
+            test_bed.run();
+        }
+
+        test_bed.write_by_name(UpdateContext::GROUND_SPEED_KEY, Velocity::default());
+        test_bed.command(|a| a.set_brake_pressures(Pressure::default(), Pressure::default()));
+    }
+
+    fn brake_temperatures_test_bed(
+        has_brake_fans: bool,
+    ) -> SimulationTestBed<TestBrakeTemperaturesAircraft> {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            TestBrakeTemperaturesAircraft::new(BrakeTemperatures::new(
+                context,
+                &[1, 2],
+                &[3, 4],
+                brake_heat_sink_characteristics(),
+                has_brake_fans,
+            ))
+        });
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.set_on_ground(true);
+
+        test_bed
+    }
+
+    fn brake_heat_sink_characteristics() -> BrakeHeatSinkCharacteristics {
+        BrakeHeatSinkCharacteristics::new(
+            Mass::new::<kilogram>(90.),
+            Torque::new::<newton_meter>(45000.),
+            Length::new::<meter>(0.58),
+        )
+    }
+
     fn brake_circuit(context: &mut InitContext, init_max_vol: Volume) -> BrakeCircuit {
         BrakeCircuit::new(
             context,
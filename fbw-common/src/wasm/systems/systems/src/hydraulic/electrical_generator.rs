@@ -17,6 +17,7 @@ use crate::shared::{
 };
 
 use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
     InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
 };
 
@@ -125,6 +126,20 @@ impl GeneratorControlUnit {
             .get::<revolution_per_minute>()
             <= Self::NOMINAL_SPEED_MARGIN_RPM
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        self.pid_controller.save_state(state);
+        state.write(self.is_active);
+        state.write(self.current_speed);
+        state.write(self.manual_generator_on_was_pressed);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.pid_controller.restore_state(state);
+        self.is_active = state.read();
+        self.current_speed = state.read();
+        self.manual_generator_on_was_pressed = state.read();
+    }
 }
 impl EmergencyGeneratorControlUnit for GeneratorControlUnit {
     fn max_allowed_power(&self) -> Power {
@@ -301,6 +316,24 @@ impl SimulationElement for HydraulicGeneratorMotor {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.generator_rpm_id, self.speed());
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.speed);
+        state.write(self.virtual_displacement);
+        state.write(self.current_flow);
+        self.valve.save_state(state);
+        state.write(self.total_volume_to_actuator);
+        state.write(self.total_volume_to_reservoir);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.speed = state.read();
+        self.virtual_displacement = state.read();
+        self.current_flow = state.read();
+        self.valve.restore_state(state);
+        self.total_volume_to_actuator = state.read();
+        self.total_volume_to_reservoir = state.read();
+    }
 }
 impl AngularSpeedSensor for HydraulicGeneratorMotor {
     fn speed(&self) -> AngularVelocity {
@@ -327,6 +360,14 @@ impl MeteringValve {
     fn position(&self) -> Ratio {
         self.position.output()
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.position.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.position.restore_state(state);
+    }
 }
 
 #[derive(Default)]
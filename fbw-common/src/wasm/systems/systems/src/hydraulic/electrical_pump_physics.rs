@@ -17,6 +17,7 @@ use crate::shared::{
     ConsumePower, ElectricalBusType, ElectricalBuses,
 };
 use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
     InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
     VariableIdentifier, Write,
 };
@@ -283,6 +284,38 @@ impl SimulationElement for ElectricalPumpPhysics {
     fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
         consumption.consume_from_bus(self.powered_by, self.consumed_power);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_powered);
+        state.write(self.available_potential);
+        state.write(self.consumed_power);
+        state.write(self.acceleration);
+        state.write(self.speed_raw);
+        self.speed_filtered.save_state(state);
+        state.write(self.is_active);
+        state.write(self.output_current);
+        state.write(self.generated_torque);
+        state.write(self.resistant_torque);
+        self.current_controller.save_state(state);
+        self.displacement_filtered.save_state(state);
+        self.heat_state.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_powered = state.read();
+        self.available_potential = state.read();
+        self.consumed_power = state.read();
+        self.acceleration = state.read();
+        self.speed_raw = state.read();
+        self.speed_filtered.restore_state(state);
+        self.is_active = state.read();
+        self.output_current = state.read();
+        self.generated_torque = state.read();
+        self.resistant_torque = state.read();
+        self.current_controller.restore_state(state);
+        self.displacement_filtered.restore_state(state);
+        self.heat_state.restore_state(state);
+    }
 }
 impl HeatingElement for ElectricalPumpPhysics {
     fn is_damaged(&self) -> bool {
@@ -3,6 +3,7 @@ use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, FeedbackPositionPickoffUnit, SectionPressure,
 };
 use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
     InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
 };
 
@@ -107,6 +108,20 @@ impl FlapSlatHydraulicMotor {
     fn flow(&self) -> VolumeRate {
         self.current_flow
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.speed.save_state(state);
+        state.write(self.current_flow);
+        state.write(self.total_volume_to_actuator);
+        state.write(self.total_volume_returned_to_reservoir);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.speed.restore_state(state);
+        self.current_flow = state.read();
+        self.total_volume_to_actuator = state.read();
+        self.total_volume_returned_to_reservoir = state.read();
+    }
 }
 impl Actuator for FlapSlatHydraulicMotor {
     fn used_volume(&self) -> Volume {
@@ -534,6 +549,32 @@ impl SimulationElement for FlapSlatAssembly {
 
         writer.write(&self.is_moving_id, self.is_surface_moving());
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.surface_control_arm_position);
+        state.write(self.max_synchro_gear_position);
+        state.write(self.final_requested_synchro_gear_position);
+        state.write(self.speed);
+        self.current_max_speed.save_state(state);
+        self.left_motor.save_state(state);
+        self.right_motor.save_state(state);
+        state.write(self.circuit_target_pressure);
+        state.write(self.left_position);
+        state.write(self.right_position);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.surface_control_arm_position = state.read();
+        self.max_synchro_gear_position = state.read();
+        self.final_requested_synchro_gear_position = state.read();
+        self.speed = state.read();
+        self.current_max_speed.restore_state(state);
+        self.left_motor.restore_state(state);
+        self.right_motor.restore_state(state);
+        self.circuit_target_pressure = state.read();
+        self.left_position = state.read();
+        self.right_position = state.read();
+    }
 }
 impl FeedbackPositionPickoffUnit for FlapSlatAssembly {
     fn angle(&self) -> Angle {
@@ -1117,6 +1158,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn flap_slat_assembly_snapshot_restores_all_written_variables() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(20.))));
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(2000));
+
+        let differing =
+            test_bed.variables_differing_after_snapshot_round_trip(Duration::from_millis(500));
+
+        assert!(differing.is_empty(), "{:?}", differing);
+        assert!(test_bed.query(|a| a.flaps_slats.speed.get::<radian_per_second>()) > 0.);
+    }
+
     fn flap_system(context: &mut InitContext, max_speed: AngularVelocity) -> FlapSlatAssembly {
         FlapSlatAssembly::new(
             context,
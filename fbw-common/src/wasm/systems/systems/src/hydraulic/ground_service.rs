@@ -0,0 +1,546 @@
+use super::{HeatingElement, HeatingPressureSource, PressureSource, Reservoir};
+use crate::shared::{HydraulicColor, SectionPressure};
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+    VariableIdentifier, Write,
+};
+
+use uom::si::{
+    f64::*,
+    pressure::psi,
+    ratio::ratio,
+    volume::gallon,
+    volume_rate::{gallon_per_minute, gallon_per_second},
+};
+
+/// External hydraulic power unit used by maintenance to pressurise a circuit with engines off.
+/// Its supply and return lines are connected to the aircraft circuit, so the flow it delivers is taken
+/// from the aircraft reservoir and comes back to it through the circuit return.
+/// Its own tank is only used to service the aircraft reservoir, its fill line closing automatically
+/// when the reservoir reaches its full level.
+pub struct HydraulicGroundCart {
+    max_flow: VolumeRate,
+    regulated_pressure: Pressure,
+    fill_flow: VolumeRate,
+
+    is_on: bool,
+
+    delta_vol_max: Volume,
+    current_flow: VolumeRate,
+    current_fill_flow: VolumeRate,
+
+    tank_quantity: Volume,
+}
+impl HydraulicGroundCart {
+    // Pressure band below regulated pressure in which the cart pressure compensator reduces flow
+    const REGULATION_BAND_PSI: f64 = 200.;
+
+    const DEFAULT_MAX_FLOW_GPM: f64 = 20.;
+    const DEFAULT_REGULATED_PRESSURE_PSI: f64 = 3000.;
+    const DEFAULT_FILL_FLOW_GPM: f64 = 2.;
+    const DEFAULT_TANK_QUANTITY_GAL: f64 = 10.;
+
+    pub fn new(
+        max_flow: VolumeRate,
+        regulated_pressure: Pressure,
+        fill_flow: VolumeRate,
+        tank_quantity: Volume,
+    ) -> Self {
+        Self {
+            max_flow,
+            regulated_pressure,
+            fill_flow,
+
+            is_on: false,
+
+            delta_vol_max: Volume::default(),
+            current_flow: VolumeRate::default(),
+            current_fill_flow: VolumeRate::default(),
+
+            tank_quantity,
+        }
+    }
+
+    pub fn default_cart() -> Self {
+        Self::new(
+            VolumeRate::new::<gallon_per_minute>(Self::DEFAULT_MAX_FLOW_GPM),
+            Pressure::new::<psi>(Self::DEFAULT_REGULATED_PRESSURE_PSI),
+            VolumeRate::new::<gallon_per_minute>(Self::DEFAULT_FILL_FLOW_GPM),
+            Volume::new::<gallon>(Self::DEFAULT_TANK_QUANTITY_GAL),
+        )
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        section: &impl SectionPressure,
+        reservoir: &Reservoir,
+    ) {
+        let max_flow = if self.is_on {
+            self.max_flow * self.compensator_ratio(section.pressure())
+        } else {
+            VolumeRate::default()
+        };
+
+        self.delta_vol_max =
+            reservoir.request_flow_availability(context, max_flow) * context.delta_as_time();
+    }
+
+    fn compensator_ratio(&self, pressure: Pressure) -> Ratio {
+        Ratio::new::<ratio>(
+            ((self.regulated_pressure - pressure).get::<psi>() / Self::REGULATION_BAND_PSI)
+                .clamp(0., 1.),
+        )
+    }
+
+    fn deliver(
+        &mut self,
+        context: &UpdateContext,
+        volume_required: Volume,
+        reservoir: &mut Reservoir,
+        is_connected_to_reservoir: bool,
+    ) -> Volume {
+        let volume_delivered = volume_required
+            .max(Volume::default())
+            .min(self.delta_vol_max);
+
+        self.current_flow = if is_connected_to_reservoir {
+            reservoir.try_take_flow(context, volume_delivered / context.delta_as_time())
+        } else {
+            VolumeRate::default()
+        };
+
+        self.current_flow * context.delta_as_time()
+    }
+
+    fn fill(&mut self, context: &UpdateContext, reservoir: &mut Reservoir, is_filling: bool) {
+        self.current_fill_flow = if is_filling {
+            let volume_available = (self.fill_flow * context.delta_as_time())
+                .min(self.tank_quantity)
+                .min(reservoir.volume_to_full_level());
+            let volume_filled = reservoir.fill(volume_available);
+            self.tank_quantity -= volume_filled;
+
+            volume_filled / context.delta_as_time()
+        } else {
+            VolumeRate::default()
+        };
+    }
+
+    fn stop(&mut self) {
+        self.delta_vol_max = Volume::default();
+        self.current_flow = VolumeRate::default();
+        self.current_fill_flow = VolumeRate::default();
+    }
+
+    pub fn flow(&self) -> VolumeRate {
+        self.current_flow
+    }
+
+    pub fn fill_flow(&self) -> VolumeRate {
+        self.current_fill_flow
+    }
+
+    pub fn tank_quantity(&self) -> Volume {
+        self.tank_quantity
+    }
+}
+
+/// Ground service panel of a hydraulic circuit, where a ground cart can be connected to pressurise
+/// the circuit and to fill its reservoir. Its reservoir hand pump allows servicing without a cart,
+/// but has no automatic stop so the reservoir can be overfilled.
+pub struct GroundServicePanel {
+    cart_connected_id: VariableIdentifier,
+    cart_on_id: VariableIdentifier,
+    fill_selected_id: VariableIdentifier,
+    cart_flow_id: VariableIdentifier,
+    fill_flow_id: VariableIdentifier,
+    hand_pump_active_id: VariableIdentifier,
+
+    is_cart_connected: bool,
+    is_fill_selected: bool,
+    is_hand_pump_active: bool,
+
+    cart: HydraulicGroundCart,
+}
+impl GroundServicePanel {
+    const HAND_PUMP_FLOW_GPM: f64 = 1.;
+
+    pub fn new(
+        context: &mut InitContext,
+        color: HydraulicColor,
+        cart: HydraulicGroundCart,
+    ) -> Self {
+        Self {
+            cart_connected_id: context
+                .get_identifier(format!("HYD_{}_GROUND_CART_CONNECTED", color)),
+            cart_on_id: context.get_identifier(format!("HYD_{}_GROUND_CART_ON", color)),
+            fill_selected_id: context
+                .get_identifier(format!("HYD_{}_GROUND_CART_FILL_SELECTED", color)),
+            cart_flow_id: context.get_identifier(format!("HYD_{}_GROUND_CART_FLOW", color)),
+            fill_flow_id: context.get_identifier(format!("HYD_{}_GROUND_CART_FILL_FLOW", color)),
+            hand_pump_active_id: context
+                .get_identifier(format!("HYD_{}_RESERVOIR_HAND_PUMP_ACTIVE", color)),
+
+            is_cart_connected: false,
+            is_fill_selected: false,
+            is_hand_pump_active: false,
+
+            cart,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        section: &impl SectionPressure,
+        reservoir: &Reservoir,
+    ) {
+        if self.is_cart_connected {
+            self.cart.update(context, section, reservoir);
+        } else {
+            self.cart.stop();
+        }
+    }
+
+    /// Gives the pressure source seen by the section the panel is connected to: the ground cart in
+    /// parallel with the aircraft pump of that section, if any.
+    pub fn connected_to<'a>(
+        &'a mut self,
+        pump: Option<&'a mut dyn HeatingPressureSource>,
+    ) -> GroundServiceConnection<'a> {
+        GroundServiceConnection { panel: self, pump }
+    }
+
+    pub fn is_cart_connected(&self) -> bool {
+        self.is_cart_connected
+    }
+
+    pub fn cart_flow(&self) -> VolumeRate {
+        self.cart.flow()
+    }
+
+    pub fn cart_fill_flow(&self) -> VolumeRate {
+        self.cart.fill_flow()
+    }
+
+    fn is_filling(&self) -> bool {
+        self.is_cart_connected && self.is_fill_selected
+    }
+
+    fn update_servicing(&mut self, context: &UpdateContext, reservoir: &mut Reservoir) {
+        if self.is_hand_pump_active {
+            reservoir.fill(
+                VolumeRate::new::<gallon_per_minute>(Self::HAND_PUMP_FLOW_GPM)
+                    * context.delta_as_time(),
+            );
+        }
+
+        let is_filling = self.is_filling();
+        self.cart.fill(context, reservoir, is_filling);
+    }
+}
+impl SimulationElement for GroundServicePanel {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_cart_connected = reader.read(&self.cart_connected_id);
+        self.cart.is_on = reader.read(&self.cart_on_id);
+        self.is_fill_selected = reader.read(&self.fill_selected_id);
+        self.is_hand_pump_active = reader.read(&self.hand_pump_active_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.cart_flow_id,
+            self.cart.flow().get::<gallon_per_second>(),
+        );
+        writer.write(
+            &self.fill_flow_id,
+            self.cart.fill_flow().get::<gallon_per_second>(),
+        );
+    }
+}
+
+/// Ground cart and aircraft pump feeding the same section. The ground cart has priority as it
+/// is usually regulated above the aircraft pumps, the aircraft pump only delivers what is still missing.
+pub struct GroundServiceConnection<'a> {
+    panel: &'a mut GroundServicePanel,
+    pump: Option<&'a mut dyn HeatingPressureSource>,
+}
+impl PressureSource for GroundServiceConnection<'_> {
+    fn delta_vol_max(&self) -> Volume {
+        self.panel.cart.delta_vol_max
+            + self
+                .pump
+                .as_ref()
+                .map_or(Volume::default(), |pump| pump.delta_vol_max())
+    }
+
+    fn update_after_pressure_regulation(
+        &mut self,
+        context: &UpdateContext,
+        volume_required: Volume,
+        reservoir: &mut Reservoir,
+        is_pump_connected_to_reservoir: bool,
+    ) {
+        self.panel.update_servicing(context, reservoir);
+
+        let volume_from_cart = self.panel.cart.deliver(
+            context,
+            volume_required,
+            reservoir,
+            is_pump_connected_to_reservoir,
+        );
+
+        if let Some(pump) = self.pump.as_mut() {
+            pump.update_after_pressure_regulation(
+                context,
+                volume_required - volume_from_cart,
+                reservoir,
+                is_pump_connected_to_reservoir,
+            );
+        }
+    }
+
+    fn flow(&self) -> VolumeRate {
+        self.panel.cart.flow()
+            + self
+                .pump
+                .as_ref()
+                .map_or(VolumeRate::default(), |pump| pump.flow())
+    }
+
+    fn displacement(&self) -> Volume {
+        self.pump
+            .as_ref()
+            .map_or(Volume::default(), |pump| pump.displacement())
+    }
+
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.pump
+            .as_ref()
+            .map_or(VolumeRate::default(), |pump| pump.case_drain_flow())
+    }
+}
+impl HeatingElement for GroundServiceConnection<'_> {
+    fn is_overheating(&self) -> bool {
+        self.pump
+            .as_ref()
+            .map_or(false, |pump| pump.is_overheating())
+    }
+
+    fn is_damaged(&self) -> bool {
+        self.pump.as_ref().map_or(false, |pump| pump.is_damaged())
+    }
+}
+impl HeatingPressureSource for GroundServiceConnection<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{ElementCtorFn, ReadByName, SimulationTestBed, WriteByName};
+    use crate::simulation::{SimulationElementVisitor, UpdateContext};
+    use ntest::assert_about_eq;
+
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct TestHydraulicSection {
+        pressure: Pressure,
+    }
+    impl SectionPressure for TestHydraulicSection {
+        fn pressure(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_leak_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_priority_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn is_pressure_switch_pressurised(&self) -> bool {
+            self.pressure.get::<psi>() > 1700.
+        }
+    }
+
+    struct TestGroundService {
+        panel: GroundServicePanel,
+        section: TestHydraulicSection,
+        reservoir: Reservoir,
+    }
+    impl TestGroundService {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                panel: GroundServicePanel::new(
+                    context,
+                    HydraulicColor::Green,
+                    HydraulicGroundCart::default_cart(),
+                ),
+                section: TestHydraulicSection::default(),
+                reservoir: Reservoir::new(
+                    context,
+                    HydraulicColor::Green,
+                    Volume::new::<gallon>(5.),
+                    Volume::new::<gallon>(4.),
+                    Volume::new::<gallon>(2.),
+                    vec![],
+                    Volume::new::<gallon>(0.5),
+                ),
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.panel.update(context, &self.section, &self.reservoir);
+
+            // Section always requires more than what the cart can deliver
+            self.panel
+                .connected_to(None)
+                .update_after_pressure_regulation(
+                    context,
+                    Volume::new::<gallon>(1.),
+                    &mut self.reservoir,
+                    true,
+                );
+        }
+    }
+    impl SimulationElement for TestGroundService {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.panel.accept(visitor);
+            self.reservoir.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestGroundService> {
+        SimulationTestBed::from(ElementCtorFn(TestGroundService::new))
+            .with_update_after_power_distribution(|element, context| element.update(context))
+    }
+
+    fn connect_cart(test_bed: &mut SimulationTestBed<TestGroundService>, is_on: bool) {
+        test_bed.write_by_name("HYD_GREEN_GROUND_CART_CONNECTED", true);
+        test_bed.write_by_name("HYD_GREEN_GROUND_CART_ON", is_on);
+    }
+
+    #[test]
+    fn disconnected_cart_delivers_no_flow() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("HYD_GREEN_GROUND_CART_ON", true);
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            test_bed.query_element(|e| e.panel.cart_flow()),
+            VolumeRate::default()
+        );
+    }
+
+    #[test]
+    fn connected_cart_delivers_its_max_flow_at_low_pressure() {
+        let mut test_bed = test_bed();
+        connect_cart(&mut test_bed, true);
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_about_eq!(
+            test_bed
+                .query_element(|e| e.panel.cart_flow())
+                .get::<gallon_per_minute>(),
+            HydraulicGroundCart::DEFAULT_MAX_FLOW_GPM,
+            0.01
+        );
+    }
+
+    #[test]
+    fn connected_cart_switched_off_delivers_no_flow() {
+        let mut test_bed = test_bed();
+        connect_cart(&mut test_bed, false);
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            test_bed.query_element(|e| e.panel.cart_flow()),
+            VolumeRate::default()
+        );
+    }
+
+    #[test]
+    fn cart_delivers_no_flow_above_its_regulated_pressure() {
+        let mut test_bed = test_bed();
+        connect_cart(&mut test_bed, true);
+        test_bed.command_element(|e| e.section.pressure = Pressure::new::<psi>(3000.));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            test_bed.query_element(|e| e.panel.cart_flow()),
+            VolumeRate::default()
+        );
+    }
+
+    #[test]
+    fn cart_flow_is_reduced_close_to_its_regulated_pressure() {
+        let mut test_bed = test_bed();
+        connect_cart(&mut test_bed, true);
+        test_bed.command_element(|e| e.section.pressure = Pressure::new::<psi>(2900.));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let flow = test_bed
+            .query_element(|e| e.panel.cart_flow())
+            .get::<gallon_per_minute>();
+        assert!(flow > 0.);
+        assert!(flow < HydraulicGroundCart::DEFAULT_MAX_FLOW_GPM);
+    }
+
+    #[test]
+    fn hand_pump_can_overfill_reservoir() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("HYD_GREEN_RESERVOIR_HAND_PUMP_ACTIVE", true);
+
+        test_bed.run_with_delta(Duration::from_secs(60));
+        assert!(!test_bed.query_element(|e| e.reservoir.is_overfilled()));
+
+        test_bed.run_with_delta(Duration::from_secs(60));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(test_bed.query_element(|e| e.reservoir.is_overfilled()));
+        let is_overfill_indicated: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVERFILL");
+        assert!(is_overfill_indicated);
+        assert!(
+            test_bed.query_element(|e| e.reservoir.fluid_level_real()) <= Volume::new::<gallon>(5.)
+        );
+    }
+
+    #[test]
+    fn cart_fills_reservoir_up_to_its_gaugeable_level() {
+        let mut test_bed = test_bed();
+        connect_cart(&mut test_bed, false);
+        test_bed.write_by_name("HYD_GREEN_GROUND_CART_FILL_SELECTED", true);
+
+        test_bed.run_with_delta(Duration::from_secs(10));
+        assert!(test_bed.query_element(|e| e.panel.cart_fill_flow()) > VolumeRate::default());
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(10));
+        }
+
+        assert_about_eq!(
+            test_bed
+                .query_element(|e| e.reservoir.fluid_level_real())
+                .get::<gallon>(),
+            4.,
+            0.001
+        );
+        assert_eq!(
+            test_bed.query_element(|e| e.panel.cart_fill_flow()),
+            VolumeRate::default()
+        );
+        assert!(
+            test_bed.query_element(|e| e.panel.cart.tank_quantity())
+                < Volume::new::<gallon>(HydraulicGroundCart::DEFAULT_TANK_QUANTITY_GAL)
+        );
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
         ProximityDetectorId, SectionPressure,
     },
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
@@ -609,6 +610,15 @@ impl SimulationElement for GearSystemComponentAssembly {
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.hydraulic_assembly.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.hydraulic_assembly.restore_state(state);
+        self.update_proximity_detectors();
+    }
 }
 
 struct GearSystemComponentHydraulicController {
@@ -25,6 +25,7 @@ use crate::{
         ElectricalBuses,
     },
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
         UpdateContext, VariableIdentifier,
     },
@@ -39,6 +40,9 @@ pub trait Actuator {
     fn used_volume(&self) -> Volume;
     fn reservoir_return(&self) -> Volume;
     fn reset_volumes(&mut self);
+
+    /// Ratio of its nominal flow the actuator can get with current fluid viscosity
+    fn set_fluid_flow_factor(&mut self, _flow_factor: Ratio) {}
 }
 
 /// Trait linked to anything moving bounded between a minimum and maximum position.
@@ -397,6 +401,7 @@ struct CoreHydraulicForce {
 
     max_flow: VolumeRate,
     min_flow: VolumeRate,
+    fluid_flow_factor: Ratio,
 
     bore_side_area: Area,
     rod_side_area: Area,
@@ -487,6 +492,7 @@ impl CoreHydraulicForce {
 
             max_flow,
             min_flow,
+            fluid_flow_factor: Ratio::new::<ratio>(1.),
 
             bore_side_area,
             rod_side_area,
@@ -768,10 +774,16 @@ impl CoreHydraulicForce {
             )
         };
 
-        (open_loop_flow_target.min(self.max_flow).max(self.min_flow))
+        (open_loop_flow_target
+            .min(self.max_flow * self.fluid_flow_factor)
+            .max(self.min_flow * self.fluid_flow_factor))
             * open_loop_modifier_from_position
     }
 
+    fn set_fluid_flow_factor(&mut self, flow_factor: Ratio) {
+        self.fluid_flow_factor = flow_factor;
+    }
+
     fn update_force_min_max(
         &mut self,
         context: &UpdateContext,
@@ -1249,6 +1261,10 @@ impl Actuator for LinearActuator {
             eha.reset_volumes();
         }
     }
+
+    fn set_fluid_flow_factor(&mut self, flow_factor: Ratio) {
+        self.core_hydraulics.set_fluid_flow_factor(flow_factor);
+    }
 }
 impl HydraulicLocking for LinearActuator {
     fn should_soft_lock(&self) -> bool {
@@ -1483,6 +1499,14 @@ impl SimulationElement for HydraulicLinearActuatorAssembly<1> {
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.rigid_body.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.rigid_body.restore_state(state);
+    }
 }
 impl Debug for HydraulicLinearActuatorAssembly<1> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1499,6 +1523,14 @@ impl SimulationElement for HydraulicLinearActuatorAssembly<2> {
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.rigid_body.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.rigid_body.restore_state(state);
+    }
 }
 impl Debug for HydraulicLinearActuatorAssembly<2> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1515,6 +1547,14 @@ impl SimulationElement for HydraulicLinearActuatorAssembly<3> {
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.rigid_body.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.rigid_body.restore_state(state);
+    }
 }
 impl Debug for HydraulicLinearActuatorAssembly<3> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1878,6 +1918,25 @@ impl LinearActuatedRigidBodyOnHingeAxis {
         self.is_lock_requested = false;
     }
 
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.angular_position);
+        state.write(self.angular_speed);
+        state.write(self.is_locked);
+        state.write(self.is_lock_requested);
+        state.write(self.lock_position_request);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.angular_position = state.read();
+        self.angular_speed = state.read();
+        self.is_locked = state.read();
+        self.is_lock_requested = state.read();
+        self.lock_position_request = state.read();
+
+        self.init_position_normalized();
+        self.update_all_rotations();
+    }
+
     pub fn lock_at_position_normalized(&mut self, position_normalized: Ratio) {
         self.is_lock_requested = true;
         self.lock_position_request = position_normalized;
@@ -14,6 +14,7 @@ use crate::shared::{
     ElectricalBusType, ElectricalBuses, HydraulicColor, RamAirTurbineController, SectionPressure,
 };
 use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
     SimulatorWriter, UpdateContext, VariableIdentifier, Write,
 };
@@ -25,10 +26,12 @@ use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     f64::*,
     length::meter,
+    power::watt,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     torque::{newton_meter, pound_force_inch},
-    volume::{cubic_inch, cubic_meter, gallon},
+    volume::{cubic_inch, cubic_meter, gallon, liter},
     volume_rate::{gallon_per_minute, gallon_per_second},
 };
 
@@ -39,6 +42,7 @@ pub mod cargo_doors;
 pub mod electrical_generator;
 pub mod electrical_pump_physics;
 pub mod flap_slat;
+pub mod ground_service;
 pub mod landing_gear;
 pub mod linear_actuator;
 pub mod nose_steering;
@@ -79,33 +83,48 @@ pub trait PressureSource {
 
     /// This is the physical displacement of the pump
     fn displacement(&self) -> Volume;
+
+    /// Internal leakage of the pump returning to reservoir through its case drain
+    fn case_drain_flow(&self) -> VolumeRate {
+        VolumeRate::default()
+    }
 }
 
+/// Hydraulic fluid of a circuit.
+/// Its temperature is the one of the fluid mixing in the reservoir. It is heated by pumps and ptu losses
+/// and by fluid throttling through actuators and leaks. It is cooled by the ambient air, and by the
+/// heat exchanger in the fuel tank as long as fluid returns to the reservoir.
 pub struct Fluid {
     current_bulk: Pressure,
-    heat_state: HeatingProperties,
+    temperature: Option<ThermodynamicTemperature>,
+    heat_rejected_to_fuel: Power,
 }
 impl Fluid {
-    const HEATING_TIME_CONSTANT_MEAN_S: f64 = 40.;
-    const HEATING_TIME_CONSTANT_STD_S: f64 = 10.;
+    // Phosphate ester based fluid
+    const DENSITY_KILOGRAM_PER_LITER: f64 = 1.;
+    const SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN: f64 = 1750.;
 
-    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
-    const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
+    // Fluid in the return lines and heat exchanger mixing with the reservoir fluid
+    const RETURN_LINES_VOLUME_GAL: f64 = 1.;
+    const RETURN_LINES_THERMAL_MASS_JOULE_PER_KELVIN: f64 = 4000.;
+
+    const AMBIENT_HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 20.;
+    const HEAT_EXCHANGER_HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 100.;
+
+    const VISCOSITY_TEMPERATURE_BREAKPOINTS_CELSIUS: [f64; 9] =
+        [-54., -40., -20., 0., 20., 38., 60., 99., 130.];
+    const KINEMATIC_VISCOSITY_CENTISTOKES: [f64; 9] =
+        [1800., 500., 130., 45., 20., 11.4, 7., 3.9, 2.8];
+
+    // Above 50cSt fluid is thick enough to slow down pump suction and actuators
+    const FLOW_FACTOR_VISCOSITY_BREAKPOINTS_CENTISTOKES: [f64; 5] = [50., 150., 400., 1000., 2000.];
+    const FLOW_FACTOR_MAP: [f64; 5] = [1., 0.95, 0.85, 0.6, 0.4];
 
     pub fn new(bulk: Pressure) -> Self {
         Self {
             current_bulk: bulk,
-            heat_state: HeatingProperties::new(
-                Duration::from_secs_f64(
-                    random_from_normal_distribution(
-                        Self::HEATING_TIME_CONSTANT_MEAN_S,
-                        Self::HEATING_TIME_CONSTANT_STD_S,
-                    )
-                    .max(10.),
-                ),
-                Self::COOLING_TIME_CONSTANT,
-                Self::DAMAGE_TIME_CONSTANT,
-            ),
+            temperature: None,
+            heat_rejected_to_fuel: Power::default(),
         }
     }
 
@@ -113,17 +132,79 @@ impl Fluid {
         self.current_bulk
     }
 
-    fn update(&mut self, context: &UpdateContext, is_heating: bool) {
-        self.heat_state.update(context, is_heating);
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        // Until the first update the fluid is assumed to be at standard temperature.
+        self.temperature
+            .unwrap_or_else(|| ThermodynamicTemperature::new::<degree_celsius>(15.))
     }
-}
-impl HeatingElement for Fluid {
-    fn is_overheating(&self) -> bool {
-        self.heat_state.is_overheating()
+
+    pub fn heat_rejected_to_fuel(&self) -> Power {
+        self.heat_rejected_to_fuel
     }
 
-    fn is_damaged(&self) -> bool {
-        self.heat_state.is_damaged()
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        heat: Power,
+        reservoir: &Reservoir,
+        fuel_temperature: ThermodynamicTemperature,
+    ) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+        let fuel_temperature = fuel_temperature.get::<degree_celsius>();
+
+        let temperature = self
+            .temperature
+            .map_or(ambient_temperature, |t| t.get::<degree_celsius>());
+
+        self.heat_rejected_to_fuel = if reservoir.has_fluid_return() {
+            Power::new::<watt>(
+                Self::HEAT_EXCHANGER_HEAT_TRANSFER_WATT_PER_KELVIN
+                    * (temperature - fuel_temperature),
+            )
+        } else {
+            Power::default()
+        };
+
+        let heat_loss = Self::AMBIENT_HEAT_TRANSFER_WATT_PER_KELVIN
+            * (temperature - ambient_temperature)
+            + self.heat_rejected_to_fuel.get::<watt>();
+
+        let thermal_mass = (reservoir.fluid_level_real()
+            + Volume::new::<gallon>(Self::RETURN_LINES_VOLUME_GAL))
+        .get::<liter>()
+            * Self::DENSITY_KILOGRAM_PER_LITER
+            * Self::SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN
+            + Self::RETURN_LINES_THERMAL_MASS_JOULE_PER_KELVIN;
+
+        self.temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(
+            temperature
+                + (heat.get::<watt>() - heat_loss) * context.delta_as_secs_f64() / thermal_mass,
+        ));
+    }
+
+    fn kinematic_viscosity_centistokes(temperature: ThermodynamicTemperature) -> f64 {
+        interpolation(
+            &Self::VISCOSITY_TEMPERATURE_BREAKPOINTS_CELSIUS,
+            &Self::KINEMATIC_VISCOSITY_CENTISTOKES,
+            temperature.get::<degree_celsius>(),
+        )
+    }
+
+    /// Ratio of the nominal flow that pumps and actuators can pass with fluid at given temperature
+    fn flow_factor(temperature: ThermodynamicTemperature) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::FLOW_FACTOR_VISCOSITY_BREAKPOINTS_CENTISTOKES,
+            &Self::FLOW_FACTOR_MAP,
+            Self::kinematic_viscosity_centistokes(temperature),
+        ))
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.temperature());
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.temperature = Some(state.read());
     }
 }
 
@@ -285,6 +366,20 @@ impl HeatingProperties {
                 .min(1.),
         )
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_overheating);
+        state.write(self.is_damaged_by_heat);
+        self.damaging_time.save_state(state);
+        self.heat_factor.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_overheating = state.read();
+        self.is_damaged_by_heat = state.read();
+        self.damaging_time.restore_state(state);
+        self.heat_factor.restore_state(state);
+    }
 }
 impl HeatingElement for HeatingProperties {
     fn is_overheating(&self) -> bool {
@@ -346,6 +441,7 @@ pub struct PowerTransferUnit {
     has_stopped_since_last_write: bool,
 
     efficiency: Ratio,
+    heat_dissipated: Power,
 
     heat_state: HeatingProperties,
 }
@@ -389,6 +485,9 @@ impl PowerTransferUnit {
 
     const MAX_SPEED_BEFORE_HEATING_UP_RPM: f64 = 2000.;
 
+    // Heat released into each connected circuit by an overheating ptu
+    const OVERHEAT_HEAT_DISSIPATION_WATT: f64 = 75000.;
+
     // We consider that ptu can't overheat if there's enough pressure on both side (it's cooled by hyd fluid)
     const MIN_PRESSURE_ALLOWING_PTU_HEATING_UP_RPM: f64 = 500.;
 
@@ -438,6 +537,7 @@ impl PowerTransferUnit {
             has_stopped_since_last_write: false,
 
             efficiency: characteristics.efficiency(),
+            heat_dissipated: Power::default(),
 
             heat_state: HeatingProperties::new(
                 Duration::from_secs_f64(
@@ -494,6 +594,33 @@ impl PowerTransferUnit {
                     || loop_right_section.pressure().get::<psi>()
                         < Self::MIN_PRESSURE_ALLOWING_PTU_HEATING_UP_RPM),
         );
+
+        self.update_heat_dissipated(loop_left_section, loop_right_section);
+    }
+
+    fn update_heat_dissipated(
+        &mut self,
+        loop_left_section: &impl SectionPressure,
+        loop_right_section: &impl SectionPressure,
+    ) {
+        // Losses are taken on the driving side pressure, and shared between both circuits
+        let driving_pressure = (loop_left_section
+            .pressure()
+            .max(loop_right_section.pressure())
+            - Pressure::new::<psi>(14.7))
+        .max(Pressure::default());
+
+        self.heat_dissipated =
+            self.last_flow * driving_pressure * (1. - self.efficiency.get::<ratio>()) / 2.;
+
+        if self.is_overheating() && self.is_rotating() {
+            self.heat_dissipated += Power::new::<watt>(Self::OVERHEAT_HEAT_DISSIPATION_WATT);
+        }
+    }
+
+    /// Heat released into each of the two circuits connected to the ptu
+    pub fn heat_dissipated(&self) -> Power {
+        self.heat_dissipated
     }
 
     fn update_displacement(
@@ -754,6 +881,60 @@ impl SimulationElement for PowerTransferUnit {
         // we ensure here to reset the flag indicating we missed a stop
         self.has_stopped_since_last_write = false;
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_enabled);
+        state.write(self.is_active_right);
+        state.write(self.is_active_left);
+        state.write(self.flow_to_right);
+        state.write(self.flow_to_left);
+        state.write(self.left_displacement);
+        self.right_displacement.save_state(state);
+        state.write(self.last_flow);
+        state.write(self.control_valve_opened);
+        state.write(self.shaft_speed);
+        self.shaft_speed_filtered.save_state(state);
+        state.write(self.is_in_continuous_mode);
+        self.is_rotating_after_delay.save_state(state);
+        state.write(self.activation_delta_pressure);
+        state.write(self.deactivation_delta_pressure);
+        state.write(self.shot_to_shot_activation_coefficient);
+        state.write(self.shot_to_shot_deactivation_coefficient);
+        state.write(self.duration_since_active);
+        state.write(self.speed_captured_at_active_duration);
+        state.write(self.bark_strength as f64);
+        state.write(self.has_stopped_since_last_write);
+        state.write(self.efficiency);
+        state.write(self.heat_dissipated);
+        self.heat_state.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_enabled = state.read();
+        self.is_active_right = state.read();
+        self.is_active_left = state.read();
+        self.flow_to_right = state.read();
+        self.flow_to_left = state.read();
+        self.left_displacement = state.read();
+        self.right_displacement.restore_state(state);
+        self.last_flow = state.read();
+        self.control_valve_opened = state.read();
+        self.shaft_speed = state.read();
+        self.shaft_speed_filtered.restore_state(state);
+        self.is_in_continuous_mode = state.read();
+        self.is_rotating_after_delay.restore_state(state);
+        self.activation_delta_pressure = state.read();
+        self.deactivation_delta_pressure = state.read();
+        self.shot_to_shot_activation_coefficient = state.read();
+        self.shot_to_shot_deactivation_coefficient = state.read();
+        self.duration_since_active = state.read();
+        self.speed_captured_at_active_duration = state.read();
+        self.bark_strength = state.read::<f64>() as u8;
+        self.has_stopped_since_last_write = state.read();
+        self.efficiency = state.read();
+        self.heat_dissipated = state.read();
+        self.heat_state.restore_state(state);
+    }
 }
 impl HeatingElement for PowerTransferUnit {
     fn is_overheating(&self) -> bool {
@@ -945,6 +1126,17 @@ impl Accumulator {
         gas_precharge * total_volume / (total_volume - current_volume)
     }
 
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.fluid_volume);
+        state.write(self.current_gas_init_precharge);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.fluid_volume = state.read();
+        self.set_gas_precharge_pressure(state.read());
+        self.gas_volume = self.total_volume - self.fluid_volume;
+    }
+
     #[cfg(test)]
     fn total_volume(&self) -> Volume {
         self.total_volume
@@ -987,6 +1179,9 @@ impl HydraulicCircuit {
 
     const FLUID_BULK_MODULUS_PASCAL: f64 = 1450000000.0;
 
+    // Heat released into the fluid by an overheating pump still delivering flow
+    const PUMP_OVERHEAT_HEAT_DISSIPATION_WATT: f64 = 75000.;
+
     // TODO firevalves are actually powered by a sub-bus (401PP DC ESS)
     const DEFAULT_FIRE_VALVE_POWERING_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentEssential;
@@ -1123,11 +1318,13 @@ impl HydraulicCircuit {
     }
 
     pub fn update_system_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
+        actuator.set_fluid_flow_factor(self.reservoir.fluid_flow_factor());
         self.system_section.update_actuator_volumes(actuator);
     }
 
     pub fn update_auxiliary_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
         if let Some(auxiliary_section) = self.auxiliary_section.as_mut() {
+            actuator.set_fluid_flow_factor(self.reservoir.fluid_flow_factor());
             auxiliary_section.update_actuator_volumes(actuator);
         } else {
             panic!("No auxiliary section available but an actuator was provided")
@@ -1143,33 +1340,35 @@ impl HydraulicCircuit {
         ptu: Option<&PowerTransferUnit>,
         controller: &impl HydraulicCircuitController,
         reservoir_pressure: Pressure,
+        heat_exchanger_fuel_temperature: ThermodynamicTemperature,
     ) {
-        let mut any_pump_is_overheating = false;
+        let mut heat = self.sections_heat_dissipated();
+
         for pump in main_section_pumps.iter() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
+            heat += Self::pump_overheat_heat_dissipated(&**pump);
         }
 
         if let Some(pump) = system_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
+            heat += Self::pump_overheat_heat_dissipated(&**pump);
         }
 
         if let Some(pump) = auxiliary_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
+            heat += Self::pump_overheat_heat_dissipated(&**pump);
         }
 
-        let ptu_overheats_fluid = ptu.map_or(false, |p| p.is_overheating() && p.is_rotating());
+        if let Some(ptu) = ptu {
+            heat += ptu.heat_dissipated();
+        }
 
-        self.fluid
-            .update(context, ptu_overheats_fluid || any_pump_is_overheating);
+        self.fluid.update(
+            context,
+            heat,
+            &self.reservoir,
+            heat_exchanger_fuel_temperature,
+        );
 
         self.reservoir
-            .update(context, reservoir_pressure, &self.fluid);
+            .update(context, reservoir_pressure, self.fluid.temperature());
 
         self.update_shutoff_valves(controller);
         self.update_leak_measurement_valves(context, controller);
@@ -1210,6 +1409,28 @@ impl HydraulicCircuit {
         self.update_final_delta_vol_and_pressure(context);
     }
 
+    fn sections_heat_dissipated(&self) -> Power {
+        let mut heat = self.system_section.heat_dissipated();
+
+        for section in &self.pump_sections {
+            heat += section.heat_dissipated();
+        }
+
+        if let Some(auxiliary_section) = self.auxiliary_section.as_ref() {
+            heat += auxiliary_section.heat_dissipated();
+        }
+
+        heat
+    }
+
+    fn pump_overheat_heat_dissipated(pump: &dyn HeatingPressureSource) -> Power {
+        if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
+            Power::new::<watt>(Self::PUMP_OVERHEAT_HEAT_DISSIPATION_WATT)
+        } else {
+            Power::default()
+        }
+    }
+
     fn update_delta_vol_from_valves(&mut self) {
         for (pump_index, section) in self.pump_sections.iter_mut().enumerate() {
             section.update_downstream_delta_vol(&self.pump_sections_check_valves[pump_index]);
@@ -1441,6 +1662,14 @@ impl HydraulicCircuit {
         &self.reservoir
     }
 
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.fluid.temperature()
+    }
+
+    pub fn heat_rejected_to_fuel(&self) -> Power {
+        self.fluid.heat_rejected_to_fuel()
+    }
+
     pub fn system_section_pressure(&self) -> Pressure {
         self.system_section.pressure()
     }
@@ -1477,6 +1706,14 @@ impl SimulationElement for HydraulicCircuit {
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.fluid.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.fluid.restore_state(state);
+    }
 }
 impl HydraulicPressureSensors for HydraulicCircuit {
     fn pump_section_switch_pressurised(&self, pump_index: usize) -> bool {
@@ -1527,8 +1764,13 @@ pub struct Section {
 
     total_actuator_consumed_volume: Volume,
     total_actuator_returned_volume: Volume,
+
+    heat_dissipated: Power,
 }
 impl Section {
+    // Part of the hydraulic power delivered by a pump that is lost as heat in the pump itself
+    const PUMP_HEAT_LOSS_RATIO: f64 = 0.15;
+
     pub fn new(
         context: &mut InitContext,
         loop_id: HydraulicColor,
@@ -1582,6 +1824,8 @@ impl Section {
 
             total_actuator_consumed_volume: Volume::new::<gallon>(0.),
             total_actuator_returned_volume: Volume::new::<gallon>(0.),
+
+            heat_dissipated: Power::default(),
         }
     }
 
@@ -1671,9 +1915,22 @@ impl Section {
 
         self.delta_volume_flow_pass = delta_volume_flow_pass;
 
+        // Fluid leaking or going through actuators is throttled from section pressure down to return pressure
+        self.heat_dissipated = (static_leak + self.total_actuator_consumed_volume)
+            * self.relative_pressure()
+            / context.delta_as_time();
+
         self.reset_actuator_volumes();
     }
 
+    fn relative_pressure(&self) -> Pressure {
+        (self.current_pressure - Pressure::new::<psi>(14.7)).max(Pressure::default())
+    }
+
+    fn heat_dissipated(&self) -> Power {
+        self.heat_dissipated
+    }
+
     fn update_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
         self.total_actuator_consumed_volume += actuator.used_volume();
         self.total_actuator_returned_volume += actuator.reservoir_return();
@@ -1721,6 +1978,9 @@ impl Section {
             self.fire_valve_is_open(),
         );
         self.total_volume_pumped = pump.flow() * context.delta_as_time();
+
+        self.heat_dissipated += pump.flow() * self.relative_pressure() * Self::PUMP_HEAT_LOSS_RATIO
+            + pump.case_drain_flow() * self.relative_pressure();
     }
 
     pub fn update_final_delta_vol_and_pressure(&mut self, context: &UpdateContext, fluid: &Fluid) {
@@ -1826,6 +2086,24 @@ impl SimulationElement for Section {
             self.pressure_switch_state() == PressureSwitchState::Pressurised,
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.current_volume);
+        state.write(self.current_pressure);
+
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.save_state(state);
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.current_volume = state.read();
+        self.current_pressure = state.read();
+
+        if let Some(accumulator) = &mut self.accumulator {
+            accumulator.restore_state(state);
+        }
+    }
 }
 impl SectionPressure for Section {
     fn pressure(&self) -> Pressure {
@@ -2218,6 +2496,8 @@ pub struct Reservoir {
     low_level_id: VariableIdentifier,
     low_air_press_id: VariableIdentifier,
     overheating_id: VariableIdentifier,
+    fluid_temperature_id: VariableIdentifier,
+    overfill_id: VariableIdentifier,
 
     max_capacity: Volume,
     max_gaugeable: Volume,
@@ -2234,7 +2514,10 @@ pub struct Reservoir {
 
     fluid_physics: FluidPhysics,
 
-    heat_state: HeatingProperties,
+    fluid_temperature: ThermodynamicTemperature,
+    is_overheating: bool,
+    is_damaged_by_heat: bool,
+    damaging_time: DelayedTrueLogicGate,
 
     total_return_flow: VolumeRate,
     total_return_volume: Volume,
@@ -2247,9 +2530,10 @@ impl Reservoir {
     // Part of the fluid lost instead of returning to reservoir
     const RETURN_FAILURE_LEAK_RATIO: f64 = 0.1;
 
-    const HEATING_TIME_CONSTANT_MEAN_S: f64 = 30.;
-    const HEATING_TIME_CONSTANT_STD_S: f64 = 5.;
-    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
+    const OVERHEAT_THRESHOLD_CELSIUS: f64 = 95.;
+    const OVERHEAT_RESET_THRESHOLD_CELSIUS: f64 = 85.;
+    // Fluid temperature at which pumps can't deliver any flow anymore
+    const MAX_FLUID_TEMPERATURE_CELSIUS: f64 = 130.;
     const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 5);
 
     pub fn new(
@@ -2268,6 +2552,9 @@ impl Reservoir {
             low_air_press_id: context
                 .get_identifier(format!("HYD_{}_RESERVOIR_AIR_PRESSURE_IS_LOW", hyd_loop_id)),
             overheating_id: context.get_identifier(format!("HYD_{}_RESERVOIR_OVHT", hyd_loop_id)),
+            fluid_temperature_id: context
+                .get_identifier(format!("HYD_{}_FLUID_TEMPERATURE", hyd_loop_id)),
+            overfill_id: context.get_identifier(format!("HYD_{}_RESERVOIR_OVERFILL", hyd_loop_id)),
 
             max_capacity,
             max_gaugeable,
@@ -2280,17 +2567,11 @@ impl Reservoir {
             level_switch: LevelSwitch::new(low_level_threshold),
             fluid_physics: FluidPhysics::new(),
 
-            heat_state: HeatingProperties::new(
-                Duration::from_secs_f64(
-                    random_from_normal_distribution(
-                        Self::HEATING_TIME_CONSTANT_MEAN_S,
-                        Self::HEATING_TIME_CONSTANT_STD_S,
-                    )
-                    .max(10.),
-                ),
-                Self::COOLING_TIME_CONSTANT,
-                Self::DAMAGE_TIME_CONSTANT,
-            ),
+            fluid_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            is_overheating: false,
+            is_damaged_by_heat: false,
+            damaging_time: DelayedTrueLogicGate::new(Self::DAMAGE_TIME_CONSTANT),
+
             total_return_flow: VolumeRate::default(),
             total_return_volume: Volume::default(),
         }
@@ -2300,12 +2581,12 @@ impl Reservoir {
         &mut self,
         context: &UpdateContext,
         air_pressure: Pressure,
-        fluid: &impl HeatingElement,
+        fluid_temperature: ThermodynamicTemperature,
     ) {
         self.air_pressure = air_pressure;
 
         self.update_return_flow(context);
-        self.update_heat(context, fluid);
+        self.update_heat(context, fluid_temperature);
 
         self.fluid_physics.update(context);
 
@@ -2324,10 +2605,44 @@ impl Reservoir {
         self.total_return_volume = Volume::default();
     }
 
-    fn update_heat(&mut self, context: &UpdateContext, fluid: &impl HeatingElement) {
-        let has_fluid_return = self.total_return_flow.get::<gallon_per_second>() > 0.01;
-        self.heat_state
-            .update(context, has_fluid_return && fluid.is_overheating())
+    fn update_heat(
+        &mut self,
+        context: &UpdateContext,
+        fluid_temperature: ThermodynamicTemperature,
+    ) {
+        self.fluid_temperature = fluid_temperature;
+
+        let temperature = fluid_temperature.get::<degree_celsius>();
+        if temperature > Self::OVERHEAT_THRESHOLD_CELSIUS {
+            self.is_overheating = true;
+        } else if temperature < Self::OVERHEAT_RESET_THRESHOLD_CELSIUS {
+            self.is_overheating = false;
+        }
+
+        self.damaging_time.update(context, self.is_overheating);
+        self.is_damaged_by_heat = self.is_damaged_by_heat || self.damaging_time.output();
+    }
+
+    fn has_fluid_return(&self) -> bool {
+        self.total_return_flow.get::<gallon_per_second>() > 0.01
+    }
+
+    /// When overheating, provides a ratio of the heating severity
+    /// It rises from 0 at overheat threshold to 1 at max fluid temperature
+    fn overheat_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            ((self.fluid_temperature.get::<degree_celsius>() - Self::OVERHEAT_THRESHOLD_CELSIUS)
+                / (Self::MAX_FLUID_TEMPERATURE_CELSIUS - Self::OVERHEAT_THRESHOLD_CELSIUS))
+                .clamp(0., 1.),
+        )
+    }
+
+    fn fluid_flow_factor(&self) -> Ratio {
+        Fluid::flow_factor(self.fluid_temperature)
+    }
+
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.fluid_temperature
     }
 
     fn update_leak_failure(&mut self, context: &UpdateContext) {
@@ -2383,6 +2698,27 @@ impl Reservoir {
         self.total_return_volume += volume_actually_returned;
     }
 
+    /// Fills the reservoir from a servicing source, returns the volume accepted.
+    /// Nothing prevents filling above the full level: an overfilled reservoir vents fluid
+    /// overboard as soon as the returning fluid exceeds its capacity.
+    fn fill(&mut self, volume: Volume) -> Volume {
+        let volume_filled = volume
+            .min(self.max_capacity - self.current_level)
+            .max(Volume::default());
+
+        self.current_level += volume_filled;
+
+        volume_filled
+    }
+
+    fn volume_to_full_level(&self) -> Volume {
+        (self.max_gaugeable - self.current_level).max(Volume::default())
+    }
+
+    pub fn is_overfilled(&self) -> bool {
+        self.current_level > self.max_gaugeable
+    }
+
     fn fluid_level_real(&self) -> Volume {
         self.current_level
     }
@@ -2428,6 +2764,16 @@ impl SimulationElement for Reservoir {
         writer.write(&self.low_level_id, self.is_low_level());
         writer.write(&self.low_air_press_id, self.is_low_air_pressure());
         writer.write(&self.overheating_id, self.is_overheating());
+        writer.write(&self.fluid_temperature_id, self.fluid_temperature);
+        writer.write(&self.overfill_id, self.is_overfilled());
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.current_level);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.current_level = state.read();
     }
 }
 impl PressurizeableReservoir for Reservoir {
@@ -2437,11 +2783,11 @@ impl PressurizeableReservoir for Reservoir {
 }
 impl HeatingElement for Reservoir {
     fn is_damaged(&self) -> bool {
-        self.heat_state.is_damaged()
+        self.is_damaged_by_heat
     }
 
     fn is_overheating(&self) -> bool {
-        self.heat_state.is_overheating()
+        self.is_overheating
     }
 }
 
@@ -2458,6 +2804,105 @@ pub trait PumpController {
     }
 }
 
+/// Wear of a pump over the airframe life.
+/// A worn pump loses part of its displacement, and its internal leakage returning to the reservoir
+/// through the case drain increases with pressure. Health slowly decreases with the volume pumped.
+/// It can be set by maintenance tools through the health setting variable, and a wear failure
+/// makes the pump behave as a worn out one.
+pub struct PumpWear {
+    health_id: VariableIdentifier,
+    health_setting_id: VariableIdentifier,
+
+    health: Ratio,
+    last_health_setting: f64,
+
+    wear_failure: Failure,
+}
+impl PumpWear {
+    // Health lost per gallon pumped at nominal pressure, a pump being worn out after tens of thousands of flight hours
+    const HEALTH_LOSS_PER_GALLON_AT_NOMINAL_PRESSURE: f64 = 5e-8;
+    const NOMINAL_PRESSURE_PSI: f64 = 3000.;
+
+    const WORN_OUT_HEALTH: f64 = 0.2;
+
+    // Displacement loss and case drain flow at nominal pressure of a pump at zero health
+    const MAX_DISPLACEMENT_LOSS: f64 = 0.3;
+    const MAX_CASE_DRAIN_FLOW_GAL_PER_S: f64 = 0.1;
+
+    fn new(context: &mut InitContext, pump_name: &str, failure_type: FailureType) -> Self {
+        Self {
+            health_id: context.get_identifier(format!("{}_HEALTH", pump_name)),
+            health_setting_id: context.get_identifier(format!("{}_HEALTH_SETTING", pump_name)),
+
+            health: Ratio::new::<ratio>(1.),
+            last_health_setting: 0.,
+
+            wear_failure: Failure::new(failure_type),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, flow: VolumeRate, pressure: Pressure) {
+        let pumped_volume = flow * context.delta_as_time();
+
+        self.health -= Ratio::new::<ratio>(
+            pumped_volume.get::<gallon>()
+                * (pressure.get::<psi>() / Self::NOMINAL_PRESSURE_PSI).max(0.)
+                * Self::HEALTH_LOSS_PER_GALLON_AT_NOMINAL_PRESSURE,
+        );
+        self.health = self.health.max(Ratio::default());
+    }
+
+    pub fn health(&self) -> Ratio {
+        if self.wear_failure.is_active() {
+            self.health.min(Ratio::new::<ratio>(Self::WORN_OUT_HEALTH))
+        } else {
+            self.health
+        }
+    }
+
+    fn wear(&self) -> f64 {
+        1. - self.health().get::<ratio>()
+    }
+
+    fn displacement_factor(&self) -> Ratio {
+        Ratio::new::<ratio>(1. - self.wear() * Self::MAX_DISPLACEMENT_LOSS)
+    }
+
+    fn max_case_drain_flow(&self) -> VolumeRate {
+        VolumeRate::new::<gallon_per_second>(self.wear() * Self::MAX_CASE_DRAIN_FLOW_GAL_PER_S)
+    }
+}
+impl SimulationElement for PumpWear {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.wear_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        // A new setting is only applied once, so that the pump keeps wearing afterwards
+        let health_setting: f64 = reader.read(&self.health_setting_id);
+        if health_setting > 0. && health_setting != self.last_health_setting {
+            self.health = Ratio::new::<ratio>(health_setting.min(1.));
+        }
+        self.last_health_setting = health_setting;
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.health_id, self.health());
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.health);
+        state.write(self.last_health_setting);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.health = state.read();
+        self.last_health_setting = state.read();
+    }
+}
+
 pub struct Pump {
     delta_vol_max: Volume,
     current_displacement: Volume,
@@ -2469,6 +2914,10 @@ pub struct Pump {
     speed: AngularVelocity,
 
     cavitation_efficiency: Ratio,
+
+    displacement_factor: Ratio,
+    max_case_drain_flow: VolumeRate,
+    case_drain_flow: VolumeRate,
 }
 impl Pump {
     const SECONDS_PER_MINUTES: f64 = 60.;
@@ -2489,9 +2938,34 @@ impl Pump {
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
 
             cavitation_efficiency: Ratio::new::<ratio>(1.),
+
+            displacement_factor: Ratio::new::<ratio>(1.),
+            max_case_drain_flow: VolumeRate::default(),
+            case_drain_flow: VolumeRate::default(),
         }
     }
 
+    fn set_wear(&mut self, wear: &PumpWear) {
+        self.displacement_factor = wear.displacement_factor();
+        self.max_case_drain_flow = wear.max_case_drain_flow();
+    }
+
+    fn update_case_drain_flow(&mut self, section: &impl SectionPressure) {
+        self.case_drain_flow = if self.speed
+            > self
+                .pump_characteristics
+                .min_speed_for_non_zero_efficiency()
+        {
+            self.max_case_drain_flow
+                * Ratio::new::<ratio>(
+                    (section.pressure().get::<psi>() / PumpWear::NOMINAL_PRESSURE_PSI)
+                        .clamp(0., 1.),
+                )
+        } else {
+            VolumeRate::default()
+        };
+    }
+
     fn update<T: PumpController>(
         &mut self,
         context: &UpdateContext,
@@ -2503,18 +2977,20 @@ impl Pump {
         self.speed = speed;
 
         self.update_cavitation(reservoir);
+        self.update_case_drain_flow(section);
 
         let theoretical_displacement = self.calculate_displacement(section, controller);
 
         self.current_max_displacement.update(
             context.delta(),
             self.cavitation_efficiency
+                * self.displacement_factor
                 * theoretical_displacement
                 * controller.max_displacement_restriction(),
         );
 
-        let max_flow = self
-            .get_max_flow_from_max_displacement()
+        // Flow leaking internally to the case drain is never delivered to the circuit
+        let max_flow = (self.get_max_flow_from_max_displacement() - self.case_drain_flow)
             .max(VolumeRate::new::<gallon_per_second>(0.));
 
         let max_flow_available_from_reservoir =
@@ -2525,10 +3001,9 @@ impl Pump {
 
     fn update_cavitation(&mut self, reservoir: &Reservoir) {
         self.cavitation_efficiency = if !reservoir.is_empty() {
-            self.pump_characteristics.cavitation_efficiency(
-                reservoir.air_pressure(),
-                reservoir.heat_state.overheat_ratio(),
-            )
+            self.pump_characteristics
+                .cavitation_efficiency(reservoir.air_pressure(), reservoir.overheat_ratio())
+                * reservoir.fluid_flow_factor()
         } else {
             Ratio::new::<ratio>(0.)
         };
@@ -2600,6 +3075,30 @@ impl Pump {
     fn cavitation_efficiency(&self) -> Ratio {
         self.cavitation_efficiency
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.delta_vol_max);
+        state.write(self.current_displacement);
+        state.write(self.current_flow);
+        self.current_max_displacement.save_state(state);
+        state.write(self.speed);
+        state.write(self.cavitation_efficiency);
+        state.write(self.displacement_factor);
+        state.write(self.max_case_drain_flow);
+        state.write(self.case_drain_flow);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.delta_vol_max = state.read();
+        self.current_displacement = state.read();
+        self.current_flow = state.read();
+        self.current_max_displacement.restore_state(state);
+        self.speed = state.read();
+        self.cavitation_efficiency = state.read();
+        self.displacement_factor = state.read();
+        self.max_case_drain_flow = state.read();
+        self.case_drain_flow = state.read();
+    }
 }
 impl PressureSource for Pump {
     fn delta_vol_max(&self) -> Volume {
@@ -2613,9 +3112,11 @@ impl PressureSource for Pump {
         reservoir: &mut Reservoir,
         is_pump_connected_to_reservoir: bool,
     ) {
-        let required_flow = volume_required / context.delta_as_time();
+        let required_flow = volume_required / context.delta_as_time() + self.case_drain_flow;
         self.current_displacement = self.calculate_displacement_from_required_flow(required_flow);
-        let max_current_flow = self.get_max_flow_from_current_dsiplacement();
+        let max_current_flow = (self.get_max_flow_from_current_dsiplacement()
+            - self.case_drain_flow)
+            .max(VolumeRate::new::<gallon_per_second>(0.));
 
         self.current_flow = if is_pump_connected_to_reservoir {
             reservoir.try_take_flow(context, max_current_flow)
@@ -2631,6 +3132,10 @@ impl PressureSource for Pump {
     fn displacement(&self) -> Volume {
         self.current_displacement
     }
+
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.case_drain_flow
+    }
 }
 
 pub struct ElectricPump {
@@ -2638,6 +3143,7 @@ pub struct ElectricPump {
     overheat_id: VariableIdentifier,
     pump: Pump,
     pump_physics: ElectricalPumpPhysics,
+    wear: PumpWear,
 }
 impl ElectricPump {
     pub fn new(
@@ -2659,6 +3165,11 @@ impl ElectricPump {
                 max_current,
                 regulated_speed,
             ),
+            wear: PumpWear::new(
+                context,
+                &format!("HYD_{}_EPUMP", id),
+                FailureType::ElecPumpWear(id),
+            ),
         }
     }
 
@@ -2673,6 +3184,10 @@ impl ElectricPump {
         self.pump_physics
             .update(context, section, self.pump.displacement());
 
+        self.wear
+            .update(context, self.pump.flow(), section.pressure());
+        self.pump.set_wear(&self.wear);
+
         self.pump.update(
             context,
             section,
@@ -2682,6 +3197,10 @@ impl ElectricPump {
         );
     }
 
+    pub fn health(&self) -> Ratio {
+        self.wear.health()
+    }
+
     pub fn cavitation_efficiency(&self) -> Ratio {
         self.pump.cavitation_efficiency()
     }
@@ -2721,10 +3240,15 @@ impl PressureSource for ElectricPump {
     fn displacement(&self) -> Volume {
         self.pump.displacement()
     }
+
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.pump.case_drain_flow()
+    }
 }
 impl SimulationElement for ElectricPump {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.pump_physics.accept(visitor);
+        self.wear.accept(visitor);
 
         visitor.visit(self);
     }
@@ -2736,6 +3260,14 @@ impl SimulationElement for ElectricPump {
         );
         writer.write(&self.overheat_id, self.is_overheating());
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.pump.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.pump.restore_state(state);
+    }
 }
 impl HeatingElement for ElectricPump {
     fn is_damaged(&self) -> bool {
@@ -2757,6 +3289,8 @@ pub struct EngineDrivenPump {
 
     overheat_failure: Failure,
     heat_state: HeatingProperties,
+
+    wear: PumpWear,
 }
 impl EngineDrivenPump {
     const HEATING_TIME_CONSTANT_MEAN_S: f64 = 30.;
@@ -2791,6 +3325,11 @@ impl EngineDrivenPump {
                 Self::COOLING_TIME_CONSTANT,
                 Self::DAMAGE_TIME_CONSTANT,
             ),
+            wear: PumpWear::new(
+                context,
+                &format!("HYD_{}_EDPUMP", id),
+                FailureType::EnginePumpWear(id),
+            ),
         }
     }
 
@@ -2819,11 +3358,19 @@ impl EngineDrivenPump {
         };
         self.speed = self.speed.max(AngularVelocity::default());
 
+        self.wear
+            .update(context, self.pump.flow(), section.pressure());
+        self.pump.set_wear(&self.wear);
+
         self.pump
             .update(context, section, reservoir, self.speed, controller);
 
         self.is_active = controller.should_pressurise();
     }
+
+    pub fn health(&self) -> Ratio {
+        self.wear.health()
+    }
 }
 impl PressureSource for EngineDrivenPump {
     fn delta_vol_max(&self) -> Volume {
@@ -2852,16 +3399,35 @@ impl PressureSource for EngineDrivenPump {
     fn displacement(&self) -> Volume {
         self.pump.current_displacement
     }
+
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.pump.case_drain_flow()
+    }
 }
 impl SimulationElement for EngineDrivenPump {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.overheat_failure.accept(visitor);
+        self.wear.accept(visitor);
         visitor.visit(self);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.active_id, self.is_active);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_active);
+        state.write(self.speed);
+        self.pump.save_state(state);
+        self.heat_state.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_active = state.read();
+        self.speed = state.read();
+        self.pump.restore_state(state);
+        self.heat_state.restore_state(state);
+    }
 }
 impl HeatingElement for EngineDrivenPump {
     fn is_damaged(&self) -> bool {
@@ -2900,6 +3466,14 @@ impl RatAntiStallPumpController {
         self.anti_stall_ratio
             .update(context.delta(), Ratio::new::<ratio>(cut_off_ratio));
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        self.anti_stall_ratio.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.anti_stall_ratio.restore_state(state);
+    }
 }
 impl PumpController for RatAntiStallPumpController {
     fn should_pressurise(&self) -> bool {
@@ -3049,6 +3623,20 @@ impl SimulationElement for RamAirTurbine {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.stow_position_id, self.position);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.deployment_commanded);
+        self.pump.save_state(state);
+        self.pump_controller.save_state(state);
+        state.write(self.position);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.deployment_commanded = state.read();
+        self.pump.restore_state(state);
+        self.pump_controller.restore_state(state);
+        self.position = state.read();
+    }
 }
 impl HeatingElement for RamAirTurbine {}
 impl HeatingPressureSource for RamAirTurbine {}
@@ -3134,6 +3722,17 @@ impl PressureSource for ManualPump {
         self.pump.displacement()
     }
 }
+impl SimulationElement for ManualPump {
+    fn save_state(&self, state: &mut StateWriter) {
+        self.pump.save_state(state);
+        state.write(self.speed);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.pump.restore_state(state);
+        self.speed = state.read();
+    }
+}
 impl HeatingElement for ManualPump {}
 impl HeatingPressureSource for ManualPump {}
 
@@ -3243,22 +3842,8 @@ mod tests {
 
     use super::*;
 
-    struct TestFluid {
-        is_hot: bool,
-    }
-    impl TestFluid {
-        fn overheat() -> Self {
-            Self { is_hot: true }
-        }
-
-        fn nominal() -> Self {
-            Self { is_hot: false }
-        }
-    }
-    impl HeatingElement for TestFluid {
-        fn is_overheating(&self) -> bool {
-            self.is_hot
-        }
+    fn nominal_fluid_temperature() -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(40.)
     }
 
     impl SimulationElement for PriorityValve {}
@@ -3445,7 +4030,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
@@ -3468,7 +4057,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
@@ -3491,7 +4084,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         let is_low: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_LEVEL_IS_LOW");
@@ -3517,7 +4114,11 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.run_multiple_frames(Duration::from_secs(2));
@@ -3544,7 +4145,11 @@ mod tests {
             )
         }))
         .with_update_after_power_distribution(|el, context| {
-            el.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            el.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            )
         });
 
         test_bed.write_by_name("PLANE BANK DEGREES", 180.);
@@ -3588,7 +4193,7 @@ mod tests {
     }
 
     #[test]
-    fn reservoir_receiving_heating_fluid_overheats() {
+    fn reservoir_receiving_hot_fluid_overheats() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
             reservoir(
                 context,
@@ -3600,23 +4205,67 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::overheat());
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                ThermodynamicTemperature::new::<degree_celsius>(100.),
+            );
+        });
 
-            reservoir.try_take_volume(Volume::new::<gallon>(0.10));
+        test_bed.run();
 
-            reservoir.add_return_volume(Volume::new::<gallon>(0.10));
+        let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
+        assert!(is_overheating);
+    }
+
+    #[test]
+    fn reservoir_overheat_resets_only_when_fluid_cooled_below_hysteresis() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(2.),
+                Volume::new::<gallon>(0.5),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                ThermodynamicTemperature::new::<degree_celsius>(100.),
+            );
         });
+        test_bed.run();
 
-        test_bed.run_multiple_frames(Duration::from_secs_f64(
-            Reservoir::HEATING_TIME_CONSTANT_MEAN_S + 4. * Reservoir::HEATING_TIME_CONSTANT_STD_S,
-        ));
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                ThermodynamicTemperature::new::<degree_celsius>(90.),
+            );
+        });
+        test_bed.run();
 
         let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
         assert!(is_overheating);
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                ThermodynamicTemperature::new::<degree_celsius>(80.),
+            );
+        });
+        test_bed.run();
+
+        let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
+        assert!(!is_overheating);
     }
 
     #[test]
-    fn reservoir_receiving_zero_flow_of_heating_fluid_do_not_overheat() {
+    fn reservoir_receiving_nominal_fluid_do_not_overheat() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
             reservoir(
                 context,
@@ -3628,15 +4277,207 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::overheat());
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_fluid_temperature(),
+            );
         });
 
-        test_bed.run_multiple_frames(Duration::from_secs_f64(
-            Reservoir::HEATING_TIME_CONSTANT_MEAN_S + 4. * Reservoir::HEATING_TIME_CONSTANT_STD_S,
-        ));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
 
         let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
         assert!(!is_overheating);
+
+        let fluid_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_FLUID_TEMPERATURE");
+        assert_about_eq!(
+            fluid_temperature.get::<degree_celsius>(),
+            nominal_fluid_temperature().get::<degree_celsius>()
+        );
+    }
+
+    #[test]
+    fn cold_fluid_reduces_flow_factor() {
+        assert_eq!(
+            Fluid::flow_factor(ThermodynamicTemperature::new::<degree_celsius>(40.)),
+            Ratio::new::<ratio>(1.)
+        );
+
+        let flow_factor_at_minus_40 =
+            Fluid::flow_factor(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+        let flow_factor_at_minus_54 =
+            Fluid::flow_factor(ThermodynamicTemperature::new::<degree_celsius>(-54.));
+
+        assert!(flow_factor_at_minus_40 < Ratio::new::<ratio>(0.9));
+        assert!(flow_factor_at_minus_54 < flow_factor_at_minus_40);
+    }
+
+    struct FluidTestElement {
+        fluid: Fluid,
+        reservoir: Reservoir,
+        heat: Power,
+        return_flow: VolumeRate,
+        fuel_temperature: Option<ThermodynamicTemperature>,
+    }
+    impl FluidTestElement {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                fluid: Fluid::new(Pressure::new::<pascal>(
+                    HydraulicCircuit::FLUID_BULK_MODULUS_PASCAL,
+                )),
+                reservoir: reservoir(
+                    context,
+                    HydraulicColor::Green,
+                    Volume::new::<gallon>(5.),
+                    Volume::new::<gallon>(4.),
+                    Volume::new::<gallon>(3.),
+                ),
+                heat: Power::default(),
+                return_flow: VolumeRate::default(),
+                fuel_temperature: None,
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.fluid.update(
+                context,
+                self.heat,
+                &self.reservoir,
+                self.fuel_temperature
+                    .unwrap_or_else(|| context.ambient_temperature()),
+            );
+            // The returning fluid is the one drawn by the pumps.
+            let circulated_volume = self
+                .reservoir
+                .try_take_volume(self.return_flow * context.delta_as_time());
+            self.reservoir.add_return_volume(circulated_volume);
+            self.reservoir
+                .update(context, Pressure::new::<psi>(50.), self.fluid.temperature());
+        }
+    }
+    impl SimulationElement for FluidTestElement {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.reservoir.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn fluid_test_bed(ambient_temperature_celsius: f64) -> SimulationTestBed<FluidTestElement> {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(FluidTestElement::new))
+            .with_update_after_power_distribution(|element, context| element.update(context));
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+            ambient_temperature_celsius,
+        ));
+
+        test_bed
+    }
+
+    fn fluid_temperature_celsius(test_bed: &mut SimulationTestBed<FluidTestElement>) -> f64 {
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_FLUID_TEMPERATURE");
+        temperature.get::<degree_celsius>()
+    }
+
+    #[test]
+    fn fluid_starts_at_ambient_temperature() {
+        let mut test_bed = fluid_test_bed(-30.);
+
+        test_bed.run();
+
+        assert_about_eq!(fluid_temperature_celsius(&mut test_bed), -30., 0.1);
+    }
+
+    #[test]
+    fn fluid_heats_up_with_dissipated_heat() {
+        let mut test_bed = fluid_test_bed(15.);
+        test_bed.command_element(|e| e.heat = Power::new::<watt>(5000.));
+
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(fluid_temperature_celsius(&mut test_bed) > 20.);
+        assert!(!test_bed.query_element(|e| e.reservoir.is_overheating()));
+    }
+
+    #[test]
+    fn fluid_overheats_reservoir_with_overheating_heat() {
+        let mut test_bed = fluid_test_bed(15.);
+        test_bed.command_element(|e| {
+            e.heat = Power::new::<watt>(HydraulicCircuit::PUMP_OVERHEAT_HEAT_DISSIPATION_WATT)
+        });
+
+        test_bed.run_multiple_frames(Duration::from_secs(90));
+
+        assert!(fluid_temperature_celsius(&mut test_bed) > Reservoir::OVERHEAT_THRESHOLD_CELSIUS);
+        assert!(test_bed.query_element(|e| e.reservoir.is_overheating()));
+    }
+
+    #[test]
+    fn fluid_cools_down_towards_ambient_temperature() {
+        let mut test_bed = fluid_test_bed(15.);
+        test_bed.command_element(|e| e.heat = Power::new::<watt>(20000.));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        let hot_temperature = fluid_temperature_celsius(&mut test_bed);
+
+        test_bed.command_element(|e| e.heat = Power::default());
+        test_bed.run_multiple_frames(Duration::from_secs(600));
+
+        let cooled_temperature = fluid_temperature_celsius(&mut test_bed);
+        assert!(cooled_temperature < hot_temperature);
+        assert!(cooled_temperature > 15.);
+    }
+
+    #[test]
+    fn fluid_rejects_heat_into_colder_fuel_while_returning_to_reservoir() {
+        let mut test_bed = fluid_test_bed(15.);
+        test_bed.command_element(|e| {
+            e.heat = Power::new::<watt>(5000.);
+            e.return_flow = VolumeRate::new::<gallon_per_second>(0.1);
+            e.fuel_temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(test_bed.query_element(|e| e.fluid.heat_rejected_to_fuel() > Power::default()));
+
+        let mut warm_fuel_test_bed = fluid_test_bed(15.);
+        warm_fuel_test_bed.command_element(|e| {
+            e.heat = Power::new::<watt>(5000.);
+            e.return_flow = VolumeRate::new::<gallon_per_second>(0.1);
+            e.fuel_temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(30.));
+        });
+        warm_fuel_test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(
+            fluid_temperature_celsius(&mut test_bed)
+                < fluid_temperature_celsius(&mut warm_fuel_test_bed)
+        );
+    }
+
+    #[test]
+    fn fluid_does_not_reject_heat_into_fuel_without_return_flow() {
+        let mut test_bed = fluid_test_bed(15.);
+        test_bed.command_element(|e| {
+            e.heat = Power::new::<watt>(5000.);
+            e.fuel_temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert_eq!(
+            test_bed.query_element(|e| e.fluid.heat_rejected_to_fuel()),
+            Power::default()
+        );
+    }
+
+    #[test]
+    fn cold_soaked_fluid_reduces_reservoir_flow_factor() {
+        let mut test_bed = fluid_test_bed(-45.);
+
+        test_bed.run();
+
+        assert!(test_bed.query_element(|e| e.reservoir.fluid_flow_factor().get::<ratio>() < 0.9));
     }
 
     fn section(
@@ -3756,5 +4597,172 @@ mod tests {
 
             assert!(test_bed.query_element(|e| !e.is_active));
         }
+
+        struct TestPumpController {}
+        impl PumpController for TestPumpController {
+            fn should_pressurise(&self) -> bool {
+                true
+            }
+        }
+
+        struct EdpTestElement {
+            pump: EngineDrivenPump,
+            section: Section,
+            reservoir: Reservoir,
+        }
+        impl EdpTestElement {
+            fn new(context: &mut InitContext) -> Self {
+                let mut section = section(context, HydraulicColor::Green, "PUMP", 1);
+                section.current_pressure = Pressure::new::<psi>(2000.);
+
+                Self {
+                    pump: engine_driven_pump(context),
+                    section,
+                    reservoir: reservoir(
+                        context,
+                        HydraulicColor::Green,
+                        Volume::new::<gallon>(5.),
+                        Volume::new::<gallon>(4.),
+                        Volume::new::<gallon>(3.),
+                    ),
+                }
+            }
+
+            fn update(&mut self, context: &UpdateContext) {
+                self.pump.update(
+                    context,
+                    &self.section,
+                    &self.reservoir,
+                    AngularVelocity::new::<revolution_per_minute>(4000.),
+                    &TestPumpController {},
+                );
+                let max_volume = self.pump.delta_vol_max();
+                self.pump.update_after_pressure_regulation(
+                    context,
+                    max_volume,
+                    &mut self.reservoir,
+                    true,
+                );
+
+                self.reservoir
+                    .add_return_volume(self.pump.flow() * context.delta_as_time());
+                self.reservoir.update(
+                    context,
+                    Pressure::new::<psi>(50.),
+                    nominal_fluid_temperature(),
+                );
+            }
+        }
+        impl SimulationElement for EdpTestElement {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.pump.accept(visitor);
+                self.section.accept(visitor);
+                self.reservoir.accept(visitor);
+
+                visitor.visit(self);
+            }
+        }
+
+        fn edp_test_bed() -> SimulationTestBed<EdpTestElement> {
+            SimulationTestBed::from(ElementCtorFn(EdpTestElement::new))
+                .with_update_after_power_distribution(|element, context| element.update(context))
+        }
+
+        fn pump_flow_gal_per_s(test_bed: &SimulationTestBed<EdpTestElement>) -> f64 {
+            test_bed.query_element(|e| e.pump.flow().get::<gallon_per_second>())
+        }
+
+        #[test]
+        fn new_pump_is_healthy() {
+            let mut test_bed = edp_test_bed();
+
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert_about_eq!(
+                test_bed.query_element(|e| e.pump.health().get::<ratio>()),
+                1.,
+                0.001
+            );
+            assert_eq!(
+                test_bed.query_element(|e| e.pump.case_drain_flow()),
+                VolumeRate::default()
+            );
+        }
+
+        #[test]
+        fn worn_pump_delivers_less_flow() {
+            let mut test_bed = edp_test_bed();
+            test_bed.run_with_delta(Duration::from_secs(1));
+            let new_pump_flow = pump_flow_gal_per_s(&test_bed);
+
+            test_bed.write_by_name("HYD_GREEN_EDPUMP_HEALTH_SETTING", 0.3);
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert_about_eq!(
+                test_bed.query_element(|e| e.pump.health().get::<ratio>()),
+                0.3,
+                0.001
+            );
+            assert!(pump_flow_gal_per_s(&test_bed) < new_pump_flow * 0.9);
+        }
+
+        #[test]
+        fn health_setting_is_applied_only_once() {
+            let mut test_bed = edp_test_bed();
+            test_bed.write_by_name("HYD_GREEN_EDPUMP_HEALTH_SETTING", 0.5);
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            test_bed.command_element(|e| e.pump.wear.health = Ratio::new::<ratio>(0.4));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert!(test_bed.query_element(|e| e.pump.health().get::<ratio>()) < 0.41);
+        }
+
+        #[test]
+        fn wear_failure_makes_pump_worn_out() {
+            let mut test_bed = edp_test_bed();
+            test_bed.run_with_delta(Duration::from_secs(1));
+            let new_pump_flow = pump_flow_gal_per_s(&test_bed);
+
+            test_bed.fail(FailureType::EnginePumpWear(AirbusEngineDrivenPumpId::Green));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert_about_eq!(
+                test_bed.query_element(|e| e.pump.health().get::<ratio>()),
+                PumpWear::WORN_OUT_HEALTH,
+                0.001
+            );
+            assert!(pump_flow_gal_per_s(&test_bed) < new_pump_flow * 0.9);
+        }
+
+        #[test]
+        fn pumping_slowly_wears_the_pump() {
+            let mut test_bed = edp_test_bed();
+
+            for _ in 0..60 {
+                test_bed.run_with_delta(Duration::from_secs(60));
+            }
+
+            let health = test_bed.query_element(|e| e.pump.health().get::<ratio>());
+            assert!(health < 1.);
+            assert!(health > 0.99);
+        }
+
+        #[test]
+        fn restoring_snapshot_restores_health() {
+            let mut test_bed = edp_test_bed();
+            test_bed.write_by_name("HYD_GREEN_EDPUMP_HEALTH_SETTING", 0.6);
+            test_bed.run_with_delta(Duration::from_secs(1));
+            let snapshot = test_bed.save_snapshot();
+
+            test_bed.command_element(|e| e.pump.wear.health = Ratio::new::<ratio>(1.));
+            assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+
+            assert_about_eq!(
+                test_bed.query_element(|e| e.pump.health().get::<ratio>()),
+                0.6,
+                0.001
+            );
+        }
     }
 }
@@ -1,11 +1,13 @@
 use crate::air_conditioning::AdirsToAirCondInterface;
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     overhead::{IndicationLight, OnOffFaultPushButton},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
         low_pass_filter::LowPassFilter,
-        AdirsDiscreteOutputs, AdirsMeasurementOutputs, MachNumber,
+        random_from_normal_distribution, random_from_range, AdirsDiscreteOutputs,
+        AdirsMeasurementOutputs, GpsMeasurementOutputs, MachNumber,
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -22,9 +24,10 @@ use uom::si::{
     angle::radian,
     angular_velocity::degree_per_second,
     f64::*,
-    length::foot,
+    length::{foot, nautical_mile},
     pressure::hectopascal,
     ratio::ratio,
+    time::second,
     velocity::{foot_per_minute, knot},
 };
 
@@ -81,7 +84,11 @@ impl AirDataInertialReferenceSystemOverheadPanel {
         self.ir
             .iter_mut()
             .enumerate()
-            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)))
+            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)));
+        self.adr
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, adr)| adr.set_fault(adirs.adr_has_fault(index + 1)));
     }
 
     fn mode_of(&self, number: usize) -> InertialReferenceMode {
@@ -432,10 +439,13 @@ impl AirDataInertialReferenceSystem {
         }
     }
 
+    /// Updates the ADIRS, where the GPS receivers are given in order of their number.
+    /// IR 1 and 3 are hybridised with GPS 1, IR 2 with GPS 2.
     pub fn update(
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        gps: [&impl GpsMeasurementOutputs; 2],
     ) {
         // adirs_quick_mode is set by the Aircraft Presets to allow expedited presets without
         // changing the alignment time setting
@@ -445,15 +455,19 @@ impl AirDataInertialReferenceSystem {
             self.configured_align_time
         };
         let simulator_data = self.simulator_data;
-        self.adirus.iter_mut().for_each(|adiru| {
-            adiru.update(
-                context,
-                overhead,
-                align_time,
-                self.aircraft_preset_quick_mode,
-                simulator_data,
-            )
-        });
+        self.adirus
+            .iter_mut()
+            .zip([gps[0], gps[1], gps[0]])
+            .for_each(|(adiru, gps)| {
+                adiru.update(
+                    context,
+                    overhead,
+                    align_time,
+                    self.aircraft_preset_quick_mode,
+                    simulator_data,
+                    gps,
+                )
+            });
     }
 
     fn remaining_align_duration(&self) -> Duration {
@@ -477,6 +491,10 @@ impl AirDataInertialReferenceSystem {
     fn ir_has_fault(&self, number: usize) -> bool {
         self.adirus[number - 1].ir_has_fault()
     }
+
+    fn adr_has_fault(&self, number: usize) -> bool {
+        self.adirus[number - 1].adr_has_fault()
+    }
 }
 impl SimulationElement for AirDataInertialReferenceSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -546,6 +564,14 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
         self.adirus[adiru_number - 1].longitude()
     }
 
+    fn gpirs_latitude(&self, adiru_number: usize) -> Arinc429Word<Angle> {
+        self.adirus[adiru_number - 1].gpirs_latitude()
+    }
+
+    fn gpirs_longitude(&self, adiru_number: usize) -> Arinc429Word<Angle> {
+        self.adirus[adiru_number - 1].gpirs_longitude()
+    }
+
     fn heading(&self, adiru_number: usize) -> Arinc429Word<Angle> {
         self.adirus[adiru_number - 1].heading()
     }
@@ -562,6 +588,10 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
         self.adirus[adiru_number - 1].altitude()
     }
 
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].computed_airspeed()
+    }
+
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle> {
         self.adirus[adiru_number - 1].angle_of_attack()
     }
@@ -601,6 +631,7 @@ impl AirDataInertialReferenceUnit {
         // TODO: this should be refactored (abstracted) away from the AirInertialReferenceUnit struct
         aircraft_preset_quick_mode: bool,
         simulator_data: AdirsSimulatorData,
+        gps: &impl GpsMeasurementOutputs,
     ) {
         self.adr.update(
             context,
@@ -615,6 +646,7 @@ impl AirDataInertialReferenceUnit {
             align_time,
             aircraft_preset_quick_mode,
             simulator_data,
+            gps,
         );
 
         self.update_discrete_outputs();
@@ -650,6 +682,10 @@ impl AirDataInertialReferenceUnit {
         self.ir.has_fault()
     }
 
+    fn adr_has_fault(&self) -> bool {
+        self.adr.has_failed()
+    }
+
     // When the ADR is unpowered (i.e. knob is set to OFF), all discretes go to open circuit (false).
     // Discrete #4 is inverted, so ground when below 260kts, OC otherwise.
     // When CAS is invalid but the ADR is otherwise powered (currently only possible when ADR p/b is off),
@@ -707,6 +743,14 @@ impl AirDataInertialReferenceUnit {
         self.ir.longitude()
     }
 
+    fn gpirs_latitude(&self) -> Arinc429Word<Angle> {
+        self.ir.gpirs_latitude()
+    }
+
+    fn gpirs_longitude(&self) -> Arinc429Word<Angle> {
+        self.ir.gpirs_longitude()
+    }
+
     fn heading(&self) -> Arinc429Word<Angle> {
         self.ir.heading()
     }
@@ -723,6 +767,10 @@ impl AirDataInertialReferenceUnit {
         self.adr.altitude()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        self.adr.computed_airspeed()
+    }
+
     fn ground_speed(&self) -> Arinc429Word<Velocity> {
         self.ir.ground_speed()
     }
@@ -888,6 +936,42 @@ bitflags! {
     }
 }
 
+/// The air data sensed through the static ports.
+#[derive(Clone, Copy)]
+struct StaticAirData {
+    pressure: Pressure,
+    pressure_altitude: Length,
+    baro_corrected_altitude: Length,
+}
+impl StaticAirData {
+    fn sensed(context: &UpdateContext) -> Self {
+        Self {
+            pressure: context.ambient_pressure(),
+            pressure_altitude: Length::new::<foot>(
+                ((context.pressure_altitude().get::<foot>() * 2.).round() / 2.)
+                    .clamp(-131072., 131072.),
+            ),
+            // FIXME split sides and do the correction ourselves
+            // FIXME this currently returns pressure alt when STD mode is selected on the FCU
+            baro_corrected_altitude: Length::new::<foot>(
+                ((context.indicated_altitude().get::<foot>() * 2.).round() / 2.)
+                    .clamp(-131072., 131072.),
+            ),
+        }
+    }
+}
+
+/// The total pressure sensed by a pitot probe at the given static pressure and mach number.
+fn total_pressure(static_pressure: Pressure, mach: MachNumber) -> Pressure {
+    static_pressure * (1. + 0.2 * f64::from(mach).powi(2)).powf(3.5)
+}
+
+/// The mach number computed from the total and static pressure.
+fn mach_from_pressures(total_pressure: Pressure, static_pressure: Pressure) -> MachNumber {
+    let pressure_ratio: f64 = (total_pressure / static_pressure).get::<ratio>().max(1.);
+    MachNumber::from((5. * (pressure_ratio.powf(1. / 3.5) - 1.)).sqrt())
+}
+
 struct AirDataReference {
     number: usize,
     vmo: Velocity,
@@ -924,6 +1008,18 @@ struct AirDataReference {
     discrete_word_1: AdirsData<u32>,
 
     remaining_initialisation_duration: Option<Duration>,
+
+    failure: Failure,
+    adiru_failure: Failure,
+    pitot_probe_blockage: Failure,
+    static_port_blockage: Failure,
+    angle_of_attack_vane_jam: Failure,
+    /// The total pressure trapped in the pitot probe since it became blocked.
+    trapped_total_pressure: Option<Pressure>,
+    /// The static air data as sensed when the static ports became blocked.
+    trapped_static_air_data: Option<StaticAirData>,
+    /// The angle of attack at which the vane jammed.
+    jammed_angle_of_attack: Option<Angle>,
 }
 impl AirDataReference {
     const INITIALISATION_DURATION: Duration = Duration::from_secs(18);
@@ -1007,6 +1103,15 @@ impl AirDataReference {
 
             // Start fully initialised.
             remaining_initialisation_duration: Some(Duration::from_secs(0)),
+
+            failure: Failure::new(FailureType::Adr(number)),
+            adiru_failure: Failure::new(FailureType::Adiru(number)),
+            pitot_probe_blockage: Failure::new(FailureType::PitotProbeBlocked(number)),
+            static_port_blockage: Failure::new(FailureType::StaticPortBlocked(number)),
+            angle_of_attack_vane_jam: Failure::new(FailureType::AngleOfAttackVaneJammed(number)),
+            trapped_total_pressure: None,
+            trapped_static_air_data: None,
+            jammed_angle_of_attack: None,
         }
     }
 
@@ -1023,10 +1128,46 @@ impl AirDataReference {
             aircraft_preset_quick_mode,
             overhead,
         );
+        self.update_sensor_failures(context, simulator_data);
         self.update_values(context, simulator_data);
         self.update_discrete_word_1();
     }
 
+    /// Captures the sensed values at the moment a probe becomes blocked or a vane jams,
+    /// such that the ADR keeps computing its outputs from those values.
+    fn update_sensor_failures(
+        &mut self,
+        context: &UpdateContext,
+        simulator_data: AdirsSimulatorData,
+    ) {
+        self.trapped_total_pressure =
+            if self.pitot_probe_blockage.is_active() {
+                Some(self.trapped_total_pressure.unwrap_or_else(|| {
+                    total_pressure(context.ambient_pressure(), simulator_data.mach)
+                }))
+            } else {
+                None
+            };
+
+        self.trapped_static_air_data = if self.static_port_blockage.is_active() {
+            Some(
+                self.trapped_static_air_data
+                    .unwrap_or_else(|| StaticAirData::sensed(context)),
+            )
+        } else {
+            None
+        };
+
+        self.jammed_angle_of_attack = if self.angle_of_attack_vane_jam.is_active() {
+            Some(
+                self.jammed_angle_of_attack
+                    .unwrap_or(simulator_data.angle_of_attack),
+            )
+        } else {
+            None
+        };
+    }
+
     fn update_remaining_initialisation_duration(
         &mut self,
         context: &UpdateContext,
@@ -1081,36 +1222,58 @@ impl AirDataReference {
             self.baro_correction_2_inhg
                 .set_normal_operation_value(simulator_data.baro_correction_1);
 
-            let pressure_alt = Length::new::<foot>(
-                ((context.pressure_altitude().get::<foot>() * 2.).round() / 2.)
-                    .clamp(-131072., 131072.),
-            );
-
-            // FIXME split sides and do the correction ourselves
-            // FIXME this currently returns pressure alt when STD mode is selected on the FCU
-            let baro_alt = Length::new::<foot>(
-                ((context.indicated_altitude().get::<foot>() * 2.).round() / 2.)
-                    .clamp(-131072., 131072.),
-            );
+            let static_air_data = self
+                .trapped_static_air_data
+                .unwrap_or_else(|| StaticAirData::sensed(context));
 
             self.corrected_average_static_pressure
-                .set_normal_operation_value(context.ambient_pressure());
-            self.altitude.set_normal_operation_value(pressure_alt);
+                .set_normal_operation_value(static_air_data.pressure);
+            self.altitude
+                .set_normal_operation_value(static_air_data.pressure_altitude);
             self.baro_corrected_altitude_1
-                .set_normal_operation_value(baro_alt);
+                .set_normal_operation_value(static_air_data.baro_corrected_altitude);
             self.baro_corrected_altitude_2
-                .set_normal_operation_value(baro_alt);
-            self.barometric_vertical_speed
-                .set_normal_operation_value(simulator_data.vertical_speed.get::<foot_per_minute>());
+                .set_normal_operation_value(static_air_data.baro_corrected_altitude);
+            // With blocked static ports the pressure no longer changes, and thus no vertical speed is sensed.
+            self.barometric_vertical_speed.set_normal_operation_value(
+                if self.trapped_static_air_data.is_some() {
+                    0.
+                } else {
+                    simulator_data.vertical_speed.get::<foot_per_minute>()
+                },
+            );
+
+            let (computed_airspeed, mach, true_airspeed) = if self.trapped_total_pressure.is_none()
+                && self.trapped_static_air_data.is_none()
+            {
+                (
+                    context.indicated_airspeed(),
+                    simulator_data.mach,
+                    simulator_data.true_airspeed,
+                )
+            } else {
+                // The airspeeds are computed from the difference between the total and static
+                // pressure. When either is trapped, they become erroneous as the aircraft climbs
+                // or descends.
+                let total_pressure = self.trapped_total_pressure.unwrap_or_else(|| {
+                    total_pressure(context.ambient_pressure(), simulator_data.mach)
+                });
+                let mach = mach_from_pressures(total_pressure, static_air_data.pressure);
+
+                (
+                    mach.to_cas(static_air_data.pressure),
+                    mach,
+                    mach.to_tas(context.ambient_temperature()),
+                )
+            };
 
             // If CAS is below 30kn, output as 0 with SSM = NCD
-            let computed_airspeed = context.indicated_airspeed();
             self.computed_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_CAS),
                 computed_airspeed,
             );
 
-            let max_airspeed = self.calculate_max_airspeed(context);
+            let max_airspeed = self.calculate_max_airspeed(static_air_data.pressure);
             self.max_airspeed
                 .set_value(max_airspeed, SignStatus::NormalOperation);
 
@@ -1118,19 +1281,18 @@ impl AirDataReference {
                 > (max_airspeed + Velocity::new::<knot>(if self.is_overspeed { 4. } else { 8. }));
 
             // If mach is below 0.1, output as 0 with SSM = NCD
-            self.mach.normal_above_threshold_ncd_otherwise(
-                MachNumber::from(Self::MINIMUM_MACH),
-                simulator_data.mach,
-            );
+            self.mach
+                .normal_above_threshold_ncd_otherwise(MachNumber::from(Self::MINIMUM_MACH), mach);
 
             // If TAS is below 60 kts, output as 0 kt with SSM = NCD.
             self.true_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_TAS),
-                simulator_data.true_airspeed,
+                true_airspeed,
             );
 
             self.angle_of_attack.set_value(
-                simulator_data.angle_of_attack,
+                self.jammed_angle_of_attack
+                    .unwrap_or(simulator_data.angle_of_attack),
                 if computed_airspeed < Velocity::new::<knot>(Self::MINIMUM_CAS_FOR_AOA) {
                     SignStatus::NoComputedData
                 } else {
@@ -1175,7 +1337,7 @@ impl AirDataReference {
 
         self.discrete_word_1.set_value(
             discrete_word.bits(),
-            if self.is_on {
+            if self.is_on && !self.adiru_failure.is_active() {
                 SignStatus::NormalOperation
             } else {
                 SignStatus::FailureWarning
@@ -1188,7 +1350,11 @@ impl AirDataReference {
     }
 
     fn is_valid(&self) -> bool {
-        self.is_on && self.is_initialised()
+        self.is_on && self.is_initialised() && !self.has_failed()
+    }
+
+    fn has_failed(&self) -> bool {
+        self.failure.is_active() || self.adiru_failure.is_active()
     }
 
     fn computed_airspeed_raw(&self) -> Velocity {
@@ -1199,6 +1365,10 @@ impl AirDataReference {
         Arinc429Word::new(self.altitude.value(), self.altitude.ssm())
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(self.computed_airspeed.value(), self.computed_airspeed.ssm())
+    }
+
     fn baro_correction_1(&self) -> Arinc429Word<Pressure> {
         Arinc429Word::new(
             self.baro_correction_1_hpa.value(),
@@ -1213,9 +1383,9 @@ impl AirDataReference {
         )
     }
 
-    fn calculate_max_airspeed(&self, context: &UpdateContext) -> Velocity {
+    fn calculate_max_airspeed(&self, static_pressure: Pressure) -> Velocity {
         if self.is_valid() {
-            self.vmo.min(self.mmo.to_cas(context.ambient_pressure()))
+            self.vmo.min(self.mmo.to_cas(static_pressure))
         } else {
             self.vmo
         }
@@ -1231,6 +1401,16 @@ impl TrueAirspeedSource for AirDataReference {
     }
 }
 impl SimulationElement for AirDataReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        self.adiru_failure.accept(visitor);
+        self.pitot_probe_blockage.accept(visitor);
+        self.static_port_blockage.accept(visitor);
+        self.angle_of_attack_vane_jam.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.baro_correction_1_hpa
             .write_to_converted(writer, |value| value.get::<hectopascal>());
@@ -1315,6 +1495,11 @@ struct InertialReference {
     remaining_attitude_initialisation_duration: Option<Duration>,
     wind_velocity: LowPassFilter<Vector2<f64>>,
     extreme_latitude: bool,
+    /// The drift of the pure inertial position, present while the IR is aligned.
+    position_drift: Option<InertialPositionDrift>,
+    /// The estimate of the inertial position error, as determined by comparing the
+    /// inertial position with the GPS position. East and north components in nautical miles.
+    gpirs_position_correction: LowPassFilter<Vector2<f64>>,
 
     pitch: AdirsData<Angle>,
     roll: AdirsData<Angle>,
@@ -1345,8 +1530,15 @@ struct InertialReference {
     wind_direction_bnr: AdirsData<Angle>,
     latitude: AdirsData<Angle>,
     longitude: AdirsData<Angle>,
+    /// Label 254
+    gpirs_latitude: AdirsData<Angle>,
+    /// Label 255
+    gpirs_longitude: AdirsData<Angle>,
     /// label 270
     maint_word: AdirsData<u32>,
+
+    failure: Failure,
+    adiru_failure: Failure,
 }
 impl InertialReference {
     const FAST_ALIGNMENT_TIME_IN_SECS: f64 = 90.;
@@ -1378,11 +1570,14 @@ impl InertialReference {
     const WIND_SPEED_BNR: &'static str = "WIND_SPEED_BNR";
     const LATITUDE: &'static str = "LATITUDE";
     const LONGITUDE: &'static str = "LONGITUDE";
+    const GPIRS_LATITUDE: &'static str = "GPIRS_LATITUDE";
+    const GPIRS_LONGITUDE: &'static str = "GPIRS_LONGITUDE";
     const MAINT_WORD: &'static str = "MAINT_WORD";
     const MINIMUM_TRUE_AIRSPEED_FOR_WIND_DETERMINATION_KNOTS: f64 = 100.;
     const MINIMUM_GROUND_SPEED_FOR_TRACK_KNOTS: f64 = 50.;
 
     const WIND_VELOCITY_TIME_CONSTANT: Duration = Duration::from_millis(100);
+    const GPIRS_POSITION_CORRECTION_TIME_CONSTANT: Duration = Duration::from_secs(60);
 
     fn new(context: &mut InitContext, number: usize) -> Self {
         Self {
@@ -1396,6 +1591,10 @@ impl InertialReference {
             remaining_attitude_initialisation_duration: Some(Duration::from_secs(0)),
             wind_velocity: LowPassFilter::new(Self::WIND_VELOCITY_TIME_CONSTANT),
             extreme_latitude: false,
+            position_drift: None,
+            gpirs_position_correction: LowPassFilter::new(
+                Self::GPIRS_POSITION_CORRECTION_TIME_CONSTANT,
+            ),
 
             pitch: AdirsData::new_ir(context, number, Self::PITCH),
             roll: AdirsData::new_ir(context, number, Self::ROLL),
@@ -1422,7 +1621,12 @@ impl InertialReference {
             wind_speed_bnr: AdirsData::new_ir(context, number, Self::WIND_SPEED_BNR),
             latitude: AdirsData::new_ir(context, number, Self::LATITUDE),
             longitude: AdirsData::new_ir(context, number, Self::LONGITUDE),
+            gpirs_latitude: AdirsData::new_ir(context, number, Self::GPIRS_LATITUDE),
+            gpirs_longitude: AdirsData::new_ir(context, number, Self::GPIRS_LONGITUDE),
             maint_word: AdirsData::new_ir(context, number, Self::MAINT_WORD),
+
+            failure: Failure::new(FailureType::Ir(number)),
+            adiru_failure: Failure::new(FailureType::Adiru(number)),
         }
     }
 
@@ -1434,6 +1638,7 @@ impl InertialReference {
         configured_align_time: AlignTime,
         aircraft_preset_quick_mode: bool,
         simulator_data: AdirsSimulatorData,
+        gps: &impl GpsMeasurementOutputs,
     ) {
         self.is_on = overhead.ir_is_on(self.number);
 
@@ -1449,12 +1654,55 @@ impl InertialReference {
             configured_align_time,
             simulator_data,
         );
+        self.update_position_drift(context, configured_align_time);
 
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
         self.update_heading_values(overhead, simulator_data);
-        self.update_non_attitude_values(context, true_airspeed_source, overhead, simulator_data);
+        self.update_non_attitude_values(
+            context,
+            true_airspeed_source,
+            overhead,
+            simulator_data,
+            gps,
+        );
         self.update_maint_word(overhead);
+
+        if self.has_failed() {
+            self.set_failure_warning();
+        }
+    }
+
+    /// A failed IR outputs all labels as FW with value 0.
+    fn set_failure_warning(&mut self) {
+        self.pitch.set_failure_warning();
+        self.roll.set_failure_warning();
+        self.heading.set_failure_warning();
+        self.true_heading.set_failure_warning();
+        self.track.set_failure_warning();
+        self.true_track.set_failure_warning();
+        self.drift_angle.set_failure_warning();
+        self.flight_path_angle.set_failure_warning();
+        self.body_pitch_rate.set_failure_warning();
+        self.body_roll_rate.set_failure_warning();
+        self.body_yaw_rate.set_failure_warning();
+        self.body_longitudinal_acc.set_failure_warning();
+        self.body_lateral_acc.set_failure_warning();
+        self.body_normal_acc.set_failure_warning();
+        self.heading_rate.set_failure_warning();
+        self.pitch_att_rate.set_failure_warning();
+        self.roll_att_rate.set_failure_warning();
+        self.vertical_speed.set_failure_warning();
+        self.ground_speed.set_failure_warning();
+        self.wind_direction.set_failure_warning();
+        self.wind_direction_bnr.set_failure_warning();
+        self.wind_speed.set_failure_warning();
+        self.wind_speed_bnr.set_failure_warning();
+        self.latitude.set_failure_warning();
+        self.longitude.set_failure_warning();
+        self.gpirs_latitude.set_failure_warning();
+        self.gpirs_longitude.set_failure_warning();
+        self.maint_word.set_failure_warning();
     }
 
     fn update_fault_flash_duration(
@@ -1520,6 +1768,19 @@ impl InertialReference {
         };
     }
 
+    fn update_position_drift(&mut self, context: &UpdateContext, configured_align_time: AlignTime) {
+        if self.is_fully_aligned() {
+            match self.position_drift.as_mut() {
+                Some(drift) => drift.update(context),
+                None => {
+                    self.position_drift = Some(InertialPositionDrift::new(configured_align_time))
+                }
+            }
+        } else {
+            self.position_drift = None;
+        }
+    }
+
     fn update_latitude(&mut self, simulator_data: AdirsSimulatorData) {
         let latitude = simulator_data.latitude.get::<degree>();
         let longitude = simulator_data.longitude.get::<degree>();
@@ -1730,6 +1991,7 @@ impl InertialReference {
         true_airspeed_source: &impl TrueAirspeedSource,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
+        gps: &impl GpsMeasurementOutputs,
     ) {
         let ssm = if self.is_on && self.is_fully_aligned() {
             SignStatus::NormalOperation
@@ -1798,12 +2060,56 @@ impl InertialReference {
         self.ground_speed
             .set_value(simulator_data.ground_speed, ssm);
 
-        self.latitude.set_value(simulator_data.latitude, ssm);
-        self.longitude.set_value(simulator_data.longitude, ssm);
+        self.update_position(context, simulator_data, gps, ssm);
 
         self.update_wind_velocity(context, true_airspeed_source, overhead, simulator_data);
     }
 
+    fn update_position(
+        &mut self,
+        context: &UpdateContext,
+        simulator_data: AdirsSimulatorData,
+        gps: &impl GpsMeasurementOutputs,
+        ssm: SignStatus,
+    ) {
+        let inertial_position_error = self
+            .position_drift
+            .map(|drift| drift.error())
+            .unwrap_or_default();
+
+        let (latitude, longitude) = offset_position(
+            simulator_data.latitude,
+            simulator_data.longitude,
+            inertial_position_error,
+        );
+        self.latitude.set_value(latitude, ssm);
+        self.longitude.set_value(longitude, ssm);
+
+        // The GPIRS position is the inertial position corrected by the difference with the GPS
+        // position. As the correction is filtered, a small residual error remains which increases
+        // with the drift rate. Without a GPS position, the last correction is kept and the GPIRS
+        // position drifts along with the inertial position.
+        if self.position_drift.is_none() {
+            self.gpirs_position_correction.reset(Vector2::default());
+        } else if let (Some(gps_latitude), Some(gps_longitude)) = (
+            gps.latitude().normal_value(),
+            gps.longitude().normal_value(),
+        ) {
+            self.gpirs_position_correction.update(
+                context.delta(),
+                position_offset(gps_latitude, gps_longitude, latitude, longitude),
+            );
+        }
+
+        let (gpirs_latitude, gpirs_longitude) = offset_position(
+            latitude,
+            longitude,
+            -self.gpirs_position_correction.output(),
+        );
+        self.gpirs_latitude.set_value(gpirs_latitude, ssm);
+        self.gpirs_longitude.set_value(gpirs_longitude, ssm);
+    }
+
     fn update_maint_word(&mut self, overhead: &AirDataInertialReferenceSystemOverheadPanel) {
         // TODO check status of these during mode transitions (first need to implement mode FSM)
         let mut maint_word: IrMaintFlags = IrMaintFlags::default();
@@ -1912,7 +2218,11 @@ impl InertialReference {
     }
 
     fn has_fault(&self) -> bool {
-        self.ir_fault_flash_duration.is_some()
+        self.ir_fault_flash_duration.is_some() || self.has_failed()
+    }
+
+    fn has_failed(&self) -> bool {
+        self.failure.is_active() || self.adiru_failure.is_active()
     }
 
     fn has_magnetic_data(&self) -> bool {
@@ -1927,6 +2237,14 @@ impl InertialReference {
         Arinc429Word::new(self.longitude.value(), self.longitude.ssm())
     }
 
+    fn gpirs_latitude(&self) -> Arinc429Word<Angle> {
+        Arinc429Word::new(self.gpirs_latitude.value(), self.gpirs_latitude.ssm())
+    }
+
+    fn gpirs_longitude(&self) -> Arinc429Word<Angle> {
+        Arinc429Word::new(self.gpirs_longitude.value(), self.gpirs_longitude.ssm())
+    }
+
     fn heading(&self) -> Arinc429Word<Angle> {
         Arinc429Word::new(self.heading.value(), self.heading.ssm())
     }
@@ -1947,6 +2265,13 @@ impl InertialReference {
     }
 }
 impl SimulationElement for InertialReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        self.adiru_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.pitch.write_to(writer);
         self.roll.write_to(writer);
@@ -1983,10 +2308,93 @@ impl SimulationElement for InertialReference {
         self.wind_speed_bnr.write_to(writer);
         self.latitude.write_to(writer);
         self.longitude.write_to(writer);
+        self.gpirs_latitude.write_to(writer);
+        self.gpirs_longitude.write_to(writer);
         self.maint_word.write_to(writer);
     }
 }
 
+/// The error of the pure inertial position, which grows with the time spent in navigation since
+/// the alignment completed. The drift rate and direction are determined at the end of the alignment,
+/// where an expedited alignment results in a less accurate position.
+#[derive(Clone, Copy)]
+struct InertialPositionDrift {
+    rate: Velocity,
+    direction: Angle,
+    time_in_navigation: Duration,
+}
+impl InertialPositionDrift {
+    /// The mean and standard deviation of the drift rate in knots, by alignment time.
+    /// The IR is certified to drift less than 2 NM/h in 95% of the cases after a full alignment.
+    const REALISTIC_ALIGNMENT_DRIFT_RATE_KNOTS: (f64, f64) = (0.6, 0.4);
+    const FAST_ALIGNMENT_DRIFT_RATE_KNOTS: (f64, f64) = (1.5, 0.6);
+    const INSTANT_ALIGNMENT_DRIFT_RATE_KNOTS: (f64, f64) = (3., 1.);
+
+    fn new(align_time: AlignTime) -> Self {
+        let (mean, std_dev) = match align_time {
+            AlignTime::Realistic => Self::REALISTIC_ALIGNMENT_DRIFT_RATE_KNOTS,
+            AlignTime::Fast => Self::FAST_ALIGNMENT_DRIFT_RATE_KNOTS,
+            AlignTime::Instant => Self::INSTANT_ALIGNMENT_DRIFT_RATE_KNOTS,
+        };
+
+        Self {
+            rate: Velocity::new::<knot>(random_from_normal_distribution(mean, std_dev).abs()),
+            direction: Angle::new::<degree>(random_from_range(0., 360.)),
+            time_in_navigation: Duration::default(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.time_in_navigation += context.delta();
+    }
+
+    /// The east and north components of the position error in nautical miles.
+    fn error(&self) -> Vector2<f64> {
+        let distance: Length =
+            self.rate * Time::new::<second>(self.time_in_navigation.as_secs_f64());
+
+        Vector2::new(
+            self.direction.sin().get::<ratio>(),
+            self.direction.cos().get::<ratio>(),
+        ) * distance.get::<nautical_mile>()
+    }
+}
+
+/// Offsets the position by the given east and north components in nautical miles.
+fn offset_position(latitude: Angle, longitude: Angle, offset: Vector2<f64>) -> (Angle, Angle) {
+    let offset_latitude = (latitude + Angle::new::<degree>(offset.y / 60.))
+        .max(Angle::new::<degree>(-90.))
+        .min(Angle::new::<degree>(90.));
+    // Avoid the singularity at the poles, where a meridian spans no distance.
+    let nautical_miles_per_degree_of_longitude =
+        60. * offset_latitude.cos().get::<ratio>().max(0.01);
+    let offset_longitude = (longitude
+        + Angle::new::<degree>(offset.x / nautical_miles_per_degree_of_longitude))
+    .normalised_180();
+
+    (offset_latitude, offset_longitude)
+}
+
+/// The east and north components in nautical miles of the offset from the first to the second
+/// position, which is the inverse of [`offset_position`].
+fn position_offset(
+    latitude: Angle,
+    longitude: Angle,
+    offset_latitude: Angle,
+    offset_longitude: Angle,
+) -> Vector2<f64> {
+    let nautical_miles_per_degree_of_longitude =
+        60. * offset_latitude.cos().get::<ratio>().max(0.01);
+
+    Vector2::new(
+        (offset_longitude - longitude)
+            .normalised_180()
+            .get::<degree>()
+            * nautical_miles_per_degree_of_longitude,
+        (offset_latitude - latitude).get::<degree>() * 60.,
+    )
+}
+
 fn remaining_initialisation_duration(
     context: &UpdateContext,
     starting_initialisation_duration: Duration,
@@ -2070,9 +2478,60 @@ mod tests {
         velocity::{foot_per_minute, knot},
     };
 
+    struct TestGps {
+        latitude_id: VariableIdentifier,
+        longitude_id: VariableIdentifier,
+        latitude: Angle,
+        longitude: Angle,
+        is_available: bool,
+    }
+    impl TestGps {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                latitude_id: context.get_identifier(AdirsSimulatorData::LATITUDE.to_owned()),
+                longitude_id: context.get_identifier(AdirsSimulatorData::LONGITUDE.to_owned()),
+                latitude: Angle::default(),
+                longitude: Angle::default(),
+                is_available: true,
+            }
+        }
+
+        fn output(&self, value: Angle) -> Arinc429Word<Angle> {
+            if self.is_available {
+                Arinc429Word::new(value, SignStatus::NormalOperation)
+            } else {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+        }
+    }
+    impl GpsMeasurementOutputs for TestGps {
+        fn latitude(&self) -> Arinc429Word<Angle> {
+            self.output(self.latitude)
+        }
+
+        fn longitude(&self) -> Arinc429Word<Angle> {
+            self.output(self.longitude)
+        }
+
+        fn altitude(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::default(), SignStatus::NoComputedData)
+        }
+
+        fn horizontal_integrity_limit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::default(), SignStatus::NoComputedData)
+        }
+    }
+    impl SimulationElement for TestGps {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.latitude = reader.read(&self.latitude_id);
+            self.longitude = reader.read(&self.longitude_id);
+        }
+    }
+
     struct TestAircraft {
         adirs: AirDataInertialReferenceSystem,
         overhead: AirDataInertialReferenceSystemOverheadPanel,
+        gps: [TestGps; 2],
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
@@ -2101,12 +2560,20 @@ mod tests {
             Self {
                 adirs: AirDataInertialReferenceSystem::new(context, adirs_programming),
                 overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
+                gps: [TestGps::new(context), TestGps::new(context)],
             }
         }
+
+        fn set_gps_available(&mut self, is_available: bool) {
+            self.gps
+                .iter_mut()
+                .for_each(|gps| gps.is_available = is_available);
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
-            self.adirs.update(context, &self.overhead);
+            self.adirs
+                .update(context, &self.overhead, [&self.gps[0], &self.gps[1]]);
             self.overhead.update(context, &self.adirs);
         }
     }
@@ -2114,6 +2581,7 @@ mod tests {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.adirs.accept(visitor);
             self.overhead.accept(visitor);
+            accept_iterable!(self.gps, visitor);
 
             visitor.visit(self);
         }
@@ -2152,6 +2620,11 @@ mod tests {
             self
         }
 
+        fn gps_unavailable(mut self) -> Self {
+            self.command(|a| a.set_gps_available(false));
+            self
+        }
+
         fn latitude_of(mut self, latitude: Angle) -> Self {
             self.write_by_name(AdirsSimulatorData::LATITUDE, latitude);
             self
@@ -2321,6 +2794,13 @@ mod tests {
             )))
         }
 
+        fn adr_fault_light_illuminated(&mut self, number: usize) -> bool {
+            self.read_by_name(&OnOffFaultPushButton::has_fault_id(&format!(
+                "ADIRS_ADR_{}",
+                number
+            )))
+        }
+
         fn is_aligned(&mut self, adiru_number: usize) -> bool {
             self.align_state(adiru_number) == AlignState::Aligned
         }
@@ -2708,6 +3188,22 @@ mod tests {
             ))
         }
 
+        fn gpirs_latitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::GPIRS_LATITUDE,
+            ))
+        }
+
+        fn gpirs_longitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::GPIRS_LONGITUDE,
+            ))
+        }
+
         fn maint_word(&mut self, adiru_number: usize) -> Arinc429Word<u32> {
             self.read_arinc429_by_name(&output_data_id(
                 OutputDataType::Ir,
@@ -3728,11 +4224,209 @@ mod tests {
                 SignStatus::NoComputedData
             );
         }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn data_is_not_valid_when_adr_failed(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adr(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(false, adiru_number);
+            assert_true!(test_bed.adr_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn data_is_valid_again_when_adr_failure_is_cleared(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adr(adiru_number));
+            test_bed.run();
+
+            test_bed.unfail(FailureType::Adr(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(true, adiru_number);
+            assert_false!(test_bed.adr_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn data_is_not_valid_when_adiru_failed(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adiru(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(false, adiru_number);
+            assert_true!(test_bed
+                .adr_discrete_word_1(adiru_number)
+                .is_failure_warning());
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn computed_airspeed_increases_when_climbing_with_blocked_pitot_probe(
+            #[case] adiru_number: usize,
+        ) {
+            let mach = MachNumber(0.5);
+            let pressure =
+                InternationalStandardAtmosphere::pressure_at_altitude(Length::new::<foot>(10000.));
+            let mut test_bed = all_adirus_aligned_test_bed_with().mach_of(mach);
+            test_bed.set_ambient_pressure(pressure);
+            test_bed.fail(FailureType::PitotProbeBlocked(adiru_number));
+            test_bed.run();
+
+            let computed_airspeed = test_bed.computed_airspeed(adiru_number).normal_value();
+            assert_about_eq!(
+                computed_airspeed.unwrap().get::<knot>(),
+                mach.to_cas(pressure).get::<knot>(),
+                0.1
+            );
+
+            test_bed.set_ambient_pressure(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(15000.),
+            ));
+            test_bed.run();
+
+            assert_true!(
+                test_bed
+                    .computed_airspeed(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    > computed_airspeed.unwrap() + Velocity::new::<knot>(20.)
+            );
+            assert_true!(test_bed.mach(adiru_number).normal_value().unwrap() > mach);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn computed_airspeed_is_supplied_again_when_pitot_probe_is_no_longer_blocked(
+            #[case] adiru_number: usize,
+        ) {
+            let velocity = Velocity::new::<knot>(250.);
+            let mut test_bed = all_adirus_aligned_test_bed_with().mach_of(MachNumber(0.5));
+            test_bed.set_indicated_airspeed(velocity);
+            test_bed.fail(FailureType::PitotProbeBlocked(adiru_number));
+            test_bed.run();
+
+            test_bed.unfail(FailureType::PitotProbeBlocked(adiru_number));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed
+                    .computed_airspeed(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                velocity
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn altitude_and_vertical_speed_freeze_when_static_ports_are_blocked(
+            #[case] adiru_number: usize,
+        ) {
+            let altitude = Length::new::<foot>(10000.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .vertical_speed_of(Velocity::new::<foot_per_minute>(1500.));
+            test_bed.set_pressure_altitude(altitude);
+            test_bed.set_indicated_altitude(altitude);
+            test_bed.fail(FailureType::StaticPortBlocked(adiru_number));
+            test_bed.run();
+
+            test_bed.set_pressure_altitude(Length::new::<foot>(12000.));
+            test_bed.set_indicated_altitude(Length::new::<foot>(12000.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.altitude(adiru_number).normal_value().unwrap(),
+                altitude
+            );
+            assert_eq!(
+                test_bed
+                    .baro_corrected_altitude_1(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                altitude
+            );
+            assert_about_eq!(
+                test_bed
+                    .barometric_vertical_speed(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<foot_per_minute>(),
+                0.
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn computed_airspeed_overreads_when_descending_with_blocked_static_ports(
+            #[case] adiru_number: usize,
+        ) {
+            let mach = MachNumber(0.5);
+            let mut test_bed = all_adirus_aligned_test_bed_with().mach_of(mach);
+            test_bed.set_ambient_pressure(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(10000.),
+            ));
+            test_bed.fail(FailureType::StaticPortBlocked(adiru_number));
+            test_bed.run();
+
+            let pressure =
+                InternationalStandardAtmosphere::pressure_at_altitude(Length::new::<foot>(5000.));
+            test_bed.set_ambient_pressure(pressure);
+            test_bed.run();
+
+            assert_true!(
+                test_bed
+                    .computed_airspeed(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    > mach.to_cas(pressure) + Velocity::new::<knot>(20.)
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn angle_of_attack_remains_at_jammed_angle(#[case] adiru_number: usize) {
+            let angle = Angle::new::<degree>(5.);
+            let mut test_bed = all_adirus_aligned_test_bed_with().angle_of_attack_of(angle);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(200.));
+            test_bed.fail(FailureType::AngleOfAttackVaneJammed(adiru_number));
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .angle_of_attack_of(Angle::new::<degree>(12.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.angle_of_attack(adiru_number).normal_value(),
+                Some(angle)
+            );
+        }
     }
 
     mod ir {
         use super::*;
-        use uom::si::angular_velocity::revolution_per_minute;
+        use crate::shared::seed_random;
+        use uom::si::{angular_velocity::revolution_per_minute, length::nautical_mile};
 
         #[rstest]
         #[case(1)]
@@ -4550,6 +5244,249 @@ mod tests {
                 longitude
             );
         }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn position_drifts_with_time_in_navigation(#[case] adiru_number: usize) {
+            seed_random(1);
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(latitude)
+                .longitude_of(longitude);
+            test_bed.run();
+
+            test_bed.run_with_delta(Duration::from_secs(60 * 60));
+
+            let error = position_error(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                test_bed.longitude(adiru_number).normal_value().unwrap(),
+                latitude,
+                longitude,
+            );
+            assert!(error > Length::new::<nautical_mile>(0.));
+            // The drift rate is limited to 4 standard deviations above the mean.
+            assert!(error < Length::new::<nautical_mile>(2.3));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn position_drift_is_reset_by_realignment(#[case] adiru_number: usize) {
+            seed_random(1);
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .align_time_configured_as(AlignTime::Instant)
+                .and()
+                .latitude_of(latitude)
+                .longitude_of(longitude);
+            test_bed.run();
+            test_bed.run_with_delta(Duration::from_secs(60 * 60));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+            test_bed.run();
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                latitude
+            );
+            assert_eq!(
+                test_bed.longitude(adiru_number).normal_value().unwrap(),
+                longitude
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_is_more_accurate_than_inertial_position(#[case] adiru_number: usize) {
+            seed_random(1);
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(latitude)
+                .longitude_of(longitude);
+            test_bed.run();
+
+            for _ in 0..360 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            let inertial_error = position_error(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                test_bed.longitude(adiru_number).normal_value().unwrap(),
+                latitude,
+                longitude,
+            );
+            let gpirs_error = position_error(
+                test_bed
+                    .gpirs_latitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                test_bed
+                    .gpirs_longitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                latitude,
+                longitude,
+            );
+            assert!(gpirs_error < inertial_error);
+            assert!(gpirs_error < Length::new::<nautical_mile>(0.1));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_is_the_inertial_position_without_gps(#[case] adiru_number: usize) {
+            seed_random(1);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::new::<degree>(45.))
+                .longitude_of(Angle::new::<degree>(10.))
+                .and()
+                .gps_unavailable();
+            test_bed.run();
+
+            for _ in 0..360 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            assert_about_eq!(
+                test_bed
+                    .gpirs_latitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                test_bed
+                    .latitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>()
+            );
+            assert_about_eq!(
+                test_bed
+                    .gpirs_longitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                test_bed
+                    .longitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>()
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_drifts_again_when_gps_is_lost(#[case] adiru_number: usize) {
+            seed_random(1);
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(latitude)
+                .longitude_of(longitude);
+            test_bed.run();
+
+            for _ in 0..360 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+            let error_when_lost = position_error(
+                test_bed
+                    .gpirs_latitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                test_bed
+                    .gpirs_longitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                latitude,
+                longitude,
+            );
+
+            test_bed = test_bed.then_continue_with().gps_unavailable();
+            for _ in 0..360 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            let inertial_error = position_error(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                test_bed.longitude(adiru_number).normal_value().unwrap(),
+                latitude,
+                longitude,
+            );
+            let gpirs_error = position_error(
+                test_bed
+                    .gpirs_latitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                test_bed
+                    .gpirs_longitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                latitude,
+                longitude,
+            );
+            assert!(gpirs_error > error_when_lost);
+            assert!(gpirs_error < inertial_error);
+        }
+
+        /// The distance between the given position and the true position.
+        fn position_error(
+            latitude: Angle,
+            longitude: Angle,
+            true_latitude: Angle,
+            true_longitude: Angle,
+        ) -> Length {
+            let north = (latitude - true_latitude).get::<degree>() * 60.;
+            let east = (longitude - true_longitude).get::<degree>()
+                * 60.
+                * true_latitude.cos().get::<ratio>();
+
+            Length::new::<nautical_mile>((north.powi(2) + east.powi(2)).sqrt())
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn data_fails_when_ir_failed(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Ir(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_all_ir_data_available(false, adiru_number);
+            assert!(test_bed.pitch(adiru_number).is_failure_warning());
+            assert!(test_bed.latitude(adiru_number).is_failure_warning());
+            assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn data_fails_when_adiru_failed(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adiru(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_all_ir_data_available(false, adiru_number);
+            assert!(test_bed.heading(adiru_number).is_failure_warning());
+            assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+        }
     }
 
     mod gps {
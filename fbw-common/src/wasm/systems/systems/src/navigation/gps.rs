@@ -0,0 +1,397 @@
+use crate::failures::{Failure, FailureType};
+use crate::shared::arinc429::{Arinc429Word, SignStatus};
+use crate::shared::{
+    random_from_range, ConsumePower, ElectricalBusType, ElectricalBuses, GpsMeasurementOutputs,
+};
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+};
+use std::time::Duration;
+use uom::si::f64::{Angle, Length, Power};
+use uom::si::length::nautical_mile;
+use uom::si::power::watt;
+
+/// A highly simplified multi-mode receiver (MMR), of which only the GPS function is modelled.
+/// After being powered up, the receiver first has to acquire the satellites before it is able
+/// to output a position. Any loss of power or satellites requires a new acquisition.
+pub struct MultiModeReceiver {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    satellite_loss: Failure,
+
+    /// The remaining time to acquire the satellites, where 0 indicates the position is available.
+    /// None indicates the receiver isn't acquiring satellites.
+    remaining_acquisition_duration: Option<Duration>,
+    horizontal_integrity_limit: Length,
+
+    simulator_latitude_id: VariableIdentifier,
+    simulator_longitude_id: VariableIdentifier,
+    simulator_altitude_id: VariableIdentifier,
+    simulator_latitude: Angle,
+    simulator_longitude: Angle,
+    simulator_altitude: Length,
+
+    // Outputs
+    latitude_id: VariableIdentifier,
+    longitude_id: VariableIdentifier,
+    altitude_id: VariableIdentifier,
+    horizontal_integrity_limit_id: VariableIdentifier,
+}
+impl MultiModeReceiver {
+    const SIMULATOR_LATITUDE: &'static str = "PLANE LATITUDE";
+    const SIMULATOR_LONGITUDE: &'static str = "PLANE LONGITUDE";
+    const SIMULATOR_ALTITUDE: &'static str = "PLANE ALTITUDE";
+    const LATITUDE: &'static str = "LATITUDE";
+    const LONGITUDE: &'static str = "LONGITUDE";
+    const ALTITUDE: &'static str = "ALTITUDE";
+    const HORIZONTAL_INTEGRITY_LIMIT: &'static str = "HIL";
+
+    const MINIMUM_ACQUISITION_TIME_SECS: f64 = 45.;
+    const MAXIMUM_ACQUISITION_TIME_SECS: f64 = 90.;
+    const MINIMUM_HORIZONTAL_INTEGRITY_LIMIT_NM: f64 = 0.05;
+    const MAXIMUM_HORIZONTAL_INTEGRITY_LIMIT_NM: f64 = 0.3;
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+            satellite_loss: Failure::new(FailureType::GpsSatelliteLoss(number)),
+
+            // We start with the satellites acquired to support starting on the
+            // runway or in the air.
+            remaining_acquisition_duration: Some(Duration::ZERO),
+            horizontal_integrity_limit: Self::random_horizontal_integrity_limit(),
+
+            simulator_latitude_id: context.get_identifier(Self::SIMULATOR_LATITUDE.to_owned()),
+            simulator_longitude_id: context.get_identifier(Self::SIMULATOR_LONGITUDE.to_owned()),
+            simulator_altitude_id: context.get_identifier(Self::SIMULATOR_ALTITUDE.to_owned()),
+            simulator_latitude: Angle::default(),
+            simulator_longitude: Angle::default(),
+            simulator_altitude: Length::default(),
+
+            latitude_id: context.get_identifier(Self::output_id(number, Self::LATITUDE)),
+            longitude_id: context.get_identifier(Self::output_id(number, Self::LONGITUDE)),
+            altitude_id: context.get_identifier(Self::output_id(number, Self::ALTITUDE)),
+            horizontal_integrity_limit_id: context
+                .get_identifier(Self::output_id(number, Self::HORIZONTAL_INTEGRITY_LIMIT)),
+        }
+    }
+
+    fn output_id(number: usize, name: &str) -> String {
+        format!("GPS_{}_{}", number, name)
+    }
+
+    fn random_horizontal_integrity_limit() -> Length {
+        Length::new::<nautical_mile>(random_from_range(
+            Self::MINIMUM_HORIZONTAL_INTEGRITY_LIMIT_NM,
+            Self::MAXIMUM_HORIZONTAL_INTEGRITY_LIMIT_NM,
+        ))
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.remaining_acquisition_duration = if !self.is_powered || self.has_lost_satellites() {
+            None
+        } else {
+            match self.remaining_acquisition_duration {
+                Some(remaining) => Some(remaining.saturating_sub(context.delta())),
+                None => {
+                    self.horizontal_integrity_limit = Self::random_horizontal_integrity_limit();
+
+                    Some(Duration::from_secs_f64(random_from_range(
+                        Self::MINIMUM_ACQUISITION_TIME_SECS,
+                        Self::MAXIMUM_ACQUISITION_TIME_SECS,
+                    )))
+                }
+            }
+        };
+    }
+
+    pub fn has_lost_satellites(&self) -> bool {
+        self.satellite_loss.is_active()
+    }
+
+    fn has_acquired_satellites(&self) -> bool {
+        self.remaining_acquisition_duration == Some(Duration::ZERO)
+    }
+
+    fn ssm(&self) -> SignStatus {
+        if !self.is_powered {
+            SignStatus::FailureWarning
+        } else if self.has_acquired_satellites() {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::NoComputedData
+        }
+    }
+
+    fn output<T: Copy + Default>(&self, value: T) -> Arinc429Word<T> {
+        let ssm = self.ssm();
+        match ssm {
+            SignStatus::NormalOperation => Arinc429Word::new(value, ssm),
+            _ => Arinc429Word::new(T::default(), ssm),
+        }
+    }
+}
+impl GpsMeasurementOutputs for MultiModeReceiver {
+    fn latitude(&self) -> Arinc429Word<Angle> {
+        self.output(self.simulator_latitude)
+    }
+
+    fn longitude(&self) -> Arinc429Word<Angle> {
+        self.output(self.simulator_longitude)
+    }
+
+    fn altitude(&self) -> Arinc429Word<Length> {
+        self.output(self.simulator_altitude)
+    }
+
+    fn horizontal_integrity_limit(&self) -> Arinc429Word<Length> {
+        self.output(self.horizontal_integrity_limit)
+    }
+}
+impl SimulationElement for MultiModeReceiver {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.satellite_loss.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.simulator_latitude = reader.read(&self.simulator_latitude_id);
+        self.simulator_longitude = reader.read(&self.simulator_longitude_id);
+        self.simulator_altitude = reader.read(&self.simulator_altitude_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.latitude_id, self.latitude());
+        writer.write(&self.longitude_id, self.longitude());
+        writer.write(&self.altitude_id, self.altitude());
+
+        let horizontal_integrity_limit = self.horizontal_integrity_limit();
+        writer.write_arinc429(
+            &self.horizontal_integrity_limit_id,
+            horizontal_integrity_limit.value().get::<nautical_mile>(),
+            horizontal_integrity_limit.ssm(),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_powered {
+            consumption.consume_from_bus(self.powered_by, Power::new::<watt>(35.));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, InitContext, SimulationElementVisitor};
+    use uom::si::angle::degree;
+    use uom::si::electric_potential::volt;
+    use uom::si::f64::ElectricPotential;
+    use uom::si::length::foot;
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        mmr: MultiModeReceiver,
+        is_ac_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                mmr: MultiModeReceiver::new(context, 1, ElectricalBusType::AlternatingCurrent(1)),
+                is_ac_1_powered: true,
+            }
+        }
+
+        fn set_ac_1_power(&mut self, is_powered: bool) {
+            self.is_ac_1_powered = is_powered;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.mmr.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.mmr.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct MultiModeReceiverTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl MultiModeReceiverTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.write_by_name(MultiModeReceiver::SIMULATOR_LATITUDE, 45.);
+            test_bed.write_by_name(MultiModeReceiver::SIMULATOR_LONGITUDE, 10.);
+            test_bed.write_by_name(MultiModeReceiver::SIMULATOR_ALTITUDE, 12000.);
+
+            test_bed
+        }
+
+        fn latitude(&mut self) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&MultiModeReceiver::output_id(
+                1,
+                MultiModeReceiver::LATITUDE,
+            ))
+        }
+
+        fn longitude(&mut self) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&MultiModeReceiver::output_id(
+                1,
+                MultiModeReceiver::LONGITUDE,
+            ))
+        }
+
+        fn altitude(&mut self) -> Arinc429Word<Length> {
+            self.read_arinc429_by_name(&MultiModeReceiver::output_id(
+                1,
+                MultiModeReceiver::ALTITUDE,
+            ))
+        }
+
+        fn horizontal_integrity_limit(&mut self) -> Arinc429Word<f64> {
+            self.read_arinc429_by_name(&MultiModeReceiver::output_id(
+                1,
+                MultiModeReceiver::HORIZONTAL_INTEGRITY_LIMIT,
+            ))
+        }
+
+        fn run_until_acquired(&mut self) {
+            self.run_with_delta(Duration::from_secs_f64(
+                MultiModeReceiver::MAXIMUM_ACQUISITION_TIME_SECS,
+            ));
+        }
+    }
+    impl TestBed for MultiModeReceiverTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn starts_with_satellites_acquired() {
+        let mut test_bed = MultiModeReceiverTestBed::new();
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.latitude().normal_value(),
+            Some(Angle::new::<degree>(45.))
+        );
+        assert_eq!(
+            test_bed.longitude().normal_value(),
+            Some(Angle::new::<degree>(10.))
+        );
+        assert_eq!(
+            test_bed.altitude().normal_value(),
+            Some(Length::new::<foot>(12000.))
+        );
+
+        let horizontal_integrity_limit = test_bed.horizontal_integrity_limit().normal_value();
+        assert!(
+            horizontal_integrity_limit
+                >= Some(MultiModeReceiver::MINIMUM_HORIZONTAL_INTEGRITY_LIMIT_NM)
+        );
+        assert!(
+            horizontal_integrity_limit
+                <= Some(MultiModeReceiver::MAXIMUM_HORIZONTAL_INTEGRITY_LIMIT_NM)
+        );
+    }
+
+    #[test]
+    fn outputs_failure_warning_when_unpowered() {
+        let mut test_bed = MultiModeReceiverTestBed::new();
+        test_bed.command(|a| a.set_ac_1_power(false));
+        test_bed.run();
+
+        assert!(test_bed.latitude().is_failure_warning());
+        assert!(test_bed.longitude().is_failure_warning());
+        assert!(test_bed.altitude().is_failure_warning());
+        assert!(test_bed.horizontal_integrity_limit().is_failure_warning());
+    }
+
+    #[test]
+    fn acquires_satellites_after_power_is_restored() {
+        let mut test_bed = MultiModeReceiverTestBed::new();
+        test_bed.command(|a| a.set_ac_1_power(false));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.run();
+
+        assert!(test_bed.latitude().is_no_computed_data());
+
+        test_bed.run_until_acquired();
+
+        assert!(test_bed.latitude().is_normal_operation());
+    }
+
+    #[test]
+    fn outputs_no_computed_data_when_satellites_are_lost() {
+        let mut test_bed = MultiModeReceiverTestBed::new();
+        test_bed.fail(FailureType::GpsSatelliteLoss(1));
+        test_bed.run();
+
+        assert!(test_bed.latitude().is_no_computed_data());
+        assert!(test_bed.longitude().is_no_computed_data());
+        assert!(test_bed.altitude().is_no_computed_data());
+        assert!(test_bed.horizontal_integrity_limit().is_no_computed_data());
+    }
+
+    #[test]
+    fn reacquires_satellites_after_satellite_loss() {
+        let mut test_bed = MultiModeReceiverTestBed::new();
+        test_bed.fail(FailureType::GpsSatelliteLoss(1));
+        test_bed.run();
+
+        test_bed.unfail(FailureType::GpsSatelliteLoss(1));
+        test_bed.run();
+        assert!(test_bed.latitude().is_no_computed_data());
+
+        test_bed.run_until_acquired();
+
+        assert!(test_bed.latitude().is_normal_operation());
+    }
+}
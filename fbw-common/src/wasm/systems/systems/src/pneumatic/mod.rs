@@ -6,6 +6,7 @@ use crate::{
         HydraulicColor, PneumaticValve,
     },
     simulation::{
+        snapshot::{StateReader, StateWriter},
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
     },
@@ -702,6 +703,19 @@ impl SimulationElement for PressurisedReservoirWithExhaustValve<VariableVolumeCo
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.reservoir_pressure_id, self.reservoir.pressure());
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.reservoir.pressure());
+        state.write(self.reservoir.container.temperature());
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        let pressure = state.read();
+        let temperature = state.read();
+
+        let volume = self.reservoir.container.volume();
+        self.reservoir.container = VariableVolumeContainer::new(volume, pressure, temperature);
+    }
 }
 
 struct PneumaticContainerWithConnector<T: PneumaticContainer> {
@@ -3,6 +3,8 @@ use std::ops::Mul;
 use std::ops::Sub;
 use std::time::Duration;
 
+use crate::simulation::snapshot::{StateReader, StateValue, StateWriter};
+
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
 /// First order low pass filter
 /// y(k) = y(k-1)  +  (1-a)*( x(k) - y(k-1) ) with a = exp (-T/tau)
@@ -53,6 +55,20 @@ where
         self.filtered_output = reset_value;
     }
 }
+impl<T> LowPassFilter<T>
+where
+    T: AddAssign<T> + Sub<Output = T> + Mul<f64, Output = T> + Copy + Default + StateValue,
+{
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.time_constant);
+        state.write(self.filtered_output);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.time_constant = state.read();
+        self.filtered_output = state.read();
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -2,7 +2,10 @@ use crate::{
     apu::ApuGenerator,
     electrical::{ElectricalElement, Potential},
     pneumatic::{EngineModeSelector, EngineState, PneumaticValveSignal},
-    simulation::UpdateContext,
+    simulation::{
+        snapshot::{StateReader, StateWriter},
+        UpdateContext,
+    },
 };
 
 use arinc429::Arinc429Word;
@@ -208,6 +211,29 @@ pub trait EngineUncorrectedN2 {
     fn uncorrected_n2(&self) -> Ratio;
 }
 
+pub trait EngineFuelFlow {
+    fn fuel_flow(&self) -> MassRate;
+}
+
+pub trait FuelTemperature {
+    /// Temperature of the fuel around the heat exchanger of the given hydraulic circuit.
+    fn hydraulic_heat_exchanger_fuel_temperature(
+        &self,
+        color: HydraulicColor,
+    ) -> ThermodynamicTemperature;
+
+    /// Temperature of the fuel fed to the given engine, which cools its generator drive oil.
+    fn engine_feed_fuel_temperature(&self, engine_number: usize) -> ThermodynamicTemperature;
+}
+
+pub trait HydraulicHeatRejection {
+    fn heat_rejected_to_fuel(&self, color: HydraulicColor) -> Power;
+}
+
+pub trait GeneratorDriveHeatRejection {
+    fn generator_drive_heat_rejected_to_fuel(&self, engine_number: usize) -> Power;
+}
+
 pub trait CabinAltitude {
     fn altitude(&self) -> Length;
 }
@@ -247,13 +273,23 @@ pub trait AdirsMeasurementOutputs {
     fn is_fully_aligned(&self, adiru_number: usize) -> bool;
     fn latitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn longitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
+    fn gpirs_latitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
+    fn gpirs_longitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn true_heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn vertical_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length>;
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle>;
 }
 
+pub trait GpsMeasurementOutputs {
+    fn latitude(&self) -> Arinc429Word<Angle>;
+    fn longitude(&self) -> Arinc429Word<Angle>;
+    fn altitude(&self) -> Arinc429Word<Length>;
+    fn horizontal_integrity_limit(&self) -> Arinc429Word<Length>;
+}
+
 pub trait AdirsDiscreteOutputs {
     fn low_speed_warning_1(&self, adiru_number: usize) -> bool;
     fn low_speed_warning_2(&self, adiru_number: usize) -> bool;
@@ -457,6 +493,15 @@ pub trait PowerConsumptionReport {
 
     /// Returns the total power consumed from the given [PotentialOrigin].
     fn total_consumption_of(&self, potential_origin: PotentialOrigin) -> Power;
+
+    /// Returns the total power consumed directly from the given element,
+    /// such as the power consumed by all consumers of an electrical bus.
+    fn total_consumption_from(&self, element: &impl ElectricalElement) -> Power;
+
+    /// Returns the potential of the given element, including the voltage drop caused by the
+    /// resistance of the electrical network. For elements which aren't part of a network
+    /// with feeders, this equals the raw potential provided to the element.
+    fn network_potential_of(&self, element: &impl ElectricalElement) -> ElectricPotential;
 }
 
 /// Trait through which elements can consume power from the aircraft's electrical system.
@@ -505,8 +550,8 @@ pub enum ContactorSignal {
     Close,
 }
 
-#[derive(FromPrimitive)]
-pub(crate) enum FwcFlightPhase {
+#[derive(Clone, Copy, PartialEq, Eq, FromPrimitive)]
+pub enum FwcFlightPhase {
     ElecPwr = 1,
     FirstEngineStarted = 2,
     FirstEngineTakeOffPower = 3,
@@ -562,6 +607,16 @@ impl DelayedTrueLogicGate {
     pub fn output(&self) -> bool {
         self.expression_result && self.delay <= self.true_duration
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.expression_result);
+        state.write(self.true_duration);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.expression_result = state.read();
+        self.true_duration = state.read();
+    }
 }
 
 /// The delay pulse logic gate delays the true result of a given expression by the given amount of time.
@@ -600,6 +655,18 @@ impl DelayedPulseTrueLogicGate {
     pub fn output(&self) -> bool {
         self.output
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.output);
+        state.write(self.last_gate_output);
+        self.true_delayed_gate.save_state(state);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.output = state.read();
+        self.last_gate_output = state.read();
+        self.true_delayed_gate.restore_state(state);
+    }
 }
 
 /// The delay logic gate delays the false result of a given expression by the given amount of time.
@@ -631,6 +698,16 @@ impl DelayedFalseLogicGate {
     pub fn output(&self) -> bool {
         self.expression_result || self.delay > self.false_duration
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.expression_result);
+        state.write(self.false_duration);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.expression_result = state.read();
+        self.false_duration = state.read();
+    }
 }
 
 /// The latched logic gate latches the true result of a given expression.
@@ -651,6 +728,14 @@ impl LatchedTrueLogicGate {
     pub fn output(&self) -> bool {
         self.expression_result
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.expression_result);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.expression_result = state.read();
+    }
 }
 
 /// Given a current and target temperature, takes a coefficient and delta to
@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+use crate::simulation::snapshot::{StateReader, StateWriter};
+
 #[derive(PartialEq, Clone, Copy)]
 /// Pid controller implementation
 /// Implementation in a recursive form
@@ -122,6 +124,37 @@ impl PidController {
         self.kd = kd;
         self.output_gain = output_gain;
     }
+
+    pub fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.kp);
+        state.write(self.ki);
+        state.write(self.kd);
+        state.write(self.min_output);
+        state.write(self.max_output);
+        state.write(self.setpoint);
+        for error in [self.error_k_1, self.error_k_2] {
+            state.write(error.is_some());
+            state.write(error.unwrap_or_default());
+        }
+        state.write(self.output);
+        state.write(self.output_gain);
+    }
+
+    pub fn restore_state(&mut self, state: &mut StateReader) {
+        self.kp = state.read();
+        self.ki = state.read();
+        self.kd = state.read();
+        self.min_output = state.read();
+        self.max_output = state.read();
+        self.setpoint = state.read();
+        for error in [&mut self.error_k_1, &mut self.error_k_2] {
+            let is_known: bool = state.read();
+            let value: f64 = state.read();
+            *error = is_known.then_some(value);
+        }
+        self.output = state.read();
+        self.output_gain = state.read();
+    }
 }
 
 #[cfg(test)]
@@ -12,6 +12,15 @@ mod wasm {
     static RAND_INIT: Once = Once::new();
     static mut RAND: MaybeUninit<SmallRng> = MaybeUninit::uninit();
 
+    /// Reseeds the random number generator, making subsequent random values reproducible.
+    pub fn seed_random(seed: u64) {
+        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
+        RAND_INIT.call_once(|| {});
+        unsafe {
+            RAND = MaybeUninit::new(SmallRng::seed_from_u64(seed));
+        }
+    }
+
     pub fn random_number() -> u8 {
         // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
         RAND_INIT.call_once(|| unsafe {
@@ -57,23 +66,41 @@ pub use not_wasm::*;
 
 #[cfg(not(any(target_arch = "wasm32", doc)))]
 mod not_wasm {
-    use rand::Rng;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, RngCore, SeedableRng};
     use rand_distr::{Distribution, Normal};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SEEDED_RAND: RefCell<Option<SmallRng>> = RefCell::new(None);
+    }
+
+    /// Reseeds the random number generator of the current thread, making subsequent
+    /// random values reproducible. Unseeded threads use the thread local generator.
+    pub fn seed_random(seed: u64) {
+        SEEDED_RAND.with(|rand| *rand.borrow_mut() = Some(SmallRng::seed_from_u64(seed)));
+    }
+
+    fn with_rng<T>(func: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        SEEDED_RAND.with(|rand| match rand.borrow_mut().as_mut() {
+            Some(rng) => func(rng),
+            None => func(&mut rand::thread_rng()),
+        })
+    }
 
     pub fn random_number() -> u8 {
-        rand::thread_rng().gen()
+        with_rng(|rng| rng.gen())
     }
 
     pub fn random_from_range(from: f64, to: f64) -> f64 {
-        rand::thread_rng().gen_range(from..to)
+        with_rng(|rng| rng.gen_range(from..to))
     }
 
     /// Random value from normal distribution. Output limited to -4 / +4 sigma
     pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
         let normal = Normal::new(mean, std_dev).unwrap();
         let limit_offset = 4. * std_dev;
-        normal
-            .sample(&mut rand::thread_rng())
+        with_rng(|rng| normal.sample(rng))
             .max(mean - limit_offset)
             .min(mean + limit_offset)
     }
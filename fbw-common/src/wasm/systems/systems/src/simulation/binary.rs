@@ -0,0 +1,71 @@
+//! Helpers for the little endian binary representation shared by traces and snapshots.
+use std::io::{self, Read, Write};
+
+/// Counts read from a file are only trusted up to this many elements when preallocating,
+/// such that a corrupt count fails on the missing data rather than on allocation.
+const MAXIMUM_PREALLOCATED_COUNT: usize = 4096;
+
+pub(super) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+pub(super) fn write_header(
+    writer: &mut impl Write,
+    magic: &[u8; 8],
+    version: u16,
+) -> io::Result<()> {
+    writer.write_all(magic)?;
+    writer.write_all(&version.to_le_bytes())
+}
+
+pub(super) fn read_header(reader: &mut impl Read, magic: &[u8; 8], version: u16) -> io::Result<()> {
+    let mut read_magic = [0; 8];
+    reader.read_exact(&mut read_magic)?;
+    if &read_magic != magic {
+        return Err(invalid_data("unexpected file type"));
+    }
+
+    let mut read_version = [0; 2];
+    reader.read_exact(&mut read_version)?;
+    if u16::from_le_bytes(read_version) != version {
+        return Err(invalid_data("unsupported version"));
+    }
+
+    Ok(())
+}
+
+pub(super) fn write_u32(writer: &mut impl Write, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(super) fn write_f64(writer: &mut impl Write, value: f64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(super) fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+pub(super) fn read_f64(reader: &mut impl Read) -> io::Result<f64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+/// Creates a vector for the given number of elements read from a file.
+pub(super) fn with_read_capacity<T>(count: u32) -> Vec<T> {
+    Vec::with_capacity((count as usize).min(MAXIMUM_PREALLOCATED_COUNT))
+}
+
+pub(super) fn read_string(reader: &mut impl Read, maximum_length: u32) -> io::Result<String> {
+    let length = read_u32(reader)?;
+    if length > maximum_length {
+        return Err(invalid_data("string too long"));
+    }
+
+    let mut bytes = vec![0; length as usize];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|_| invalid_data("invalid string"))
+}
@@ -1,11 +1,15 @@
 use std::time::Duration;
 
+mod binary;
 mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
 use crate::shared::{from_bool, ElectricalBusType};
 use crate::{
     electrical::Electricity,
-    failures::FailureType,
+    failures::{
+        trigger::{ArmedFailures, FailureActivation, FailureTrigger},
+        FailureType,
+    },
     shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus},
     shared::arinc825::{from_arinc825, to_arinc825, Arinc825Word},
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
@@ -21,7 +25,14 @@ use uom::si::{
 };
 pub use update_context::*;
 
+pub mod snapshot;
 pub mod test;
+pub mod trace;
+
+use snapshot::{
+    RestoreStateVisitor, SaveStateVisitor, SimulatedFailuresVisitor, SimulationSnapshot,
+    SnapshotError, StateReader, StateWriter,
+};
 
 /// Trait for a type which can read and write simulator data.
 /// Using this trait implementors can abstract away the way the code
@@ -329,6 +340,47 @@ pub trait SimulationElement {
 
     /// Receives a failure in order to activate or deactivate it.
     fn receive_failure(&mut self, _failure_type: FailureType, _is_active: bool) {}
+
+    /// Returns the type of failure the element simulates, if it is a [`Failure`].
+    /// Armed failures are saved into a [`SimulationSnapshot`] by the position of their
+    /// failure in the order the elements are visited.
+    ///
+    /// [`Failure`]: ../failures/struct.Failure.html
+    /// [`SimulationSnapshot`]: snapshot/struct.SimulationSnapshot.html
+    fn simulated_failure(&self) -> Option<FailureType> {
+        None
+    }
+
+    /// Saves the internal state of the element into a [`SimulationSnapshot`].
+    /// Only state which isn't read from the simulator on every tick needs to be saved.
+    /// # Examples
+    /// ```rust
+    /// # use systems::simulation::{SimulationElement, snapshot::{StateReader, StateWriter}};
+    /// # use uom::si::f64::*;
+    /// struct MySimulationElement {
+    ///     charge: ElectricCharge,
+    ///     is_on: bool,
+    /// }
+    /// impl SimulationElement for MySimulationElement {
+    ///     fn save_state(&self, state: &mut StateWriter) {
+    ///         state.write(self.charge);
+    ///         state.write(self.is_on);
+    ///     }
+    ///
+    ///     fn restore_state(&mut self, state: &mut StateReader) {
+    ///         self.charge = state.read();
+    ///         self.is_on = state.read();
+    ///     }
+    /// }
+    /// ```
+    /// [`SimulationSnapshot`]: snapshot/struct.SimulationSnapshot.html
+    fn save_state(&self, _state: &mut StateWriter) {}
+
+    /// Restores the internal state of the element, reading values in the same order
+    /// as they were written by [`save_state`].
+    ///
+    /// [`save_state`]: #method.save_state
+    fn restore_state(&mut self, _state: &mut StateReader) {}
 }
 
 /// Trait for visitors that visit the aircraft's system simulation to call
@@ -360,6 +412,8 @@ pub struct Simulation<T: Aircraft> {
     aircraft: T,
     electricity: Electricity,
     update_context: UpdateContext,
+    armed_failures: ArmedFailures,
+    triggered_failures: Vec<(FailureType, bool)>,
 }
 impl<T: Aircraft> Simulation<T> {
     pub fn new<U: FnOnce(&mut InitContext) -> T>(
@@ -370,10 +424,13 @@ impl<T: Aircraft> Simulation<T> {
         let mut electricity = Electricity::new();
         let mut context = InitContext::new(start_state, &mut electricity, registry);
         let update_context = UpdateContext::new_for_simulation(&mut context);
+        let armed_failures = ArmedFailures::new(&mut context);
         Self {
             aircraft: (aircraft_ctor_fn)(&mut context),
             electricity,
             update_context,
+            armed_failures,
+            triggered_failures: vec![],
         }
     }
 
@@ -439,10 +496,17 @@ impl<T: Aircraft> Simulation<T> {
         let mut reader = SimulatorReader::new(reader_writer);
         self.update_context
             .update(&mut reader, delta, simulation_time);
+        self.armed_failures.read(&mut reader);
 
         let mut visitor = SimulatorToSimulationVisitor::new(&mut reader);
         self.aircraft.accept(&mut visitor);
 
+        let triggered_failures = self.armed_failures.update(&self.update_context);
+        for &(failure_type, is_active) in &triggered_failures {
+            self.handle_failure(failure_type, is_active);
+        }
+        self.triggered_failures = triggered_failures;
+
         self.aircraft
             .update_before_power_distribution(&self.update_context, &mut self.electricity);
 
@@ -465,10 +529,94 @@ impl<T: Aircraft> Simulation<T> {
         self.handle_failure(failure_type, true);
     }
 
+    /// Deactivates the failure. A failure which is armed is disarmed as well.
     pub fn deactivate_failure(&mut self, failure_type: FailureType) {
+        self.armed_failures.disarm(failure_type);
         self.handle_failure(failure_type, false);
     }
 
+    /// Arms the failure such that it is activated once the trigger condition is met,
+    /// in the way described by the activation.
+    pub fn arm_failure(
+        &mut self,
+        failure_type: FailureType,
+        trigger: FailureTrigger,
+        activation: FailureActivation,
+    ) {
+        self.armed_failures.arm(failure_type, trigger, activation);
+    }
+
+    /// Disarms the failure. An intermittent failure which is active at that
+    /// moment is deactivated.
+    pub fn disarm_failure(&mut self, failure_type: FailureType) {
+        if self.armed_failures.disarm(failure_type) {
+            self.handle_failure(failure_type, false);
+        }
+    }
+
+    pub fn is_failure_armed(&self, failure_type: FailureType) -> bool {
+        self.armed_failures.is_armed(failure_type)
+    }
+
+    /// The armed failures which were activated (true) or deactivated (false)
+    /// during the last tick, such that the simulator can be informed of them.
+    pub fn triggered_failures(&self) -> &[(FailureType, bool)] {
+        &self.triggered_failures
+    }
+
+    /// Saves the internal state of the aircraft, such that it can be restored later
+    /// without simulating everything that happened in between.
+    pub fn save_snapshot(&mut self) -> SimulationSnapshot {
+        let mut context = StateWriter::default();
+        self.update_context.save_state(&mut context);
+
+        let mut electricity = StateWriter::default();
+        self.electricity.save_state(&mut electricity);
+
+        let failure_types = self.simulated_failures();
+        let mut armed_failures = StateWriter::default();
+        self.armed_failures
+            .save_state(&failure_types, &mut armed_failures);
+
+        let mut visitor = SaveStateVisitor::default();
+        self.aircraft.accept(&mut visitor);
+
+        SimulationSnapshot::new(
+            context.into_values(),
+            electricity.into_values(),
+            armed_failures.into_values(),
+            visitor.into_elements(),
+        )
+    }
+
+    /// Restores the internal state of the aircraft from a snapshot taken from
+    /// a simulation of the same aircraft. Nothing is restored when the snapshot
+    /// doesn't match the aircraft.
+    pub fn restore_snapshot(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        snapshot.verify_compatible_with(&self.save_snapshot())?;
+
+        self.update_context
+            .restore_state(&mut StateReader::new(snapshot.context()));
+        self.electricity
+            .restore_state(&mut StateReader::new(snapshot.electricity()));
+        let failure_types = self.simulated_failures();
+        self.armed_failures.restore_state(
+            &failure_types,
+            &mut StateReader::new(snapshot.armed_failures()),
+        );
+        self.aircraft
+            .accept(&mut RestoreStateVisitor::new(snapshot));
+
+        Ok(())
+    }
+
+    fn simulated_failures(&mut self) -> Vec<FailureType> {
+        let mut visitor = SimulatedFailuresVisitor::default();
+        self.aircraft.accept(&mut visitor);
+
+        visitor.into_failure_types()
+    }
+
     fn handle_failure(&mut self, failure_type: FailureType, is_active: bool) {
         self.aircraft
             .accept(&mut FailureSimulationElementVisitor::new(
@@ -0,0 +1,509 @@
+//! Saving and restoring of the internal state of a [`Simulation`].
+//!
+//! Each [`SimulationElement`] saves the state which cannot be derived from the simulator
+//! through [`SimulationElement::save_state`]. Elements are visited in the same order for
+//! saving and restoring, thus a snapshot can only be restored into a simulation of the
+//! same aircraft as the one it was taken from. Besides the elements, a snapshot contains
+//! the update context, the electricity distributed during the last tick and the armed
+//! failures.
+//!
+//! [`Simulation`]: ../struct.Simulation.html
+//! [`SimulationElement`]: ../trait.SimulationElement.html
+//! [`SimulationElement::save_state`]: ../trait.SimulationElement.html#method.save_state
+use std::{
+    io::{self, Read, Write},
+    time::Duration,
+};
+
+use uom::si::{
+    angle::radian, angular_acceleration::radian_per_second_squared,
+    angular_velocity::radian_per_second, electric_charge::ampere_hour, electric_current::ampere,
+    electric_potential::volt, f64::*, frequency::hertz, length::meter, mass::kilogram, power::watt,
+    pressure::psi, ratio::ratio, temperature_interval, thermodynamic_temperature::kelvin,
+    torque::newton_meter, velocity::meter_per_second, volume::cubic_meter,
+    volume_rate::cubic_meter_per_second,
+};
+
+use super::{
+    binary::{
+        read_f64, read_header, read_u32, with_read_capacity, write_f64, write_header, write_u32,
+    },
+    SimulationElement, SimulationElementVisitor,
+};
+use crate::failures::FailureType;
+
+/// The saved state of all elements of a [`Simulation`].
+///
+/// [`Simulation`]: ../struct.Simulation.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationSnapshot {
+    context: Vec<f64>,
+    electricity: Vec<f64>,
+    armed_failures: Vec<f64>,
+    elements: Vec<Vec<f64>>,
+}
+impl SimulationSnapshot {
+    const MAGIC: &'static [u8; 8] = b"FBWSNAPS";
+    const VERSION: u16 = 3;
+
+    pub(super) fn new(
+        context: Vec<f64>,
+        electricity: Vec<f64>,
+        armed_failures: Vec<f64>,
+        elements: Vec<Vec<f64>>,
+    ) -> Self {
+        Self {
+            context,
+            electricity,
+            armed_failures,
+            elements,
+        }
+    }
+
+    pub(super) fn context(&self) -> &[f64] {
+        &self.context
+    }
+
+    pub(super) fn electricity(&self) -> &[f64] {
+        &self.electricity
+    }
+
+    pub(super) fn armed_failures(&self) -> &[f64] {
+        &self.armed_failures
+    }
+
+    pub(super) fn elements(&self) -> &[Vec<f64>] {
+        &self.elements
+    }
+
+    /// Verifies the snapshot can be restored into a simulation which
+    /// produced the given snapshot. The electricity and armed failures vary in size
+    /// and are thus not verified.
+    pub(super) fn verify_compatible_with(&self, other: &Self) -> Result<(), SnapshotError> {
+        if self.elements.len() != other.elements.len() {
+            return Err(SnapshotError::ElementCountMismatch {
+                expected: other.elements.len(),
+                actual: self.elements.len(),
+            });
+        }
+
+        if self.context.len() != other.context.len() {
+            return Err(SnapshotError::ContextMismatch);
+        }
+
+        match self
+            .elements
+            .iter()
+            .zip(other.elements.iter())
+            .position(|(a, b)| a.len() != b.len())
+        {
+            Some(element) => Err(SnapshotError::ElementStateMismatch { element }),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes the snapshot in its binary representation.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        write_header(writer, Self::MAGIC, Self::VERSION)?;
+
+        write_values(writer, &self.context)?;
+        write_values(writer, &self.electricity)?;
+        write_values(writer, &self.armed_failures)?;
+        write_u32(writer, self.elements.len() as u32)?;
+        for element in &self.elements {
+            write_values(writer, element)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a snapshot previously written by [`write_to`].
+    ///
+    /// [`write_to`]: #method.write_to
+    pub fn read_from(reader: &mut impl Read) -> io::Result<Self> {
+        read_header(reader, Self::MAGIC, Self::VERSION)?;
+
+        let context = read_values(reader)?;
+        let electricity = read_values(reader)?;
+        let armed_failures = read_values(reader)?;
+        let element_count = read_u32(reader)?;
+        let mut elements = with_read_capacity(element_count);
+        for _ in 0..element_count {
+            elements.push(read_values(reader)?);
+        }
+
+        Ok(Self {
+            context,
+            electricity,
+            armed_failures,
+            elements,
+        })
+    }
+}
+
+fn write_values(writer: &mut impl Write, values: &[f64]) -> io::Result<()> {
+    write_u32(writer, values.len() as u32)?;
+    for value in values {
+        write_f64(writer, *value)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the values written by [`write_values`]. NaN is a valid value, as elements
+/// can save state which isn't known yet, such as the output of a filter which hasn't
+/// received any input.
+fn read_values(reader: &mut impl Read) -> io::Result<Vec<f64>> {
+    let count = read_u32(reader)?;
+    let mut values = with_read_capacity(count);
+    for _ in 0..count {
+        values.push(read_f64(reader)?);
+    }
+
+    Ok(values)
+}
+
+/// The reason a [`SimulationSnapshot`] couldn't be restored.
+///
+/// [`SimulationSnapshot`]: struct.SimulationSnapshot.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The snapshot was taken from an aircraft with a different number of elements.
+    ElementCountMismatch { expected: usize, actual: usize },
+    /// The element at the given visiting order saves a different amount of state.
+    ElementStateMismatch { element: usize },
+    /// The update context saves a different amount of state.
+    ContextMismatch,
+}
+
+/// Writes the internal state of an element into a snapshot.
+#[derive(Default)]
+pub struct StateWriter {
+    values: Vec<f64>,
+}
+impl StateWriter {
+    pub fn write<T: StateValue>(&mut self, value: T) {
+        self.values.push(value.to_state());
+    }
+
+    /// Writes the number of items which follow, for state of a variable size.
+    pub fn write_count(&mut self, count: usize) {
+        self.values.push(count as f64);
+    }
+
+    pub(super) fn into_values(self) -> Vec<f64> {
+        self.values
+    }
+}
+
+/// Reads the internal state of an element from a snapshot, in the order it was written.
+pub struct StateReader<'a> {
+    values: &'a [f64],
+    position: usize,
+}
+impl<'a> StateReader<'a> {
+    pub(super) fn new(values: &'a [f64]) -> Self {
+        Self {
+            values,
+            position: 0,
+        }
+    }
+
+    pub fn read<T: StateValue>(&mut self) -> T {
+        let value = self.values.get(self.position).copied().unwrap_or_default();
+        self.position += 1;
+
+        T::from_state(value)
+    }
+
+    /// Reads the number of items written by [`StateWriter::write_count`]. The count
+    /// never exceeds the number of values left to read.
+    pub fn read_count(&mut self) -> usize {
+        let count: f64 = self.read();
+        (count.max(0.) as usize).min(self.values.len().saturating_sub(self.position))
+    }
+}
+
+/// A value which can be stored in a [`SimulationSnapshot`].
+///
+/// [`SimulationSnapshot`]: struct.SimulationSnapshot.html
+pub trait StateValue {
+    fn to_state(self) -> f64;
+    fn from_state(value: f64) -> Self;
+}
+
+impl StateValue for f64 {
+    fn to_state(self) -> f64 {
+        self
+    }
+
+    fn from_state(value: f64) -> Self {
+        value
+    }
+}
+
+impl StateValue for bool {
+    fn to_state(self) -> f64 {
+        if self {
+            1.
+        } else {
+            0.
+        }
+    }
+
+    fn from_state(value: f64) -> Self {
+        value > 0.
+    }
+}
+
+impl StateValue for Duration {
+    fn to_state(self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    fn from_state(value: f64) -> Self {
+        Duration::try_from_secs_f64(value.max(0.)).unwrap_or(Duration::MAX)
+    }
+}
+
+macro_rules! state_value_uom {
+    ($t: ty, $t2: ty) => {
+        impl StateValue for $t {
+            fn to_state(self) -> f64 {
+                self.get::<$t2>()
+            }
+
+            fn from_state(value: f64) -> Self {
+                <$t>::new::<$t2>(value)
+            }
+        }
+    };
+}
+
+state_value_uom!(Angle, radian);
+state_value_uom!(AngularAcceleration, radian_per_second_squared);
+state_value_uom!(AngularVelocity, radian_per_second);
+state_value_uom!(ElectricCharge, ampere_hour);
+state_value_uom!(ElectricCurrent, ampere);
+state_value_uom!(ElectricPotential, volt);
+state_value_uom!(Frequency, hertz);
+state_value_uom!(Length, meter);
+state_value_uom!(Mass, kilogram);
+state_value_uom!(Power, watt);
+state_value_uom!(Pressure, psi);
+state_value_uom!(Ratio, ratio);
+state_value_uom!(TemperatureInterval, temperature_interval::kelvin);
+state_value_uom!(ThermodynamicTemperature, kelvin);
+state_value_uom!(Torque, newton_meter);
+state_value_uom!(Velocity, meter_per_second);
+state_value_uom!(Volume, cubic_meter);
+state_value_uom!(VolumeRate, cubic_meter_per_second);
+
+/// Visits aircraft components in order to save their internal state.
+#[derive(Default)]
+pub(super) struct SaveStateVisitor {
+    elements: Vec<Vec<f64>>,
+}
+impl SaveStateVisitor {
+    pub(super) fn into_elements(self) -> Vec<Vec<f64>> {
+        self.elements
+    }
+}
+impl SimulationElementVisitor for SaveStateVisitor {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        let mut writer = StateWriter::default();
+        visited.save_state(&mut writer);
+        self.elements.push(writer.into_values());
+    }
+}
+
+/// Visits aircraft components in order to collect the failures they simulate, which
+/// identifies the armed failures in a snapshot.
+#[derive(Default)]
+pub(super) struct SimulatedFailuresVisitor {
+    failure_types: Vec<FailureType>,
+}
+impl SimulatedFailuresVisitor {
+    pub(super) fn into_failure_types(self) -> Vec<FailureType> {
+        self.failure_types
+    }
+}
+impl SimulationElementVisitor for SimulatedFailuresVisitor {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        if let Some(failure_type) = visited.simulated_failure() {
+            self.failure_types.push(failure_type);
+        }
+    }
+}
+
+/// Visits aircraft components in order to restore their internal state.
+pub(super) struct RestoreStateVisitor<'a> {
+    elements: std::slice::Iter<'a, Vec<f64>>,
+}
+impl<'a> RestoreStateVisitor<'a> {
+    pub(super) fn new(snapshot: &'a SimulationSnapshot) -> Self {
+        Self {
+            elements: snapshot.elements().iter(),
+        }
+    }
+}
+impl<'a> SimulationElementVisitor for RestoreStateVisitor<'a> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        if let Some(values) = self.elements.next() {
+            visited.restore_state(&mut StateReader::new(values));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::{Failure, FailureType},
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, UpdateContext,
+        },
+    };
+    use ntest::assert_about_eq;
+
+    struct Integrator {
+        failure: Failure,
+        total: Length,
+        is_counting: bool,
+        /// NaN until the first update.
+        last_delta: f64,
+    }
+    impl Integrator {
+        fn new() -> Self {
+            Self {
+                failure: Failure::new(FailureType::StaticInverter),
+                total: Length::default(),
+                is_counting: true,
+                last_delta: f64::NAN,
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            if self.is_counting && !self.failure.is_active() {
+                self.total += Length::new::<meter>(context.delta_as_secs_f64());
+            }
+            self.last_delta = context.delta_as_secs_f64();
+        }
+    }
+    impl Aircraft for Integrator {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
+        }
+    }
+    impl SimulationElement for Integrator {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn save_state(&self, state: &mut StateWriter) {
+            state.write(self.total);
+            state.write(self.is_counting);
+            state.write(self.last_delta);
+        }
+
+        fn restore_state(&mut self, state: &mut StateReader) {
+            self.total = state.read();
+            self.is_counting = state.read();
+            self.last_delta = state.read();
+        }
+    }
+
+    struct OtherAircraft {}
+    impl Aircraft for OtherAircraft {}
+    impl SimulationElement for OtherAircraft {}
+
+    fn test_bed() -> SimulationTestBed<Integrator> {
+        SimulationTestBed::new(|_: &mut InitContext| Integrator::new())
+    }
+
+    #[test]
+    fn restores_element_state() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(5));
+        let snapshot = test_bed.save_snapshot();
+
+        test_bed.run_with_delta(Duration::from_secs(5));
+        test_bed.command(|a| a.is_counting = false);
+        assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+
+        assert_about_eq!(test_bed.query(|a| a.total.get::<meter>()), 5.);
+        assert!(test_bed.query(|a| a.is_counting));
+    }
+
+    #[test]
+    fn restores_failures() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::StaticInverter);
+        let snapshot = test_bed.save_snapshot();
+
+        test_bed.unfail(FailureType::StaticInverter);
+        assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert_about_eq!(test_bed.query(|a| a.total.get::<meter>()), 0.);
+    }
+
+    #[test]
+    fn rejects_snapshot_of_other_aircraft() {
+        let mut other = SimulationTestBed::new(|_| OtherAircraft {});
+        let snapshot = other.save_snapshot();
+
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert_eq!(
+            test_bed.restore_snapshot(&snapshot),
+            Err(SnapshotError::ElementCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_about_eq!(test_bed.query(|a| a.total.get::<meter>()), 5.);
+    }
+
+    #[test]
+    fn snapshot_survives_binary_round_trip() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(5));
+        let snapshot = test_bed.save_snapshot();
+
+        let mut bytes = vec![];
+        snapshot.write_to(&mut bytes).unwrap();
+
+        assert_eq!(
+            SimulationSnapshot::read_from(&mut bytes.as_slice()).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn restores_nan_state_after_binary_round_trip() {
+        let mut test_bed = test_bed();
+        let snapshot = test_bed.save_snapshot();
+
+        let mut bytes = vec![];
+        snapshot.write_to(&mut bytes).unwrap();
+        let snapshot = SimulationSnapshot::read_from(&mut bytes.as_slice()).unwrap();
+
+        test_bed.run_with_delta(Duration::from_secs(5));
+        assert!(test_bed.restore_snapshot(&snapshot).is_ok());
+
+        assert!(test_bed.query(|a| a.last_delta.is_nan()));
+    }
+
+    #[test]
+    fn reading_snapshot_of_other_version_fails() {
+        let mut bytes = vec![];
+        write_header(&mut bytes, SimulationSnapshot::MAGIC, 0).unwrap();
+
+        assert!(SimulationSnapshot::read_from(&mut bytes.as_slice()).is_err());
+    }
+}
@@ -14,7 +14,10 @@ use uom::si::{
 
 use crate::{
     electrical::{Electricity, Potential},
-    failures::FailureType,
+    failures::{
+        trigger::{FailureActivation, FailureTrigger},
+        FailureType,
+    },
 };
 
 use super::{
@@ -24,6 +27,7 @@ use super::{
 };
 use crate::landing_gear::LandingGear;
 use crate::shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus};
+use crate::simulation::snapshot::{SimulationSnapshot, SnapshotError};
 use crate::simulation::update_context::Delta;
 use crate::simulation::{
     DeltaContext, InitContext, StartState, VariableIdentifier, VariableRegistry,
@@ -51,10 +55,37 @@ pub trait TestBed {
         self.test_bed_mut().fail(failure_type);
     }
 
+    fn save_snapshot(&mut self) -> SimulationSnapshot {
+        self.test_bed_mut().save_snapshot()
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.test_bed_mut().restore_snapshot(snapshot)
+    }
+
+    fn variables_differing_after_snapshot_round_trip(&mut self, delta: Duration) -> Vec<String> {
+        self.test_bed_mut()
+            .variables_differing_after_snapshot_round_trip(delta)
+    }
+
     fn unfail(&mut self, failure_type: FailureType) {
         self.test_bed_mut().unfail(failure_type);
     }
 
+    fn arm_failure(
+        &mut self,
+        failure_type: FailureType,
+        trigger: FailureTrigger,
+        activation: FailureActivation,
+    ) {
+        self.test_bed_mut()
+            .arm_failure(failure_type, trigger, activation);
+    }
+
+    fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.test_bed_mut().disarm_failure(failure_type);
+    }
+
     fn command<V: FnOnce(&mut Self::Aircraft)>(&mut self, func: V) {
         self.test_bed_mut().command(func);
     }
@@ -325,10 +356,81 @@ impl<T: Aircraft> SimulationTestBed<T> {
         self.simulation.activate_failure(failure_type);
     }
 
+    fn save_snapshot(&mut self) -> SimulationSnapshot {
+        self.simulation.save_snapshot()
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.simulation.restore_snapshot(snapshot)
+    }
+
+    /// Saves a snapshot and runs a tick of the given duration, then restores the snapshot
+    /// after another tick and runs the same tick again. Returns the names of the variables
+    /// written with a different value by the tick following the restore, which are the
+    /// variables depending on state missing from the snapshot.
+    fn variables_differing_after_snapshot_round_trip(&mut self, delta: Duration) -> Vec<String> {
+        const SEED: u64 = 42;
+
+        let snapshot = self.save_snapshot();
+        let variables = self.reader_writer.variables.clone();
+
+        crate::shared::seed_random(SEED);
+        self.run_with_delta(delta);
+        let expected = self.reader_writer.variables.clone();
+
+        self.run_with_delta(delta);
+        self.restore_snapshot(&snapshot)
+            .expect("the snapshot of the same aircraft should restore");
+        self.reader_writer.variables = variables;
+
+        crate::shared::seed_random(SEED);
+        self.run_with_delta(delta);
+
+        let mut differing: Vec<String> = self
+            .variable_registry
+            .name_to_identifier
+            .iter()
+            .filter(|(_, identifier)| {
+                expected.get(identifier).map(|value| value.to_bits())
+                    != self
+                        .reader_writer
+                        .variables
+                        .get(identifier)
+                        .map(|value| value.to_bits())
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        differing.sort();
+
+        differing
+    }
+
     fn unfail(&mut self, failure_type: FailureType) {
         self.simulation.deactivate_failure(failure_type);
     }
 
+    fn arm_failure(
+        &mut self,
+        failure_type: FailureType,
+        trigger: FailureTrigger,
+        activation: FailureActivation,
+    ) {
+        self.simulation
+            .arm_failure(failure_type, trigger, activation);
+    }
+
+    fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.simulation.disarm_failure(failure_type);
+    }
+
+    pub fn is_failure_armed(&self, failure_type: FailureType) -> bool {
+        self.simulation.is_failure_armed(failure_type)
+    }
+
+    pub fn triggered_failures(&self) -> &[(FailureType, bool)] {
+        self.simulation.triggered_failures()
+    }
+
     fn aircraft(&self) -> &T {
         self.simulation.aircraft()
     }
@@ -0,0 +1,773 @@
+//! Recording and replaying of the data exchanged between a [`Simulation`] and the simulator.
+//!
+//! A trace contains every variable read and written per frame, keyed by variable name.
+//! As names are resolved through the [`VariableRegistry`], a trace recorded within the
+//! simulator can be replayed through an identical aircraft on any platform, without
+//! the simulator being present.
+//!
+//! Only values which changed since the previous frame are stored, keeping traces of
+//! long flights compact.
+//!
+//! [`Simulation`]: ../struct.Simulation.html
+//! [`VariableRegistry`]: ../trait.VariableRegistry.html
+use std::{
+    io::{self, Read, Write},
+    time::Duration,
+};
+
+use fxhash::FxHashMap;
+
+use super::{
+    binary::{
+        invalid_data, read_f64, read_header, read_string, read_u32, with_read_capacity, write_f64,
+        write_header, write_u32,
+    },
+    Aircraft, Simulation, SimulatorReaderWriter, VariableIdentifier, VariableRegistry,
+};
+
+/// Bidirectional mapping between variable names and the identifiers
+/// handed out by a [`VariableRegistry`].
+///
+/// [`VariableRegistry`]: ../trait.VariableRegistry.html
+#[derive(Default)]
+pub struct VariableNames {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    identifier_to_name: FxHashMap<VariableIdentifier, String>,
+}
+impl VariableNames {
+    fn insert(&mut self, name: String, identifier: VariableIdentifier) {
+        self.identifier_to_name.insert(identifier, name.clone());
+        self.name_to_identifier.insert(name, identifier);
+    }
+
+    pub fn name(&self, identifier: &VariableIdentifier) -> Option<&str> {
+        self.identifier_to_name.get(identifier).map(|x| x.as_str())
+    }
+
+    pub fn identifier(&self, name: &str) -> Option<VariableIdentifier> {
+        self.name_to_identifier.get(name).copied()
+    }
+}
+
+/// A [`VariableRegistry`] which delegates to another registry, while remembering the name
+/// of every identifier it hands out. Use this when constructing a [`Simulation`] which is
+/// to be recorded.
+///
+/// [`VariableRegistry`]: ../trait.VariableRegistry.html
+/// [`Simulation`]: ../struct.Simulation.html
+pub struct RecordingVariableRegistry<'a, T: VariableRegistry> {
+    registry: &'a mut T,
+    names: VariableNames,
+}
+impl<'a, T: VariableRegistry> RecordingVariableRegistry<'a, T> {
+    pub fn new(registry: &'a mut T) -> Self {
+        Self {
+            registry,
+            names: Default::default(),
+        }
+    }
+
+    pub fn into_names(self) -> VariableNames {
+        self.names
+    }
+}
+impl<'a, T: VariableRegistry> VariableRegistry for RecordingVariableRegistry<'a, T> {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        let identifier = self.registry.get(name.clone());
+        self.names.insert(name, identifier);
+
+        identifier
+    }
+}
+
+/// A standalone [`VariableRegistry`] for constructing a [`Simulation`] which replays a trace.
+///
+/// [`VariableRegistry`]: ../trait.VariableRegistry.html
+/// [`Simulation`]: ../struct.Simulation.html
+#[derive(Default)]
+pub struct ReplayVariableRegistry {
+    names: VariableNames,
+    next_identifier: VariableIdentifier,
+}
+impl ReplayVariableRegistry {
+    pub fn into_names(self) -> VariableNames {
+        self.names
+    }
+}
+impl VariableRegistry for ReplayVariableRegistry {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        match self.names.identifier(&name) {
+            Some(identifier) => identifier,
+            None => {
+                let identifier = self.next_identifier;
+                self.names.insert(name, identifier);
+                self.next_identifier = identifier.next();
+
+                identifier
+            }
+        }
+    }
+}
+
+/// The variables exchanged with the simulator during a single [`Simulation`] tick.
+/// Values are stored by index into the name table of the owning [`SimulationTrace`].
+///
+/// [`Simulation`]: ../struct.Simulation.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TraceFrame {
+    delta: Duration,
+    simulation_time: f64,
+    reads: Vec<(u32, f64)>,
+    writes: Vec<(u32, f64)>,
+}
+impl TraceFrame {
+    fn new(delta: Duration, simulation_time: f64) -> Self {
+        Self {
+            delta,
+            simulation_time,
+            reads: vec![],
+            writes: vec![],
+        }
+    }
+
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn simulation_time(&self) -> f64 {
+        self.simulation_time
+    }
+}
+
+/// A recording of all frames of a [`Simulation`] run.
+///
+/// [`Simulation`]: ../struct.Simulation.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationTrace {
+    names: Vec<String>,
+    frames: Vec<TraceFrame>,
+}
+impl SimulationTrace {
+    const MAGIC: &'static [u8; 8] = b"FBWTRACE";
+    const VERSION: u16 = 1;
+    const MAXIMUM_NAME_LENGTH: u32 = 1024;
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn frames(&self) -> &[TraceFrame] {
+        &self.frames
+    }
+
+    /// Writes the trace in its compact binary representation.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        write_header(writer, Self::MAGIC, Self::VERSION)?;
+
+        write_u32(writer, self.names.len() as u32)?;
+        for name in &self.names {
+            write_u32(writer, name.len() as u32)?;
+            writer.write_all(name.as_bytes())?;
+        }
+
+        write_u32(writer, self.frames.len() as u32)?;
+        for frame in &self.frames {
+            write_f64(writer, frame.delta.as_secs_f64())?;
+            write_f64(writer, frame.simulation_time)?;
+            write_values(writer, &frame.reads)?;
+            write_values(writer, &frame.writes)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a trace previously written by [`write_to`].
+    ///
+    /// [`write_to`]: #method.write_to
+    pub fn read_from(reader: &mut impl Read) -> io::Result<Self> {
+        read_header(reader, Self::MAGIC, Self::VERSION)?;
+
+        let name_count = read_u32(reader)?;
+        let mut names = with_read_capacity(name_count);
+        for _ in 0..name_count {
+            names.push(read_string(reader, Self::MAXIMUM_NAME_LENGTH)?);
+        }
+
+        let frame_count = read_u32(reader)?;
+        let mut frames = with_read_capacity(frame_count);
+        for _ in 0..frame_count {
+            let delta = read_f64(reader)?;
+            if !delta.is_finite() || delta < 0. {
+                return Err(invalid_data("invalid frame delta"));
+            }
+
+            let mut frame = TraceFrame::new(Duration::from_secs_f64(delta), read_f64(reader)?);
+            frame.reads = read_values(reader, name_count)?;
+            frame.writes = read_values(reader, name_count)?;
+            frames.push(frame);
+        }
+
+        Ok(Self { names, frames })
+    }
+}
+
+fn write_values(writer: &mut impl Write, values: &[(u32, f64)]) -> io::Result<()> {
+    write_u32(writer, values.len() as u32)?;
+    for (index, value) in values {
+        write_u32(writer, *index)?;
+        write_f64(writer, *value)?;
+    }
+
+    Ok(())
+}
+
+fn read_values(reader: &mut impl Read, name_count: u32) -> io::Result<Vec<(u32, f64)>> {
+    let count = read_u32(reader)?;
+    let mut values = with_read_capacity(count);
+    for _ in 0..count {
+        let index = read_u32(reader)?;
+        if index >= name_count {
+            return Err(invalid_data("variable index out of range"));
+        }
+
+        values.push((index, read_f64(reader)?));
+    }
+
+    Ok(values)
+}
+
+/// Records a [`Simulation`] by sitting between it and the [`SimulatorReaderWriter`]
+/// it would otherwise tick with.
+///
+/// # Examples
+/// ```rust
+/// # use std::time::Duration;
+/// # use systems::simulation::{Aircraft, InitContext, SimulationElement, Simulation,
+/// # SimulatorReaderWriter, VariableIdentifier,
+/// # test::TestVariableRegistry, trace::{RecordingVariableRegistry, TraceRecorder}};
+/// # struct MyAircraft {}
+/// # impl Aircraft for MyAircraft {}
+/// # impl SimulationElement for MyAircraft {}
+/// # struct MySimulatorReaderWriter {}
+/// # impl SimulatorReaderWriter for MySimulatorReaderWriter {
+/// #     fn read(&mut self, identifier: &VariableIdentifier) -> f64 { 0.0 }
+/// #     fn write(&mut self, identifier: &VariableIdentifier, value: f64) { }
+/// # }
+/// let mut registry = TestVariableRegistry::default();
+/// let mut recording_registry = RecordingVariableRegistry::new(&mut registry);
+/// let mut simulation =
+///     Simulation::new(Default::default(), |_| MyAircraft {}, &mut recording_registry);
+/// let mut recorder = TraceRecorder::new(recording_registry.into_names());
+///
+/// let mut reader_writer = MySimulatorReaderWriter {};
+/// recorder.tick(&mut simulation, Duration::from_millis(50), 20., &mut reader_writer);
+///
+/// let mut bytes = vec![];
+/// recorder.into_trace().write_to(&mut bytes).unwrap();
+/// ```
+///
+/// [`Simulation`]: ../struct.Simulation.html
+/// [`SimulatorReaderWriter`]: ../trait.SimulatorReaderWriter.html
+pub struct TraceRecorder {
+    names: VariableNames,
+    identifier_to_index: FxHashMap<VariableIdentifier, u32>,
+    /// The value a read returns during replay, which is either the value last read
+    /// or the value last written by the simulation.
+    last_value: FxHashMap<u32, f64>,
+    last_written: FxHashMap<u32, f64>,
+    trace: SimulationTrace,
+}
+impl TraceRecorder {
+    pub fn new(names: VariableNames) -> Self {
+        Self {
+            names,
+            identifier_to_index: FxHashMap::default(),
+            last_value: FxHashMap::default(),
+            last_written: FxHashMap::default(),
+            trace: Default::default(),
+        }
+    }
+
+    /// Ticks the simulation with the given reader writer, recording all values exchanged.
+    pub fn tick<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        delta: Duration,
+        simulation_time: f64,
+        reader_writer: &mut impl SimulatorReaderWriter,
+    ) {
+        self.trace
+            .frames
+            .push(TraceFrame::new(delta, simulation_time));
+
+        simulation.tick(
+            delta,
+            simulation_time,
+            &mut RecordingReaderWriter {
+                recorder: self,
+                reader_writer,
+            },
+        );
+    }
+
+    pub fn trace(&self) -> &SimulationTrace {
+        &self.trace
+    }
+
+    pub fn into_trace(self) -> SimulationTrace {
+        self.trace
+    }
+
+    fn index(&mut self, identifier: &VariableIdentifier) -> Option<u32> {
+        if let Some(index) = self.identifier_to_index.get(identifier) {
+            return Some(*index);
+        }
+
+        // Identifiers which weren't handed out by the registry cannot be resolved
+        // during replay, and are therefore not recorded.
+        let name = self.names.name(identifier)?.to_owned();
+        let index = self.trace.names.len() as u32;
+        self.trace.names.push(name);
+        self.identifier_to_index.insert(*identifier, index);
+
+        Some(index)
+    }
+
+    fn record_read(&mut self, identifier: &VariableIdentifier, value: f64) {
+        if let Some(index) = self.index(identifier) {
+            if Self::changed(&mut self.last_value, index, value) {
+                if let Some(frame) = self.trace.frames.last_mut() {
+                    frame.reads.push((index, value));
+                }
+            }
+        }
+    }
+
+    fn record_write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        if let Some(index) = self.index(identifier) {
+            self.last_value.insert(index, value);
+            if Self::changed(&mut self.last_written, index, value) {
+                if let Some(frame) = self.trace.frames.last_mut() {
+                    frame.writes.push((index, value));
+                }
+            }
+        }
+    }
+
+    fn changed(last_values: &mut FxHashMap<u32, f64>, index: u32, value: f64) -> bool {
+        match last_values.insert(index, value) {
+            Some(last_value) => last_value.to_bits() != value.to_bits(),
+            None => true,
+        }
+    }
+}
+
+struct RecordingReaderWriter<'a, T: SimulatorReaderWriter> {
+    recorder: &'a mut TraceRecorder,
+    reader_writer: &'a mut T,
+}
+impl<'a, T: SimulatorReaderWriter> SimulatorReaderWriter for RecordingReaderWriter<'a, T> {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let value = self.reader_writer.read(identifier);
+        self.recorder.record_read(identifier, value);
+
+        value
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.reader_writer.write(identifier, value);
+        self.recorder.record_write(identifier, value);
+    }
+}
+
+/// A variable written during replay which differs from the value written
+/// at the same frame of the recording.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceMismatch {
+    pub frame: usize,
+    pub name: String,
+    pub recorded: f64,
+    pub replayed: f64,
+}
+
+/// Replays a [`SimulationTrace`] through a [`Simulation`], feeding it the recorded reads
+/// frame by frame and comparing the values it writes against the recorded writes.
+///
+/// The [`Simulation`] must be constructed with a [`ReplayVariableRegistry`], whose names
+/// are passed to the replayer. For random elements of the simulation to behave identically
+/// to the recording, seed them through `shared::seed_random` before constructing the
+/// [`Simulation`].
+///
+/// [`SimulationTrace`]: struct.SimulationTrace.html
+/// [`Simulation`]: ../struct.Simulation.html
+/// [`ReplayVariableRegistry`]: struct.ReplayVariableRegistry.html
+pub struct TraceReplayer {
+    trace: SimulationTrace,
+    names: VariableNames,
+    index_to_identifier: Vec<Option<VariableIdentifier>>,
+    values: FxHashMap<VariableIdentifier, f64>,
+    recorded_writes: FxHashMap<VariableIdentifier, f64>,
+    replayed_writes: FxHashMap<VariableIdentifier, f64>,
+    next_frame: usize,
+    mismatches: Vec<TraceMismatch>,
+}
+impl TraceReplayer {
+    pub fn new(trace: SimulationTrace, names: VariableNames) -> Self {
+        let index_to_identifier = trace
+            .names
+            .iter()
+            .map(|name| names.identifier(name))
+            .collect();
+
+        Self {
+            trace,
+            names,
+            index_to_identifier,
+            values: FxHashMap::default(),
+            recorded_writes: FxHashMap::default(),
+            replayed_writes: FxHashMap::default(),
+            next_frame: 0,
+            mismatches: vec![],
+        }
+    }
+
+    /// Ticks the simulation with the next recorded frame.
+    /// Returns `false` when all frames have been replayed.
+    pub fn tick<T: Aircraft>(&mut self, simulation: &mut Simulation<T>) -> bool {
+        let frame_index = self.next_frame;
+        let frame = match self.trace.frames.get(frame_index) {
+            Some(frame) => frame,
+            None => return false,
+        };
+
+        for (index, value) in &frame.reads {
+            if let Some(identifier) = self.index_to_identifier[*index as usize] {
+                self.values.insert(identifier, *value);
+            }
+        }
+        for (index, value) in &frame.writes {
+            if let Some(identifier) = self.index_to_identifier[*index as usize] {
+                self.recorded_writes.insert(identifier, *value);
+            }
+        }
+
+        let (delta, simulation_time) = (frame.delta, frame.simulation_time);
+        self.replayed_writes.clear();
+        simulation.tick(delta, simulation_time, self);
+        self.compare_writes(frame_index);
+
+        self.next_frame += 1;
+
+        true
+    }
+
+    /// Replays all remaining frames.
+    pub fn run<T: Aircraft>(&mut self, simulation: &mut Simulation<T>) {
+        while self.tick(simulation) {}
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_frame >= self.trace.frames.len()
+    }
+
+    pub fn mismatches(&self) -> &[TraceMismatch] {
+        &self.mismatches
+    }
+
+    /// The current value of the variable with the given name, as read or written during replay.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.names
+            .identifier(name)
+            .and_then(|identifier| self.values.get(&identifier).copied())
+    }
+
+    fn compare_writes(&mut self, frame_index: usize) {
+        let mut mismatches: Vec<_> = self
+            .replayed_writes
+            .iter()
+            .filter_map(|(identifier, replayed)| {
+                let recorded = self.recorded_writes.get(identifier)?;
+                if recorded.to_bits() != replayed.to_bits() {
+                    Some(TraceMismatch {
+                        frame: frame_index,
+                        name: self.names.name(identifier)?.to_owned(),
+                        recorded: *recorded,
+                        replayed: *replayed,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        mismatches.sort_by(|a, b| a.name.cmp(&b.name));
+        self.mismatches.extend(mismatches);
+    }
+}
+impl SimulatorReaderWriter for TraceReplayer {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        *self.values.get(identifier).unwrap_or(&0.)
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        // Like simulator variables, written values are read back by subsequent reads.
+        self.values.insert(*identifier, value);
+        self.replayed_writes.insert(*identifier, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::TestVariableRegistry, InitContext, Read, SimulationElement, SimulatorReader,
+        SimulatorWriter, StartState, UpdateContext, Write,
+    };
+
+    struct Doubler {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        input: f64,
+        output: f64,
+    }
+    impl Doubler {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("OUTPUT".to_owned()),
+                input: 0.,
+                output: 0.,
+            }
+        }
+    }
+    impl Aircraft for Doubler {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.output = self.input * 2. + context.delta_as_secs_f64();
+        }
+    }
+    impl SimulationElement for Doubler {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.output);
+        }
+    }
+
+    /// Reads a variable and writes it back incremented, like a simulation variable which is
+    /// also modified by the simulation.
+    struct Incrementer {
+        counter_id: VariableIdentifier,
+        counter: f64,
+    }
+    impl Incrementer {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                counter_id: context.get_identifier("INPUT".to_owned()),
+                counter: 0.,
+            }
+        }
+    }
+    impl Aircraft for Incrementer {
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.counter += 1.;
+        }
+    }
+    impl SimulationElement for Incrementer {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.counter = reader.read(&self.counter_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.counter_id, self.counter);
+        }
+    }
+
+    #[derive(Default)]
+    struct TestSimulator {
+        input_id: Option<VariableIdentifier>,
+        input: f64,
+        values: FxHashMap<VariableIdentifier, f64>,
+    }
+    impl SimulatorReaderWriter for TestSimulator {
+        fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+            if Some(*identifier) == self.input_id {
+                self.input
+            } else {
+                *self.values.get(identifier).unwrap_or(&0.)
+            }
+        }
+
+        fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+            self.values.insert(*identifier, value);
+        }
+    }
+
+    fn record(inputs: &[f64]) -> SimulationTrace {
+        record_aircraft(Doubler::new, inputs)
+    }
+
+    /// Records the aircraft while the simulator provides the given inputs for the "INPUT"
+    /// variable, regardless of the values written to it.
+    fn record_aircraft<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+        aircraft_ctor_fn: U,
+        inputs: &[f64],
+    ) -> SimulationTrace {
+        let mut registry = TestVariableRegistry::default();
+        let mut recording_registry = RecordingVariableRegistry::new(&mut registry);
+        let mut simulation = Simulation::new(
+            StartState::Cruise,
+            aircraft_ctor_fn,
+            &mut recording_registry,
+        );
+        let names = recording_registry.into_names();
+
+        let mut simulator = TestSimulator {
+            input_id: names.identifier("INPUT"),
+            ..Default::default()
+        };
+        let mut recorder = TraceRecorder::new(names);
+        for (i, input) in inputs.iter().enumerate() {
+            simulator.input = *input;
+            recorder.tick(
+                &mut simulation,
+                Duration::from_millis(50),
+                i as f64 * 0.05,
+                &mut simulator,
+            );
+        }
+
+        recorder.into_trace()
+    }
+
+    fn replay(trace: SimulationTrace) -> TraceReplayer {
+        replay_aircraft(Doubler::new, trace)
+    }
+
+    fn replay_aircraft<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+        aircraft_ctor_fn: U,
+        trace: SimulationTrace,
+    ) -> TraceReplayer {
+        let mut registry = ReplayVariableRegistry::default();
+        let mut simulation = Simulation::new(StartState::Cruise, aircraft_ctor_fn, &mut registry);
+        let mut replayer = TraceReplayer::new(trace, registry.into_names());
+        replayer.run(&mut simulation);
+
+        replayer
+    }
+
+    #[test]
+    fn records_a_frame_per_tick() {
+        let trace = record(&[1., 2., 3.]);
+
+        assert_eq!(trace.frames().len(), 3);
+        assert!(trace.names().contains(&"INPUT".to_owned()));
+        assert!(trace.names().contains(&"OUTPUT".to_owned()));
+    }
+
+    #[test]
+    fn only_records_changed_values() {
+        let trace = record(&[1., 1., 1.]);
+        let input_index = trace.names().iter().position(|x| x == "INPUT").unwrap() as u32;
+
+        assert!(trace.frames()[0]
+            .reads
+            .iter()
+            .any(|(index, _)| *index == input_index));
+        assert!(trace.frames()[1].reads.is_empty());
+        assert!(trace.frames()[2].writes.is_empty());
+    }
+
+    #[test]
+    fn trace_survives_binary_round_trip() {
+        let trace = record(&[1., 2., 3.]);
+
+        let mut bytes = vec![];
+        trace.write_to(&mut bytes).unwrap();
+
+        assert_eq!(
+            SimulationTrace::read_from(&mut bytes.as_slice()).unwrap(),
+            trace
+        );
+    }
+
+    #[test]
+    fn reading_invalid_data_fails() {
+        assert!(SimulationTrace::read_from(&mut b"NOTATRACE".as_slice()).is_err());
+    }
+
+    #[test]
+    fn reading_a_trace_with_a_corrupt_name_length_fails_without_allocating_it() {
+        let mut bytes = vec![];
+        write_header(&mut bytes, SimulationTrace::MAGIC, SimulationTrace::VERSION).unwrap();
+        write_u32(&mut bytes, 1).unwrap();
+        write_u32(&mut bytes, u32::MAX).unwrap();
+
+        assert_eq!(
+            SimulationTrace::read_from(&mut bytes.as_slice())
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn reading_a_trace_with_a_corrupt_count_fails_without_allocating_it() {
+        let mut bytes = vec![];
+        write_header(&mut bytes, SimulationTrace::MAGIC, SimulationTrace::VERSION).unwrap();
+        write_u32(&mut bytes, u32::MAX).unwrap();
+
+        assert!(SimulationTrace::read_from(&mut bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn replay_reproduces_recorded_writes() {
+        let replayer = replay(record(&[1., 2., 3., 5., 8.]));
+
+        assert!(replayer.is_finished());
+        assert!(replayer.mismatches().is_empty());
+        assert_eq!(replayer.value("OUTPUT"), Some(16.05));
+    }
+
+    #[test]
+    fn records_reads_which_differ_from_the_value_written_before() {
+        let trace = record_aircraft(Incrementer::new, &[1., 1., 1.]);
+        let input_index = trace.names().iter().position(|x| x == "INPUT").unwrap() as u32;
+
+        assert!(trace
+            .frames()
+            .iter()
+            .all(|frame| frame.reads.contains(&(input_index, 1.))));
+    }
+
+    #[test]
+    fn replay_reproduces_writes_to_variables_which_are_also_read() {
+        let replayer = replay_aircraft(
+            Incrementer::new,
+            record_aircraft(Incrementer::new, &[1., 1., 5.]),
+        );
+
+        assert!(replayer.mismatches().is_empty());
+        assert_eq!(replayer.value("INPUT"), Some(6.));
+    }
+
+    #[test]
+    fn replay_reports_diverging_writes() {
+        let mut trace = record(&[1., 2., 3.]);
+        let output_index = trace.names().iter().position(|x| x == "OUTPUT").unwrap() as u32;
+        trace.frames[1].writes = vec![(output_index, 42.)];
+
+        let replayer = replay(trace);
+
+        assert_eq!(
+            replayer.mismatches(),
+            &[TraceMismatch {
+                frame: 1,
+                name: "OUTPUT".to_owned(),
+                recorded: 42.,
+                replayed: 4.05,
+            }]
+        );
+    }
+}
@@ -12,7 +12,10 @@ use uom::si::{
     velocity::{foot_per_minute, foot_per_second, meter_per_second},
 };
 
-use super::{Read, SimulatorReader};
+use super::{
+    snapshot::{StateReader, StateWriter},
+    Read, SimulatorReader,
+};
 use crate::{
     shared::{low_pass_filter::LowPassFilter, MachNumber},
     simulation::{InitContext, VariableIdentifier},
@@ -663,6 +666,18 @@ impl UpdateContext {
         self.update_local_acceleration_plane_reference(delta);
     }
 
+    pub(super) fn save_state(&self, state: &mut StateWriter) {
+        let acceleration = self.local_acceleration_plane_reference_filtered.output();
+        state.write(acceleration[0]);
+        state.write(acceleration[1]);
+        state.write(acceleration[2]);
+    }
+
+    pub(super) fn restore_state(&mut self, state: &mut StateReader) {
+        self.local_acceleration_plane_reference_filtered
+            .reset(Vector3::new(state.read(), state.read(), state.read()));
+    }
+
     // Computes local acceleration including world gravity and plane acceleration
     // Note that this does not compute acceleration due to angular velocity of the plane
     fn update_local_acceleration_plane_reference(&mut self, delta: Duration) {
@@ -1,6 +1,7 @@
 use crate::shared::interpolation;
 use crate::shared::low_pass_filter::LowPassFilter;
 use crate::simulation::{
+    snapshot::{StateReader, StateWriter},
     InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
 };
 use uom::si::{
@@ -267,6 +268,26 @@ impl SimulationElement for WindTurbine {
             1. - self.propeller_beta_pitch.output().get::<ratio>(),
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.tip_speed);
+        state.write(self.tip_speed_ratio);
+        state.write(self.position);
+        state.write(self.speed);
+        state.write(self.acceleration);
+        state.write(self.torque_sum);
+        self.propeller_beta_pitch.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.tip_speed = state.read();
+        self.tip_speed_ratio = state.read();
+        self.position = state.read();
+        self.speed = state.read();
+        self.acceleration = state.read();
+        self.torque_sum = state.read();
+        self.propeller_beta_pitch.restore_state(state);
+    }
 }
 
 #[cfg(test)]
@@ -4,20 +4,75 @@ use crate::msfs::legacy::NamedVariable;
 use msfs::legacy::NamedVariable;
 
 use fxhash::FxHashMap;
+use num_traits::FromPrimitive;
+use std::{collections::VecDeque, time::Duration};
+use uom::si::{
+    f64::{Length, Velocity},
+    length::foot,
+    velocity::knot,
+};
 
-use systems::failures::FailureType;
+use systems::failures::{
+    trigger::{FailureActivation, FailureTrigger},
+    FailureType,
+};
 
+/// Reads failures which should be activated, deactivated, armed or disarmed from named variables.
+///
+/// A failure is armed by writing its trigger to `FAILURE_ARM_TRIGGER` and `FAILURE_ARM_TRIGGER_VALUE`,
+/// optionally followed by the activation variables, and then writing its identifier to `FAILURE_ARM`.
+/// The trigger is one of:
+///
+/// 1. after `value` seconds;
+/// 2. altitude above `value` feet;
+/// 3. altitude below `value` feet;
+/// 4. indicated airspeed above `value` knots;
+/// 5. indicated airspeed below `value` knots;
+/// 6. entering FWC flight phase `value`;
+/// 7. randomly, with a mean time between failures of `value` seconds.
+///
+/// The failure is latent when `FAILURE_ARM_LATENCY` holds a number of seconds, and intermittent when
+/// both `FAILURE_ARM_INTERMITTENT_ACTIVE` and `FAILURE_ARM_INTERMITTENT_INACTIVE` hold a number of seconds.
+///
+/// Armed failures which are activated or deactivated by their trigger are written to
+/// `FAILURE_TRIGGERED_ACTIVATE` and `FAILURE_TRIGGERED_DEACTIVATE`. Like the other variables, these are
+/// queued: the next identifier is only written once the reader has consumed the previous one by setting
+/// the variable to 0.
 pub(super) struct Failures {
     activate_sim_var: NamedVariable,
     deactivate_sim_var: NamedVariable,
+    arm_sim_var: NamedVariable,
+    arm_trigger_sim_var: NamedVariable,
+    arm_trigger_value_sim_var: NamedVariable,
+    arm_latency_sim_var: NamedVariable,
+    arm_intermittent_active_sim_var: NamedVariable,
+    arm_intermittent_inactive_sim_var: NamedVariable,
+    disarm_sim_var: NamedVariable,
+    triggered_activate_sim_var: NamedVariable,
+    triggered_deactivate_sim_var: NamedVariable,
     identifier_to_failure_type: FxHashMap<u64, FailureType>,
+    triggered_activate_queue: VecDeque<u64>,
+    triggered_deactivate_queue: VecDeque<u64>,
 }
 impl Failures {
-    pub(super) fn new(activate_sim_var: NamedVariable, deactivate_sim_var: NamedVariable) -> Self {
+    pub(super) fn new(key_prefix: &str) -> Self {
+        let variable = |name: &str| NamedVariable::from(&format!("{}{}", key_prefix, name));
+
         Self {
-            activate_sim_var,
-            deactivate_sim_var,
+            activate_sim_var: variable("FAILURE_ACTIVATE"),
+            deactivate_sim_var: variable("FAILURE_DEACTIVATE"),
+            arm_sim_var: variable("FAILURE_ARM"),
+            arm_trigger_sim_var: variable("FAILURE_ARM_TRIGGER"),
+            arm_trigger_value_sim_var: variable("FAILURE_ARM_TRIGGER_VALUE"),
+            arm_latency_sim_var: variable("FAILURE_ARM_LATENCY"),
+            arm_intermittent_active_sim_var: variable("FAILURE_ARM_INTERMITTENT_ACTIVE"),
+            arm_intermittent_inactive_sim_var: variable("FAILURE_ARM_INTERMITTENT_INACTIVE"),
+            disarm_sim_var: variable("FAILURE_DISARM"),
+            triggered_activate_sim_var: variable("FAILURE_TRIGGERED_ACTIVATE"),
+            triggered_deactivate_sim_var: variable("FAILURE_TRIGGERED_DEACTIVATE"),
             identifier_to_failure_type: FxHashMap::default(),
+            triggered_activate_queue: VecDeque::new(),
+            triggered_deactivate_queue: VecDeque::new(),
         }
     }
 
@@ -34,6 +89,64 @@ impl Failures {
         self.read_failure(&self.deactivate_sim_var)
     }
 
+    pub(super) fn read_failure_arm(
+        &self,
+    ) -> Option<(FailureType, FailureTrigger, FailureActivation)> {
+        let failure_type = self.read_failure(&self.arm_sim_var)?;
+        let trigger = self.read_trigger();
+        if trigger.is_none() {
+            eprintln!(
+                "Ignoring the arming of a failure with invalid trigger {} and value {}.",
+                self.arm_trigger_sim_var.get_value(),
+                self.arm_trigger_value_sim_var.get_value()
+            );
+        }
+
+        Some((failure_type, trigger?, self.read_activation()))
+    }
+
+    pub(super) fn read_failure_disarm(&self) -> Option<FailureType> {
+        self.read_failure(&self.disarm_sim_var)
+    }
+
+    /// Queues the failures activated or deactivated by their trigger and writes the next
+    /// queued failures whose previous notification has been consumed.
+    pub(super) fn write_triggered_failures(&mut self, triggered_failures: &[(FailureType, bool)]) {
+        for &(failure_type, is_active) in triggered_failures {
+            if let Some(identifier) = self.identifier(failure_type) {
+                if is_active {
+                    self.triggered_activate_queue.push_back(identifier);
+                } else {
+                    self.triggered_deactivate_queue.push_back(identifier);
+                }
+            }
+        }
+
+        Self::write_next(
+            &self.triggered_activate_sim_var,
+            &mut self.triggered_activate_queue,
+        );
+        Self::write_next(
+            &self.triggered_deactivate_sim_var,
+            &mut self.triggered_deactivate_queue,
+        );
+    }
+
+    fn write_next(to: &NamedVariable, queue: &mut VecDeque<u64>) {
+        if to.get_value() == 0. {
+            if let Some(identifier) = queue.pop_front() {
+                to.set_value(identifier as f64);
+            }
+        }
+    }
+
+    fn identifier(&self, failure_type: FailureType) -> Option<u64> {
+        self.identifier_to_failure_type
+            .iter()
+            .find(|(_, &candidate)| candidate == failure_type)
+            .map(|(&identifier, _)| identifier)
+    }
+
     fn read_failure(&self, from: &NamedVariable) -> Option<FailureType> {
         let identifier: f64 = from.get_value();
         if let Some(failure_type) = self.identifier_to_failure_type.get(&(identifier as u64)) {
@@ -43,4 +156,39 @@ impl Failures {
             None
         }
     }
+
+    fn read_trigger(&self) -> Option<FailureTrigger> {
+        let value = self.arm_trigger_value_sim_var.get_value();
+        match self.arm_trigger_sim_var.get_value() as u8 {
+            1 => Some(FailureTrigger::After(seconds(value))),
+            2 => Some(FailureTrigger::AltitudeAbove(Length::new::<foot>(value))),
+            3 => Some(FailureTrigger::AltitudeBelow(Length::new::<foot>(value))),
+            4 => Some(FailureTrigger::AirspeedAbove(Velocity::new::<knot>(value))),
+            5 => Some(FailureTrigger::AirspeedBelow(Velocity::new::<knot>(value))),
+            6 => FromPrimitive::from_f64(value).map(FailureTrigger::FlightPhase),
+            7 => Some(FailureTrigger::Random(seconds(value))),
+            _ => None,
+        }
+    }
+
+    fn read_activation(&self) -> FailureActivation {
+        let latency = self.arm_latency_sim_var.get_value();
+        let active = self.arm_intermittent_active_sim_var.get_value();
+        let inactive = self.arm_intermittent_inactive_sim_var.get_value();
+
+        if active > 0. && inactive > 0. {
+            FailureActivation::Intermittent {
+                active: seconds(active),
+                inactive: seconds(inactive),
+            }
+        } else if latency > 0. {
+            FailureActivation::Latent(seconds(latency))
+        } else {
+            FailureActivation::Permanent
+        }
+    }
+}
+
+fn seconds(value: f64) -> Duration {
+    Duration::from_secs_f64(value.max(0.))
 }
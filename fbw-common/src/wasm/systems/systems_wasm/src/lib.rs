@@ -4,6 +4,7 @@ mod anti_ice;
 mod electrical;
 mod failures;
 mod msfs;
+mod snapshots;
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::msfs::legacy::{AircraftVariable, NamedVariable};
@@ -19,6 +20,7 @@ use ::msfs::{
 };
 use failures::Failures;
 use fxhash::FxHashMap;
+use snapshots::Snapshots;
 use std::fmt::{Display, Formatter};
 use std::{error::Error, time::Duration};
 use systems::shared::ElectricalBusType;
@@ -38,6 +40,7 @@ pub struct MsfsSimulationBuilder<'a, 'b> {
     start_state: StartState,
     sim_connect: &'a mut SimConnect<'b>,
     failures: Option<Failures>,
+    snapshots: Option<Snapshots>,
     aspects: Vec<Box<dyn Aspect>>,
 }
 
@@ -55,6 +58,7 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
             key_prefix: key_prefix.into(),
             sim_connect,
             failures: None,
+            snapshots: None,
             aspects: vec![],
         }
     }
@@ -68,7 +72,13 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
 
         Ok((
             simulation,
-            MsfsHandler::new(registry, self.aspects, self.failures, self.sim_connect)?,
+            MsfsHandler::new(
+                registry,
+                self.aspects,
+                self.failures,
+                self.snapshots,
+                self.sim_connect,
+            )?,
         ))
     }
 
@@ -120,10 +130,7 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
     }
 
     pub fn with_failures(mut self, failures: Vec<(u64, FailureType)>) -> Self {
-        let mut f = Failures::new(
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_ACTIVATE")),
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_DEACTIVATE")),
-        );
+        let mut f = Failures::new(&self.key_prefix);
         for failure in failures {
             f.add(failure.0, failure.1);
         }
@@ -133,6 +140,14 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         self
     }
 
+    /// Keeps the internal state of the simulation across sessions by saving it to the file
+    /// at the given path.
+    pub fn with_snapshots(mut self, path: &str) -> Self {
+        self.snapshots = Some(Snapshots::new(path, self.start_state));
+
+        self
+    }
+
     pub fn provides_aircraft_variable(
         mut self,
         name: &str,
@@ -164,6 +179,7 @@ pub struct MsfsHandler {
     variables: Option<MsfsVariableRegistry>,
     aspects: Vec<Box<dyn Aspect>>,
     failures: Option<Failures>,
+    snapshots: Option<Snapshots>,
     time: Time,
 }
 impl MsfsHandler {
@@ -171,12 +187,14 @@ impl MsfsHandler {
         variables: MsfsVariableRegistry,
         aspects: Vec<Box<dyn Aspect>>,
         failures: Option<Failures>,
+        snapshots: Option<Snapshots>,
         sim_connect: &mut SimConnect,
     ) -> Result<Self, Box<dyn Error>> {
         Ok(Self {
             variables: Some(variables),
             aspects,
             failures,
+            snapshots,
             time: Time::new(sim_connect)?,
         })
     }
@@ -192,14 +210,28 @@ impl MsfsHandler {
                 if !self.time.is_pausing() {
                     let delta_time = self.time.take();
                     self.pre_tick(sim_connect, delta_time)?;
+                    if let Some(snapshots) = &mut self.snapshots {
+                        snapshots.pre_tick(simulation);
+                    }
                     if let Some(failures) = &self.failures {
                         Self::read_failures_into_simulation(failures, simulation);
                     }
 
                     simulation.tick(delta_time, self.time.simulation_time(), self);
+                    if let Some(failures) = &mut self.failures {
+                        failures.write_triggered_failures(simulation.triggered_failures());
+                    }
+                    if let Some(snapshots) = &mut self.snapshots {
+                        snapshots.post_tick(simulation, delta_time);
+                    }
                     self.post_tick(sim_connect)?;
                 }
             }
+            MSFSEvent::PreKill => {
+                if let Some(snapshots) = &mut self.snapshots {
+                    snapshots.save(simulation);
+                }
+            }
             MSFSEvent::SimConnect(message) => match message {
                 SimConnectRecv::SimObjectData(data) if data.id() == SimulationTime::REQUEST_ID => {
                     self.time
@@ -269,6 +301,14 @@ impl MsfsHandler {
         if let Some(failure_type) = failures.read_failure_deactivate() {
             simulation.deactivate_failure(failure_type);
         }
+
+        if let Some((failure_type, trigger, activation)) = failures.read_failure_arm() {
+            simulation.arm_failure(failure_type, trigger, activation);
+        }
+
+        if let Some(failure_type) = failures.read_failure_disarm() {
+            simulation.disarm_failure(failure_type);
+        }
     }
 }
 impl SimulatorReaderWriter for MsfsHandler {
@@ -0,0 +1,75 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    time::Duration,
+};
+use systems::simulation::{snapshot::SimulationSnapshot, Aircraft, Simulation, StartState};
+
+/// Keeps the internal state of the simulation across sessions, by regularly saving a
+/// snapshot to a file and when the aircraft is unloaded.
+///
+/// The snapshot is only restored when the flight starts at the gate or in the hangar, as the
+/// state of an aircraft which starts on the runway or in the air is set up by the start state.
+pub(super) struct Snapshots {
+    path: String,
+    is_restore_pending: bool,
+    time_since_save: Duration,
+}
+impl Snapshots {
+    const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub(super) fn new(path: &str, start_state: StartState) -> Self {
+        Self {
+            path: path.to_owned(),
+            is_restore_pending: matches!(start_state, StartState::Hangar | StartState::Apron),
+            time_since_save: Duration::ZERO,
+        }
+    }
+
+    /// Restores the saved snapshot, if any, before the first tick of the simulation.
+    pub(super) fn pre_tick<T: Aircraft>(&mut self, simulation: &mut Simulation<T>) {
+        if !self.is_restore_pending {
+            return;
+        }
+        self.is_restore_pending = false;
+
+        // No snapshot is saved before the aircraft is loaded for the first time.
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(_) => return,
+        };
+
+        match SimulationSnapshot::read_from(&mut BufReader::new(file)) {
+            Ok(snapshot) => {
+                if let Err(error) = simulation.restore_snapshot(&snapshot) {
+                    eprintln!("Discarding snapshot \"{}\": {:?}.", self.path, error);
+                }
+            }
+            Err(error) => eprintln!("Failed to read snapshot \"{}\": {}.", self.path, error),
+        }
+    }
+
+    pub(super) fn post_tick<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        delta: Duration,
+    ) {
+        self.time_since_save += delta;
+        if self.time_since_save >= Self::SAVE_INTERVAL {
+            self.save(simulation);
+        }
+    }
+
+    pub(super) fn save<T: Aircraft>(&mut self, simulation: &mut Simulation<T>) {
+        self.time_since_save = Duration::ZERO;
+
+        let result = File::create(&self.path).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            simulation.save_snapshot().write_to(&mut writer)?;
+            writer.flush()
+        });
+        if let Err(error) = result {
+            eprintln!("Failed to save snapshot \"{}\": {}.", self.path, error);
+        }
+    }
+}